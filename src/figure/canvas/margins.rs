@@ -0,0 +1,56 @@
+/// The space between the edges of a canvas and its plot area, per side, in pixels.
+///
+/// Titles, axis labels and legends are drawn in the margins, so a side that holds more of
+/// them can be given more room than the others.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Margins {
+    /// Space above the plot area, e.g. for the title.
+    pub top: u32,
+    /// Space to the right of the plot area.
+    pub right: u32,
+    /// Space below the plot area, e.g. for the X-axis values and the legend.
+    pub bottom: u32,
+    /// Space to the left of the plot area, e.g. for the Y-axis values.
+    pub left: u32,
+}
+
+impl Margins {
+    /// Creates margins with a different size on every side.
+    ///
+    /// # Parameters
+    /// - `top`, `right`, `bottom`, `left`: The size of each margin in pixels, in CSS order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::margins::Margins;
+    ///
+    /// let margins = Margins::new(30, 20, 60, 50);
+    /// assert_eq!((margins.horizontal(), margins.vertical()), (70, 90));
+    /// ```
+    pub fn new(top: u32, right: u32, bottom: u32, left: u32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Creates margins of the same size on all four sides.
+    ///
+    /// # Parameters
+    /// - `margin`: The size of every margin in pixels.
+    pub fn uniform(margin: u32) -> Self {
+        Self::new(margin, margin, margin, margin)
+    }
+
+    /// Returns the combined size of the left and right margins.
+    pub fn horizontal(&self) -> u32 {
+        self.left + self.right
+    }
+
+    /// Returns the combined size of the top and bottom margins.
+    pub fn vertical(&self) -> u32 {
+        self.top + self.bottom
+    }
+}
@@ -1,8 +1,9 @@
 use ab_glyph::{FontRef, PxScale};
-use image::Rgb;
+use image::{GrayImage, Luma, Rgb};
 use imageproc::drawing::{draw_text_mut, text_size};
 
-use crate::figure::utilities::linetype::LineType;
+use crate::figure::canvas::margins::Margins;
+use crate::figure::utilities::{linetype::LineType, textrendering::TextRendering};
 
 /// A structure representing a pixel-based drawing canvas.
 pub struct PixelCanvas {
@@ -14,8 +15,12 @@ pub struct PixelCanvas {
     pub background_color: [u8; 3],
     /// Buffer storing pixel data as a linear array.
     pub buffer: Vec<u8>,
-    /// Margin around the canvas (in pixels).
-    pub margin: u32,
+    /// Margins around the plot area (in pixels).
+    pub margins: Margins,
+    /// Whether text is drawn with smoothed (partially covered) edge pixels.
+    pub antialias: bool,
+    /// Whether text is rasterized or replaced by placeholder boxes.
+    pub text_rendering: TextRendering,
 }
 
 impl PixelCanvas {
@@ -25,39 +30,342 @@ impl PixelCanvas {
     /// - `width`: The width of the canvas in pixels.
     /// - `height`: The height of the canvas in pixels.
     /// - `background_color`: The RGB color of the canvas background.
-    /// - `margin`: Margin size in pixels.
+    /// - `margin`: Margin size in pixels, used on all four sides.
     ///
     /// # Returns
     /// A new `PixelCanvas` instance.
     pub fn new(width: u32, height: u32, background_color: [u8; 3], margin: u32) -> Self {
+        Self::with_margins(width, height, background_color, Margins::uniform(margin))
+    }
+
+    /// Creates a new `PixelCanvas` with a different margin on every side.
+    ///
+    /// # Parameters
+    /// - `width`: The width of the canvas in pixels.
+    /// - `height`: The height of the canvas in pixels.
+    /// - `background_color`: The RGB color of the canvas background.
+    /// - `margins`: The margins around the plot area in pixels.
+    ///
+    /// # Returns
+    /// A new `PixelCanvas` instance.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::{margins::Margins, pixelcanvas::PixelCanvas};
+    ///
+    /// // Extra room on the left for long Y-axis values
+    /// let canvas = PixelCanvas::with_margins(400, 300, [255, 255, 255], Margins::new(40, 20, 40, 80));
+    /// assert_eq!(canvas.plot_width(), 300);
+    /// ```
+    pub fn with_margins(
+        width: u32,
+        height: u32,
+        background_color: [u8; 3],
+        margins: Margins,
+    ) -> Self {
         let buffer = vec![0; (width * height * 3) as usize];
         Self {
             width,
             height,
             background_color,
             buffer,
-            margin,
+            margins,
+            antialias: true,
+            text_rendering: TextRendering::Normal,
+        }
+    }
+
+    /// Creates a new `PixelCanvas` drawing into an existing buffer instead of allocating one.
+    ///
+    /// Together with [`PixelCanvas::into_buffer`] this lets callers recycle one buffer across
+    /// renders, e.g. in tight loops. The buffer contents are kept until the canvas is cleared.
+    ///
+    /// # Parameters
+    /// - `width`: The width of the canvas in pixels.
+    /// - `height`: The height of the canvas in pixels.
+    /// - `buffer`: The RGB pixel buffer, `width * height * 3` bytes long.
+    /// - `background_color`: The RGB color of the canvas background.
+    /// - `margin`: Margin size in pixels, used on all four sides.
+    ///
+    /// # Returns
+    /// A new `PixelCanvas` instance, or an error if the buffer length doesn't match the size.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    ///
+    /// let mut buffer = vec![0; 400 * 300 * 3];
+    /// for _ in 0..3 {
+    ///     let mut canvas = PixelCanvas::from_buffer(400, 300, buffer, [255, 255, 255], 40).unwrap();
+    ///     canvas.clear();
+    ///     buffer = canvas.into_buffer();
+    /// }
+    /// ```
+    pub fn from_buffer(
+        width: u32,
+        height: u32,
+        buffer: Vec<u8>,
+        background_color: [u8; 3],
+        margin: u32,
+    ) -> Result<Self, String> {
+        let expected = width as usize * height as usize * 3;
+        if buffer.len() != expected {
+            return Err(format!(
+                "Buffer length {} doesn't match a {}x{} canvas ({} bytes).",
+                buffer.len(),
+                width,
+                height,
+                expected
+            ));
         }
+        Ok(Self {
+            width,
+            height,
+            background_color,
+            buffer,
+            margins: Margins::uniform(margin),
+            antialias: true,
+            text_rendering: TextRendering::Normal,
+        })
+    }
+
+    /// Returns the edges of the plot area inside the margins as `(left, top, right, bottom)`.
+    pub fn plot_bounds(&self) -> (u32, u32, u32, u32) {
+        (
+            self.margins.left,
+            self.margins.top,
+            self.width.saturating_sub(self.margins.right),
+            self.height.saturating_sub(self.margins.bottom),
+        )
+    }
+
+    /// Returns the width of the plot area between the left and right margins.
+    pub fn plot_width(&self) -> u32 {
+        self.width.saturating_sub(self.margins.horizontal())
+    }
+
+    /// Returns the height of the plot area between the top and bottom margins.
+    pub fn plot_height(&self) -> u32 {
+        self.height.saturating_sub(self.margins.vertical())
+    }
+
+    /// Consumes the canvas and returns its pixel buffer, e.g. to pass it to
+    /// [`PixelCanvas::from_buffer`] for the next render.
+    pub fn into_buffer(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    /// Computes canvas dimensions with the given aspect ratio whose larger side is `max_dimension`.
+    ///
+    /// Use it to size a canvas before constructing it, e.g. to render a chart at most 800 pixels
+    /// wide in 16:9.
+    ///
+    /// # Parameters
+    /// - `max_dimension`: The length in pixels of the larger side.
+    /// - `aspect_ratio`: The ratio of width to height, e.g. `16.0 / 9.0`.
+    ///
+    /// # Returns
+    /// The `(width, height)` of the canvas, each at least 1 pixel.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    ///
+    /// let (width, height) = PixelCanvas::size_for_aspect_ratio(800, 16.0 / 9.0);
+    /// let canvas = PixelCanvas::new(width, height, [255, 255, 255], 40);
+    /// ```
+    pub fn size_for_aspect_ratio(max_dimension: u32, aspect_ratio: f64) -> (u32, u32) {
+        let (width, height) = if aspect_ratio >= 1.0 {
+            (max_dimension as f64, max_dimension as f64 / aspect_ratio)
+        } else {
+            (max_dimension as f64 * aspect_ratio, max_dimension as f64)
+        };
+        (
+            (width.round() as u32).max(1),
+            (height.round() as u32).max(1),
+        )
     }
 
     /// Clears the canvas by filling it with the background color.
     pub fn clear(&mut self) {
-        self.buffer.fill(self.background_color[0]);
+        for pixel in self.buffer.chunks_exact_mut(3) {
+            pixel.copy_from_slice(&self.background_color);
+        }
+    }
+
+    /// Fills a rectangular region with a color, e.g. to wipe the plot area before redrawing
+    /// the data on top of an unchanged title, axes and legend.
+    ///
+    /// Parts of the region outside the canvas are ignored.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The top-left corner of the region.
+    /// - `width`, `height`: The size of the region in pixels.
+    /// - `color`: The RGB color to fill the region with.
+    pub fn clear_region(&mut self, x: u32, y: u32, width: u32, height: u32, color: [u8; 3]) {
+        self.draw_rect_filled(x, y, width, height, color);
+    }
+
+    /// Draws a filled rectangle.
+    ///
+    /// Parts of the rectangle outside the canvas are ignored.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The top-left corner of the rectangle.
+    /// - `w`, `h`: The size of the rectangle in pixels.
+    /// - `color`: The RGB color of the rectangle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    ///
+    /// let mut canvas = PixelCanvas::new(100, 100, [255, 255, 255], 10);
+    /// canvas.draw_rect_filled(20, 20, 30, 10, [255, 0, 0]);
+    /// canvas.draw_rect_outline(20, 20, 30, 10, [0, 0, 0]);
+    /// ```
+    pub fn draw_rect_filled(&mut self, x: u32, y: u32, w: u32, h: u32, color: [u8; 3]) {
+        let x_end = x.saturating_add(w).min(self.width);
+        let y_end = y.saturating_add(h).min(self.height);
+
+        for row in y..y_end {
+            for col in x..x_end {
+                self.draw_pixel(col, row, color);
+            }
+        }
+    }
+
+    /// Draws the one pixel wide outline of a rectangle.
+    ///
+    /// The outline lies on the outermost pixels of the rectangle, i.e. it covers the same
+    /// area as [`PixelCanvas::draw_rect_filled`] with the same arguments. Parts of the
+    /// outline outside the canvas are ignored.
+    ///
+    /// # Parameters
+    /// - `x`, `y`: The top-left corner of the rectangle.
+    /// - `w`, `h`: The size of the rectangle in pixels.
+    /// - `color`: The RGB color of the outline.
+    pub fn draw_rect_outline(&mut self, x: u32, y: u32, w: u32, h: u32, color: [u8; 3]) {
+        if w == 0 || h == 0 {
+            return;
+        }
+        let right = x.saturating_add(w - 1);
+        let bottom = y.saturating_add(h - 1);
+
+        self.draw_rect_filled(x, y, w, 1, color);
+        self.draw_rect_filled(x, bottom, w, 1, color);
+        self.draw_rect_filled(x, y, 1, h, color);
+        self.draw_rect_filled(right, y, 1, h, color);
+    }
+
+    /// Draws a filled circle, one horizontal span per row.
+    ///
+    /// A pixel is filled if its distance to the center is at most `radius`. Parts of the
+    /// circle outside the canvas are ignored.
+    ///
+    /// # Parameters
+    /// - `cx`, `cy`: The center of the circle.
+    /// - `radius`: The radius of the circle in pixels.
+    /// - `color`: The RGB color of the circle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    ///
+    /// let mut canvas = PixelCanvas::new(100, 100, [255, 255, 255], 10);
+    /// canvas.draw_circle_filled(50, 50, 10, [255, 0, 0]);
+    /// ```
+    pub fn draw_circle_filled(&mut self, cx: i32, cy: i32, radius: u32, color: [u8; 3]) {
+        let radius = radius as i64;
+        let (cx, cy) = (cx as i64, cy as i64);
+        let y_start = (cy - radius).max(0);
+        let y_end = (cy + radius).min(self.height as i64 - 1);
+
+        for y in y_start..=y_end {
+            let dy = y - cy;
+            let half_span = ((radius * radius - dy * dy) as f64).sqrt() as i64;
+            let x_start = (cx - half_span).max(0);
+            let x_end = (cx + half_span).min(self.width as i64 - 1);
+            for x in x_start..=x_end {
+                self.draw_pixel(x as u32, y as u32, color);
+            }
+        }
+    }
+
+    /// Draws the outline of a circle with the midpoint circle algorithm.
+    ///
+    /// Parts of the circle outside the canvas are ignored.
+    ///
+    /// # Parameters
+    /// - `cx`, `cy`: The center of the circle.
+    /// - `radius`: The radius of the circle in pixels.
+    /// - `color`: The RGB color of the outline.
+    pub fn draw_circle_outline(&mut self, cx: i32, cy: i32, radius: u32, color: [u8; 3]) {
+        let (cx, cy) = (cx as i64, cy as i64);
+        let mut x = radius as i64;
+        let mut y = 0i64;
+        let mut error = 1 - x;
+
+        while x >= y {
+            for (dx, dy) in [
+                (x, y),
+                (y, x),
+                (-y, x),
+                (-x, y),
+                (-x, -y),
+                (-y, -x),
+                (y, -x),
+                (x, -y),
+            ] {
+                let (px, py) = (cx + dx, cy + dy);
+                if px >= 0 && py >= 0 && px <= u32::MAX as i64 && py <= u32::MAX as i64 {
+                    self.draw_pixel(px as u32, py as u32, color);
+                }
+            }
+
+            y += 1;
+            if error < 0 {
+                error += 2 * y + 1;
+            } else {
+                x -= 1;
+                error += 2 * (y - x) + 1;
+            }
+        }
     }
 
     /// Draws a single pixel at the specified coordinates with the given color.
     ///
+    /// Pixels outside the canvas are ignored, including coordinates that wrapped around from
+    /// negative values.
+    ///
     /// # Parameters
     /// - `x`: The x-coordinate of the pixel.
     /// - `y`: The y-coordinate of the pixel.
     /// - `color`: The RGB color of the pixel.
     pub fn draw_pixel(&mut self, x: u32, y: u32, color: [u8; 3]) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
         let index = ((y * self.width + x) * 3) as usize;
-        if index + 2 < self.buffer.len() {
-            self.buffer[index] = color[0];
-            self.buffer[index + 1] = color[1];
-            self.buffer[index + 2] = color[2];
+        self.buffer[index] = color[0];
+        self.buffer[index + 1] = color[1];
+        self.buffer[index + 2] = color[2];
+    }
+
+    /// Draws a single pixel like [`PixelCanvas::draw_pixel`], reporting whether it was drawn.
+    ///
+    /// # Parameters
+    /// - `x`: The x-coordinate of the pixel.
+    /// - `y`: The y-coordinate of the pixel.
+    /// - `color`: The RGB color of the pixel.
+    ///
+    /// # Returns
+    /// `true` if the pixel lies within the canvas and was drawn, `false` if it was clipped.
+    pub fn try_draw_pixel(&mut self, x: u32, y: u32, color: [u8; 3]) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
         }
+        self.draw_pixel(x, y, color);
+        true
     }
 
     /// Blends a pixel with the specified color and alpha value.
@@ -94,7 +402,7 @@ impl PixelCanvas {
     /// - `y`: The y-coordinate of the line.
     /// - `color`: The RGB color of the line.
     pub fn draw_horizontal_line(&mut self, y: u32, color: [u8; 3]) {
-        for x in self.margin..self.width - self.margin {
+        for x in self.margins.left..self.width - self.margins.right {
             self.draw_pixel(x, y, color);
         }
     }
@@ -105,22 +413,83 @@ impl PixelCanvas {
     /// - `x`: The x-coordinate of the line.
     /// - `color`: The RGB color of the line.
     pub fn draw_vertical_line(&mut self, x: u32, color: [u8; 3]) {
-        for y in self.margin..self.height - self.margin {
+        for y in self.margins.top..self.height - self.margins.bottom {
             self.draw_pixel(x, y, color);
         }
     }
 
-    /// Draws a grid on the canvas.
+    /// Draws a grid of evenly spaced lines over the plot area.
+    ///
+    /// The plot area is split into the given number of divisions in each direction, with grid
+    /// lines on both edges, so `n` divisions give `n + 1` lines.
+    ///
+    /// # Parameters
+    /// - `divisions`: The number of divisions in the x and y directions.
+    /// - `color`: The RGB color of the grid lines.
+    /// - `line_type`: The style of the grid lines (`LineType`).
+    pub fn draw_grid(&mut self, divisions: &[usize; 2], color: [u8; 3], line_type: LineType) {
+        let plot_width = self.plot_width() as usize;
+        let plot_height = self.plot_height() as usize;
+        let [columns, rows] = divisions.map(|count| count.max(1));
+
+        for i in 0..=columns {
+            let x = self.margins.left + (i * plot_width / columns) as u32;
+            self.draw_vertical_grid_line(x, color, &line_type);
+        }
+        for i in 0..=rows {
+            let y = self.margins.top + (i * plot_height / rows) as u32;
+            self.draw_horizontal_grid_line(y, color, &line_type);
+        }
+    }
+
+    /// Draws grid lines at the given positions, e.g. at the tick positions of the axes.
+    ///
+    /// Positions outside the plot area (inside the margin) are skipped.
     ///
     /// # Parameters
-    /// - `grid_size`: An array specifying the spacing of grid lines in the x and y directions.
+    /// - `x_positions`: The x-coordinates of the vertical grid lines.
+    /// - `y_positions`: The y-coordinates of the horizontal grid lines.
     /// - `color`: The RGB color of the grid lines.
-    pub fn draw_grid(&mut self, grid_size: &[usize; 2], color: [u8; 3]) {
-        for x in (self.margin..=self.width - self.margin).step_by(grid_size[0]) {
+    /// - `line_type`: The style of the grid lines (`LineType`).
+    pub fn draw_grid_lines(
+        &mut self,
+        x_positions: &[u32],
+        y_positions: &[u32],
+        color: [u8; 3],
+        line_type: LineType,
+    ) {
+        let x_range = self.margins.left..=self.width.saturating_sub(self.margins.right);
+        let y_range = self.margins.top..=self.height.saturating_sub(self.margins.bottom);
+        if x_range.is_empty() || y_range.is_empty() {
+            return;
+        }
+        for &x in x_positions.iter().filter(|x| x_range.contains(x)) {
+            self.draw_vertical_grid_line(x, color, &line_type);
+        }
+        for &y in y_positions.iter().filter(|y| y_range.contains(y)) {
+            self.draw_horizontal_grid_line(y, color, &line_type);
+        }
+    }
+
+    /// Draws a vertical grid line across the plot area in the given style.
+    fn draw_vertical_grid_line(&mut self, x: u32, color: [u8; 3], line_type: &LineType) {
+        if *line_type == LineType::Solid {
             self.draw_vertical_line(x, color);
+        } else if self.plot_height() > 0 {
+            let top = self.margins.top as i32;
+            let bottom = (self.height - self.margins.bottom) as i32 - 1;
+            self.draw_line(x as i32, top, x as i32, bottom, color, line_type.clone());
         }
-        for y in (self.margin..=self.height - self.margin).step_by(grid_size[1]) {
+    }
+
+    /// Draws a horizontal grid line across the plot area in the given style.
+    fn draw_horizontal_grid_line(&mut self, y: u32, color: [u8; 3], line_type: &LineType) {
+        if *line_type == LineType::Solid {
             self.draw_horizontal_line(y, color);
+        } else if self.plot_width() > 0 {
+            let left = self.margins.left as i32;
+            let right = (self.width - self.margins.right) as i32 - 1;
+            self.draw_line(left, y as i32, right, y as i32, color, line_type.clone());
         }
     }
 
@@ -147,7 +516,7 @@ impl PixelCanvas {
         // Draw each character vertically
         for ch in text.chars() {
             let char_as_str = ch.to_string();
-            let (_char_width, char_height) = text_size(scale, font, &char_as_str);
+            let (_char_width, char_height) = self.text_size(scale, font, &char_as_str);
 
             // Draw the character
             self.draw_text(x, current_y, &char_as_str, color, font, scale);
@@ -157,8 +526,30 @@ impl PixelCanvas {
         }
     }
 
+    /// Returns the size of `text` as drawn by [`PixelCanvas::draw_text`].
+    ///
+    /// With `TextRendering::BoxPlaceholder` this is the placeholder size, independent of `font`.
+    ///
+    /// # Parameters
+    /// - `scale`: The scaling factor for the font size.
+    /// - `font`: The font reference for rendering text.
+    /// - `text`: The text content.
+    ///
+    /// # Returns
+    /// The `(width, height)` of the text in pixels.
+    pub fn text_size(&self, scale: PxScale, font: &FontRef, text: &str) -> (u32, u32) {
+        match self.text_rendering {
+            TextRendering::Normal => text_size(scale, font, text),
+            TextRendering::BoxPlaceholder => TextRendering::placeholder_size(text, scale.y),
+        }
+    }
+
     /// Draws text at the specified position.
     ///
+    /// With `antialias` off, glyph pixels are drawn in full color if at least half covered
+    /// and skipped otherwise. With `TextRendering::BoxPlaceholder`, a filled box of the
+    /// text's size is drawn instead of the glyphs.
+    ///
     /// # Parameters
     /// - `x`: The x-coordinate for the text.
     /// - `y`: The y-coordinate for the text.
@@ -175,6 +566,37 @@ impl PixelCanvas {
         font: &FontRef,
         scale: PxScale,
     ) {
+        if self.text_rendering == TextRendering::BoxPlaceholder {
+            let (w, h) = self.text_size(scale, font, text);
+            self.clear_region(x, y, w, h, color);
+            return;
+        }
+
+        if !self.antialias {
+            // Render the coverage into an image around the text only, with a padding of one
+            // line height for glyphs reaching past the measured size
+            let (w, h) = text_size(scale, font, text);
+            let pad = scale.y.ceil().max(0.0) as u32;
+            let mut coverage = GrayImage::new(w + 2 * pad, h + 2 * pad);
+            draw_text_mut(
+                &mut coverage,
+                Luma([255]),
+                pad as i32,
+                pad as i32,
+                scale,
+                &font,
+                text,
+            );
+            for (px, py, value) in coverage.enumerate_pixels() {
+                let cx = x as i64 + px as i64 - pad as i64;
+                let cy = y as i64 + py as i64 - pad as i64;
+                if value[0] >= 128 && cx >= 0 && cy >= 0 {
+                    self.draw_pixel(cx as u32, cy as u32, color);
+                }
+            }
+            return;
+        }
+
         let img: &mut [u8] = &mut self.buffer;
         let mut buffer =
             image::ImageBuffer::from_raw(self.width, self.height, img.to_vec()).unwrap();
@@ -192,13 +614,17 @@ impl PixelCanvas {
     }
 
     /// Draws a line with the specified type (solid, dashed, or dotted).
-    /// ??? are dotted lines drawn same way as dashed lines ???
+    ///
+    /// Dashed lines alternate runs of drawn and skipped pixels of the dash length, dotted lines
+    /// draw a single pixel every `spacing` pixels.
+    ///
     /// # Parameters
     /// - `x1`, `y1`: Coordinates of the start point.
     /// - `x2`, `y2`: Coordinates of the end point.
     /// - `color`: The RGB color of the line.
     /// - `line_type`: The type of line to draw (`LineType`).
-    /// - would be nice to have line thickness parameter or med_line & thick_line enums
+    ///
+    /// See [`PixelCanvas::draw_line_thick`] for lines wider than one pixel.
     pub fn draw_line(
         &mut self,
         x1: i32,
@@ -237,28 +663,9 @@ impl PixelCanvas {
                 self.draw_pixel(x2 as u32, y2 as u32, color);
             }
             LineType::SolidThick => {
-                // Draw a continuous line without any gaps
-                while x != x2 || y != y2 {
-                    self.draw_pixel(x as u32, (y-2) as u32, color);
-                    self.draw_pixel(x as u32, (y-1) as u32, color);
-                    self.draw_pixel(x as u32, (y+1) as u32, color);
-                    self.draw_pixel(x as u32, (y+2) as u32, color);
-                    self.draw_pixel(x as u32, y as u32, color);
-
-                    let e2 = 2 * err;
-                    if e2 >= dy {
-                        err += dy;
-                        x += sx;
-                    }
-                    if e2 <= dx {
-                        err += dx;
-                        y += sy;
-                    }
-                }
-                // Draw the final pixel
-                self.draw_pixel(x2 as u32, y2 as u32, color);
+                self.draw_line_thick(x1, y1, x2, y2, color, 5, LineType::Solid);
             }
-            LineType::Dashed(dash_length) | LineType::Dotted(dash_length) => {
+            LineType::Dashed(dash_length) => {
                 let mut is_drawing = true;
                 let mut segment_length = 0;
 
@@ -288,37 +695,315 @@ impl PixelCanvas {
                     self.draw_pixel(x2 as u32, y2 as u32, color);
                 }
             }
-            LineType::Squared(gap, side_length) =>{
-                let _can_draw;
-                let _gap_length = gap;
-                let _side = side_length;
-                if true {
-                    _can_draw = false;
+            LineType::Dotted(spacing) => {
+                let spacing = spacing.max(1);
+                let mut step = 0;
+
+                loop {
+                    if step % spacing == 0 {
+                        self.draw_pixel(x as u32, y as u32, color);
+                    }
+                    if x == x2 && y == y2 {
+                        break;
+                    }
+                    step += 1;
+
+                    let e2 = 2 * err;
+                    if e2 >= dy {
+                        err += dy;
+                        x += sx;
+                    }
+                    if e2 <= dx {
+                        err += dx;
+                        y += sy;
+                    }
+                }
+            }
+            LineType::Squared(gap, side_length) => {
+                let gap = gap.max(1);
+                // Spread the side evenly around the current point
+                let side = side_length as i32;
+                let offsets = -((side - 1) / 2)..=(side / 2);
+                let mut step = 0;
+
+                loop {
+                    if step % gap == 0 {
+                        for offset_y in offsets.clone() {
+                            for offset_x in offsets.clone() {
+                                let (px, py) = (x + offset_x, y + offset_y);
+                                if px >= 0 && py >= 0 {
+                                    self.try_draw_pixel(px as u32, py as u32, color);
+                                }
+                            }
+                        }
+                    }
+                    if x == x2 && y == y2 {
+                        break;
+                    }
+                    step += 1;
+
+                    let e2 = 2 * err;
+                    if e2 >= dy {
+                        err += dy;
+                        x += sx;
+                    }
+                    if e2 <= dx {
+                        err += dx;
+                        y += sy;
+                    }
                 }
-            
             }
         }
     }
 
+    /// Draws a line of uniform thickness with the specified type.
+    ///
+    /// The stroke extends perpendicular to the line direction, so steep and shallow lines are
+    /// equally wide. Dashes and dots are laid out along the line and keep the thickness too.
+    ///
+    /// # Parameters
+    /// - `x1`, `y1`: Coordinates of the start point.
+    /// - `x2`, `y2`: Coordinates of the end point.
+    /// - `color`: The RGB color of the line.
+    /// - `thickness`: The width of the stroke in pixels. `SolidThick` is at least 5 pixels wide,
+    ///   a thickness of 1 draws the same line as [`PixelCanvas::draw_line`].
+    /// - `line_type`: The type of line to draw (`LineType`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    /// use dataviz::figure::utilities::linetype::LineType;
+    ///
+    /// let mut canvas = PixelCanvas::new(20, 20, [255, 255, 255], 0);
+    /// canvas.draw_line_thick(2, 10, 17, 10, [0, 0, 0], 3, LineType::Solid);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_line_thick(
+        &mut self,
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        color: [u8; 3],
+        thickness: u32,
+        line_type: LineType,
+    ) {
+        match thickness {
+            0 => return,
+            1 => {
+                self.draw_line(x1, y1, x2, y2, color, line_type);
+                return;
+            }
+            _ => {}
+        }
+
+        // Drawn length and period of the pattern along the line; dots are as long as wide
+        let (thickness, pattern) = match line_type {
+            LineType::Solid => (thickness, None),
+            LineType::SolidThick => (thickness.max(5), None),
+            LineType::Dashed(dash_length) => {
+                let dash_length = dash_length.max(1) as f64;
+                (thickness, Some((dash_length, 2.0 * dash_length)))
+            }
+            LineType::Dotted(spacing) => {
+                let spacing = spacing.max(1) as f64;
+                (thickness, Some((thickness as f64, spacing)))
+            }
+            LineType::Squared(..) => {
+                // Squares already have their own size
+                self.draw_line(x1, y1, x2, y2, color, line_type);
+                return;
+            }
+        };
+
+        let (dx, dy) = ((x2 - x1) as f64, (y2 - y1) as f64);
+        let length = dx.hypot(dy);
+        // Unit vector along the line; a single point is stamped as a horizontal dash
+        let (ux, uy) = if length > 0.0 {
+            (dx / length, dy / length)
+        } else {
+            (1.0, 0.0)
+        };
+        let half = thickness as f64 / 2.0;
+
+        let reach = half.ceil() as i32;
+        let x_start = (x1.min(x2) - reach).max(0);
+        let x_end = (x1.max(x2) + reach).min(self.width as i32 - 1);
+        let y_start = (y1.min(y2) - reach).max(0);
+        let y_end = (y1.max(y2) + reach).min(self.height as i32 - 1);
+
+        for y in y_start..=y_end {
+            for x in x_start..=x_end {
+                let (px, py) = ((x - x1) as f64, (y - y1) as f64);
+                // Position along the line and signed distance across it
+                let along = px * ux + py * uy;
+                let across = px * -uy + py * ux;
+                if along < 0.0 || along > length || across <= -half || across > half {
+                    continue;
+                }
+                if let Some((drawn, period)) = pattern {
+                    if along % period >= drawn {
+                        continue;
+                    }
+                }
+                self.draw_pixel(x as u32, y as u32, color);
+            }
+        }
+    }
+
+    /// Encodes the current canvas as a PNG image in memory, e.g. to serve it from a web
+    /// handler without writing a file.
+    ///
+    /// # Returns
+    /// The encoded PNG bytes, or an `image::ImageError` if the buffer doesn't match the canvas
+    /// size or encoding fails.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    ///
+    /// let mut canvas = PixelCanvas::new(100, 100, [255, 255, 255], 10);
+    /// canvas.clear();
+    /// let png = canvas.encode_png().unwrap();
+    /// assert!(png.starts_with(b"\x89PNG"));
+    /// ```
+    pub fn encode_png(&self) -> Result<Vec<u8>, image::ImageError> {
+        use image::ImageFormat;
+        use std::io::Cursor;
+
+        let img = self.to_rgb_image()?;
+        let mut bytes = Cursor::new(Vec::new());
+        img.write_to(&mut bytes, ImageFormat::Png)?;
+        Ok(bytes.into_inner())
+    }
+
+    /// Encodes the current canvas as a PNG in a base64 `data:` URI, e.g. for the `src` of an
+    /// HTML `<img>`.
+    ///
+    /// Requires the `base64` feature.
+    ///
+    /// # Returns
+    /// The PNG of [`PixelCanvas::encode_png`] as `data:image/png;base64,...`, or an
+    /// `image::ImageError` if encoding fails.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    ///
+    /// let mut canvas = PixelCanvas::new(100, 100, [255, 255, 255], 10);
+    /// canvas.clear();
+    /// let uri = canvas.to_png_data_uri().unwrap();
+    /// assert!(uri.starts_with("data:image/png;base64,"));
+    /// ```
+    #[cfg(feature = "base64")]
+    pub fn to_png_data_uri(&self) -> Result<String, image::ImageError> {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        Ok(format!(
+            "data:image/png;base64,{}",
+            STANDARD.encode(self.encode_png()?)
+        ))
+    }
+
     /// Saves the current canvas as an image file.
     ///
+    /// The format is picked from the file extension, e.g. `.png`, `.jpg` or `.bmp`; PNG files
+    /// are written with [`PixelCanvas::encode_png`].
+    ///
+    /// # Parameters
+    /// - `file_path`: The path to save the image file.
+    ///
+    /// # Errors
+    /// Returns an `image::ImageError` if the extension isn't a supported format, the image
+    /// cannot be encoded or the file cannot be written.
+    pub fn save_as_image(&self, file_path: &str) -> Result<(), image::ImageError> {
+        let is_png = std::path::Path::new(file_path)
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("png"));
+        if !is_png {
+            return self.to_rgb_image()?.save(file_path);
+        }
+        let bytes = self.encode_png()?;
+        std::fs::write(file_path, bytes)?;
+        Ok(())
+    }
+
+    /// Saves the current canvas as a JPEG image file, which is much smaller than a PNG for
+    /// large charts at the cost of some blurring around sharp edges.
+    ///
     /// # Parameters
     /// - `file_path`: The path to save the image file.
+    /// - `quality`: The JPEG quality from 1 (smallest file) to 100 (best quality).
     ///
-    /// # Panics
-    /// Panics if the image cannot be saved.
-    pub fn save_as_image(&self, file_path: &str) {
+    /// # Errors
+    /// Returns an `image::ImageError` if `quality` is not in `1..=100`, the image cannot be
+    /// encoded or the file cannot be written.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    ///
+    /// let mut canvas = PixelCanvas::new(100, 100, [255, 255, 255], 10);
+    /// canvas.clear();
+    /// let path = std::env::temp_dir().join("pixelcanvas_example.jpg");
+    /// canvas.save_as_jpeg(path.to_str().unwrap(), 80).unwrap();
+    /// assert!(canvas.save_as_jpeg(path.to_str().unwrap(), 0).is_err());
+    /// ```
+    pub fn save_as_jpeg(&self, file_path: &str, quality: u8) -> Result<(), image::ImageError> {
+        use image::codecs::jpeg::JpegEncoder;
+        use image::error::{ParameterError, ParameterErrorKind};
+        use image::ImageError;
+        use std::{fs::File, io::BufWriter};
+
+        if !(1..=100).contains(&quality) {
+            return Err(ImageError::Parameter(ParameterError::from_kind(
+                ParameterErrorKind::Generic(format!(
+                    "JPEG quality must be between 1 and 100, got {}.",
+                    quality
+                )),
+            )));
+        }
+
+        let img = self.to_rgb_image()?;
+        let writer = BufWriter::new(File::create(file_path)?);
+        img.write_with_encoder(JpegEncoder::new_with_quality(writer, quality))
+    }
+
+    /// Copies the canvas buffer into an `image::RgbImage`.
+    ///
+    /// # Returns
+    /// The image, or an `image::ImageError` if the buffer doesn't match the canvas size.
+    fn to_rgb_image(&self) -> Result<image::RgbImage, image::ImageError> {
+        use image::error::{ParameterError, ParameterErrorKind};
+        use image::{ImageBuffer, ImageError};
+
+        ImageBuffer::from_raw(self.width, self.height, self.buffer.clone()).ok_or_else(|| {
+            ImageError::Parameter(ParameterError::from_kind(
+                ParameterErrorKind::DimensionMismatch,
+            ))
+        })
+    }
+
+    /// Converts the canvas into an `image::DynamicImage`.
+    ///
+    /// This is the interop point for further processing with the `image` crate
+    /// (resizing, overlaying, format conversion) without saving to disk first.
+    ///
+    /// # Returns
+    /// A `DynamicImage::ImageRgb8` containing a copy of the canvas buffer.
+    pub fn to_dynamic_image(&self) -> image::DynamicImage {
         use image::{ImageBuffer, RgbImage};
 
         let img: RgbImage = ImageBuffer::from_raw(self.width, self.height, self.buffer.clone())
-            .expect("Failed to create image buffer");
-        img.save(file_path).expect("Failed to save image");
+            .expect("Canvas buffer does not match its dimensions");
+        image::DynamicImage::ImageRgb8(img)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::figure::canvas::testing::pixel_at;
     use image::GenericImageView;
     use std::env::current_dir;
     use std::path::{PathBuf};
@@ -334,9 +1019,33 @@ mod tests {
         let ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
         let mut my_path = current_dir().unwrap();
         my_path.push(format!("{}_{}.png", base_name, ts));
+        std::fs::create_dir_all(my_path.parent().unwrap()).unwrap();
         my_path
     }
 
+    #[test]
+    fn test_from_buffer_draws_into_recycled_buffer() {
+        let buffer = vec![0; 20 * 10 * 3];
+        let address = buffer.as_ptr();
+
+        let mut canvas = PixelCanvas::from_buffer(20, 10, buffer, [255, 255, 255], 0).unwrap();
+        canvas.clear();
+        canvas.draw_pixel(3, 2, [255, 0, 0]);
+        let buffer = canvas.into_buffer();
+
+        // The same allocation comes back, holding the drawing
+        assert_eq!(buffer.as_ptr(), address);
+        assert_eq!(buffer.len(), 20 * 10 * 3);
+        let index = (2 * 20 + 3) * 3;
+        assert_eq!(buffer[index..index + 3], [255, 0, 0]);
+        assert_eq!(buffer[0..3], [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_from_buffer_rejects_wrong_length() {
+        assert!(PixelCanvas::from_buffer(20, 10, vec![0; 20 * 10], [255, 255, 255], 0).is_err());
+    }
+
     #[test]
     fn test_draw_pixel_and_save_as_image() {
         let mut canvas = PixelCanvas::new(10, 10, [100, 100, 100], 0);
@@ -345,7 +1054,7 @@ mod tests {
         let path = unique_current_path("test_out/draw_pixel".to_string());
         let path_str = path.to_str().unwrap();
 
-        canvas.save_as_image(path_str);
+        canvas.save_as_image(path_str).unwrap();
 
         let img = image::open(&path).expect("failed to open saved image");
         let pixel = img.get_pixel(5, 5);
@@ -365,7 +1074,7 @@ mod tests {
         let path = unique_current_path("test_out/draw_line".to_string());
         let path_str = path.to_str().unwrap();
 
-        canvas.save_as_image(path_str);
+        canvas.save_as_image(path_str).unwrap();
 
         let img = image::open(&path).expect("failed to open saved image");
         let pixel = img.get_pixel(60, 31);
@@ -382,7 +1091,7 @@ mod tests {
         let path = unique_path();
         let path_str = path.to_str().unwrap();
 
-        canvas.save_as_image(path_str);
+        canvas.save_as_image(path_str).unwrap();
 
         assert!(path.exists(), "image file was not created: {}", path.display());
 
@@ -408,7 +1117,7 @@ mod tests {
         canvas.draw_pixel(0, 0, [7, 8, 9]);
 
         // save should overwrite the dummy file with a valid image
-        canvas.save_as_image(path_str);
+        canvas.save_as_image(path_str).unwrap();
 
         let img = image::open(&path).expect("failed to open overwritten image");
         assert_eq!(img.dimensions(), (4, 4));
@@ -422,7 +1131,6 @@ mod tests {
     #[test]
     fn test_draw_line_solid() {
         let mut canvas = PixelCanvas::new(10, 10, [255, 255, 255], 2);
-        // setting background to any color doesn't seem to work - it's always black !!!
         canvas.draw_line(0, 0, 9, 9, [50, 150, 250], LineType::Solid);
         //print!("Buffer: {:?}", canvas.buffer); // use -- --show-output to see output
         for i in 0..10 {
@@ -435,6 +1143,59 @@ mod tests {
         assert_ne!(canvas.buffer[4], 255, "setting canvas background color not supported so shouldn't match" );
         assert_ne!(canvas.buffer[5], 255, "setting canvas background color not supported so shouldn't match" );
     }
+    #[test]
+    fn test_draw_line_squared_stamps_squares_at_gap() {
+        let mut canvas = PixelCanvas::new(50, 50, [255, 255, 255], 0);
+        canvas.clear();
+        canvas.draw_line(5, 5, 45, 45, [0, 0, 0], LineType::Squared(10, 4));
+        let is_set = |x: u32, y: u32| canvas.buffer[((y * 50 + x) * 3) as usize] == 0;
+
+        // A 4x4 square spans 1 pixel before and 2 pixels after each point on the diagonal
+        for center in [5, 15, 25, 35, 45] {
+            assert!(is_set(center - 1, center - 1));
+            assert!(is_set(center + 2, center - 1));
+            assert!(is_set(center - 1, center + 2));
+            assert!(!is_set(center - 2, center));
+            assert!(!is_set(center + 3, center));
+        }
+        // Nothing is drawn between the squares
+        assert!(!is_set(10, 10));
+        assert!(!is_set(20, 20));
+
+        // Squares at the canvas edges are cut off instead of overflowing the buffer
+        canvas.draw_line(0, 0, 49, 0, [0, 0, 0], LineType::Squared(7, 6));
+        canvas.draw_line(49, 49, 49, 30, [0, 0, 0], LineType::Squared(7, 6));
+    }
+
+    #[test]
+    fn test_draw_line_thick_has_uniform_band() {
+        let color = [50, 150, 250];
+        let is_set =
+            |canvas: &PixelCanvas, x: u32, y: u32| canvas.buffer[((y * 20 + x) * 3) as usize] == 50;
+
+        // Near-horizontal: 3 rows around the line
+        let mut canvas = PixelCanvas::new(20, 20, [255, 255, 255], 0);
+        canvas.clear();
+        canvas.draw_line_thick(2, 10, 17, 11, color, 3, LineType::Solid);
+        let rows: Vec<u32> = (0..20).filter(|&y| is_set(&canvas, 9, y)).collect();
+        assert_eq!(rows, vec![9, 10, 11]);
+
+        // Near-vertical: 3 columns around the line
+        let mut canvas = PixelCanvas::new(20, 20, [255, 255, 255], 0);
+        canvas.clear();
+        canvas.draw_line_thick(10, 2, 11, 17, color, 3, LineType::Solid);
+        let columns: Vec<u32> = (0..20).filter(|&x| is_set(&canvas, x, 9)).collect();
+        assert_eq!(columns, vec![9, 10, 11]);
+
+        // Dashes keep the thickness and leave gaps along the line
+        let mut canvas = PixelCanvas::new(20, 20, [255, 255, 255], 0);
+        canvas.clear();
+        canvas.draw_line_thick(0, 10, 19, 10, color, 3, LineType::Dashed(4));
+        let band: Vec<u32> = (0..20).filter(|&y| is_set(&canvas, 1, y)).collect();
+        assert_eq!(band, vec![9, 10, 11]);
+        assert!(!is_set(&canvas, 5, 10));
+    }
+
     #[test]
     fn test_draw_line_dashed() {
         let mut canvas = PixelCanvas::new(10, 10, [255, 255, 255], 0);
@@ -470,4 +1231,314 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_draw_line_dotted_draws_single_pixels() {
+        let mut canvas = PixelCanvas::new(10, 10, [255, 255, 255], 0);
+        canvas.clear();
+        canvas.draw_line(0, 0, 9, 9, [40, 140, 240], LineType::Dotted(3));
+
+        let drawn: Vec<u32> = (0..10)
+            .filter(|&i| canvas.buffer[((i * 10 + i) * 3) as usize] == 40)
+            .collect();
+        assert_eq!(drawn, vec![0, 3, 6, 9]);
+
+        // Dashes of the same length draw runs instead of single pixels
+        canvas.clear();
+        canvas.draw_line(0, 0, 9, 9, [40, 140, 240], LineType::Dashed(3));
+        let drawn: Vec<u32> = (0..10)
+            .filter(|&i| canvas.buffer[((i * 10 + i) * 3) as usize] == 40)
+            .collect();
+        assert_eq!(drawn, vec![0, 1, 2, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_save_as_image_reports_unwritable_path() {
+        let canvas = PixelCanvas::new(4, 4, [255, 255, 255], 0);
+        let path = std::env::temp_dir().join("dataviz_missing_dir").join("canvas.png");
+
+        assert!(canvas.save_as_image(path.to_str().unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_save_as_image_picks_the_format_from_the_extension() {
+        let mut canvas = PixelCanvas::new(4, 4, [255, 255, 255], 0);
+        canvas.clear();
+        canvas.draw_pixel(1, 2, [255, 0, 0]);
+        let path = std::env::temp_dir().join(format!("dataviz_canvas_{}.bmp", std::process::id()));
+
+        canvas.save_as_image(path.to_str().unwrap()).unwrap();
+
+        assert!(std::fs::read(&path).unwrap().starts_with(b"BM"));
+        let img = image::open(&path)
+            .expect("failed to open saved bmp")
+            .to_rgb8();
+        assert_eq!(img.get_pixel(1, 2).0, [255, 0, 0]);
+        std::fs::remove_file(path).unwrap();
+
+        let unknown = std::env::temp_dir().join("dataviz_canvas.unknown");
+        let error = canvas.save_as_image(unknown.to_str().unwrap()).unwrap_err();
+        assert!(matches!(error, image::ImageError::Unsupported(_)));
+    }
+
+    #[test]
+    fn test_save_as_jpeg_matches_dimensions() {
+        let mut canvas = PixelCanvas::new(24, 12, [255, 255, 255], 0);
+        canvas.clear();
+        let path = std::env::temp_dir().join("pixelcanvas_test_quality.jpg");
+        let path_str = path.to_str().unwrap();
+
+        canvas.save_as_jpeg(path_str, 80).unwrap();
+
+        let img = image::open(&path).expect("failed to open saved jpeg");
+        assert_eq!((img.width(), img.height()), (24, 12));
+        assert!(canvas.save_as_jpeg(path_str, 101).is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_encode_png_starts_with_png_signature() {
+        let mut canvas = PixelCanvas::new(16, 8, [255, 255, 255], 0);
+        canvas.clear();
+
+        let bytes = canvas.encode_png().unwrap();
+
+        assert!(bytes.starts_with(&[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']));
+        let img = image::load_from_memory(&bytes).unwrap();
+        assert_eq!((img.width(), img.height()), (16, 8));
+    }
+
+    #[test]
+    fn test_to_dynamic_image() {
+        let mut canvas = PixelCanvas::new(20, 10, [255, 255, 255], 0);
+        canvas.draw_pixel(7, 3, [12, 34, 56]);
+
+        let img = canvas.to_dynamic_image();
+
+        assert_eq!(img.dimensions(), (20, 10));
+        assert_eq!(img.get_pixel(7, 3).0, [12, 34, 56, 255]);
+    }
+
+    #[test]
+    fn test_draw_without_antialias_has_no_blended_pixels() {
+        let font = FontRef::try_from_slice(crate::figure::configuration::figureconfig::DEFAULT_FONT)
+            .unwrap();
+        let mut canvas = PixelCanvas::new(60, 40, [255, 255, 255], 0);
+        canvas.antialias = false;
+        canvas.clear();
+
+        canvas.draw_line(0, 0, 59, 39, [0, 0, 0], LineType::Solid);
+        canvas.draw_text(5, 5, "Aa", [0, 0, 0], &font, PxScale { x: 20.0, y: 20.0 });
+
+        let mut drawn = 0;
+        for pixel in canvas.buffer.chunks(3) {
+            assert!(pixel == [0, 0, 0] || pixel == [255, 255, 255]);
+            if pixel == [0, 0, 0] {
+                drawn += 1;
+            }
+        }
+        // Both the line and the text are drawn
+        assert!(drawn > 60);
+    }
+
+    #[test]
+    fn test_text_without_antialias_matches_full_canvas_coverage() {
+        let font = FontRef::try_from_slice(crate::figure::configuration::figureconfig::DEFAULT_FONT)
+            .unwrap();
+        let scale = PxScale { x: 24.0, y: 24.0 };
+        let mut canvas = PixelCanvas::new(80, 40, [255, 255, 255], 0);
+        canvas.antialias = false;
+        canvas.clear();
+        // Partly outside the right and bottom edges
+        canvas.draw_text(50, 20, "gjQy", [0, 0, 0], &font, scale);
+
+        let mut coverage = GrayImage::new(80, 40);
+        draw_text_mut(&mut coverage, Luma([255]), 50, 20, scale, &font, "gjQy");
+        for (x, y, value) in coverage.enumerate_pixels() {
+            let index = ((y * 80 + x) * 3) as usize;
+            let expected = if value[0] >= 128 { 0 } else { 255 };
+            assert_eq!(canvas.buffer[index], expected, "pixel ({}, {})", x, y);
+        }
+    }
+
+    #[test]
+    fn test_grid_lines_on_canvas_smaller_than_margins_are_skipped() {
+        let mut canvas = PixelCanvas::new(30, 20, [255, 255, 255], 40);
+        canvas.clear();
+
+        canvas.draw_grid_lines(&[25], &[15], [0, 0, 0], LineType::Dashed(2));
+
+        assert!(canvas.buffer.iter().all(|&value| value == 255));
+    }
+
+    #[test]
+    fn test_try_draw_pixel_reports_bounds() {
+        let mut canvas = PixelCanvas::new(10, 10, [255, 255, 255], 0);
+
+        assert!(canvas.try_draw_pixel(9, 9, [1, 2, 3]));
+        assert_eq!(&canvas.buffer[297..300], &[1, 2, 3]);
+
+        assert!(!canvas.try_draw_pixel(10, 0, [1, 2, 3]));
+        assert!(!canvas.try_draw_pixel(0, 10, [1, 2, 3]));
+        // The rejected x = 10 would have wrapped into the first pixel of the next row
+        assert_eq!(&canvas.buffer[30..33], &[0, 0, 0]);
+    }
+
+    #[test]
+    fn test_draw_pixel_outside_canvas_is_ignored() {
+        let mut canvas = PixelCanvas::new(8, 6, [255, 255, 255], 0);
+        canvas.clear();
+
+        // Past the right edge, past the bottom edge and wrapped around from -1
+        canvas.draw_pixel(8, 2, [255, 0, 0]);
+        canvas.draw_pixel(3, 6, [255, 0, 0]);
+        canvas.draw_pixel(-1i32 as u32, 2, [255, 0, 0]);
+        canvas.draw_pixel(3, -2i32 as u32, [255, 0, 0]);
+
+        assert!(canvas.buffer.iter().all(|&channel| channel == 255));
+    }
+
+    #[test]
+    fn test_clear_fills_every_channel() {
+        let mut canvas = PixelCanvas::new(8, 6, [10, 20, 30], 0);
+        canvas.draw_pixel(3, 2, [255, 255, 255]);
+
+        canvas.clear();
+
+        let index = ((2 * 8 + 3) * 3) as usize;
+        assert_eq!(&canvas.buffer[index..index + 3], &[10, 20, 30]);
+        assert!(canvas.buffer.chunks_exact(3).all(|pixel| pixel == [10, 20, 30]));
+    }
+
+    #[test]
+    fn test_clear_region_only_erases_inside() {
+        let mut canvas = PixelCanvas::new(20, 20, [255, 255, 255], 0);
+        canvas.draw_pixel(2, 2, [255, 0, 0]);
+        canvas.draw_pixel(10, 10, [255, 0, 0]);
+
+        canvas.clear_region(5, 5, 10, 10, [255, 255, 255]);
+
+        let index = |x: usize, y: usize| (y * 20 + x) * 3;
+        assert_eq!(&canvas.buffer[index(2, 2)..index(2, 2) + 3], &[255, 0, 0]);
+        assert_eq!(&canvas.buffer[index(10, 10)..index(10, 10) + 3], &[255, 255, 255]);
+        // Pixels just outside the region are untouched
+        assert_eq!(&canvas.buffer[index(4, 5)..index(4, 5) + 3], &[0, 0, 0]);
+        assert_eq!(&canvas.buffer[index(15, 14)..index(15, 14) + 3], &[0, 0, 0]);
+    }
+
+    #[test]
+    fn test_clear_region_is_clamped_to_canvas() {
+        let mut canvas = PixelCanvas::new(10, 10, [255, 255, 255], 0);
+
+        canvas.clear_region(8, 8, 100, 100, [9, 9, 9]);
+
+        assert_eq!(&canvas.buffer[297..300], &[9, 9, 9]);
+        assert_eq!(canvas.buffer.iter().filter(|&&c| c == 9).count(), 4 * 3);
+    }
+
+    #[test]
+    fn test_draw_grid_draws_one_line_per_division_edge() {
+        let mut canvas = PixelCanvas::new(120, 100, [255, 255, 255], 10);
+        canvas.clear();
+
+        canvas.draw_grid(&[4, 5], [0, 0, 0], LineType::Solid);
+
+        // Count the grid lines crossed along a row and a column between two lines
+        let is_black =
+            |canvas: &PixelCanvas, x: u32, y: u32| canvas.buffer[((y * 120 + x) * 3) as usize] == 0;
+        let vertical_lines = (0..120).filter(|&x| is_black(&canvas, x, 15)).count();
+        let horizontal_lines = (0..100).filter(|&y| is_black(&canvas, 15, y)).count();
+        assert_eq!(vertical_lines, 5);
+        assert_eq!(horizontal_lines, 6);
+        // The outermost lines lie on the plot area edges
+        assert!(is_black(&canvas, 10, 15) && is_black(&canvas, 110, 15));
+    }
+
+    #[test]
+    fn test_dashed_grid_lines_have_gaps() {
+        let mut canvas = PixelCanvas::new(120, 100, [255, 255, 255], 10);
+        canvas.clear();
+
+        canvas.draw_grid_lines(&[60], &[], [0, 0, 0], LineType::Dashed(4));
+
+        let column: Vec<bool> = (10..90)
+            .map(|y| canvas.buffer[((y * 120 + 60) * 3) as usize] == 0)
+            .collect();
+        assert_eq!(
+            column[..8],
+            [true, true, true, true, false, false, false, false]
+        );
+        assert_eq!(column.iter().filter(|&&drawn| drawn).count(), 40);
+    }
+
+    #[test]
+    fn test_draw_rect_filled_is_clipped_to_canvas() {
+        let mut canvas = PixelCanvas::new(10, 10, [255, 255, 255], 0);
+        canvas.clear();
+
+        canvas.draw_rect_filled(6, 7, 20, 2, [255, 0, 0]);
+
+        let index = |x: usize, y: usize| (y * 10 + x) * 3;
+        assert_eq!(&canvas.buffer[index(6, 7)..index(6, 7) + 3], &[255, 0, 0]);
+        assert_eq!(&canvas.buffer[index(9, 8)..index(9, 8) + 3], &[255, 0, 0]);
+        assert_eq!(&canvas.buffer[index(5, 7)..index(5, 7) + 3], &[255, 255, 255]);
+        assert_eq!(&canvas.buffer[index(6, 9)..index(6, 9) + 3], &[255, 255, 255]);
+        assert_eq!(canvas.buffer.chunks_exact(3).filter(|p| p == &[255, 0, 0]).count(), 4 * 2);
+    }
+
+    #[test]
+    fn test_draw_rect_outline_leaves_inside_untouched() {
+        let mut canvas = PixelCanvas::new(10, 10, [255, 255, 255], 0);
+        canvas.clear();
+
+        canvas.draw_rect_outline(2, 3, 5, 4, [0, 0, 255]);
+
+        let index = |x: usize, y: usize| (y * 10 + x) * 3;
+        for (x, y) in [(2, 3), (6, 3), (2, 6), (6, 6), (4, 3), (2, 5)] {
+            assert_eq!(&canvas.buffer[index(x, y)..index(x, y) + 3], &[0, 0, 255]);
+        }
+        assert_eq!(&canvas.buffer[index(4, 5)..index(4, 5) + 3], &[255, 255, 255]);
+        assert_eq!(&canvas.buffer[index(7, 3)..index(7, 3) + 3], &[255, 255, 255]);
+        assert_eq!(canvas.buffer.chunks_exact(3).filter(|p| p == &[0, 0, 255]).count(), 14);
+    }
+
+    #[test]
+    fn test_draw_circle_filled_covers_center_and_boundary() {
+        let mut canvas = PixelCanvas::new(20, 20, [255, 255, 255], 0);
+        canvas.clear();
+
+        canvas.draw_circle_filled(10, 10, 5, [255, 0, 0]);
+
+        assert_eq!(pixel_at(&canvas, 10, 10), [255, 0, 0]);
+        assert_eq!(pixel_at(&canvas, 15, 10), [255, 0, 0]);
+        assert_eq!(pixel_at(&canvas, 10, 5), [255, 0, 0]);
+        assert_eq!(pixel_at(&canvas, 16, 10), [255, 255, 255]);
+        assert_eq!(pixel_at(&canvas, 14, 14), [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_draw_circle_outline_is_clipped_to_canvas() {
+        let mut canvas = PixelCanvas::new(20, 20, [255, 255, 255], 0);
+        canvas.clear();
+
+        canvas.draw_circle_outline(0, 0, 5, [0, 0, 255]);
+
+        let index = |x: usize, y: usize| (y * 20 + x) * 3;
+        assert_eq!(&canvas.buffer[index(5, 0)..index(5, 0) + 3], &[0, 0, 255]);
+        assert_eq!(&canvas.buffer[index(0, 5)..index(0, 5) + 3], &[0, 0, 255]);
+        assert_eq!(&canvas.buffer[index(0, 0)..index(0, 0) + 3], &[255, 255, 255]);
+    }
+
+    #[test]
+    fn test_size_for_aspect_ratio() {
+        assert_eq!(
+            PixelCanvas::size_for_aspect_ratio(800, 16.0 / 9.0),
+            (800, 450)
+        );
+        assert_eq!(
+            PixelCanvas::size_for_aspect_ratio(800, 9.0 / 16.0),
+            (450, 800)
+        );
+        assert_eq!(PixelCanvas::size_for_aspect_ratio(600, 1.0), (600, 600));
+    }
 }
@@ -1,8 +1,34 @@
 use std::{
     fs::File,
     io::{self, Write},
+    ops::Range,
 };
 
+use crate::figure::{canvas::margins::Margins, utilities::linetype::LineType};
+
+/// Escapes the characters with a special meaning in XML, so user text such as titles and
+/// labels can be embedded in SVG markup.
+///
+/// # Parameters
+/// - `text`: The text to escape.
+///
+/// # Returns
+/// The text with `&`, `<`, `>`, `"` and `'` replaced by their XML entities.
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// A structure for creating and managing an SVG-based drawing canvas.
 pub struct SvgCanvas {
     /// Width of the SVG canvas.
@@ -11,10 +37,12 @@ pub struct SvgCanvas {
     pub height: u32,
     /// A vector storing SVG elements as strings.
     pub elements: Vec<String>,
-    /// Margin size for the SVG canvas.
-    pub margin: u32,
+    /// Margins around the plot area of the SVG canvas.
+    pub margins: Margins,
     /// Background color of the SVG canvas.
     pub background_color: String,
+    /// The number of element ids handed out by [`SvgCanvas::unique_id`].
+    next_id: usize,
 }
 
 impl SvgCanvas {
@@ -24,11 +52,25 @@ impl SvgCanvas {
     /// - `width`: The width of the canvas in pixels.
     /// - `height`: The height of the canvas in pixels.
     /// - `background_color`: The background color as a string.
-    /// - `margin`: Margin size in pixels.
+    /// - `margin`: Margin size in pixels, used on all four sides.
     ///
     /// # Returns
     /// A new `SvgCanvas` instance.
     pub fn new(width: u32, height: u32, background_color: &str, margin: u32) -> Self {
+        Self::with_margins(width, height, background_color, Margins::uniform(margin))
+    }
+
+    /// Creates a new `SvgCanvas` with a different margin on every side.
+    ///
+    /// # Parameters
+    /// - `width`: The width of the canvas in pixels.
+    /// - `height`: The height of the canvas in pixels.
+    /// - `background_color`: The background color as a string.
+    /// - `margins`: The margins around the plot area in pixels.
+    ///
+    /// # Returns
+    /// A new `SvgCanvas` instance.
+    pub fn with_margins(width: u32, height: u32, background_color: &str, margins: Margins) -> Self {
         Self {
             width,
             height,
@@ -37,11 +79,50 @@ impl SvgCanvas {
 <svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {} {}">"#,
                 width, height
             )],
-            margin,
+            margins,
             background_color: background_color.to_string(),
+            next_id: 0,
         }
     }
 
+    /// Returns an element id that is unique within this canvas.
+    ///
+    /// The ids are numbered in the order they are requested, so drawing the same chart again
+    /// produces the same SVG.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the element, e.g. `plot-area`.
+    ///
+    /// # Returns
+    /// The `name` followed by the number of ids handed out before.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::svgcanvas::SvgCanvas;
+    ///
+    /// let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+    /// assert_eq!(svg_canvas.unique_id("plot-area"), "plot-area-0");
+    /// assert_eq!(svg_canvas.unique_id("plot-area"), "plot-area-1");
+    ///
+    /// let mut other = SvgCanvas::new(400, 300, "white", 40);
+    /// assert_eq!(other.unique_id("plot-area"), "plot-area-0");
+    /// ```
+    pub fn unique_id(&mut self, name: &str) -> String {
+        let id = format!("{}-{}", name, self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    /// Returns the edges of the plot area inside the margins as `(left, top, right, bottom)`.
+    pub fn plot_bounds(&self) -> (f64, f64, f64, f64) {
+        (
+            self.margins.left as f64,
+            self.margins.top as f64,
+            self.width as f64 - self.margins.right as f64,
+            self.height as f64 - self.margins.bottom as f64,
+        )
+    }
+
     /// Clears the SVG canvas by removing all elements and reinitializing.
     pub fn clear(&mut self) {
         // Clear all SVG elements
@@ -64,9 +145,46 @@ impl SvgCanvas {
         color: &str,
         stroke_width: f64,
     ) {
+        self.draw_line_opacity(x1, y1, x2, y2, color, stroke_width, 1.0);
+    }
+
+    /// Adds a translucent line to the SVG canvas, e.g. to tell overlapping series apart.
+    ///
+    /// # Parameters
+    /// - `x1`, `y1`: Coordinates of the start point.
+    /// - `x2`, `y2`: Coordinates of the end point.
+    /// - `color`: The stroke color of the line.
+    /// - `stroke_width`: The width of the line stroke.
+    /// - `opacity`: Opacity of the stroke (0.0 to 1.0). Below 1.0 a `stroke-opacity` is added.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::svgcanvas::SvgCanvas;
+    ///
+    /// let mut svg_canvas = SvgCanvas::new(200, 100, "white", 10);
+    /// svg_canvas.draw_line_opacity(10.0, 10.0, 190.0, 90.0, "blue", 2.0, 0.5);
+    /// assert!(svg_canvas.get_svg_as_text().contains(r#"stroke-opacity="0.5""#));
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_line_opacity(
+        &mut self,
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        color: &str,
+        stroke_width: f64,
+        opacity: f64,
+    ) {
+        // Opaque lines keep their markup without the default attribute
+        let opacity_attribute = if opacity < 1.0 {
+            format!(r#" stroke-opacity="{}""#, opacity)
+        } else {
+            String::new()
+        };
         self.elements.push(format!(
-            r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="{:.2}"/>"#,
-            x1, y1, x2, y2, color, stroke_width
+            r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="{:.2}"{}/>"#,
+            x1, y1, x2, y2, color, stroke_width, opacity_attribute
         ));
     }
 
@@ -86,9 +204,61 @@ impl SvgCanvas {
         color: [u8; 3],
         stroke_width: f64,
     ) {
+        self.draw_line_rgb_opacity(x1, y1, x2, y2, color, stroke_width, 1.0);
+    }
+
+    /// Adds a translucent line with RGB color to the SVG canvas.
+    ///
+    /// # Parameters
+    /// - `x1`, `y1`: Coordinates of the start point.
+    /// - `x2`, `y2`: Coordinates of the end point.
+    /// - `color`: The RGB color of the line.
+    /// - `stroke_width`: The width of the line stroke.
+    /// - `opacity`: Opacity of the stroke (0.0 to 1.0).
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_line_rgb_opacity(
+        &mut self,
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        color: [u8; 3],
+        stroke_width: f64,
+        opacity: f64,
+    ) {
+        let color = format!("rgb({},{},{})", color[0], color[1], color[2]);
+        self.draw_line_opacity(x1, y1, x2, y2, &color, stroke_width, opacity);
+    }
+
+    /// Adds a line with RGB color and a `LineType` stroke pattern to the SVG canvas.
+    ///
+    /// Every `LineType` is mapped to an SVG stroke so the output matches the pixel renderer:
+    /// - `Solid` and `SolidThick` produce a continuous stroke (`SolidThick` at least 5 pixels wide).
+    /// - `Dashed(len)` alternates dashes and gaps of `len`.
+    /// - `Dotted(spacing)` draws a single dot every `spacing` units.
+    /// - `Squared(gap, side)` draws squares of `side` every `gap` units, using a stroke as wide as a square.
+    ///
+    /// # Parameters
+    /// - `x1`, `y1`: Coordinates of the start point.
+    /// - `x2`, `y2`: Coordinates of the end point.
+    /// - `color`: The RGB color of the line.
+    /// - `stroke_width`: The width of the line stroke.
+    /// - `line_type`: The style of the line (`LineType`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_line_styled(
+        &mut self,
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        color: [u8; 3],
+        stroke_width: f64,
+        line_type: LineType,
+    ) {
+        let (stroke_width, dash_attribute) = stroke_pattern(&line_type, stroke_width);
         self.elements.push(format!(
-            r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="rgb({},{},{})" stroke-width="{:.2}"/>"#,
-            x1, y1, x2, y2, color[0], color[1], color[2], stroke_width
+            r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="rgb({},{},{})" stroke-width="{:.2}"{}/>"#,
+            x1, y1, x2, y2, color[0], color[1], color[2], stroke_width, dash_attribute
         ));
     }
 
@@ -150,6 +320,52 @@ impl SvgCanvas {
         ));
     }
 
+    /// Draws an ellipse on the SVG canvas.
+    ///
+    /// # Parameters
+    /// - `cx`, `cy`: Coordinates of the ellipse's center.
+    /// - `rx`, `ry`: Horizontal and vertical radius of the ellipse.
+    /// - `fill`: Fill color of the ellipse.
+    pub fn draw_ellipse(&mut self, cx: f64, cy: f64, rx: f64, ry: f64, fill: &str) {
+        self.elements.push(format!(
+            r#"<ellipse cx="{:.2}" cy="{:.2}" rx="{:.2}" ry="{:.2}" fill="{}"/>"#,
+            cx, cy, rx, ry, fill
+        ));
+    }
+
+    /// Draws a closed polygon on the SVG canvas.
+    ///
+    /// # Parameters
+    /// - `points`: The corners of the polygon in drawing order.
+    /// - `fill`: Fill color of the polygon.
+    /// - `stroke`: Stroke color of the outline, e.g. `"none"` for no outline.
+    /// - `stroke_width`: Width of the outline.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::svgcanvas::SvgCanvas;
+    ///
+    /// let mut svg_canvas = SvgCanvas::new(200, 100, "white", 10);
+    /// svg_canvas.draw_polygon(&[(10.0, 90.0), (100.0, 10.0), (190.0, 90.0)], "green", "black", 1.0);
+    /// ```
+    pub fn draw_polygon(
+        &mut self,
+        points: &[(f64, f64)],
+        fill: &str,
+        stroke: &str,
+        stroke_width: f64,
+    ) {
+        let points = points
+            .iter()
+            .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.elements.push(format!(
+            r#"<polygon points="{}" fill="{}" stroke="{}" stroke-width="{:.2}"/>"#,
+            points, fill, stroke, stroke_width
+        ));
+    }
+
     /// Adds a text element to the SVG canvas.
     ///
     /// # Parameters
@@ -160,7 +376,7 @@ impl SvgCanvas {
     pub fn draw_text(&mut self, x: f64, y: f64, text: &str, font_size: f64, color: &str) {
         self.elements.push(format!(
             r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="{}">{}</text>"#,
-            x, y, font_size, color, text
+            x, y, font_size, color, escape_xml(text)
         ));
     }
 
@@ -174,7 +390,7 @@ impl SvgCanvas {
     pub fn draw_title(&mut self, x: f64, y: f64, text: &str, font_size: f64, color: &str) {
         self.elements.push(format!(
             r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="{}">{}</text>"#,
-            x, y, font_size, color ,text
+            x, y, font_size, color, escape_xml(text)
         ));
     }
 
@@ -186,6 +402,8 @@ impl SvgCanvas {
     /// - `x_ticks`: Number of grid lines along the x-axis.
     /// - `y_ticks`: Number of grid lines along the y-axis.
     /// - `color`: Color of the grid lines.
+    /// - `line_type`: The style of the grid lines (`LineType`), mapped to a `stroke-dasharray`
+    ///   like in [`SvgCanvas::draw_line_styled`].
     #[allow(clippy::too_many_arguments)]
     pub fn draw_grid(
         &mut self,
@@ -196,21 +414,53 @@ impl SvgCanvas {
         x_ticks: usize,
         y_ticks: usize,
         color: &str,
+        line_type: LineType,
     ) {
         let x_step = (x_max - x_min) / x_ticks as f64;
         let y_step = (y_max - y_min) / y_ticks as f64;
+        let (stroke_width, dash_attribute) = stroke_pattern(&line_type, 0.5);
+        let mut grid_line = |x1: f64, y1: f64, x2: f64, y2: f64| {
+            self.elements.push(format!(
+                r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="{:.2}"{}/>"#,
+                x1, y1, x2, y2, color, stroke_width, dash_attribute
+            ));
+        };
 
         for i in 0..=x_ticks {
             let x = x_min + i as f64 * x_step;
-            self.draw_line(x, y_min, x, y_max, color, 0.5);
+            grid_line(x, y_min, x, y_max);
         }
 
         for i in 0..=y_ticks {
             let y = y_min + i as f64 * y_step;
-            self.draw_line(x_min, y, x_max, y, color, 0.5);
+            grid_line(x_min, y, x_max, y);
         }
     }
 
+    /// Moves a range of elements to the end, so they are drawn on top of all other elements.
+    ///
+    /// # Parameters
+    /// - `range`: The indices of the elements to move, e.g. the elements of the grid.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::svgcanvas::SvgCanvas;
+    /// use dataviz::figure::utilities::linetype::LineType;
+    ///
+    /// let mut canvas = SvgCanvas::new(100, 100, "white", 10);
+    /// let grid_start = canvas.elements.len();
+    /// canvas.draw_grid(10.0, 90.0, 10.0, 90.0, 4, 4, "lightgray", LineType::Solid);
+    /// let grid = grid_start..canvas.elements.len();
+    /// canvas.draw_circle(50.0, 50.0, 20.0, "red");
+    ///
+    /// canvas.raise_elements(grid);
+    /// assert!(canvas.elements.last().unwrap().contains("lightgray"));
+    /// ```
+    pub fn raise_elements(&mut self, range: Range<usize>) {
+        let raised: Vec<String> = self.elements.drain(range).collect();
+        self.elements.extend(raised);
+    }
+
     /// Saves the SVG content to a file.
     ///
     /// # Parameters
@@ -220,8 +470,11 @@ impl SvgCanvas {
     /// Returns an `io::Result` if saving fails.
     pub fn save(&self, file_path: &str) -> io::Result<()> {
         let mut file = File::create(file_path)?;
-        for element in &self.elements {
+        for (index, element) in self.elements.iter().enumerate() {
             writeln!(file, "{}", element)?;
+            if index == 0 {
+                writeln!(file, "{}", self.background_element())?;
+            }
         }
         writeln!(file, "</svg>")?;
         Ok(())
@@ -233,10 +486,179 @@ impl SvgCanvas {
     /// The complete SVG as a string.
     pub fn get_svg_as_text(&self) -> String {
         let mut svg = String::new();
-        for element in &self.elements {
+        for (index, element) in self.elements.iter().enumerate() {
             svg.push_str(element);
+            if index == 0 {
+                svg.push_str(&self.background_element());
+            }
         }
         svg.push_str("</svg>");
         svg
     }
+
+    /// Encodes the SVG as a base64 `data:` URI, e.g. for the `src` of an HTML `<img>`.
+    ///
+    /// Requires the `base64` feature.
+    ///
+    /// # Returns
+    /// The content of [`SvgCanvas::get_svg_as_text`] as `data:image/svg+xml;base64,...`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::svgcanvas::SvgCanvas;
+    ///
+    /// let svg_canvas = SvgCanvas::new(200, 100, "white", 10);
+    /// let html = format!(r#"<img src="{}">"#, svg_canvas.to_data_uri());
+    /// assert!(html.starts_with(r#"<img src="data:image/svg+xml;base64,"#));
+    /// ```
+    #[cfg(feature = "base64")]
+    pub fn to_data_uri(&self) -> String {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        format!(
+            "data:image/svg+xml;base64,{}",
+            STANDARD.encode(self.get_svg_as_text())
+        )
+    }
+
+    /// Returns the full-canvas rectangle in `background_color` that `save` and
+    /// `get_svg_as_text` insert right after the opening `<svg>` tag.
+    fn background_element(&self) -> String {
+        format!(
+            r#"<rect x="0" y="0" width="{}" height="{}" fill="{}"/>"#,
+            self.width,
+            self.height,
+            escape_xml(&self.background_color)
+        )
+    }
+}
+
+/// Maps a `LineType` to the stroke width and `stroke-dasharray` attribute of an SVG line.
+///
+/// # Returns
+/// The stroke width and the attribute with a leading space, or an empty string for continuous
+/// strokes.
+fn stroke_pattern(line_type: &LineType, stroke_width: f64) -> (f64, String) {
+    let (stroke_width, dasharray) = match *line_type {
+        LineType::Solid => (stroke_width, None),
+        LineType::SolidThick => (stroke_width.max(5.0), None),
+        LineType::Dashed(length) => (stroke_width, Some((length as f64, length as f64))),
+        LineType::Dotted(spacing) => {
+            let spacing = spacing.max(1) as f64;
+            (stroke_width, Some((1.0, (spacing - 1.0).max(1.0))))
+        }
+        LineType::Squared(gap, side) => {
+            let side = side as f64;
+            (side.max(1.0), Some((side, (gap as f64 - side).max(1.0))))
+        }
+    };
+
+    let dash_attribute = dasharray
+        .map(|(on, off)| format!(r#" stroke-dasharray="{:.2} {:.2}""#, on, off))
+        .unwrap_or_default();
+    (stroke_width, dash_attribute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use resvg::usvg;
+
+    #[test]
+    fn test_special_characters_in_title_are_escaped() {
+        let mut svg_canvas = SvgCanvas::new(200, 100, "white", 10);
+
+        svg_canvas.draw_title(100.0, 20.0, "Sales & Revenue <Q1>", 16.0, "black");
+
+        let svg = svg_canvas.get_svg_as_text();
+        assert!(svg.contains(">Sales &amp; Revenue &lt;Q1&gt;</text>"));
+        assert!(usvg::Tree::from_str(&svg, &usvg::Options::default()).is_ok());
+    }
+
+    #[test]
+    fn test_background_color_is_drawn_behind_elements() {
+        let mut svg_canvas = SvgCanvas::new(200, 100, "#202020", 10);
+        svg_canvas.draw_circle(50.0, 50.0, 20.0, "red");
+
+        let svg = svg_canvas.get_svg_as_text();
+
+        let background = r##"<rect x="0" y="0" width="200" height="100" fill="#202020"/>"##;
+        let position = svg.find(background).expect("No background rect");
+        assert!(position > svg.find("<svg").unwrap());
+        assert!(position < svg.find("<circle").unwrap());
+    }
+
+    #[test]
+    fn test_translucent_lines_have_stroke_opacity() {
+        let mut svg_canvas = SvgCanvas::new(200, 100, "white", 10);
+
+        svg_canvas.draw_line_rgb_opacity(0.0, 0.0, 100.0, 50.0, [255, 0, 0], 2.0, 0.4);
+        svg_canvas.draw_line_rgb(0.0, 50.0, 100.0, 0.0, [0, 0, 255], 2.0);
+
+        let lines: Vec<&String> = svg_canvas
+            .elements
+            .iter()
+            .filter(|element| element.starts_with("<line"))
+            .collect();
+        assert_eq!(
+            lines[0],
+            r#"<line x1="0.00" y1="0.00" x2="100.00" y2="50.00" stroke="rgb(255,0,0)" stroke-width="2.00" stroke-opacity="0.4"/>"#
+        );
+        assert!(!lines[1].contains("stroke-opacity"));
+    }
+
+    #[test]
+    fn test_polygon_has_one_point_pair_per_corner() {
+        let mut svg_canvas = SvgCanvas::new(200, 100, "white", 10);
+        let corners = [
+            (10.0, 10.0),
+            (50.0, 10.0),
+            (60.0, 40.0),
+            (30.0, 60.0),
+            (5.0, 40.0),
+        ];
+
+        svg_canvas.draw_polygon(&corners, "rgb(0,128,0)", "none", 0.0);
+
+        let polygon = svg_canvas.elements.last().unwrap();
+        let points = polygon
+            .split("points=\"")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap();
+        let pairs: Vec<&str> = points.split(' ').collect();
+        assert_eq!(pairs.len(), corners.len());
+        assert!(pairs.iter().all(|pair| pair.split(',').count() == 2));
+        assert_eq!(pairs[2], "60.00,40.00");
+    }
+
+    #[test]
+    fn test_ellipse_has_center_radii_and_fill() {
+        let mut svg_canvas = SvgCanvas::new(200, 100, "white", 10);
+
+        svg_canvas.draw_ellipse(100.0, 50.5, 40.0, 12.25, "rgb(0,0,255)");
+
+        assert_eq!(
+            svg_canvas.elements.last().unwrap(),
+            r#"<ellipse cx="100.00" cy="50.50" rx="40.00" ry="12.25" fill="rgb(0,0,255)"/>"#
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "base64")]
+    fn test_data_uri_decodes_to_the_svg() {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+
+        let mut svg_canvas = SvgCanvas::new(200, 100, "white", 10);
+        svg_canvas.draw_title(100.0, 20.0, "Embedded", 16.0, "black");
+
+        let uri = svg_canvas.to_data_uri();
+        let payload = uri
+            .strip_prefix("data:image/svg+xml;base64,")
+            .expect("No SVG data URI prefix");
+        let svg = String::from_utf8(STANDARD.decode(payload).unwrap()).unwrap();
+
+        assert!(svg.contains("<svg"));
+        assert_eq!(svg, svg_canvas.get_svg_as_text());
+    }
 }
@@ -0,0 +1,13 @@
+//! Helpers shared by the unit tests of the crate.
+
+use super::pixelcanvas::PixelCanvas;
+
+/// Returns the RGB color of the pixel at `(x, y)` of a canvas.
+pub(crate) fn pixel_at(canvas: &PixelCanvas, x: u32, y: u32) -> [u8; 3] {
+    let index = ((y * canvas.width + x) * 3) as usize;
+    [
+        canvas.buffer[index],
+        canvas.buffer[index + 1],
+        canvas.buffer[index + 2],
+    ]
+}
@@ -1,11 +1,32 @@
+use std::{borrow::Cow, io, sync::Arc};
+
+use crate::figure::utilities::{
+    barlabeloverflow::BarLabelOverflow, legendposition::LegendPosition, linetype::LineType,
+    numberformat::NumberFormat, textrendering::TextRendering, tickformat::TickFormat,
+};
+
+/// Built-in font (Tuffy, public domain) used for all text when no font path is configured.
+pub const DEFAULT_FONT: &[u8] = include_bytes!("../../../resources/fonts/Tuffy.ttf");
+
+const DEFAULT_COLOR_GRID: [u8; 3] = [200, 200, 200];
+const DEFAULT_COLOR_AXIS: [u8; 3] = [0, 0, 0];
+const DEFAULT_COLOR_TITLE: [u8; 3] = [0, 0, 0];
+const DEFAULT_ZERO_LINE_COLOR: [u8; 3] = [0, 0, 0];
+
 /// Configuration structure for customizing the appearance of a figure.
-#[derive(Clone)]
+///
+/// With the `serde` feature the configuration can be serialized, e.g. to store themes as
+/// JSON or TOML. Missing fields are deserialized to their default, and the cached font
+/// contents are skipped and reloaded from the font paths.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct FigureConfig {
     /// Number of ticks along the axes.
     pub num_axis_ticks: usize,
-    /// Number of horizontal grid lines.
+    /// Number of rows the horizontal grid lines divide the plot area into.
     pub num_grid_horizontal: usize,
-    /// Number of vertical grid lines.
+    /// Number of columns the vertical grid lines divide the plot area into.
     pub num_grid_vertical: usize,
     /// Color of the grid lines in RGB format.
     pub color_grid: [u8; 3],
@@ -23,10 +44,45 @@ pub struct FigureConfig {
     pub font_size_legend: f32,
     /// Font size for axis labels.
     pub font_size_axis: f32,
-    /// File path to the font used for labels.
+    /// Side length in pixels of the colored square next to each legend entry.
+    pub legend_marker_size: u32,
+    /// Corner of the figure the legend is drawn in, or `LegendPosition::None` to hide it.
+    pub legend_position: LegendPosition,
+    /// File path to the font used for labels. The built-in font is used if `None`.
     pub font_label: Option<String>,
-    /// File path to the font used for the title.
+    /// File path to the font used for the title. The built-in font is used if `None`.
     pub font_title: Option<String>,
+    /// Contents of the `font_label` file, cached by [`FigureConfig::load_fonts`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub font_label_bytes: Option<Arc<Vec<u8>>>,
+    /// Contents of the `font_title` file, cached by [`FigureConfig::load_fonts`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub font_title_bytes: Option<Arc<Vec<u8>>>,
+    /// Format used for hover tooltips and value labels, and for axis values if `tick_format` is
+    /// `TickFormat::Auto`.
+    pub number_format: NumberFormat,
+    /// Format used for axis values, see [`FigureConfig::format_tick`].
+    pub tick_format: TickFormat,
+    /// Color of the emphasized zero lines (x = 0 and y = 0) in RGB format.
+    pub zero_line_color: [u8; 3],
+    /// Width of the emphasized zero lines in pixels.
+    pub zero_line_width: u32,
+    /// Whether text is anti-aliased. Turning it off trades quality for speed, e.g. in real-time previews.
+    pub antialias: bool,
+    /// Whether text is rasterized or replaced by placeholder boxes, e.g. for font-independent golden tests.
+    pub text_rendering: TextRendering,
+    /// Whether grid lines are drawn at the axis ticks, so every grid line has a tick label.
+    /// If `false`, the grid is laid out by `num_grid_horizontal` and `num_grid_vertical` instead.
+    pub grid_at_ticks: bool,
+    /// Whether the grid is drawn over the data instead of behind it.
+    pub grid_on_top: bool,
+    /// Style of the grid lines, e.g. `LineType::Dashed(4)` for a dashed grid.
+    pub grid_line_type: LineType,
+    /// Tolerance, in data units, for simplifying the lines of line and cartesian graphs before
+    /// rendering (Ramer–Douglas–Peucker), or `None` to draw every point.
+    pub simplify_tolerance: Option<f64>,
+    /// How bar value labels wider than their bar are drawn.
+    pub bar_label_overflow: BarLabelOverflow,
 }
 
 impl Default for FigureConfig {
@@ -44,8 +100,26 @@ impl Default for FigureConfig {
     /// - `font_size_title`: 24.0
     /// - `font_size_legend`: 10.0
     /// - `font_size_axis`: 10.0
-    /// - `font_label`: `../../resources/fonts/Arial.ttf`
-    /// - `font_title`: `../../resources/fonts/Arial.ttf`
+    /// - `legend_marker_size`: 10
+    /// - `legend_position`: `LegendPosition::BottomLeft`
+    /// - `font_label`: `None` (the built-in font)
+    /// - `font_title`: `None` (the built-in font)
+    /// - `font_label_bytes`: `None`
+    /// - `font_title_bytes`: `None`
+    /// - `number_format`: `NumberFormat::Auto`
+    /// - `tick_format`: `TickFormat::Auto`
+    /// - `zero_line_color`: `[0, 0, 0]` (black)
+    /// - `zero_line_width`: 1
+    /// - `antialias`: `true`
+    /// - `text_rendering`: `TextRendering::Normal`
+    /// - `grid_at_ticks`: `true`
+    /// - `grid_on_top`: `false`
+    /// - `grid_line_type`: `LineType::Solid`
+    /// - `simplify_tolerance`: `None`
+    /// - `bar_label_overflow`: `BarLabelOverflow::Rotate`
+    ///
+    /// Grid, axis, title and zero line colors left at these defaults are adjusted to contrast
+    /// the background, see [`FigureConfig::effective_grid_color`].
     ///
     /// # Returns
     /// A `FigureConfig` instance with default settings.
@@ -54,16 +128,31 @@ impl Default for FigureConfig {
             num_axis_ticks: 10,
             num_grid_horizontal: 10,
             num_grid_vertical: 10,
-            color_grid: [200, 200, 200],       // Light gray
-            color_axis: [0, 0, 0],             // Black
+            color_grid: DEFAULT_COLOR_GRID,    // Light gray
+            color_axis: DEFAULT_COLOR_AXIS,    // Black
             color_background: [255, 255, 255], // White
-            color_title: [0, 0, 0],            // Black
+            color_title: DEFAULT_COLOR_TITLE,  // Black
             font_size_label: 12.0,
             font_size_title: 24.0,
             font_size_legend: 10.0,
             font_size_axis: 10.0,
+            legend_marker_size: 10,
+            legend_position: LegendPosition::BottomLeft,
             font_label: None,
             font_title: None,
+            font_label_bytes: None,
+            font_title_bytes: None,
+            number_format: NumberFormat::Auto,
+            tick_format: TickFormat::Auto,
+            zero_line_color: DEFAULT_ZERO_LINE_COLOR, // Black
+            zero_line_width: 1,
+            antialias: true,
+            text_rendering: TextRendering::Normal,
+            grid_at_ticks: true,
+            grid_on_top: false,
+            grid_line_type: LineType::Solid,
+            simplify_tolerance: None,
+            bar_label_overflow: BarLabelOverflow::Rotate,
         }
     }
 }
@@ -72,20 +161,333 @@ impl FigureConfig {
     pub fn set_font_paths(&mut self, label_path: String, title_path: String) {
         self.font_label = Some(label_path);
         self.font_title = Some(title_path);
+        self.font_label_bytes = None;
+        self.font_title_bytes = None;
     }
 
-    pub fn validate(&self) -> Result<(), String> {
-        if self.font_label.is_none() || self.font_title.is_none() {
-            Err("Both font_label and font_title must be provided.".to_string())
+    /// Reads the configured font files once and caches their contents, so drawing doesn't
+    /// read them from disk again for every piece of text.
+    ///
+    /// Fonts without a configured path use the built-in [`DEFAULT_FONT`] and need no loading.
+    /// Call it again after changing `font_label` or `font_title` directly.
+    ///
+    /// # Errors
+    /// Returns an `io::Error` if a configured font file cannot be read. The cache is left
+    /// unchanged in that case.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    ///
+    /// let font = concat!(env!("CARGO_MANIFEST_DIR"), "/resources/fonts/Tuffy.ttf");
+    /// let mut config = FigureConfig::default();
+    /// config.set_font_paths(font.to_string(), font.to_string());
+    /// config.load_fonts().unwrap();
+    /// assert!(config.font_label_bytes.is_some());
+    /// ```
+    pub fn load_fonts(&mut self) -> io::Result<()> {
+        let read = |path: &Option<String>| -> io::Result<Option<Arc<Vec<u8>>>> {
+            path.as_deref()
+                .map(|path| std::fs::read(path).map(Arc::new))
+                .transpose()
+        };
+        let label = read(&self.font_label)?;
+        let title = read(&self.font_title)?;
+        self.font_label_bytes = label;
+        self.font_title_bytes = title;
+        Ok(())
+    }
+
+    /// Returns the font data used for labels, axis values and legends.
+    ///
+    /// The data cached by [`FigureConfig::load_fonts`] is used if present. Otherwise the file
+    /// at `font_label` is read if set, or the built-in [`DEFAULT_FONT`] is used.
+    ///
+    /// # Panics
+    /// Panics if the font is neither cached nor built-in and the file cannot be read.
+    pub fn label_font_bytes(&self) -> Cow<'_, [u8]> {
+        Self::font_bytes(self.font_label_bytes.as_deref(), self.font_label.as_deref())
+    }
+
+    /// Returns the font data used for titles.
+    ///
+    /// The data cached by [`FigureConfig::load_fonts`] is used if present. Otherwise the file
+    /// at `font_title` is read if set, or the built-in [`DEFAULT_FONT`] is used.
+    ///
+    /// # Panics
+    /// Panics if the font is neither cached nor built-in and the file cannot be read.
+    pub fn title_font_bytes(&self) -> Cow<'_, [u8]> {
+        Self::font_bytes(self.font_title_bytes.as_deref(), self.font_title.as_deref())
+    }
+
+    fn font_bytes<'a>(cached: Option<&'a Vec<u8>>, path: Option<&str>) -> Cow<'a, [u8]> {
+        match (cached, path) {
+            (Some(bytes), _) => Cow::Borrowed(bytes),
+            (None, Some(path)) => {
+                Cow::Owned(std::fs::read(path).expect("Failed to read font file"))
+            }
+            (None, None) => Cow::Borrowed(DEFAULT_FONT),
+        }
+    }
+
+    /// Returns whether `color_background` is dark, judged by its perceived luminance.
+    pub fn has_dark_background(&self) -> bool {
+        let [r, g, b] = self.color_background;
+        let luminance = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
+        luminance < 128.0
+    }
+
+    /// Returns the color used to draw grid lines.
+    ///
+    /// If `color_grid` is left at its default, a gray contrasting `color_background` is
+    /// returned instead: a darker shade on light backgrounds (the default gray on white) and a
+    /// lighter shade on dark ones.
+    pub fn effective_grid_color(&self) -> [u8; 3] {
+        if self.color_grid != DEFAULT_COLOR_GRID {
+            return self.color_grid;
+        }
+        if self.has_dark_background() {
+            self.color_background
+                .map(|c| c + ((255 - c) as f32 * 0.6) as u8)
+        } else {
+            self.color_background
+                .map(|c| c - (c as f32 * 55.0 / 255.0) as u8)
+        }
+    }
+
+    /// Formats an axis tick value with `tick_format`, or with `number_format` if `tick_format`
+    /// is `TickFormat::Auto`.
+    ///
+    /// # Parameters
+    /// - `value`: The tick value to format.
+    ///
+    /// # Returns
+    /// The tick label as a `String`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::utilities::tickformat::TickFormat;
+    ///
+    /// let mut config = FigureConfig::default();
+    /// assert_eq!(config.format_tick(100000.0), "100000");
+    /// config.tick_format = TickFormat::SiPrefix;
+    /// assert_eq!(config.format_tick(100000.0), "100k");
+    /// ```
+    pub fn format_tick(&self, value: f64) -> String {
+        match self.tick_format {
+            TickFormat::Auto => self.number_format.format(value),
+            tick_format => tick_format.format(value),
+        }
+    }
+
+    /// Returns the color used for axes and axis text, white on dark backgrounds if
+    /// `color_axis` is left at its default.
+    pub fn effective_axis_color(&self) -> [u8; 3] {
+        self.contrasting(self.color_axis, DEFAULT_COLOR_AXIS)
+    }
+
+    /// Returns the color used for the title, white on dark backgrounds if `color_title` is
+    /// left at its default.
+    pub fn effective_title_color(&self) -> [u8; 3] {
+        self.contrasting(self.color_title, DEFAULT_COLOR_TITLE)
+    }
+
+    /// Returns the color used for the zero lines, white on dark backgrounds if
+    /// `zero_line_color` is left at its default.
+    pub fn effective_zero_line_color(&self) -> [u8; 3] {
+        self.contrasting(self.zero_line_color, DEFAULT_ZERO_LINE_COLOR)
+    }
+
+    fn contrasting(&self, color: [u8; 3], default: [u8; 3]) -> [u8; 3] {
+        if color == default && self.has_dark_background() {
+            [255, 255, 255]
         } else {
-            Ok(())
+            color
         }
     }
+
+    /// Checks that the configuration can be drawn with.
+    ///
+    /// Fonts without a configured path use the built-in [`DEFAULT_FONT`] and are always valid.
+    /// A configured font path must be readable unless its data is already cached by
+    /// [`FigureConfig::load_fonts`], and all font sizes must be positive.
+    ///
+    /// # Errors
+    /// Returns a message describing the first invalid setting.
+    pub fn validate(&self) -> Result<(), String> {
+        let fonts = [
+            ("font_label", &self.font_label, &self.font_label_bytes),
+            ("font_title", &self.font_title, &self.font_title_bytes),
+        ];
+        for (name, path, cached) in fonts {
+            if let (Some(path), None) = (path, cached) {
+                std::fs::File::open(path)
+                    .map_err(|error| format!("{} {:?} cannot be read: {}", name, path, error))?;
+            }
+        }
+
+        let sizes = [
+            ("font_size_label", self.font_size_label),
+            ("font_size_title", self.font_size_title),
+            ("font_size_legend", self.font_size_legend),
+            ("font_size_axis", self.font_size_axis),
+        ];
+        for (name, size) in sizes {
+            if !(size.is_finite() && size > 0.0) {
+                return Err(format!("{} must be positive, got {}", name, size));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns a [`FigureConfigBuilder`] starting from the default configuration.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    ///
+    /// let config = FigureConfig::builder()
+    ///     .grid(5, 8)
+    ///     .axis_color([80, 80, 80])
+    ///     .title_font_size(32.0)
+    ///     .build();
+    /// assert_eq!(config.num_grid_horizontal, 5);
+    /// ```
+    pub fn builder() -> FigureConfigBuilder {
+        FigureConfigBuilder::default()
+    }
+}
+
+/// Builds a [`FigureConfig`] with chainable setters, starting from the defaults.
+///
+/// Settings without a setter can still be changed on the built config's public fields.
+#[derive(Clone, Default)]
+pub struct FigureConfigBuilder {
+    config: FigureConfig,
+}
+
+impl FigureConfigBuilder {
+    /// Sets the number of divisions of the grid.
+    ///
+    /// # Parameters
+    /// - `horizontal`: The number of rows the horizontal grid lines divide the plot into.
+    /// - `vertical`: The number of columns the vertical grid lines divide the plot into.
+    pub fn grid(mut self, horizontal: usize, vertical: usize) -> Self {
+        self.config.num_grid_horizontal = horizontal;
+        self.config.num_grid_vertical = vertical;
+        self
+    }
+
+    /// Sets the number of ticks along the axes.
+    pub fn axis_ticks(mut self, count: usize) -> Self {
+        self.config.num_axis_ticks = count;
+        self
+    }
+
+    /// Sets the color of the grid lines.
+    pub fn grid_color(mut self, color: [u8; 3]) -> Self {
+        self.config.color_grid = color;
+        self
+    }
+
+    /// Sets the color of the axes and axis text.
+    pub fn axis_color(mut self, color: [u8; 3]) -> Self {
+        self.config.color_axis = color;
+        self
+    }
+
+    /// Sets the background color of the figure.
+    pub fn background_color(mut self, color: [u8; 3]) -> Self {
+        self.config.color_background = color;
+        self
+    }
+
+    /// Sets the color of the title text.
+    pub fn title_color(mut self, color: [u8; 3]) -> Self {
+        self.config.color_title = color;
+        self
+    }
+
+    /// Sets the font size of the title.
+    pub fn title_font_size(mut self, size: f32) -> Self {
+        self.config.font_size_title = size;
+        self
+    }
+
+    /// Sets the font size of the labels.
+    pub fn label_font_size(mut self, size: f32) -> Self {
+        self.config.font_size_label = size;
+        self
+    }
+
+    /// Sets the font size of the legend text.
+    pub fn legend_font_size(mut self, size: f32) -> Self {
+        self.config.font_size_legend = size;
+        self
+    }
+
+    /// Sets the font size of the axis values.
+    pub fn axis_font_size(mut self, size: f32) -> Self {
+        self.config.font_size_axis = size;
+        self
+    }
+
+    /// Sets the paths of the label and title fonts, see [`FigureConfig::set_font_paths`].
+    pub fn fonts(mut self, label_path: &str, title_path: &str) -> Self {
+        self.config
+            .set_font_paths(label_path.to_string(), title_path.to_string());
+        self
+    }
+
+    /// Sets the format of axis values and hover tooltips.
+    pub fn number_format(mut self, format: NumberFormat) -> Self {
+        self.config.number_format = format;
+        self
+    }
+
+    /// Sets the format of axis values.
+    pub fn tick_format(mut self, format: TickFormat) -> Self {
+        self.config.tick_format = format;
+        self
+    }
+
+    /// Sets the corner of the figure the legend is drawn in.
+    pub fn legend_position(mut self, position: LegendPosition) -> Self {
+        self.config.legend_position = position;
+        self
+    }
+
+    /// Sets whether text is anti-aliased.
+    pub fn antialias(mut self, antialias: bool) -> Self {
+        self.config.antialias = antialias;
+        self
+    }
+
+    /// Sets whether the grid is drawn over the data instead of behind it.
+    pub fn grid_on_top(mut self, on_top: bool) -> Self {
+        self.config.grid_on_top = on_top;
+        self
+    }
+
+    /// Sets the style of the grid lines.
+    pub fn grid_line_type(mut self, line_type: LineType) -> Self {
+        self.config.grid_line_type = line_type;
+        self
+    }
+
+    /// Returns the configured `FigureConfig`.
+    pub fn build(self) -> FigureConfig {
+        self.config
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::figure::{
+        canvas::pixelcanvas::PixelCanvas, drawers::drawer::Drawer, figuretypes::piechart::PieChart,
+    };
 
     #[test]
     fn test_default_figure_config() {
@@ -101,8 +503,58 @@ mod tests {
         assert_eq!(config.font_size_title, 24.0);
         assert_eq!(config.font_size_legend, 10.0);
         assert_eq!(config.font_size_axis, 10.0);
+        assert_eq!(config.legend_marker_size, 10);
+        assert_eq!(config.legend_position, LegendPosition::BottomLeft);
+        assert_eq!(config.bar_label_overflow, BarLabelOverflow::Rotate);
+        assert!(config.grid_at_ticks);
+        assert!(!config.grid_on_top);
+        assert_eq!(config.grid_line_type, LineType::Solid);
+        assert!(config.simplify_tolerance.is_none());
         assert!(config.font_label.is_none());
         assert!(config.font_title.is_none());
+        assert!(config.font_label_bytes.is_none());
+        assert!(config.font_title_bytes.is_none());
+        assert_eq!(config.label_font_bytes(), DEFAULT_FONT);
+        assert_eq!(config.number_format, NumberFormat::Auto);
+        assert_eq!(config.tick_format, TickFormat::Auto);
+        assert_eq!(config.zero_line_color, [0, 0, 0]);
+        assert_eq!(config.zero_line_width, 1);
+        assert!(config.antialias);
+    }
+
+    #[test]
+    fn test_format_tick_falls_back_to_number_format() {
+        let mut config = FigureConfig {
+            number_format: NumberFormat::Fixed(1),
+            ..FigureConfig::default()
+        };
+        assert_eq!(config.format_tick(2.0), "2.0");
+
+        config.tick_format = TickFormat::Integer;
+        assert_eq!(config.format_tick(2.0), "2");
+    }
+
+    #[test]
+    fn test_builder_sets_fields() {
+        let config = FigureConfig::builder()
+            .grid(4, 6)
+            .axis_color([10, 20, 30])
+            .background_color([0, 0, 0])
+            .title_font_size(30.0)
+            .fonts("label.ttf", "title.ttf")
+            .number_format(NumberFormat::Fixed(1))
+            .build();
+
+        assert_eq!(config.num_grid_horizontal, 4);
+        assert_eq!(config.num_grid_vertical, 6);
+        assert_eq!(config.color_axis, [10, 20, 30]);
+        assert_eq!(config.color_background, [0, 0, 0]);
+        assert_eq!(config.font_size_title, 30.0);
+        assert_eq!(config.font_label, Some("label.ttf".to_string()));
+        assert_eq!(config.font_title, Some("title.ttf".to_string()));
+        assert_eq!(config.number_format, NumberFormat::Fixed(1));
+        // Settings without a setter call keep their defaults
+        assert_eq!(config.font_size_label, 12.0);
     }
 
     #[test]
@@ -122,15 +574,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_loaded_fonts_are_used_without_reading_the_files_again() {
+        let font = concat!(env!("CARGO_MANIFEST_DIR"), "/resources/fonts/Tuffy.ttf");
+        let path = std::env::temp_dir().join("figureconfig_test_font.ttf");
+        std::fs::copy(font, &path).unwrap();
+        let path = path.to_str().unwrap().to_string();
+        let mut config = FigureConfig::default();
+        config.set_font_paths(path.clone(), path.clone());
+
+        config.load_fonts().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // The file is gone, so drawing a label only works with the cached data
+        let mut canvas = PixelCanvas::new(200, 100, [255, 255, 255], 10);
+        canvas.clear();
+        let chart = PieChart::new("Fonts", config.clone());
+        chart.draw_label(&mut canvas, &config, 100, 50, "Label");
+        assert!(canvas.buffer.iter().any(|&channel| channel < 128));
+        assert_eq!(
+            config.title_font_bytes().len(),
+            std::fs::read(font).unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_load_fonts_reports_missing_file() {
+        let mut config = FigureConfig::default();
+        config.set_font_paths("missing.ttf".to_string(), "missing.ttf".to_string());
+        assert!(config.load_fonts().is_err());
+        assert!(config.font_label_bytes.is_none());
+    }
+
     #[test]
     fn test_validate_fonts() {
         let mut config = FigureConfig::default();
-        assert!(config.validate().is_err());
+        assert!(config.validate().is_ok());
 
         config.set_font_paths(
             "path/to/label_font.ttf".to_string(),
             "path/to/title_font.ttf".to_string(),
         );
+        assert!(config.validate().is_err());
+
+        let font = concat!(env!("CARGO_MANIFEST_DIR"), "/resources/fonts/Tuffy.ttf");
+        config.set_font_paths(font.to_string(), font.to_string());
         assert!(config.validate().is_ok());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_validate_rejects_non_positive_font_size() {
+        let config = FigureConfig {
+            font_size_axis: 0.0,
+            ..FigureConfig::default()
+        };
+        assert_eq!(
+            config.validate(),
+            Err("font_size_axis must be positive, got 0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_light_background_keeps_default_colors() {
+        let config = FigureConfig::default();
+        assert!(!config.has_dark_background());
+        assert_eq!(config.effective_grid_color(), [200, 200, 200]);
+        assert_eq!(config.effective_axis_color(), [0, 0, 0]);
+        assert_eq!(config.effective_title_color(), [0, 0, 0]);
+        assert_eq!(config.effective_zero_line_color(), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_black_background_gets_contrasting_colors() {
+        let config = FigureConfig {
+            color_background: [0, 0, 0],
+            ..FigureConfig::default()
+        };
+        assert!(config.has_dark_background());
+        assert_eq!(config.effective_grid_color(), [153, 153, 153]);
+        assert_eq!(config.effective_axis_color(), [255, 255, 255]);
+        assert_eq!(config.effective_title_color(), [255, 255, 255]);
+        assert_eq!(config.effective_zero_line_color(), [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_overridden_colors_are_kept_on_dark_background() {
+        let config = FigureConfig {
+            color_background: [0, 0, 0],
+            color_grid: [40, 40, 40],
+            color_axis: [255, 0, 0],
+            ..FigureConfig::default()
+        };
+        assert_eq!(config.effective_grid_color(), [40, 40, 40]);
+        assert_eq!(config.effective_axis_color(), [255, 0, 0]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_config_round_trips_through_json() {
+        let config = FigureConfig::default();
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(config == serde_json::from_str::<FigureConfig>(&json).unwrap());
+
+        // Font paths are kept, missing fields fall back to their defaults
+        let themed: FigureConfig = serde_json::from_str(
+            r#"{"color_background": [0, 0, 0], "font_title": "fonts/Title.ttf"}"#,
+        )
+        .unwrap();
+        assert_eq!(themed.color_background, [0, 0, 0]);
+        assert_eq!(themed.font_title.as_deref(), Some("fonts/Title.ttf"));
+        assert_eq!(themed.num_axis_ticks, 10);
+    }
+}
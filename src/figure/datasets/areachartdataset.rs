@@ -8,6 +8,9 @@ pub struct AreaChartDataset {
     pub color: [u8; 3],
     /// Label for the dataset, used in legends or annotations.
     pub label: String,
+    /// Whether the dataset is drawn, `true` by default. Hidden datasets are greyed out in the
+    /// legend.
+    pub visible: bool,
 }
 
 impl AreaChartDataset {
@@ -23,6 +26,8 @@ impl AreaChartDataset {
     ///
     /// # Example
     /// ```rust
+    /// use dataviz::figure::datasets::areachartdataset::AreaChartDataset;
+    ///
     /// let dataset = AreaChartDataset::new([255, 0, 0], "Example Dataset", 0.5);
     /// ```
     pub fn new(color: [u8; 3], label: &str, alpha: f64) -> Self {
@@ -31,6 +36,15 @@ impl AreaChartDataset {
             color,
             label: label.to_string(),
             alpha,
+            visible: true,
         }
     }
+
+    /// Shows or hides the dataset.
+    ///
+    /// # Parameters
+    /// - `visible`: `false` to skip the dataset when drawing and grey out its legend entry.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
 }
@@ -6,6 +6,9 @@ pub struct BarDataset {
     pub color: [u8; 3],
     /// A collection of `(x, y)` data points where `x` is the category and `y` is the value.
     pub data: Vec<(f64, f64)>,
+    /// Whether the dataset is drawn, `true` by default. Hidden datasets are greyed out in the
+    /// legend.
+    pub visible: bool,
 }
 
 impl BarDataset {
@@ -20,6 +23,8 @@ impl BarDataset {
     ///
     /// # Example
     /// ```rust
+    /// use dataviz::figure::datasets::bardataset::BarDataset;
+    ///
     /// let dataset = BarDataset::new("Sales Data", [0, 128, 255]);
     /// ```
     pub fn new(label: &str, color: [u8; 3]) -> Self {
@@ -27,9 +32,18 @@ impl BarDataset {
             data: Vec::new(),
             label: label.to_string(),
             color,
+            visible: true,
         }
     }
 
+    /// Shows or hides the dataset.
+    ///
+    /// # Parameters
+    /// - `visible`: `false` to skip the dataset when drawing and grey out its legend entry.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
     /// Adds a data point to the dataset.
     ///
     /// # Parameters
@@ -38,6 +52,8 @@ impl BarDataset {
     ///
     /// # Example
     /// ```rust
+    /// use dataviz::figure::datasets::bardataset::BarDataset;
+    ///
     /// let mut dataset = BarDataset::new("Revenue", [255, 0, 0]);
     /// dataset.add_data(2020.0, 1500.0);
     /// dataset.add_data(2021.0, 2000.0);
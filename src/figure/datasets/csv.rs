@@ -0,0 +1,277 @@
+use std::{error::Error, fmt, fs, io};
+
+use crate::figure::utilities::{
+    linetype::LineType, numberlocale::NumberLocale, scatterdottype::ScatterDotType,
+};
+
+use super::{
+    cartesiangraphdataset::CartesianDataset, dataset::Dataset,
+    scattergraphdataset::ScatterGraphDataset,
+};
+
+/// An error while importing points from a CSV file.
+#[derive(Debug)]
+pub enum CsvError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// A row has fewer columns than the requested column index.
+    MissingColumn {
+        /// The 1-based line number of the row, counting the header.
+        line: usize,
+        /// The 0-based index of the missing column.
+        column: usize,
+    },
+    /// A field of a row is not a number.
+    InvalidNumber {
+        /// The 1-based line number of the row, counting the header.
+        line: usize,
+        /// The 0-based index of the column.
+        column: usize,
+        /// The text of the field.
+        value: String,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(error) => write!(f, "failed to read CSV file: {}", error),
+            CsvError::MissingColumn { line, column } => {
+                write!(f, "line {}: missing column {}", line, column)
+            }
+            CsvError::InvalidNumber {
+                line,
+                column,
+                value,
+            } => write!(
+                f,
+                "line {}: column {} is not a number: {:?}",
+                line, column, value
+            ),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(error: io::Error) -> Self {
+        CsvError::Io(error)
+    }
+}
+
+/// Reads `(x, y)` points from two columns of a delimiter-separated file.
+///
+/// The first line is a header and is skipped, as are empty lines. Fields are trimmed and
+/// parsed as numbers written in `locale`.
+///
+/// # Parameters
+/// - `path`: The path of the CSV file.
+/// - `x_col`: The 0-based index of the column with the x-values.
+/// - `y_col`: The 0-based index of the column with the y-values.
+/// - `delimiter`: The field separator, usually `,` or `;` for files with European numbers.
+/// - `locale`: The decimal and grouping separators of the numbers.
+///
+/// # Returns
+/// The points in file order.
+///
+/// # Errors
+/// Returns `CsvError::Io` if the file cannot be read, and `CsvError::MissingColumn` or
+/// `CsvError::InvalidNumber` with the line number of the first malformed row.
+pub fn read_points(
+    path: &str,
+    x_col: usize,
+    y_col: usize,
+    delimiter: char,
+    locale: NumberLocale,
+) -> Result<Vec<(f64, f64)>, CsvError> {
+    let contents = fs::read_to_string(path)?;
+    let mut points = Vec::new();
+    for (index, row) in contents.lines().enumerate().skip(1) {
+        if row.trim().is_empty() {
+            continue;
+        }
+
+        let line = index + 1;
+        let fields: Vec<&str> = row.split(delimiter).collect();
+        let field = |column: usize| -> Result<f64, CsvError> {
+            let value = fields
+                .get(column)
+                .ok_or(CsvError::MissingColumn { line, column })?;
+            locale.parse(value).map_err(|_| CsvError::InvalidNumber {
+                line,
+                column,
+                value: value.trim().to_string(),
+            })
+        };
+        points.push((field(x_col)?, field(y_col)?));
+    }
+    Ok(points)
+}
+
+/// Creates a scatter graph dataset from two columns of a CSV file.
+///
+/// # Parameters
+/// - `path`: The path of the CSV file, see [`read_points`] for the format.
+/// - `x_col`, `y_col`: The 0-based indices of the x and y columns.
+/// - `delimiter`: The field separator.
+/// - `locale`: The decimal and grouping separators of the numbers.
+/// - `color`: The RGB color of the scatter points.
+/// - `label`: A descriptive label for the dataset.
+/// - `dot_type`: The shape of the scatter points.
+///
+/// # Errors
+/// Returns a `CsvError` if the file cannot be read or a row is malformed.
+///
+/// # Example
+/// ```rust
+/// use dataviz::figure::datasets::csv::scatter_from_csv;
+/// use dataviz::figure::utilities::numberlocale::NumberLocale;
+/// use dataviz::figure::utilities::scatterdottype::ScatterDotType;
+///
+/// let path = std::env::temp_dir().join("dataviz_scatter_example.csv");
+/// std::fs::write(&path, "height,weight\n1.80,75\n1.65,60\n").unwrap();
+/// let dataset = scatter_from_csv(
+///     path.to_str().unwrap(),
+///     0,
+///     1,
+///     ',',
+///     NumberLocale::Standard,
+///     [255, 0, 0],
+///     "People",
+///     ScatterDotType::Circle(3),
+/// )
+/// .unwrap();
+/// assert_eq!(dataset.points, vec![(1.80, 75.0), (1.65, 60.0)]);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn scatter_from_csv(
+    path: &str,
+    x_col: usize,
+    y_col: usize,
+    delimiter: char,
+    locale: NumberLocale,
+    color: [u8; 3],
+    label: &str,
+    dot_type: ScatterDotType,
+) -> Result<ScatterGraphDataset, CsvError> {
+    let mut dataset = ScatterGraphDataset::new(color, label, dot_type);
+    dataset.add_points(&read_points(path, x_col, y_col, delimiter, locale)?);
+    Ok(dataset)
+}
+
+/// Creates a Cartesian graph dataset from two columns of a CSV file.
+///
+/// # Parameters
+/// - `path`: The path of the CSV file, see [`read_points`] for the format.
+/// - `x_col`, `y_col`: The 0-based indices of the x and y columns.
+/// - `delimiter`: The field separator.
+/// - `locale`: The decimal and grouping separators of the numbers.
+/// - `color`: The RGB color of the line.
+/// - `label`: A descriptive label for the dataset.
+/// - `line_type`: The style of the line.
+///
+/// # Errors
+/// Returns a `CsvError` if the file cannot be read or a row is malformed.
+#[allow(clippy::too_many_arguments)]
+pub fn cartesian_from_csv(
+    path: &str,
+    x_col: usize,
+    y_col: usize,
+    delimiter: char,
+    locale: NumberLocale,
+    color: [u8; 3],
+    label: &str,
+    line_type: LineType,
+) -> Result<CartesianDataset, CsvError> {
+    let mut dataset = CartesianDataset::new(color, label, line_type);
+    dataset.add_points(&read_points(path, x_col, y_col, delimiter, locale)?);
+    Ok(dataset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp_csv(name: &str, contents: &str) -> String {
+        let path =
+            std::env::temp_dir().join(format!("dataviz_{}_{}.csv", name, std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_cartesian_from_csv_reads_selected_columns() {
+        let path = write_temp_csv("three_rows", "t,ignored,value\n0,a,1.5\n1,b,-2\n2,c,4e1\n");
+
+        let dataset = cartesian_from_csv(
+            &path,
+            0,
+            2,
+            ',',
+            NumberLocale::Standard,
+            [0, 0, 255],
+            "Series",
+            LineType::Solid,
+        )
+        .unwrap();
+
+        assert_eq!(dataset.points, vec![(0.0, 1.5), (1.0, -2.0), (2.0, 40.0)]);
+        assert_eq!(dataset.label, "Series");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_scatter_from_european_csv() {
+        let path = write_temp_csv(
+            "european",
+            "Jahr;Umsatz\n2021;1.234,5\n2022;-0,75\n2023;2.000\n",
+        );
+
+        let dataset = scatter_from_csv(
+            &path,
+            0,
+            1,
+            ';',
+            NumberLocale::European,
+            [255, 0, 0],
+            "Umsatz",
+            ScatterDotType::Circle(3),
+        )
+        .unwrap();
+
+        assert_eq!(
+            dataset.points,
+            vec![(2021.0, 1234.5), (2022.0, -0.75), (2023.0, 2000.0)]
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_malformed_row_reports_line_number() {
+        let path = write_temp_csv("malformed", "x,y\n1,2\n3,oops\n5\n");
+
+        let error = read_points(&path, 0, 1, ',', NumberLocale::Standard).unwrap_err();
+
+        assert!(matches!(
+            error,
+            CsvError::InvalidNumber {
+                line: 3,
+                column: 1,
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "line 3: column 1 is not a number: \"oops\""
+        );
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -1,10 +1,8 @@
 use super::{
     areachartdataset::AreaChartDataset, bardataset::BarDataset,
-    cartesiangraphdataset::CartesianDataset, scattergraphdataset::ScatterGraphDataset,
-    linegraphdataset::LineGraphDataset,
+    cartesiangraphdataset::CartesianDataset, linegraphdataset::LineGraphDataset,
+    scattergraphdataset::ScatterGraphDataset,
 };
-use crate::figure::utilities::{linetype::LineType, scatterdottype::ScatterDotType,};
-
 
 /// A trait for managing datasets used in different types of charts or graphs.
 pub trait Dataset {
@@ -19,6 +17,132 @@ pub trait Dataset {
     /// # Parameters
     /// - `point`: A tuple `(f64, f64)` representing the x and y coordinates of the point to add.
     fn add_point(&mut self, point: (f64, f64));
+
+    /// Adds several points to the dataset, in order.
+    ///
+    /// # Parameters
+    /// - `points`: A slice of `(x, y)` tuples to append.
+    fn add_points(&mut self, points: &[(f64, f64)]) {
+        for &point in points {
+            self.add_point(point);
+        }
+    }
+
+    /// Returns the range of the points along both axes.
+    ///
+    /// # Returns
+    /// `Some(((x_min, x_max), (y_min, y_max)))`, or `None` if the dataset has no points.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::datasets::{bardataset::BarDataset, dataset::Dataset};
+    ///
+    /// let mut dataset = BarDataset::new("Sales", [255, 0, 0]);
+    /// assert_eq!(dataset.get_bounds(), None);
+    /// dataset.add_points(&[(1.0, 5.0), (3.0, -2.0)]);
+    /// assert_eq!(dataset.get_bounds(), Some(((1.0, 3.0), (-2.0, 5.0))));
+    /// ```
+    fn get_bounds(&self) -> Option<((f64, f64), (f64, f64))> {
+        point_bounds(self.get_points())
+    }
+
+    /// Removes the point at `index` from the dataset.
+    ///
+    /// The points after it move down by one. An `index` past the end leaves the dataset
+    /// unchanged.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the point to remove.
+    fn remove_point(&mut self, index: usize);
+
+    /// Removes all points from the dataset, keeping its appearance.
+    fn clear(&mut self);
+
+    /// Returns the points with their y-values rescaled to the range `0.0..=1.0`.
+    ///
+    /// The smallest y-value maps to `0.0` and the largest to `1.0`, so series of different
+    /// magnitudes can be compared by shape. The x-values are kept. If all y-values are equal,
+    /// they map to `0.0`.
+    ///
+    /// # Returns
+    /// A new vector of `(x, normalized_y)` points; the dataset itself is unchanged.
+    fn normalized_minmax(&self) -> Vec<(f64, f64)> {
+        let (_, (min, max)) = self.get_bounds().unwrap_or_default();
+        let range = max - min;
+
+        self.get_points()
+            .into_iter()
+            .map(|(x, y)| (x, if range > 0.0 { (y - min) / range } else { 0.0 }))
+            .collect()
+    }
+
+    /// Returns the points with their y-values standardized to zero mean and unit standard deviation.
+    ///
+    /// The x-values are kept. If all y-values are equal, they map to `0.0`.
+    ///
+    /// # Returns
+    /// A new vector of `(x, z_score)` points; the dataset itself is unchanged.
+    fn normalized_zscore(&self) -> Vec<(f64, f64)> {
+        let points = self.get_points();
+        let count = points.len() as f64;
+        let mean = points.iter().map(|&(_, y)| y).sum::<f64>() / count;
+        let std_dev = (points.iter().map(|&(_, y)| (y - mean).powi(2)).sum::<f64>() / count).sqrt();
+
+        points
+            .into_iter()
+            .map(|(x, y)| (x, if std_dev > 0.0 { (y - mean) / std_dev } else { 0.0 }))
+            .collect()
+    }
+}
+
+/// Returns the range of points along both axes.
+///
+/// # Parameters
+/// - `points`: The `(x, y)` points to span.
+///
+/// # Returns
+/// `Some(((x_min, x_max), (y_min, y_max)))`, or `None` if there are no points.
+pub fn point_bounds(
+    points: impl IntoIterator<Item = (f64, f64)>,
+) -> Option<((f64, f64), (f64, f64))> {
+    let mut points = points.into_iter();
+    let (x, y) = points.next()?;
+    Some(points.fold(
+        ((x, x), (y, y)),
+        |((x_min, x_max), (y_min, y_max)), (x, y)| {
+            ((x_min.min(x), x_max.max(x)), (y_min.min(y), y_max.max(y)))
+        },
+    ))
+}
+
+/// Returns the range of the points of several datasets along both axes.
+///
+/// # Parameters
+/// - `datasets`: The datasets to span.
+///
+/// # Returns
+/// `Some(((x_min, x_max), (y_min, y_max)))` spanning the [`Dataset::get_bounds`] of every
+/// dataset, or `None` if none of them has points.
+///
+/// # Example
+/// ```rust
+/// use dataviz::figure::datasets::{bardataset::BarDataset, dataset::{combined_bounds, Dataset}};
+///
+/// let mut first = BarDataset::new("2023", [255, 0, 0]);
+/// first.add_points(&[(1.0, 5.0), (2.0, 3.0)]);
+/// let mut second = BarDataset::new("2024", [0, 0, 255]);
+/// second.add_points(&[(3.0, -2.0)]);
+/// assert_eq!(combined_bounds(&[first, second]), Some(((1.0, 3.0), (-2.0, 5.0))));
+/// ```
+pub fn combined_bounds<'a, D: Dataset + 'a>(
+    datasets: impl IntoIterator<Item = &'a D>,
+) -> Option<((f64, f64), (f64, f64))> {
+    datasets
+        .into_iter()
+        .filter_map(Dataset::get_bounds)
+        .reduce(|((x_min, x_max), (y_min, y_max)), ((x0, x1), (y0, y1))| {
+            ((x_min.min(x0), x_max.max(x1)), (y_min.min(y0), y_max.max(y1)))
+        })
 }
 
 impl Dataset for BarDataset {
@@ -26,6 +150,8 @@ impl Dataset for BarDataset {
     ///
     /// - `get_points`: Returns the bar data as `(x, y)` pairs.
     /// - `add_point`: Adds a new `(x, y)` pair to the bar dataset.
+    /// - `add_points`: Reserves room for and appends several pairs at once.
+    /// - `remove_point`, `clear`: Remove one or all pairs.
     fn get_points(&self) -> Vec<(f64, f64)> {
         self.data.clone()
     }
@@ -33,6 +159,21 @@ impl Dataset for BarDataset {
     fn add_point(&mut self, point: (f64, f64)) {
         self.data.push(point);
     }
+
+    fn add_points(&mut self, points: &[(f64, f64)]) {
+        self.data.reserve(points.len());
+        self.data.extend_from_slice(points);
+    }
+
+    fn remove_point(&mut self, index: usize) {
+        if index < self.data.len() {
+            self.data.remove(index);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.data.clear();
+    }
 }
 
 impl Dataset for CartesianDataset {
@@ -40,6 +181,8 @@ impl Dataset for CartesianDataset {
     ///
     /// - `get_points`: Returns the Cartesian data as `(x, y)` pairs.
     /// - `add_point`: Adds a new `(x, y)` pair to the Cartesian dataset.
+    /// - `add_points`: Reserves room for and appends several pairs at once.
+    /// - `remove_point`, `clear`: Remove one or all pairs.
     fn get_points(&self) -> Vec<(f64, f64)> {
         self.points.clone()
     }
@@ -47,6 +190,21 @@ impl Dataset for CartesianDataset {
     fn add_point(&mut self, point: (f64, f64)) {
         self.points.push(point);
     }
+
+    fn add_points(&mut self, points: &[(f64, f64)]) {
+        self.points.reserve(points.len());
+        self.points.extend_from_slice(points);
+    }
+
+    fn remove_point(&mut self, index: usize) {
+        if index < self.points.len() {
+            self.points.remove(index);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.points.clear();
+    }
 }
 
 impl Dataset for ScatterGraphDataset {
@@ -54,6 +212,8 @@ impl Dataset for ScatterGraphDataset {
     ///
     /// - `get_points`: Returns the scatter graph data as `(x, y)` pairs.
     /// - `add_point`: Adds a new `(x, y)` pair to the scatter graph dataset.
+    /// - `add_points`: Reserves room for and appends several pairs at once.
+    /// - `remove_point`, `clear`: Remove points together with their sizes and errors.
     fn get_points(&self) -> Vec<(f64, f64)> {
         self.points.clone()
     }
@@ -61,6 +221,36 @@ impl Dataset for ScatterGraphDataset {
     fn add_point(&mut self, point: (f64, f64)) {
         self.points.push(point);
     }
+
+    fn add_points(&mut self, points: &[(f64, f64)]) {
+        self.points.reserve(points.len());
+        self.points.extend_from_slice(points);
+    }
+
+    fn remove_point(&mut self, index: usize) {
+        if index >= self.points.len() {
+            return;
+        }
+        self.points.remove(index);
+        for values in [&mut self.sizes, &mut self.x_errors, &mut self.y_errors]
+            .into_iter()
+            .flatten()
+        {
+            if index < values.len() {
+                values.remove(index);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.points.clear();
+        for values in [&mut self.sizes, &mut self.x_errors, &mut self.y_errors]
+            .into_iter()
+            .flatten()
+        {
+            values.clear();
+        }
+    }
 }
 
 impl Dataset for AreaChartDataset {
@@ -68,6 +258,8 @@ impl Dataset for AreaChartDataset {
     ///
     /// - `get_points`: Returns the area chart data as `(x, y)` pairs.
     /// - `add_point`: Adds a new `(x, y)` pair to the area chart dataset.
+    /// - `add_points`: Reserves room for and appends several pairs at once.
+    /// - `remove_point`, `clear`: Remove one or all pairs.
     fn get_points(&self) -> Vec<(f64, f64)> {
         self.points.clone()
     }
@@ -75,6 +267,21 @@ impl Dataset for AreaChartDataset {
     fn add_point(&mut self, point: (f64, f64)) {
         self.points.push(point);
     }
+
+    fn add_points(&mut self, points: &[(f64, f64)]) {
+        self.points.reserve(points.len());
+        self.points.extend_from_slice(points);
+    }
+
+    fn remove_point(&mut self, index: usize) {
+        if index < self.points.len() {
+            self.points.remove(index);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.points.clear();
+    }
 }
 
 impl Dataset for LineGraphDataset {
@@ -82,6 +289,8 @@ impl Dataset for LineGraphDataset {
     ///
     /// - `get_points`: Returns the LineGraph data as `(x, y)` pairs.
     /// - `add_point`: Adds a new `(x, y)` pair to the LineGraph dataset.
+    /// - `add_points`: Reserves room for and appends several pairs at once.
+    /// - `remove_point`, `clear`: Remove one or all pairs.
     fn get_points(&self) -> Vec<(f64, f64)> {
         self.points.clone()
     }
@@ -89,11 +298,27 @@ impl Dataset for LineGraphDataset {
     fn add_point(&mut self, point: (f64, f64)) {
         self.points.push(point);
     }
+
+    fn add_points(&mut self, points: &[(f64, f64)]) {
+        self.points.reserve(points.len());
+        self.points.extend_from_slice(points);
+    }
+
+    fn remove_point(&mut self, index: usize) {
+        if index < self.points.len() {
+            self.points.remove(index);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.points.clear();
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::figure::utilities::{linetype::LineType, scatterdottype::ScatterDotType};
 
     #[test]
     fn test_bar_dataset() {
@@ -135,4 +360,99 @@ mod tests {
         let points = dataset.get_points();
         assert_eq!(points, vec![(11.0, 12.0)]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_normalized_minmax() {
+        let mut dataset = LineGraphDataset::new([0, 255, 255], "Test Line", LineType::Solid);
+        dataset.add_point((0.0, 250.0));
+        dataset.add_point((1.0, 100.0));
+        dataset.add_point((2.0, 400.0));
+
+        let normalized = dataset.normalized_minmax();
+
+        assert_eq!(normalized, vec![(0.0, 0.5), (1.0, 0.0), (2.0, 1.0)]);
+        // The dataset itself is unchanged
+        assert_eq!(dataset.points[2], (2.0, 400.0));
+    }
+
+    #[test]
+    fn test_normalized_zscore() {
+        let mut dataset = BarDataset::new("Test Bar", [255, 0, 0]);
+        for (x, y) in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0].into_iter().enumerate() {
+            dataset.add_point((x as f64 + 1.0, y));
+        }
+
+        let normalized = dataset.normalized_zscore();
+
+        // Mean 5 and standard deviation 2
+        assert_eq!(normalized[0], (1.0, -1.5));
+        assert_eq!(normalized[7], (8.0, 2.0));
+    }
+
+    #[test]
+    fn test_normalization_of_constant_values() {
+        let mut dataset = CartesianDataset::new([0, 255, 0], "Test Cartesian", LineType::Solid);
+        dataset.add_point((1.0, 3.0));
+        dataset.add_point((2.0, 3.0));
+
+        assert_eq!(dataset.normalized_minmax(), vec![(1.0, 0.0), (2.0, 0.0)]);
+        assert_eq!(dataset.normalized_zscore(), vec![(1.0, 0.0), (2.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_remove_point_ignores_out_of_range_index() {
+        let mut dataset = BarDataset::new("Test Bar", [255, 0, 0]);
+        for x in 0..3 {
+            dataset.add_point((x as f64, x as f64 * 10.0));
+        }
+
+        dataset.remove_point(1);
+        dataset.remove_point(5);
+
+        assert_eq!(dataset.get_points(), vec![(0.0, 0.0), (2.0, 20.0)]);
+        dataset.clear();
+        assert!(dataset.get_points().is_empty());
+    }
+
+    #[test]
+    fn test_scatter_remove_point_keeps_sizes_aligned() {
+        let mut dataset =
+            ScatterGraphDataset::new([0, 0, 255], "Test Scatter", ScatterDotType::Circle(5));
+        dataset.add_point((1.0, 1.0));
+        dataset.add_point((2.0, 4.0));
+        dataset.set_sizes(vec![1.0, 2.0]);
+        dataset.set_y_errors(vec![0.5, 0.25]);
+
+        dataset.remove_point(0);
+
+        assert_eq!(dataset.get_points(), vec![(2.0, 4.0)]);
+        assert_eq!(dataset.dot_type_at(0).size(), 10);
+        assert_eq!(dataset.y_error(0), Some(0.25));
+
+        dataset.clear();
+        assert!(dataset.get_points().is_empty());
+        assert_eq!(dataset.y_error(0), None);
+    }
+
+    #[test]
+    fn test_add_points_appends_all_points() {
+        let mut dataset =
+            ScatterGraphDataset::new([0, 0, 255], "Test Scatter", ScatterDotType::Circle(5));
+        let points: Vec<(f64, f64)> = (0..100).map(|x| (x as f64, (x * x) as f64)).collect();
+
+        dataset.add_points(&points);
+
+        assert_eq!(dataset.get_points().len(), 100);
+        assert_eq!(dataset.points[99], (99.0, 9801.0));
+    }
+
+    #[test]
+    fn test_get_bounds_spans_all_points() {
+        let mut dataset = LineGraphDataset::new([0, 255, 255], "Test Line", LineType::Solid);
+        dataset.add_points(&[(2.0, 7.0), (-1.0, 3.0), (4.0, -6.0)]);
+
+        assert_eq!(dataset.get_bounds(), Some(((-1.0, 4.0), (-6.0, 7.0))));
+        dataset.clear();
+        assert_eq!(dataset.get_bounds(), None);
+    }
+}
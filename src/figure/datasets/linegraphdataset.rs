@@ -1,7 +1,6 @@
 use crate::figure::utilities::linetype::LineType;
 
 /// A dataset for line graphs, first draft
-
 pub struct LineGraphDataset {
     /// A collection of `(x, y)` data points for the line graph.
     pub points: Vec<(f64, f64)>, //should these be ints?
@@ -11,6 +10,12 @@ pub struct LineGraphDataset {
     pub label: String,
     /// Style of the line (solid, dashed, dotted).
     pub line_type: LineType,
+    /// Width of the line in pixels, `1` by default.
+    /// - In SVG output it scales the graph's default stroke width.
+    pub line_width: u32,
+    /// Whether the dataset is drawn, `true` by default. Hidden datasets are greyed out in the
+    /// legend.
+    pub visible: bool,
 }
 
 impl LineGraphDataset {
@@ -26,7 +31,8 @@ impl LineGraphDataset {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::utilities::linetype::LineType;
+    /// use dataviz::figure::datasets::linegraphdataset::LineGraphDataset;
+    /// use dataviz::figure::utilities::linetype::LineType;
     ///
     /// let dataset = LineGraphDataset::new([0, 128, 255], "Temperature", LineType::Dashed(10));
     /// ```
@@ -36,6 +42,24 @@ impl LineGraphDataset {
             color,
             label: label.to_string(),
             line_type,
+            line_width: 1,
+            visible: true,
         }
     }
+
+    /// Shows or hides the dataset.
+    ///
+    /// # Parameters
+    /// - `visible`: `false` to skip the dataset when drawing and grey out its legend entry.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// Sets the width of the line.
+    ///
+    /// # Parameters
+    /// - `line_width`: The width of the line in pixels.
+    pub fn set_line_width(&mut self, line_width: u32) {
+        self.line_width = line_width;
+    }
 }
@@ -10,6 +10,18 @@ pub struct ScatterGraphDataset {
     pub label: String,
     /// Shape of the scatter points (circle, square, triangle, etc.).
     pub dot_type: ScatterDotType,
+    /// Per-point size factors turning the scatter graph into a bubble chart, or `None` to draw
+    /// every point at the size of `dot_type`.
+    pub sizes: Option<Vec<f64>>,
+    /// The `(min, max)` size in pixels that scaled dots are clamped to.
+    pub size_range: (u32, u32),
+    /// Per-point horizontal error amounts in data units, drawn as capped bars, or `None`.
+    pub x_errors: Option<Vec<f64>>,
+    /// Per-point vertical error amounts in data units, drawn as capped bars, or `None`.
+    pub y_errors: Option<Vec<f64>>,
+    /// Whether the dataset is drawn, `true` by default. Hidden datasets are greyed out in the
+    /// legend.
+    pub visible: bool,
 }
 
 impl ScatterGraphDataset {
@@ -25,7 +37,8 @@ impl ScatterGraphDataset {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::utilities::scatterdottype::ScatterDotType;
+    /// use dataviz::figure::datasets::scattergraphdataset::ScatterGraphDataset;
+    /// use dataviz::figure::utilities::scatterdottype::ScatterDotType;
     ///
     /// let dataset = ScatterGraphDataset::new(
     ///     [255, 0, 0],
@@ -39,6 +52,127 @@ impl ScatterGraphDataset {
             color,
             label: label.to_string(),
             dot_type,
+            sizes: None,
+            size_range: (1, 50),
+            x_errors: None,
+            y_errors: None,
+            visible: true,
         }
     }
+
+    /// Shows or hides the dataset.
+    ///
+    /// # Parameters
+    /// - `visible`: `false` to skip the dataset when drawing and grey out its legend entry.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// Sets per-point size factors, turning the scatter graph into a bubble chart.
+    ///
+    /// The size of the dot of the point at index `i` is the size of `dot_type` multiplied by
+    /// `sizes[i]`, clamped to `size_range`. Points without a matching (finite, non-negative) size
+    /// are drawn at the size of `dot_type`.
+    ///
+    /// # Parameters
+    /// - `sizes`: The size factors, in the same order as `points`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::datasets::scattergraphdataset::ScatterGraphDataset;
+    /// use dataviz::figure::utilities::scatterdottype::ScatterDotType;
+    ///
+    /// let mut dataset = ScatterGraphDataset::new([255, 0, 0], "Bubbles", ScatterDotType::Circle(3));
+    /// dataset.set_sizes(vec![1.0, 4.0]);
+    /// assert_eq!(dataset.dot_type_at(1).size(), 12);
+    /// assert_eq!(dataset.dot_type_at(2).size(), 3);
+    /// ```
+    pub fn set_sizes(&mut self, sizes: Vec<f64>) {
+        self.sizes = Some(sizes);
+    }
+
+    /// Sets the range in pixels that dots scaled by `sizes` are clamped to.
+    ///
+    /// # Parameters
+    /// - `min`: The smallest size of a scaled dot in pixels.
+    /// - `max`: The largest size of a scaled dot in pixels.
+    ///
+    /// # Panics
+    /// Panics if `min` is greater than `max`.
+    pub fn set_size_range(&mut self, min: u32, max: u32) {
+        assert!(min <= max, "size range min must not exceed max");
+        self.size_range = (min, max);
+    }
+
+    /// Returns the dot drawn for the point at `index`, scaled by its size factor if it has one.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the point in `points`.
+    ///
+    /// # Returns
+    /// The `ScatterDotType` with the scaled and clamped size, or `dot_type` if the point has no
+    /// valid size factor.
+    pub fn dot_type_at(&self, index: usize) -> ScatterDotType {
+        let size = self
+            .sizes
+            .as_ref()
+            .and_then(|sizes| sizes.get(index))
+            .filter(|size| size.is_finite() && **size >= 0.0);
+        match size {
+            Some(size) => {
+                let (min, max) = self.size_range;
+                let scaled = (self.dot_type.size() as f64 * size).round();
+                self.dot_type
+                    .with_size((scaled.min(u32::MAX as f64) as u32).clamp(min, max))
+            }
+            None => self.dot_type.clone(),
+        }
+    }
+
+    /// Sets per-point horizontal errors, drawn as capped bars from `x - error` to `x + error`.
+    ///
+    /// # Parameters
+    /// - `x_errors`: The error amounts in data units, in the same order as `points`.
+    pub fn set_x_errors(&mut self, x_errors: Vec<f64>) {
+        self.x_errors = Some(x_errors);
+    }
+
+    /// Sets per-point vertical errors, drawn as capped bars from `y - error` to `y + error`.
+    ///
+    /// # Parameters
+    /// - `y_errors`: The error amounts in data units, in the same order as `points`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::datasets::scattergraphdataset::ScatterGraphDataset;
+    /// use dataviz::figure::utilities::scatterdottype::ScatterDotType;
+    ///
+    /// let mut dataset = ScatterGraphDataset::new([255, 0, 0], "Samples", ScatterDotType::Circle(3));
+    /// dataset.set_y_errors(vec![0.5, f64::NAN]);
+    /// assert_eq!(dataset.y_error(0), Some(0.5));
+    /// assert_eq!(dataset.y_error(1), None);
+    /// ```
+    pub fn set_y_errors(&mut self, y_errors: Vec<f64>) {
+        self.y_errors = Some(y_errors);
+    }
+
+    /// Returns the horizontal error of the point at `index`, or `None` if it has no positive,
+    /// finite error.
+    pub fn x_error(&self, index: usize) -> Option<f64> {
+        error_at(&self.x_errors, index)
+    }
+
+    /// Returns the vertical error of the point at `index`, or `None` if it has no positive,
+    /// finite error.
+    pub fn y_error(&self, index: usize) -> Option<f64> {
+        error_at(&self.y_errors, index)
+    }
+}
+
+fn error_at(errors: &Option<Vec<f64>>, index: usize) -> Option<f64> {
+    errors
+        .as_ref()?
+        .get(index)
+        .copied()
+        .filter(|error| error.is_finite() && *error > 0.0)
 }
@@ -1,5 +1,10 @@
-use crate::figure::canvas::pixelcanvas::PixelCanvas;
-use ab_glyph::FontRef;
+use crate::figure::{canvas::pixelcanvas::PixelCanvas, configuration::figureconfig::FigureConfig};
+use ab_glyph::{FontArc, FontRef, PxScale};
+use image::{ImageBuffer, Rgb};
+use imageproc::{
+    drawing::{draw_filled_rect_mut, draw_hollow_rect_mut, draw_text_mut, text_size},
+    rect::Rect,
+};
 
 /// A trait for plots that support hover functionality, allowing interactive
 /// features like highlighting and displaying information about data points.
@@ -48,18 +53,441 @@ pub trait Hover {
     /// Panics if the font data cannot be parsed.
     fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a>;
 
+    /// Handles hover functionality, drawing the hovered frame into a reusable buffer.
+    ///
+    /// This method is used to modify a copy of the canvas in response to hover events,
+    /// such as highlighting a data point or displaying additional information. Reusing `out`
+    /// across frames avoids allocating a new image for every mouse event.
+    ///
+    /// # Parameters
+    /// - `mouse_x`: The x-coordinate of the mouse position in canvas space.
+    /// - `mouse_y`: The y-coordinate of the mouse position in canvas space.
+    /// - `canvas`: The `PixelCanvas` being used for rendering the plot.
+    /// - `fonts`: The parsed fonts kept between calls, so hovering doesn't read font files.
+    /// - `out`: The buffer the frame is drawn into, see [`HoverBuffer::pixels`].
+    ///
+    /// # Returns
+    /// `true` if `out.pixels` holds the updated frame, or `false` if there is nothing to show
+    /// and its contents are unspecified.
+    fn handle_hover_into(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+        out: &mut HoverBuffer,
+    ) -> bool;
+
     /// Handles hover functionality and returns an updated buffer if applicable.
     ///
-    /// This method is used to modify the canvas buffer in response to hover events,
-    /// such as highlighting a data point or displaying additional information.
+    /// Allocates a new buffer on every call; interactive loops should keep a `HoverBuffer`
+    /// and call [`Hover::handle_hover_into`] instead.
     ///
     /// # Parameters
     /// - `mouse_x`: The x-coordinate of the mouse position in canvas space.
     /// - `mouse_y`: The y-coordinate of the mouse position in canvas space.
     /// - `canvas`: The `PixelCanvas` being used for rendering the plot.
+    /// - `fonts`: The parsed fonts kept between calls, so hovering doesn't read font files.
     ///
     /// # Returns
     /// An optional vector of `u32` representing the updated pixel buffer.
     /// If no changes are made, returns `None`.
-    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>>;
+    fn handle_hover(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+    ) -> Option<Vec<u32>> {
+        let mut out = HoverBuffer::new();
+        self.handle_hover_into(mouse_x, mouse_y, canvas, fonts, &mut out)
+            .then_some(out.pixels)
+    }
+
+    /// Handles hover functionality in [`HoverMode::Crosshair`], drawing the hovered frame into
+    /// a reusable buffer.
+    ///
+    /// Draws guides across the plot area through the data x-value nearest to the mouse, see
+    /// [`snap_to_x`], and a tooltip with the y-values of all datasets at that x. The default
+    /// implementation draws the nearest point hover of [`Hover::handle_hover_into`] instead.
+    ///
+    /// # Parameters
+    /// - `mouse_x`: The x-coordinate of the mouse position in canvas space.
+    /// - `mouse_y`: The y-coordinate of the mouse position in canvas space.
+    /// - `canvas`: The `PixelCanvas` being used for rendering the plot.
+    /// - `fonts`: The parsed fonts kept between calls, so hovering doesn't read font files.
+    /// - `out`: The buffer the frame is drawn into, see [`HoverBuffer::pixels`].
+    ///
+    /// # Returns
+    /// `true` if `out.pixels` holds the updated frame, or `false` if there is nothing to show.
+    ///
+    /// [`HoverMode::Crosshair`]: crate::figure::utilities::hovermode::HoverMode::Crosshair
+    fn handle_crosshair_into(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+        out: &mut HoverBuffer,
+    ) -> bool {
+        self.handle_hover_into(mouse_x, mouse_y, canvas, fonts, out)
+    }
+}
+
+/// Reusable buffers for [`Hover::handle_hover_into`], kept between frames so hovering
+/// doesn't allocate.
+#[derive(Default)]
+pub struct HoverBuffer {
+    /// Copy of the canvas in RGB format that the hover elements are drawn on.
+    rgb: Vec<u8>,
+    /// The hovered frame in the `0RGB` format used by the display window.
+    pub pixels: Vec<u32>,
+}
+
+impl HoverBuffer {
+    /// Creates an empty `HoverBuffer`; its buffers grow to the canvas size on first use.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Copies `canvas` into the scratch buffer and returns it as an image to draw on.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` the hovered frame is based on.
+    pub fn image(&mut self, canvas: &PixelCanvas) -> ImageBuffer<Rgb<u8>, &mut [u8]> {
+        self.rgb.clear();
+        self.rgb.extend_from_slice(&canvas.buffer);
+        ImageBuffer::from_raw(canvas.width, canvas.height, self.rgb.as_mut_slice())
+            .expect("canvas buffer does not match its size")
+    }
+
+    /// Converts the image drawn on since [`HoverBuffer::image`] into `pixels`.
+    pub fn finish(&mut self) {
+        self.pixels.clear();
+        self.pixels.extend(
+            self.rgb
+                .chunks_exact(3)
+                .map(|rgb| (rgb[0] as u32) << 16 | (rgb[1] as u32) << 8 | rgb[2] as u32),
+        );
+    }
+}
+
+/// Parsed fonts for hover tooltips, kept between calls to [`Hover::handle_hover`].
+///
+/// The interactive windows of [`Winop`](crate::figure::display::winop::Winop) own one cache
+/// for their whole lifetime, so the font is read and parsed once instead of on every frame.
+#[derive(Default)]
+pub struct FontCache {
+    /// The `font_label` path the label font was loaded for, and the parsed font.
+    label: Option<(Option<String>, FontArc)>,
+}
+
+impl FontCache {
+    /// Creates an empty `FontCache`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the label font of `config`.
+    ///
+    /// The font is read and parsed on the first call, and again only after `font_label` of
+    /// the configuration changed.
+    ///
+    /// # Parameters
+    /// - `config`: The `FigureConfig` whose label font is used.
+    ///
+    /// # Panics
+    /// Panics if the font file cannot be read or parsed.
+    pub fn label_font(&mut self, config: &FigureConfig) -> &FontArc {
+        let stale = !matches!(&self.label, Some((path, _)) if *path == config.font_label);
+        if stale {
+            let font = FontArc::try_from_vec(config.label_font_bytes().into_owned())
+                .expect("Failed to parse font");
+            self.label = Some((config.font_label.clone(), font));
+        }
+        &self.label.as_ref().unwrap().1
+    }
+}
+
+/// Computes the top-left corner of a hover tooltip so it stays fully on the canvas.
+///
+/// The tooltip is placed `offset.0` pixels right of and `offset.1` pixels above the cursor.
+/// Near the right edge it flips to the left of the cursor, and near the top edge it flips
+/// below the cursor, each time keeping a gap of `offset.0` pixels. Tooltips larger than the
+/// canvas are aligned to its top-left corner.
+///
+/// # Parameters
+/// - `mouse_x`, `mouse_y`: The mouse position in canvas space.
+/// - `width`, `height`: The size of the tooltip in pixels.
+/// - `offset`: The `(right, up)` offset of the tooltip from the cursor in pixels.
+/// - `canvas`: The `PixelCanvas` the tooltip is drawn on.
+///
+/// # Returns
+/// The `(x, y)` position of the tooltip's top-left corner.
+///
+/// # Example
+/// ```rust
+/// use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+/// use dataviz::figure::display::hover::tooltip_position;
+///
+/// let canvas = PixelCanvas::new(200, 100, [255, 255, 255], 10);
+/// assert_eq!(tooltip_position(50, 50, 60, 25, (10, 30), &canvas), (60, 20));
+/// // Near the top-right corner the tooltip moves to the left of and below the cursor
+/// assert_eq!(tooltip_position(190, 5, 60, 25, (10, 30), &canvas), (120, 15));
+/// ```
+pub fn tooltip_position(
+    mouse_x: u32,
+    mouse_y: u32,
+    width: i32,
+    height: i32,
+    offset: (i32, i32),
+    canvas: &PixelCanvas,
+) -> (i32, i32) {
+    let (mouse_x, mouse_y) = (mouse_x as i32, mouse_y as i32);
+    let (canvas_width, canvas_height) = (canvas.width as i32, canvas.height as i32);
+
+    let mut x = mouse_x + offset.0;
+    if x + width > canvas_width {
+        x = mouse_x - offset.0 - width;
+    }
+    let mut y = mouse_y - offset.1;
+    if y < 0 {
+        y = mouse_y + offset.0;
+    }
+
+    // Clamp whatever still doesn't fit, e.g. on canvases smaller than the tooltip
+    let x = x.min(canvas_width - width).max(0);
+    let y = y.min(canvas_height - height).max(0);
+    (x, y)
+}
+
+/// Snaps a mouse position to the data x-value drawn closest to it horizontally.
+///
+/// # Parameters
+/// - `mouse_x`: The x-coordinate of the mouse position in canvas space.
+/// - `xs`: The x-values of the data points.
+/// - `to_canvas_x`: Maps a data x-value to its canvas x-coordinate, as used by the drawer.
+///
+/// # Returns
+/// The x-value nearest to `mouse_x` on the canvas, or `None` if there are no x-values.
+///
+/// # Example
+/// ```rust
+/// use dataviz::figure::display::hover::snap_to_x;
+///
+/// // Data x-values 0, 1 and 2 drawn 100 pixels apart, starting at pixel 50
+/// let to_canvas_x = |x: f64| 50.0 + x * 100.0;
+/// assert_eq!(snap_to_x(140, [0.0, 1.0, 2.0], to_canvas_x), Some(1.0));
+/// ```
+pub fn snap_to_x(
+    mouse_x: u32,
+    xs: impl IntoIterator<Item = f64>,
+    to_canvas_x: impl Fn(f64) -> f64,
+) -> Option<f64> {
+    xs.into_iter()
+        .map(|x| (x, (to_canvas_x(x) - mouse_x as f64).abs()))
+        .min_by(|(_, d1), (_, d2)| d1.total_cmp(d2))
+        .map(|(x, _)| x)
+}
+
+/// Returns the y-value of a line through `points` at `x`.
+///
+/// A point at exactly `x` is used if there is one, otherwise the first line segment spanning
+/// `x` is interpolated linearly.
+///
+/// # Returns
+/// The y-value, or `None` if the line doesn't reach `x`.
+pub(crate) fn value_at(points: &[(f64, f64)], x: f64) -> Option<f64> {
+    if let Some(&(_, y)) = points.iter().find(|&&(px, _)| px == x) {
+        return Some(y);
+    }
+    points.windows(2).find_map(|window| {
+        let [(x1, y1), (x2, y2)] = [window[0], window[1]];
+        ((x1 < x && x < x2) || (x2 < x && x < x1)).then(|| y1 + (y2 - y1) * (x - x1) / (x2 - x1))
+    })
+}
+
+/// Draws the crosshair hover: dashed guides across the plot area through `guide` and a
+/// tooltip with one text row per entry of `rows`.
+///
+/// # Parameters
+/// - `img`: The hovered frame, see [`HoverBuffer::image`].
+/// - `canvas`: The `PixelCanvas` the frame is based on.
+/// - `guide`: The canvas position the vertical and horizontal guides pass through.
+/// - `mouse`: The mouse position in canvas space, the tooltip is placed next to it.
+/// - `rows`: The text and RGB color of each tooltip row.
+/// - `font`: The font of the tooltip.
+pub(crate) fn draw_crosshair(
+    img: &mut ImageBuffer<Rgb<u8>, &mut [u8]>,
+    canvas: &PixelCanvas,
+    guide: (u32, u32),
+    mouse: (u32, u32),
+    rows: &[(String, [u8; 3])],
+    font: &FontArc,
+) {
+    let (left, top, right, bottom) = canvas.plot_bounds();
+    let (right, bottom) = (right.min(canvas.width - 1), bottom.min(canvas.height - 1));
+    let guide_color = Rgb([120, 120, 120]);
+    let dashed = |start: u32, end: u32| (start..=end).filter(move |p| (p - start) % 8 < 5);
+    if (left..=right).contains(&guide.0) {
+        for y in dashed(top, bottom) {
+            img.put_pixel(guide.0, y, guide_color);
+        }
+    }
+    if (top..=bottom).contains(&guide.1) {
+        for x in dashed(left, right) {
+            img.put_pixel(x, guide.1, guide_color);
+        }
+    }
+
+    draw_tooltip_rows(img, canvas, mouse, rows, font);
+}
+
+/// Draws a tooltip with one text row per entry of `rows` next to the mouse.
+///
+/// The tooltip is sized to its widest row and kept on the canvas, see [`tooltip_position`].
+///
+/// # Parameters
+/// - `img`: The hovered frame, see [`HoverBuffer::image`].
+/// - `canvas`: The `PixelCanvas` the frame is based on.
+/// - `mouse`: The mouse position in canvas space.
+/// - `rows`: The text and RGB color of each tooltip row.
+/// - `font`: The font of the tooltip.
+pub(crate) fn draw_tooltip_rows(
+    img: &mut ImageBuffer<Rgb<u8>, &mut [u8]>,
+    canvas: &PixelCanvas,
+    mouse: (u32, u32),
+    rows: &[(String, [u8; 3])],
+    font: &FontArc,
+) {
+    let scale = PxScale { x: 12.0, y: 12.0 };
+    let text_width = rows
+        .iter()
+        .map(|(text, _)| text_size(scale, font, text).0)
+        .max()
+        .unwrap_or(0);
+    let rect_width = text_width as i32 + 20;
+    let rect_height = rows.len() as i32 * 20 + 10;
+
+    // Keep the tooltip on the canvas, flipping it to the other side of the cursor if needed
+    let (rect_x, rect_y) =
+        tooltip_position(mouse.0, mouse.1, rect_width, rect_height, (10, 30), canvas);
+    let rect = Rect::at(rect_x, rect_y).of_size(rect_width as u32, rect_height as u32);
+    draw_filled_rect_mut(img, rect, Rgb([255, 255, 255]));
+    draw_hollow_rect_mut(img, rect, Rgb([0, 0, 0]));
+    for (row, (text, color)) in rows.iter().enumerate() {
+        let y = rect_y + 5 + row as i32 * 20;
+        draw_text_mut(img, Rgb(*color), rect_x + 10, y, scale, font, text);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::{
+        datasets::{
+            cartesiangraphdataset::CartesianDataset, dataset::Dataset,
+            linegraphdataset::LineGraphDataset,
+        },
+        figuretypes::{cartesiangraph::CartesianGraph, linegraph::LineGraph},
+        utilities::linetype::LineType,
+    };
+
+    #[test]
+    fn test_crosshair_snaps_mouse_x_to_nearest_data_x() {
+        let mut graph = CartesianGraph::new("Crosshair", "X", "Y", &FigureConfig::default());
+        for (color, offset) in [([255, 0, 0], 0.0), ([0, 0, 255], 10.0)] {
+            let mut dataset = CartesianDataset::new(color, "Series", LineType::Solid);
+            dataset.add_points(&[(0.0, offset), (2.0, offset + 2.0), (4.0, offset + 4.0)]);
+            graph.add_dataset(dataset);
+        }
+        // For snapping alone, map x = 0..4 to the 400 pixel wide plot area at 50..450
+        let mut canvas = PixelCanvas::new(500, 300, [255, 255, 255], 50);
+        canvas.clear();
+        let to_canvas_x = |x: f64| 50.0 + x * 100.0;
+        let xs = [0.0, 2.0, 4.0];
+
+        assert_eq!(snap_to_x(60, xs, to_canvas_x), Some(0.0));
+        assert_eq!(snap_to_x(240, xs, to_canvas_x), Some(2.0));
+        assert_eq!(snap_to_x(499, xs, to_canvas_x), Some(4.0));
+        assert_eq!(snap_to_x(240, [], to_canvas_x), None);
+
+        // The graph's X-axis spans -4..4, so the vertical guide for x = 2 is drawn at pixel
+        // 350 rather than through the mouse x; (202 - 50) % 8 is on a dash
+        let mut out = HoverBuffer::new();
+        let mut fonts = FontCache::new();
+        assert!(graph.handle_crosshair_into(340, 100, &canvas, &mut fonts, &mut out));
+        let pixel = |x: usize, y: usize| out.pixels[y * canvas.width as usize + x];
+        assert_ne!(pixel(350, 202), 0x00FF_FFFF);
+        assert_eq!(pixel(340, 202), 0x00FF_FFFF);
+    }
+
+    #[test]
+    fn test_tooltip_flips_at_top_right_corner() {
+        let canvas = PixelCanvas::new(300, 200, [255, 255, 255], 20);
+        let (mouse_x, mouse_y) = (299, 0);
+        let (width, height) = (80, 25);
+
+        let (x, y) = tooltip_position(mouse_x, mouse_y, width, height, (10, 30), &canvas);
+
+        // Fully on the canvas
+        assert!(x >= 0 && x + width <= canvas.width as i32);
+        assert!(y >= 0 && y + height <= canvas.height as i32);
+        // Left of and below the cursor instead of right of and above it
+        assert!(x + width < mouse_x as i32);
+        assert!(y > mouse_y as i32);
+    }
+
+    #[test]
+    fn test_repeated_hovers_do_not_read_the_font_file() {
+        // A private copy of the font, removed after the first hover
+        let font_path =
+            std::env::temp_dir().join(format!("dataviz_hover_{}.ttf", std::process::id()));
+        std::fs::copy(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/resources/fonts/Tuffy.ttf"),
+            &font_path,
+        )
+        .unwrap();
+        let font_path = font_path.to_str().unwrap().to_string();
+
+        let mut config = FigureConfig::default();
+        config.set_font_paths(font_path.clone(), font_path.clone());
+        let mut graph = LineGraph::new("Line", "X", "Y", &config);
+        let mut dataset = LineGraphDataset::new([255, 0, 0], "Line", LineType::Solid);
+        dataset.add_points(&[(-10.0, -5.0), (0.0, 0.0), (10.0, 5.0)]);
+        graph.add_dataset(dataset);
+        let canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        let mut fonts = FontCache::new();
+
+        assert!(graph.handle_hover(358, 42, &canvas, &mut fonts).is_some());
+        std::fs::remove_file(&font_path).unwrap();
+
+        // Reading the font again would panic now that the file is gone
+        for frame in 0..100 {
+            assert!(graph
+                .handle_hover(358 - frame % 5, 42, &canvas, &mut fonts)
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn test_reused_hover_buffer_leaves_no_traces_of_previous_frames() {
+        let mut graph = LineGraph::new("Line", "X", "Y", &FigureConfig::default());
+        let mut dataset = LineGraphDataset::new([255, 0, 0], "Line", LineType::Solid);
+        dataset.add_points(&[(-10.0, -5.0), (0.0, 0.0), (10.0, 5.0)]);
+        graph.add_dataset(dataset);
+        let canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        let mut fonts = FontCache::new();
+        let mut out = HoverBuffer::new();
+
+        // Each frame reuses the buffer of the previous one, which must not leave traces. Only
+        // the reuse is checked here: `handle_hover` draws into a buffer of its own.
+        for (mouse_x, mouse_y) in [(358, 42), (45, 255), (200, 150)] {
+            let fresh = graph.handle_hover(mouse_x, mouse_y, &canvas, &mut fonts);
+            let hovered = graph.handle_hover_into(mouse_x, mouse_y, &canvas, &mut fonts, &mut out);
+
+            assert!(hovered);
+            assert_eq!(fresh.as_deref(), Some(out.pixels.as_slice()));
+        }
+    }
 }
@@ -1,35 +1,39 @@
 use ab_glyph::FontRef;
-use image::ImageBuffer;
 use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
 
 use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::areachart::AreaChart};
 
-use super::hover::Hover;
+use super::hover::{tooltip_position, FontCache, Hover, HoverBuffer};
 
 impl Hover for AreaChart {
-    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+    fn handle_hover_into(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+        out: &mut HoverBuffer,
+    ) -> bool {
         if let Some(((x, y), value)) = self.find_closest_point(mouse_x, mouse_y, canvas) {
-            let mut img =
-                ImageBuffer::from_raw(canvas.width, canvas.height, canvas.buffer.clone()).unwrap();
-
-            let font_path = self
-                .config
-                .font_label
-                .as_ref()
-                .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
+            let mut img = out.image(canvas);
+
+            let font = fonts.label_font(&self.config);
             let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
-            let coord_text = format!("({:.2}, {:.2}) = {:.2}", x, y, value);
-            let text_size = text_size(scale, &font, &coord_text).0 as i32;
+            let fmt = self.config.number_format;
+            let coord_text = format!(
+                "({}, {}) = {}",
+                fmt.format(x),
+                fmt.format(y),
+                fmt.format(value)
+            );
+            let text_size = text_size(scale, font, &coord_text).0 as i32;
 
-            let rect_x = mouse_x as i32 + 10;
-            let rect_y = mouse_y as i32 - 30;
             let rect_width = text_size + 20;
             let rect_height = 25;
 
-            let rect_x = rect_x.max(0).min((canvas.width as i32 - rect_width) as i32);
-            let rect_y = rect_y.max(0).min(canvas.height as i32 - rect_height);
+            // Keep the tooltip on the canvas, flipping it to the other side of the cursor if needed
+            let (rect_x, rect_y) =
+                tooltip_position(mouse_x, mouse_y, rect_width, rect_height, (10, 30), canvas);
 
             for y in rect_y..(rect_y + rect_height) {
                 for x in rect_x..(rect_x + rect_width) {
@@ -67,7 +71,7 @@ impl Hover for AreaChart {
                 rect_x + 10,
                 rect_y + 5,
                 scale,
-                &font,
+                font,
                 &coord_text,
             );
 
@@ -79,16 +83,10 @@ impl Hover for AreaChart {
                 image::Rgb([255, 0, 0]), // Line color
             );
 
-            return Some(
-                img.pixels()
-                    .map(|pixel: &image::Rgb<u8>| {
-                        let [r, g, b] = pixel.0;
-                        (r as u32) << 16 | (g as u32) << 8 | b as u32
-                    })
-                    .collect(),
-            );
+            out.finish();
+            return true;
         }
-        None
+        false
     }
 
     fn find_closest_point(
@@ -97,31 +95,54 @@ impl Hover for AreaChart {
         mouse_y: u32,
         canvas: &PixelCanvas,
     ) -> Option<((f64, f64), f64)> {
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (self.x_max - self.x_min);
-        let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (self.y_max - self.y_min);
+        // Use the same limits as the drawer so the hovered point matches the drawn area
+        let (x_min, x_max, y_min, y_max) = self.plot_limits();
+        let scale_x = canvas.plot_width() as f64 / (x_max - x_min);
+        let scale_y = canvas.plot_height() as f64 / (y_max - y_min);
 
+        // Stacked points sit on top of the layers below them
+        let layers = self.stacked.then(|| self.stacked_layers());
+
+        // Points outside fixed limits are not drawn, so they can't be hovered either
         self.datasets
             .iter()
-            .flat_map(|dataset| {
-                dataset.points.iter().map(move |&(x, y)| {
-                    let px = canvas.margin as f64 + (x - self.x_min) * scale_x;
-                    let py =
-                        canvas.height as f64 - canvas.margin as f64 - (y - self.y_min) * scale_y;
-                    let dist =
-                        ((mouse_x as f64 - px).powi(2) + (mouse_y as f64 - py).powi(2)).sqrt();
-                    ((x, y), dist)
-                })
+            .enumerate()
+            .filter(|(_, dataset)| dataset.visible)
+            .flat_map(|(index, dataset)| {
+                let layer = layers.as_ref().map(|layers| &layers[index]);
+                dataset
+                    .points
+                    .iter()
+                    .map(move |&(x, value)| {
+                        let y = layer
+                            .and_then(|layer| layer.iter().find(|&&(layer_x, _, _)| layer_x == x))
+                            .map_or(value, |&(_, _, top)| top);
+                        (x, y, value)
+                    })
+                    .filter(move |&(x, y, _)| {
+                        (x_min..=x_max).contains(&x) && (y_min..=y_max).contains(&y)
+                    })
+                    .map(move |(x, y, value)| {
+                        let px = canvas.margins.left as f64 + (x - x_min) * scale_x;
+                        let py =
+                            (canvas.height - canvas.margins.bottom) as f64 - (y - y_min) * scale_y;
+                        let dist =
+                            ((mouse_x as f64 - px).powi(2) + (mouse_y as f64 - py).powi(2)).sqrt();
+                        (((x, y), value), dist)
+                    })
             })
             .min_by(|&(_, d1), &(_, d2)| d1.partial_cmp(&d2).unwrap())
-            .map(|((x, y), _)| ((x, y), y))
+            .map(|(point, _)| point)
     }
 
     fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (self.x_max - self.x_min);
-        let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (self.y_max - self.y_min);
+        // Use the same limits as the drawer so the hovered point matches the drawn area
+        let (x_min, x_max, y_min, y_max) = self.plot_limits();
+        let scale_x = canvas.plot_width() as f64 / (x_max - x_min);
+        let scale_y = canvas.plot_height() as f64 / (y_max - y_min);
 
-        let px = ((x - self.x_min) * scale_x + canvas.margin as f64) as u32;
-        let py = (canvas.height as f64 - canvas.margin as f64 - (y - self.y_min) * scale_y) as u32;
+        let px = ((x - x_min) * scale_x + canvas.margins.left as f64) as u32;
+        let py = ((canvas.height - canvas.margins.bottom) as f64 - (y - y_min) * scale_y) as u32;
 
         (px, py)
     }
@@ -130,3 +151,32 @@ impl Hover for AreaChart {
         FontRef::try_from_slice(font_data).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::figure::{
+        canvas::pixelcanvas::PixelCanvas,
+        configuration::figureconfig::FigureConfig,
+        datasets::{areachartdataset::AreaChartDataset, dataset::Dataset},
+        display::hover::Hover,
+        figuretypes::areachart::AreaChart,
+    };
+
+    #[test]
+    fn test_hover_matches_drawn_area_points() {
+        let mut chart = AreaChart::new("Area", "X", "Y", FigureConfig::default());
+        let mut dataset = AreaChartDataset::new([255, 0, 0], "Area", 0.5);
+        dataset.add_point((0.0, 0.0));
+        dataset.add_point((5.0, 10.0));
+        dataset.add_point((10.0, 4.0));
+        chart.add_dataset(dataset);
+        let canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+
+        // The drawer maps x 0..10 and y 0..10 onto the 320 x 220 plot area
+        assert_eq!(chart.to_canvas_coordinates(5.0, 10.0, &canvas), (200, 40));
+        assert_eq!(chart.to_canvas_coordinates(10.0, 4.0, &canvas), (360, 172));
+
+        let closest = chart.find_closest_point(358, 170, &canvas);
+        assert_eq!(closest, Some(((10.0, 4.0), 4.0)));
+    }
+}
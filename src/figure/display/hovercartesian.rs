@@ -1,37 +1,43 @@
 use ab_glyph::FontRef;
-use image::ImageBuffer;
 use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
 
 use crate::figure::{
     canvas::pixelcanvas::PixelCanvas, figuretypes::cartesiangraph::CartesianGraph,
 };
 
-use super::hover::Hover;
+use super::hover::{
+    draw_crosshair, snap_to_x, tooltip_position, value_at, FontCache, Hover, HoverBuffer,
+};
 
 impl Hover for CartesianGraph {
-    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+    fn handle_hover_into(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+        out: &mut HoverBuffer,
+    ) -> bool {
         if let Some(((x, y), value)) = self.find_closest_point(mouse_x, mouse_y, canvas) {
-            let mut img =
-                ImageBuffer::from_raw(canvas.width, canvas.height, canvas.buffer.clone()).unwrap();
-
-            let font_path = self
-                .config
-                .font_label
-                .as_ref()
-                .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
+            let mut img = out.image(canvas);
+
+            let font = fonts.label_font(&self.config);
             let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
-            let coord_text = format!("({:.2}, {:.2}) = {:.2}", x, y, value);
-            let text_size = text_size(scale, &font, &coord_text).0 as i32;
+            let fmt = self.config.number_format;
+            let coord_text = format!(
+                "({}, {}) = {}",
+                fmt.format(x),
+                fmt.format(y),
+                fmt.format(value)
+            );
+            let text_size = text_size(scale, font, &coord_text).0 as i32;
 
-            let rect_x = mouse_x as i32 + 10;
-            let rect_y = mouse_y as i32 - 30;
             let rect_width = text_size + 20;
             let rect_height = 25;
 
-            let rect_x = rect_x.max(0).min((canvas.width as i32 - rect_width) as i32);
-            let rect_y = rect_y.max(0).min(canvas.height as i32 - rect_height);
+            // Keep the tooltip on the canvas, flipping it to the other side of the cursor if needed
+            let (rect_x, rect_y) =
+                tooltip_position(mouse_x, mouse_y, rect_width, rect_height, (10, 30), canvas);
 
             for y in rect_y..(rect_y + rect_height) {
                 for x in rect_x..(rect_x + rect_width) {
@@ -69,7 +75,7 @@ impl Hover for CartesianGraph {
                 rect_x + 10,
                 rect_y + 5,
                 scale,
-                &font,
+                font,
                 &coord_text,
             );
 
@@ -81,16 +87,53 @@ impl Hover for CartesianGraph {
                 image::Rgb([255, 0, 0]), // Line color
             );
 
-            return Some(
-                img.pixels()
-                    .map(|pixel: &image::Rgb<u8>| {
-                        let [r, g, b] = pixel.0;
-                        (r as u32) << 16 | (g as u32) << 8 | b as u32
-                    })
-                    .collect(),
-            );
+            out.finish();
+            return true;
         }
-        None
+        false
+    }
+
+    fn handle_crosshair_into(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+        out: &mut HoverBuffer,
+    ) -> bool {
+        let datasets: Vec<_> = self
+            .datasets
+            .iter()
+            .filter(|dataset| dataset.visible)
+            .collect();
+        let scale_x = canvas.plot_width() as f64 / (self.x_max - self.x_min);
+        let to_canvas_x = |x: f64| canvas.margins.left as f64 + (x - self.x_min) * scale_x;
+        let xs = datasets
+            .iter()
+            .flat_map(|dataset| self.visible_points(dataset).iter().map(|&(x, _)| x));
+        let Some(x) = snap_to_x(mouse_x, xs, to_canvas_x) else {
+            return false;
+        };
+
+        // One row for the x-value, then one per dataset that reaches it
+        let fmt = self.config.number_format;
+        let rows: Vec<(String, [u8; 3])> =
+            std::iter::once((format!("x = {}", fmt.format(x)), [0, 0, 0]))
+                .chain(datasets.iter().filter_map(|dataset| {
+                    let y = value_at(self.visible_points(dataset), x)?;
+                    Some((
+                        format!("{}: {}", dataset.label, fmt.format(y)),
+                        dataset.color,
+                    ))
+                }))
+                .collect();
+
+        let mut img = out.image(canvas);
+        let font = fonts.label_font(&self.config);
+        let guide = (to_canvas_x(x).round() as u32, mouse_y);
+        draw_crosshair(&mut img, canvas, guide, (mouse_x, mouse_y), &rows, font);
+        out.finish();
+        true
     }
 
     fn find_closest_point(
@@ -99,31 +142,39 @@ impl Hover for CartesianGraph {
         mouse_y: u32,
         canvas: &PixelCanvas,
     ) -> Option<((f64, f64), f64)> {
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (self.x_max - self.x_min);
-        let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (self.y_max - self.y_min);
+        let scale_x = canvas.plot_width() as f64 / (self.x_max - self.x_min);
+        let (y_lo, y_hi) = self.y_axis_range();
+        let scale_y = canvas.plot_height() as f64 / (y_hi - y_lo);
 
         self.datasets
             .iter()
+            .filter(|dataset| dataset.visible)
             .flat_map(|dataset| {
-                dataset.points.iter().map(move |&(x, y)| {
-                    let px = canvas.margin as f64 + (x - self.x_min) * scale_x;
-                    let py =
-                        canvas.height as f64 - canvas.margin as f64 - (y - self.y_min) * scale_y;
-                    let dist =
-                        ((mouse_x as f64 - px).powi(2) + (mouse_y as f64 - py).powi(2)).sqrt();
-                    ((x, y), dist)
-                })
+                // Points that can't be shown on the Y-axis can't be hovered either
+                self.visible_points(dataset)
+                    .iter()
+                    .filter_map(move |&(x, y)| {
+                        let px = canvas.margins.left as f64 + (x - self.x_min) * scale_x;
+                        let py = (canvas.height - canvas.margins.bottom) as f64
+                            - (self.y_scale.transform(y)? - y_lo) * scale_y;
+                        let dist =
+                            ((mouse_x as f64 - px).powi(2) + (mouse_y as f64 - py).powi(2)).sqrt();
+                        Some(((x, y), dist))
+                    })
             })
             .min_by(|&(_, d1), &(_, d2)| d1.partial_cmp(&d2).unwrap())
             .map(|((x, y), _)| ((x, y), y))
     }
 
     fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (self.x_max - self.x_min);
-        let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (self.y_max - self.y_min);
-
-        let px = ((x - self.x_min) * scale_x + canvas.margin as f64) as u32;
-        let py = (canvas.height as f64 - canvas.margin as f64 - (y - self.y_min) * scale_y) as u32;
+        let scale_x = canvas.plot_width() as f64 / (self.x_max - self.x_min);
+        let (y_lo, y_hi) = self.y_axis_range();
+        let scale_y = canvas.plot_height() as f64 / (y_hi - y_lo);
+        // Only points shown on the Y-axis are hovered, so `y` can always be transformed
+        let y = self.y_scale.transform(y).unwrap_or(y_lo);
+
+        let px = ((x - self.x_min) * scale_x + canvas.margins.left as f64) as u32;
+        let py = ((canvas.height - canvas.margins.bottom) as f64 - (y - y_lo) * scale_y) as u32;
 
         (px, py)
     }
@@ -1,177 +1,245 @@
 use ab_glyph::FontRef;
-use image::ImageBuffer;
-use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
+use image::Rgb;
+use imageproc::drawing::draw_line_segment_mut;
 
 use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::groupbarchart::GroupBarChart};
 
-use super::hover::Hover;
+use super::hover::{draw_tooltip_rows, FontCache, Hover, HoverBuffer};
 
 impl Hover for GroupBarChart {
-    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / self.datasets.len() as f64;
-
-        let mut img =
-            ImageBuffer::from_raw(canvas.width, canvas.height, canvas.buffer.clone()).unwrap();
-
-        let mut closest_bar_group: Option<(f64, Vec<f64>)> = None;
-        let mut min_distance = f64::MAX;
-
-        // Find the closest bar group
-        for (group_index, _) in self.datasets.iter().enumerate() {
-            let group_center_x = canvas.margin as f64 + (group_index as f64 + 0.5) * scale_x;
-            let distance = (mouse_x as f64 - group_center_x).abs();
-
-            if distance < min_distance {
-                min_distance = distance;
+    fn handle_hover_into(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+        out: &mut HoverBuffer,
+    ) -> bool {
+        let Some((key, group_center_x)) = self.hovered_group(mouse_x, canvas) else {
+            return false;
+        };
+        let mut img = out.image(canvas);
+
+        // Draw a red line from the center of the bar group to the cursor
+        draw_line_segment_mut(
+            &mut img,
+            (
+                group_center_x as f32,
+                (canvas.height - canvas.margins.bottom) as f32,
+            ),
+            (mouse_x as f32, mouse_y as f32),
+            Rgb([255, 0, 0]), // Red line
+        );
+
+        // One black row per line of the tooltip
+        let rows: Vec<(String, [u8; 3])> = self
+            .tooltip_lines(key)
+            .into_iter()
+            .map(|line| (line, [0, 0, 0]))
+            .collect();
+        let font = fonts.label_font(&self.config);
+        draw_tooltip_rows(&mut img, canvas, (mouse_x, mouse_y), &rows, font);
+
+        out.finish();
+        true
+    }
 
-                // Collect values for this group
-                let values = self
-                    .datasets
-                    .iter()
-                    .filter_map(|dataset| dataset.data.get(group_index).map(|&(_, value)| value))
-                    .collect::<Vec<f64>>();
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        let (key, group_center_x) = self.hovered_group(mouse_x, canvas)?;
+
+        // The bars of the hovered group, measured at the center of the group and their end
+        self.bar_segments(key)
+            .into_iter()
+            .map(|(_, _, end)| {
+                let (_, py) = self.to_canvas_coordinates(key as f64, end, canvas);
+                let distance = ((mouse_x as f64 - group_center_x).powi(2)
+                    + (mouse_y as f64 - py as f64).powi(2))
+                .sqrt();
+                ((key as f64, end), distance)
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+    }
 
-                closest_bar_group = Some((group_center_x, values));
-            }
-        }
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        // Categories sit in equally wide slots in key order, like the drawer lays them out
+        let categories = self.category_keys();
+        let group_index = categories.partition_point(|&key| (key as f64) < x);
+        let px = self.group_center_x(group_index, categories.len(), canvas);
+
+        // The value axis runs from zero at the bottom to the largest bar end at the top
+        let (_, y_max) = self.value_range();
+        let scale_y = if y_max > 0.0 {
+            canvas.plot_height() as f64 / y_max
+        } else {
+            0.0
+        };
+        let py = (canvas.height - canvas.margins.bottom) as f64 - y * scale_y;
 
-        if let Some((group_center_x, group_values)) = closest_bar_group {
-            // Draw a red line from the center of the bar group to the cursor
-            draw_line_segment_mut(
-                &mut img,
-                (
-                    group_center_x as f32,
-                    (canvas.height - canvas.margin) as f32,
-                ),
-                (mouse_x as f32, mouse_y as f32),
-                image::Rgb([255, 0, 0]), // Red line
-            );
+        (px.max(0.0) as u32, py.max(0.0) as u32)
+    }
 
-            // Format the tooltip text
-            let mut tooltip_text = String::from("X Value: ");
-
-            // Calculate tooltip dimensions
-            let font_path = self
-                .config
-                .font_label
-                .as_ref()
-                .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
-            let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
-            let text_size = text_size(scale, &font, &tooltip_text).0 as i32;
-
-            let rect_x = mouse_x as i32 + 10;
-            let rect_y = mouse_y as i32 - 30;
-            let rect_width = text_size + 20;
-            let rect_height = 25 * (group_values.len() as i32 + 1);
-
-            // Ensure tooltip stays within bounds
-            let rect_x = rect_x.max(0).min((canvas.width as i32 - rect_width) as i32);
-            let mut rect_y = rect_y.max(0).min(canvas.height as i32 - rect_height);
-
-            // Draw tooltip background
-            for y in rect_y..(rect_y + rect_height) {
-                for x in rect_x..(rect_x + rect_width) {
-                    if x >= 0 && y >= 0 && (x as u32) < canvas.width && (y as u32) < canvas.height {
-                        img.put_pixel(x as u32, y as u32, image::Rgb([255, 255, 255]));
-                    }
-                }
-            }
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
 
-            // Draw tooltip border
-            for x in rect_x..(rect_x + rect_width) {
-                img.put_pixel(x as u32, rect_y as u32, image::Rgb([0, 0, 0])); // Top edge
-                img.put_pixel(
-                    x as u32,
-                    (rect_y + rect_height - 1) as u32,
-                    image::Rgb([0, 0, 0]),
-                ); // Bottom edge
-            }
-            for y in rect_y..(rect_y + rect_height) {
-                img.put_pixel(rect_x as u32, y as u32, image::Rgb([0, 0, 0])); // Left edge
-                img.put_pixel(
-                    (rect_x + rect_width - 1) as u32,
-                    y as u32,
-                    image::Rgb([0, 0, 0]),
-                ); // Right edge
-            }
+impl GroupBarChart {
+    /// Finds the bar group closest to the mouse along the X-axis.
+    ///
+    /// The groups are laid out like the drawer does: one equally wide slot per unique
+    /// category, in key order.
+    ///
+    /// # Parameters
+    /// - `mouse_x`: The X-coordinate of the mouse in canvas space.
+    /// - `canvas`: The `PixelCanvas` the chart was drawn on.
+    ///
+    /// # Returns
+    /// The category key of the group and the canvas X-coordinate of its center, or `None` if
+    /// the chart has no categories.
+    fn hovered_group(&self, mouse_x: u32, canvas: &PixelCanvas) -> Option<(u32, f64)> {
+        let categories = self.category_keys();
+        let group_count = categories.len();
+
+        categories
+            .into_iter()
+            .enumerate()
+            .map(|(group_index, key)| (key, self.group_center_x(group_index, group_count, canvas)))
+            .min_by(|(_, a), (_, b)| {
+                (mouse_x as f64 - a)
+                    .abs()
+                    .total_cmp(&(mouse_x as f64 - b).abs())
+            })
+    }
 
-            for (dataset_index, value) in group_values.iter().enumerate() {
-                if dataset_index == 0 {
-                    tooltip_text.push_str(&format!("{:.2}", value));
-                } else {
-                    let dataset_label = &self.datasets[dataset_index].label;
-                    tooltip_text = format!("{} : {:.2}", dataset_label, value);
-                }
-
-                // Draw the tooltip text
-                draw_text_mut(
-                    &mut img,
-                    image::Rgb([0, 0, 0]), // Text color
-                    rect_x + 10,
-                    rect_y + 5,
-                    scale,
-                    &font,
-                    &tooltip_text,
-                );
-
-                rect_y += 20;
-            }
+    /// Returns the canvas X-coordinate of the center of a bar group.
+    ///
+    /// # Parameters
+    /// - `group_index`: The position of the group in key order.
+    /// - `group_count`: The number of groups of the chart.
+    /// - `canvas`: The `PixelCanvas` the chart was drawn on.
+    fn group_center_x(&self, group_index: usize, group_count: usize, canvas: &PixelCanvas) -> f64 {
+        let scale_x = canvas.plot_width() as f64 / group_count.max(1) as f64;
+        canvas.margins.left as f64 + (group_index as f64 + 0.5) * scale_x
+    }
 
-            Some(
-                img.pixels()
-                    .map(|pixel: &image::Rgb<u8>| {
-                        let [r, g, b] = pixel.0;
-                        (r as u32) << 16 | (g as u32) << 8 | b as u32
-                    })
-                    .collect(),
+    /// Returns the lines of the tooltip for a bar group.
+    ///
+    /// # Parameters
+    /// - `key`: The category key of the group.
+    ///
+    /// # Returns
+    /// The category label, then one `label: value` line per visible dataset with a value in
+    /// the category, in dataset order.
+    fn tooltip_lines(&self, key: u32) -> Vec<String> {
+        let fmt = self.config.number_format;
+        std::iter::once(self.category_label(key))
+            .chain(
+                self.datasets
+                    .iter()
+                    .filter(|dataset| dataset.visible)
+                    .filter_map(|dataset| {
+                        let &(_, value) = dataset.data.iter().find(|(x, _)| *x as u32 == key)?;
+                        Some(format!("{}: {}", dataset.label, fmt.format(value)))
+                    }),
             )
-        } else {
-            None
-        }
+            .collect()
     }
+}
 
-    fn find_closest_point(
-        &self,
-        mouse_x: u32,
-        mouse_y: u32,
-        canvas: &PixelCanvas,
-    ) -> Option<((f64, f64), f64)> {
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / self.datasets.len() as f64;
-        let scale_y = (canvas.height - 2 * canvas.margin) as f64 / 10.0; // Assume a fixed y-range for now
-
-        let mut closest_bar = None;
-        let mut min_distance = f64::MAX;
-
-        for dataset in &self.datasets {
-            for &(x, y) in &dataset.data {
-                let px = (x) * scale_x + canvas.margin as f64;
-                let py = canvas.height as f64 - canvas.margin as f64 - y * scale_y;
-
-                let distance =
-                    ((mouse_x as f64 - px).powi(2) + (mouse_y as f64 - py).powi(2)).sqrt();
-                if distance < min_distance {
-                    min_distance = distance;
-                    closest_bar = Some(((x, y), y));
-                }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::canvas::testing::pixel_at;
+    use crate::figure::{
+        configuration::figureconfig::FigureConfig, datasets::bardataset::BarDataset,
+        drawers::drawer::Drawer, utilities::orientation::Orientation,
+    };
+
+    const COLORS: [[u8; 3]; 3] = [[255, 0, 0], [0, 255, 0], [0, 0, 255]];
+
+    /// Returns a chart of three datasets over the categories 1 to 5, drawn on a 600x400 canvas
+    /// with 50 pixel margins. Dataset `i` has the value `2 + i` in every category.
+    fn drawn_chart() -> (GroupBarChart, PixelCanvas) {
+        let mut chart = GroupBarChart::new(
+            "Groups",
+            "X",
+            "Y",
+            Orientation::Vertical,
+            FigureConfig::default(),
+        );
+        for (index, color) in COLORS.iter().enumerate() {
+            let mut dataset = BarDataset::new(&format!("Series {}", index), *color);
+            for key in 1..=5 {
+                dataset.add_data(key as f64, 2.0 + index as f64);
             }
+            chart.add_dataset(dataset);
         }
-
-        closest_bar
+        let mut canvas = PixelCanvas::new(600, 400, [255, 255, 255], 50);
+        chart.draw(&mut canvas);
+        (chart, canvas)
     }
 
-    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / self.datasets.len() as f64;
-        let scale_y = (canvas.height - 2 * canvas.margin) as f64 / 10.0; // Assume a fixed y-range for now
-
-        let px = ((x) * scale_x + canvas.margin as f64) as u32;
-        let py = (canvas.height as f64 - canvas.margin as f64 - y * scale_y) as u32;
+    #[test]
+    fn test_hovered_group_matches_bar_group_under_cursor() {
+        let (chart, canvas) = drawn_chart();
+
+        // The 500 pixel wide plot area holds 5 groups of 100 pixels, not 3 of 166
+        for group in 0..5u32 {
+            let mouse_x = 50 + group * 100 + 50;
+            assert_eq!(pixel_at(&canvas, mouse_x, 348), COLORS[1]);
+            assert_eq!(
+                chart.hovered_group(mouse_x, &canvas),
+                Some((group + 1, mouse_x as f64))
+            );
+        }
+    }
 
-        (px, py)
+    #[test]
+    fn test_closest_bar_is_found_on_the_drawn_value_scale() {
+        let (chart, canvas) = drawn_chart();
+
+        // The middle bar of the third group has the value 3 of the 0 to 4 value axis
+        let (px, py) = chart.to_canvas_coordinates(3.0, 3.0, &canvas);
+        assert_eq!((px, py), (300, 125));
+        assert_eq!(pixel_at(&canvas, px, py + 2), COLORS[1]);
+        assert_ne!(pixel_at(&canvas, px, py - 2), COLORS[1]);
+
+        assert_eq!(
+            chart.find_closest_point(300, 130, &canvas),
+            Some(((3.0, 3.0), 5.0))
+        );
+        assert_eq!(
+            chart.find_closest_point(300, 60, &canvas).unwrap().0,
+            (3.0, 4.0)
+        );
     }
 
-    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
-        FontRef::try_from_slice(font_data).unwrap()
+    #[test]
+    fn test_tooltip_has_one_line_per_dataset() {
+        let mut chart = GroupBarChart::new(
+            "Groups",
+            "X",
+            "Y",
+            Orientation::Vertical,
+            FigureConfig::default(),
+        );
+        for (label, value) in [("North", 1.5), ("South", 2.0), ("West", -3.0)] {
+            let mut dataset = BarDataset::new(label, [0, 0, 0]);
+            dataset.add_data(1.0, value);
+            dataset.add_data(2.0, value * 2.0);
+            chart.add_dataset(dataset);
+        }
+        chart.datasets[2].data.remove(1);
+
+        let lines = chart.tooltip_lines(1);
+        assert_eq!(lines.len(), 1 + chart.datasets.len());
+        assert_eq!(&lines[1..], ["North: 1.50", "South: 2", "West: -3"]);
+        // Datasets without a value in the category get no line
+        assert_eq!(chart.tooltip_lines(2).len(), 3);
     }
 }
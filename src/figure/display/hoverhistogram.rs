@@ -1,10 +1,9 @@
 use ab_glyph::FontRef;
-use image::ImageBuffer;
 use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
 
 use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::histogram::Histogram};
 
-use super::hover::Hover;
+use super::hover::{tooltip_position, FontCache, Hover, HoverBuffer};
 
 impl Hover for Histogram {
     fn find_closest_point(
@@ -13,17 +12,16 @@ impl Hover for Histogram {
         _mouse_y: u32,
         canvas: &PixelCanvas,
     ) -> Option<((f64, f64), f64)> {
-        let bin_data = self.calculate_bins();
-
-        let bin_width = (bin_data[1].0 - bin_data[0].0).abs();
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / self.bins as f64;
+        let edges = self.edges();
+        let offsets = self.edge_offsets(&edges, canvas.plot_width() as f64);
 
         let mut closest_bin = None;
         let mut min_distance = f64::MAX;
 
-        for (i, &(bin_start, freq)) in bin_data.iter().enumerate() {
-            let bin_end = bin_start + bin_width; // End of the bin
-            let bin_x = canvas.margin as f64 + i as f64 * scale_x;
+        let bin_data = self.calculate_bins();
+        for ((bin, &(_, freq)), offset) in edges.windows(2).zip(&bin_data).zip(offsets) {
+            let (bin_start, bin_end) = (bin[0], bin[1]);
+            let bin_x = canvas.margins.left as f64 + offset;
 
             let distance = (mouse_x as f64 - bin_x).abs(); // Distance to mouse x
             if distance < min_distance {
@@ -37,51 +35,55 @@ impl Hover for Histogram {
 
     fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
         let bin_data = self.calculate_bins();
-        let bin_width = (bin_data[1].0 - bin_data[0].0).abs();
-        let x_min = bin_data[0].0; // Start of the first bin
-        let x_max = x_min + bin_width * self.bins as f64;
+        let edges = self.edges();
+        let x_min = edges[0]; // Start of the first bin
+        let x_max = edges[edges.len() - 1]; // End of the last bin
 
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (x_max - x_min);
-        let scale_y = (canvas.height - 2 * canvas.margin) as f64
-            / bin_data.iter().map(|(_, f)| *f).fold(0.0, f64::max);
+        let scale_x = canvas.plot_width() as f64 / (x_max - x_min);
+        let scale_y =
+            canvas.plot_height() as f64 / bin_data.iter().map(|(_, f)| *f).fold(0.0, f64::max);
 
-        let px = ((x - x_min) * scale_x + canvas.margin as f64) as u32;
-        let py = (canvas.height as f64 - canvas.margin as f64 - y * scale_y) as u32;
+        let px = ((x - x_min) * scale_x + canvas.margins.left as f64) as u32;
+        let py = ((canvas.height - canvas.margins.bottom) as f64 - y * scale_y) as u32;
 
         (px, py)
     }
 
     /// Handle hover functionality and return updated buffer if applicable
-    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
+    fn handle_hover_into(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+        out: &mut HoverBuffer,
+    ) -> bool {
         // Find the closest bin's range and total value
         if let Some(((bin_start, bin_end), freq)) =
             self.find_closest_point(mouse_x, mouse_y, canvas)
         {
-            let mut img =
-                ImageBuffer::from_raw(canvas.width, canvas.height, canvas.buffer.clone()).unwrap();
+            let mut img = out.image(canvas);
 
             // Format the text as: ([x-start, x-end], total: y-value)
-            let bin_info = format!("([{:.2}, {:.2}], total: {:.2})", bin_start, bin_end, freq);
+            let fmt = self.config.number_format;
+            let bin_info = format!(
+                "([{}, {}], total: {})",
+                fmt.format(bin_start),
+                fmt.format(bin_end),
+                fmt.format(freq)
+            );
 
             // Calculate text size for background rectangle
-            let font_path = self
-                .config
-                .font_label
-                .as_ref()
-                .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
+            let font = fonts.label_font(&self.config);
             let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
-            let text_size = text_size(scale, &font, &bin_info).0 as i32;
+            let text_size = text_size(scale, font, &bin_info).0 as i32;
 
-            let rect_x = mouse_x as i32 + 15; // Rectangle start X, slightly offset from mouse
-            let rect_y = mouse_y as i32 - 20; // Rectangle start Y
             let rect_width = text_size + 20; // Add padding for rectangle width
             let rect_height = 25; // Height of the rectangle
 
-            // Adjust rectangle coordinates to stay within canvas bounds
-            let rect_x = rect_x.max(0).min((canvas.width as i32 - rect_width) as i32);
-            let rect_y = rect_y.max(0).min(canvas.height as i32 - rect_height);
+            // Keep the tooltip on the canvas, flipping it to the other side of the cursor if needed
+            let (rect_x, rect_y) =
+                tooltip_position(mouse_x, mouse_y, rect_width, rect_height, (15, 20), canvas);
 
             // Draw white rectangle as background
             for y in rect_y..(rect_y + rect_height) {
@@ -122,7 +124,7 @@ impl Hover for Histogram {
                 rect_x + 10,           // Add padding to position text
                 rect_y + 5,
                 scale,
-                &font,
+                font,
                 &bin_info,
             );
 
@@ -139,16 +141,10 @@ impl Hover for Histogram {
                 );
             }
 
-            return Some(
-                img.pixels()
-                    .map(|pixel: &image::Rgb<u8>| {
-                        let [r, g, b] = pixel.0;
-                        (r as u32) << 16 | (g as u32) << 8 | b as u32
-                    })
-                    .collect(),
-            );
+            out.finish();
+            return true;
         }
-        None
+        false
     }
 
     fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
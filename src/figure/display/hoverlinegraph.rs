@@ -0,0 +1,232 @@
+use ab_glyph::FontRef;
+use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
+
+use crate::figure::{
+    canvas::pixelcanvas::PixelCanvas, datasets::linegraphdataset::LineGraphDataset,
+    figuretypes::linegraph::LineGraph,
+};
+
+use super::hover::{
+    draw_crosshair, snap_to_x, tooltip_position, value_at, FontCache, Hover, HoverBuffer,
+};
+
+impl Hover for LineGraph {
+    fn handle_hover_into(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+        out: &mut HoverBuffer,
+    ) -> bool {
+        if let Some((dataset, (x, y))) = self.closest_point(mouse_x, mouse_y, canvas) {
+            let mut img = out.image(canvas);
+
+            let font = fonts.label_font(&self.config);
+            let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
+            let fmt = self.config.number_format;
+            let coord_text = format!("{}: ({}, {})", dataset.label, fmt.format(x), fmt.format(y));
+            let text_size = text_size(scale, font, &coord_text).0 as i32;
+
+            let rect_width = text_size + 20;
+            let rect_height = 25;
+
+            // Keep the tooltip on the canvas, flipping it to the other side of the cursor if needed
+            let (rect_x, rect_y) =
+                tooltip_position(mouse_x, mouse_y, rect_width, rect_height, (10, 30), canvas);
+
+            for y in rect_y..(rect_y + rect_height) {
+                for x in rect_x..(rect_x + rect_width) {
+                    if x >= 0 && y >= 0 && (x as u32) < canvas.width && (y as u32) < canvas.height {
+                        img.put_pixel(x as u32, y as u32, image::Rgb([255, 255, 255]));
+                    }
+                }
+            }
+
+            for x in rect_x..(rect_x + rect_width) {
+                if rect_y >= 0 && (x as u32) < canvas.width {
+                    img.put_pixel(x as u32, rect_y as u32, image::Rgb([0, 0, 0]));
+                    img.put_pixel(
+                        x as u32,
+                        (rect_y + rect_height - 1) as u32,
+                        image::Rgb([0, 0, 0]),
+                    );
+                }
+            }
+
+            for y in rect_y..(rect_y + rect_height) {
+                if rect_x >= 0 && (y as u32) < canvas.height {
+                    img.put_pixel(rect_x as u32, y as u32, image::Rgb([0, 0, 0]));
+                    img.put_pixel(
+                        (rect_x + rect_width - 1) as u32,
+                        y as u32,
+                        image::Rgb([0, 0, 0]),
+                    );
+                }
+            }
+
+            draw_text_mut(
+                &mut img,
+                image::Rgb([0, 0, 0]),
+                rect_x + 10,
+                rect_y + 5,
+                scale,
+                font,
+                &coord_text,
+            );
+
+            let (px, py) = self.to_canvas_coordinates(x, y, canvas);
+            draw_line_segment_mut(
+                &mut img,
+                (px as f32, py as f32),
+                (mouse_x as f32, mouse_y as f32),
+                image::Rgb([255, 0, 0]), // Line color
+            );
+
+            out.finish();
+            return true;
+        }
+        false
+    }
+
+    fn handle_crosshair_into(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+        out: &mut HoverBuffer,
+    ) -> bool {
+        let datasets: Vec<_> = self
+            .datasets
+            .iter()
+            .filter(|dataset| dataset.visible)
+            .collect();
+        let to_canvas_x = |x: f64| self.project(x, 0.0, canvas).0;
+        let xs = datasets
+            .iter()
+            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x));
+        let Some(x) = snap_to_x(mouse_x, xs, to_canvas_x) else {
+            return false;
+        };
+
+        // One row for the x-value, then one per dataset that reaches it
+        let fmt = self.config.number_format;
+        let rows: Vec<(String, [u8; 3])> =
+            std::iter::once((format!("x = {}", fmt.format(x)), [0, 0, 0]))
+                .chain(datasets.iter().filter_map(|dataset| {
+                    let y = value_at(&dataset.points, x)?;
+                    Some((
+                        format!("{}: {}", dataset.label, fmt.format(y)),
+                        dataset.color,
+                    ))
+                }))
+                .collect();
+
+        let mut img = out.image(canvas);
+        let font = fonts.label_font(&self.config);
+        let guide = (to_canvas_x(x).round() as u32, mouse_y);
+        draw_crosshair(&mut img, canvas, guide, (mouse_x, mouse_y), &rows, font);
+        out.finish();
+        true
+    }
+
+    fn find_closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<((f64, f64), f64)> {
+        self.closest_point(mouse_x, mouse_y, canvas)
+            .map(|(_, (x, y))| ((x, y), y))
+    }
+
+    fn to_canvas_coordinates(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (u32, u32) {
+        let (px, py) = self.project(x, y, canvas);
+        (px as u32, py as u32)
+    }
+
+    fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
+        FontRef::try_from_slice(font_data).unwrap()
+    }
+}
+
+impl LineGraph {
+    /// Finds the data point closest to the mouse position, together with its dataset.
+    ///
+    /// # Parameters
+    /// - `mouse_x`, `mouse_y`: The mouse position on the canvas.
+    /// - `canvas`: The `PixelCanvas` the graph was drawn on.
+    ///
+    /// # Returns
+    /// The dataset and the `(x, y)` data coordinates of the closest point, or `None` if the
+    /// graph has no points.
+    fn closest_point(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+    ) -> Option<(&LineGraphDataset, (f64, f64))> {
+        self.datasets
+            .iter()
+            .filter(|dataset| dataset.visible)
+            .flat_map(|dataset| {
+                dataset.points.iter().map(move |&(x, y)| {
+                    let (px, py) = self.project(x, y, canvas);
+                    let dist =
+                        ((mouse_x as f64 - px).powi(2) + (mouse_y as f64 - py).powi(2)).sqrt();
+                    ((dataset, (x, y)), dist)
+                })
+            })
+            .min_by(|&(_, d1), &(_, d2)| d1.partial_cmp(&d2).unwrap())
+            .map(|(point, _)| point)
+    }
+
+    /// Maps data coordinates to canvas coordinates the same way the drawer does.
+    ///
+    /// The drawer centers the origin and widens the X range to be symmetric around zero, so
+    /// the same is done here, whether or not the graph has been drawn yet.
+    fn project(&self, x: f64, y: f64, canvas: &PixelCanvas) -> (f64, f64) {
+        let x_extent = self.x_min.abs().max(self.x_max.abs());
+        let scale_x = canvas.plot_width() as f64 / (2.0 * x_extent);
+        let scale_y = canvas.plot_height() as f64 / (self.y_max - self.y_min);
+
+        let (left, top, right, bottom) = canvas.plot_bounds();
+        let px = (left + (right - left) / 2) as f64 + x * scale_x;
+        let py = (top + (bottom - top) / 2) as f64 - y * scale_y;
+        (px, py)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::figure::{
+        canvas::pixelcanvas::PixelCanvas,
+        configuration::figureconfig::FigureConfig,
+        datasets::{dataset::Dataset, linegraphdataset::LineGraphDataset},
+        display::hover::{FontCache, Hover},
+        figuretypes::linegraph::LineGraph,
+        utilities::linetype::LineType,
+    };
+
+    #[test]
+    fn test_hover_matches_drawn_line_points() {
+        let mut graph = LineGraph::new("Line", "X", "Y", &FigureConfig::default());
+        let mut dataset = LineGraphDataset::new([255, 0, 0], "Line", LineType::Solid);
+        dataset.add_point((-10.0, -5.0));
+        dataset.add_point((0.0, 0.0));
+        dataset.add_point((10.0, 5.0));
+        graph.add_dataset(dataset);
+        let canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+
+        // The drawer maps x -10..10 and y -5..5 onto the 320 x 220 plot area around the center
+        assert_eq!(graph.to_canvas_coordinates(0.0, 0.0, &canvas), (200, 150));
+        assert_eq!(graph.to_canvas_coordinates(10.0, 5.0, &canvas), (360, 40));
+
+        let closest = graph.find_closest_point(358, 42, &canvas);
+        assert_eq!(closest, Some(((10.0, 5.0), 5.0)));
+        assert!(graph
+            .handle_hover(358, 42, &canvas, &mut FontCache::new())
+            .is_some());
+    }
+}
@@ -1,10 +1,9 @@
 use ab_glyph::FontRef;
-use image::ImageBuffer;
 use imageproc::drawing::{draw_line_segment_mut, draw_text_mut, text_size};
 
 use crate::figure::{canvas::pixelcanvas::PixelCanvas, figuretypes::piechart::PieChart};
 
-use super::hover::Hover;
+use super::hover::{tooltip_position, FontCache, Hover, HoverBuffer};
 
 impl Hover for PieChart {
     fn find_closest_point(
@@ -15,26 +14,23 @@ impl Hover for PieChart {
     ) -> Option<((f64, f64), f64)> {
         let center_x = canvas.width as f64 / 2.0;
         let center_y = canvas.height as f64 / 2.0;
-        let total_value: f64 = self.datasets.iter().map(|(_, value, _)| *value).sum();
-        let mut start_angle = 0.0;
 
+        // Angles are counterclockwise from 3 o'clock with y pointing up, as in the drawers
         let dx = mouse_x as f64 - center_x;
-        let dy = mouse_y as f64 - center_y;
-        let mut angle = dy.atan2(dx);
-        if angle < 0.0 {
-            angle += 2.0 * std::f64::consts::PI;
-        }
+        let dy = center_y - mouse_y as f64;
+        let angle = dy.atan2(dx);
 
-        for (_, value, _) in &self.datasets {
-            let sweep_angle = (value / total_value) * 2.0 * std::f64::consts::PI;
+        for ((_, value, _), (start_angle, end_angle)) in
+            self.datasets.iter().zip(self.slice_angles())
+        {
+            let sweep_angle = end_angle - start_angle;
             let mid_angle = start_angle + sweep_angle / 2.0;
 
-            if angle >= start_angle && angle < start_angle + sweep_angle {
+            if (angle - start_angle).rem_euclid(2.0 * std::f64::consts::PI) < sweep_angle {
                 let x = center_x + mid_angle.cos() * (canvas.width as f64 / 4.0);
-                let y = center_y + mid_angle.sin() * (canvas.height as f64 / 4.0);
+                let y = center_y - mid_angle.sin() * (canvas.height as f64 / 4.0);
                 return Some(((x, y), *value));
             }
-            start_angle += sweep_angle;
         }
 
         None
@@ -44,9 +40,15 @@ impl Hover for PieChart {
         (x as u32, y as u32)
     }
 
-    fn handle_hover(&self, mouse_x: u32, mouse_y: u32, canvas: &PixelCanvas) -> Option<Vec<u32>> {
-        let mut img =
-            ImageBuffer::from_raw(canvas.width, canvas.height, canvas.buffer.clone()).unwrap();
+    fn handle_hover_into(
+        &self,
+        mouse_x: u32,
+        mouse_y: u32,
+        canvas: &PixelCanvas,
+        fonts: &mut FontCache,
+        out: &mut HoverBuffer,
+    ) -> bool {
+        let mut img = out.image(canvas);
 
         if let Some(((x, y), value)) = self.find_closest_point(mouse_x, mouse_y, canvas) {
             // Draw the line from slice center to cursor
@@ -58,25 +60,22 @@ impl Hover for PieChart {
             );
 
             // Draw an edged rectangle for the hover information
-            let font_path = self
-                .config
-                .font_label
-                .as_ref()
-                .expect("Font path is not set");
-            let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-            let font = FontRef::try_from_slice(&font_bytes).unwrap();
+            let font = fonts.label_font(&self.config);
 
             let scale = ab_glyph::PxScale { x: 12.0, y: 12.0 };
-            let coord_text = format!("{}: {:.2}", self.title, value);
-            let text_size = text_size(scale, &font, &coord_text).0 as i32;
+            let coord_text = format!(
+                "{}: {}",
+                self.title,
+                self.config.number_format.format(value)
+            );
+            let text_size = text_size(scale, font, &coord_text).0 as i32;
 
-            let rect_x = mouse_x as i32 + 10;
-            let rect_y = mouse_y as i32 - 30;
             let rect_width = text_size + 20;
             let rect_height = 25;
 
-            let rect_x = rect_x.max(0).min((canvas.width as i32 - rect_width) as i32);
-            let rect_y = rect_y.max(0).min(canvas.height as i32 - rect_height);
+            // Keep the tooltip on the canvas, flipping it to the other side of the cursor if needed
+            let (rect_x, rect_y) =
+                tooltip_position(mouse_x, mouse_y, rect_width, rect_height, (10, 30), canvas);
 
             for y in rect_y..(rect_y + rect_height) {
                 for x in rect_x..(rect_x + rect_width) {
@@ -114,19 +113,13 @@ impl Hover for PieChart {
                 rect_x + 10,
                 rect_y + 5,
                 scale,
-                &font,
+                font,
                 &coord_text,
             );
         }
 
-        Some(
-            img.pixels()
-                .map(|pixel: &image::Rgb<u8>| {
-                    let [r, g, b] = pixel.0;
-                    (r as u32) << 16 | (g as u32) << 8 | b as u32
-                })
-                .collect(),
-        )
+        out.finish();
+        true
     }
 
     fn get_font<'a>(&self, font_data: &'a [u8]) -> FontRef<'a> {
@@ -1,14 +1,24 @@
-use minifb::{Key, MouseMode, Window, WindowOptions};
+use ab_glyph::{FontArc, PxScale};
+use image::{
+    error::{ParameterError, ParameterErrorKind},
+    Rgb, RgbImage,
+};
+use imageproc::{
+    drawing::{draw_hollow_rect_mut, draw_text_mut, text_size},
+    rect::Rect,
+};
+use minifb::{Key, MouseButton, MouseMode, Window, WindowOptions};
 use resvg::{
     tiny_skia::{self, Pixmap},
     usvg::{self, fontdb},
 };
-use std::time::{Duration, Instant};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use super::hover::Hover;
+use super::hover::{FontCache, Hover, HoverBuffer};
 use crate::figure::{
     canvas::pixelcanvas::PixelCanvas, configuration::figureconfig::FigureConfig,
-    drawers::drawer::Drawer,
+    drawers::drawer::Drawer, utilities::hovermode::HoverMode,
 };
 
 /// A utility struct for managing window operations and displaying graphics interactively.
@@ -43,9 +53,7 @@ impl Winop {
         // Initialize a font database.
         let mut fontdb = fontdb::Database::new();
         fontdb.load_system_fonts();
-        if let Ok(_) = &figure_config.validate() {
-            fontdb.load_font_data(figure_config.font_label.clone().unwrap().into_bytes());
-        }
+        fontdb.load_font_data(figure_config.label_font_bytes().into_owned());
 
         // Parse the SVG content.
         let mut opt = usvg::Options::default();
@@ -133,31 +141,26 @@ impl Winop {
 
         let mut hover_enabled = false;
         let mut show_hints = false;
+        let mut fonts = FontCache::new();
+        let mut hover_buffer = HoverBuffer::new();
 
         while window.is_open() && !window.is_key_pressed(Key::Escape, minifb::KeyRepeat::No) {
             // Update data for real-time rendering.
             if last_frame_time.elapsed() >= frame_duration {
                 update_data(plot);
-                plot.draw(canvas);
+                plot.draw_incremental(canvas);
                 last_frame_time = Instant::now();
             }
 
-            // Render the canvas to a buffer.
-            let mut buffer: Vec<u32> = Self::canvas_to_buffer(canvas);
-
-            if hover_enabled {
-                if let Some(mouse_pos) = window.get_mouse_pos(MouseMode::Pass) {
-                    let (mouse_x, mouse_y) = (mouse_pos.0 as u32, mouse_pos.1 as u32);
-
-                    if let Some(updated_buffer) = plot.handle_hover(mouse_x, mouse_y, canvas) {
-                        buffer = updated_buffer;
-                    }
-                }
-            }
-
-            if show_hints {
-                Self::render_hints(canvas);
-            }
+            let hovered = hover_enabled
+                && Self::hover(
+                    &window,
+                    canvas,
+                    plot,
+                    HoverMode::Nearest,
+                    &mut fonts,
+                    &mut hover_buffer,
+                );
 
             if window.is_key_pressed(Key::C, minifb::KeyRepeat::No) {
                 hover_enabled = !hover_enabled;
@@ -167,10 +170,70 @@ impl Winop {
                 show_hints = !show_hints;
             }
 
-            window.update_with_buffer(&buffer, width, height).unwrap();
+            // Show the hovered frame, or the canvas itself, with the hints on top
+            let mut buffer;
+            let frame = if hovered {
+                &mut hover_buffer.pixels
+            } else {
+                buffer = Self::canvas_to_buffer(canvas);
+                &mut buffer
+            };
+            if window.is_key_pressed(Key::S, minifb::KeyRepeat::No) {
+                Self::save_frame(frame, canvas);
+            }
+            if show_hints {
+                let font = fonts.label_font(plot.get_figure_config());
+                Self::render_hints(frame, width, height, font);
+            }
+            window.update_with_buffer(frame, width, height).unwrap();
         }
     }
 
+    /// Draws the hover elements of `hover_mode` for the current mouse position into
+    /// `hover_buffer`.
+    ///
+    /// # Returns
+    /// `true` if `hover_buffer` holds the frame to show, `false` if the mouse is outside the
+    /// window or nothing is hovered.
+    fn hover<T: Hover>(
+        window: &Window,
+        canvas: &PixelCanvas,
+        plot: &T,
+        hover_mode: HoverMode,
+        fonts: &mut FontCache,
+        hover_buffer: &mut HoverBuffer,
+    ) -> bool {
+        window
+            .get_mouse_pos(MouseMode::Pass)
+            .is_some_and(|(mouse_x, mouse_y)| {
+                let (mouse_x, mouse_y) = (mouse_x as u32, mouse_y as u32);
+                match hover_mode {
+                    HoverMode::Nearest => {
+                        plot.handle_hover_into(mouse_x, mouse_y, canvas, fonts, hover_buffer)
+                    }
+                    HoverMode::Crosshair => {
+                        plot.handle_crosshair_into(mouse_x, mouse_y, canvas, fonts, hover_buffer)
+                    }
+                }
+            })
+    }
+
+    /// Toggles the dataset whose legend entry lies at a canvas position.
+    ///
+    /// # Parameters
+    /// - `plot`: The plot whose legend was clicked.
+    /// - `canvas`: The `PixelCanvas` the plot was drawn on.
+    /// - `x`, `y`: The clicked canvas position.
+    ///
+    /// # Returns
+    /// `true` if a dataset was shown or hidden and the plot needs to be redrawn.
+    fn toggle_dataset_at<T: Drawer>(plot: &mut T, canvas: &PixelCanvas, x: u32, y: u32) -> bool {
+        plot.legend_entries(canvas)
+            .iter()
+            .position(|entry| entry.contains(x, y))
+            .is_some_and(|index| plot.toggle_dataset(index))
+    }
+
     /// Converts the canvas buffer into a format compatible with minifb.
     ///
     /// # Parameters
@@ -191,16 +254,40 @@ impl Winop {
             .collect()
     }
 
+    /// Converts a frame in the `0RGB` format used by minifb back into an RGB canvas buffer.
+    ///
+    /// This is the inverse of [`Winop::canvas_to_buffer`]; the unused top byte is dropped.
+    ///
+    /// # Parameters
+    /// - `frame`: The frame pixels, one `u32` per pixel.
+    ///
+    /// # Returns
+    /// The pixels as consecutive `[r, g, b]` bytes.
+    fn buffer_to_rgb(frame: &[u32]) -> Vec<u8> {
+        frame
+            .iter()
+            .flat_map(|&pixel| [(pixel >> 16) as u8, (pixel >> 8) as u8, pixel as u8])
+            .collect()
+    }
+
     /// Displays a plot in an interactive window with hover functionality.
     ///
+    /// Clicking a legend entry hides its dataset, clicking it again shows it.
+    ///
     /// # Parameters
     /// - `canvas`: The `PixelCanvas` to draw on.
-    /// - `plot`: The plot to be rendered, implementing `Hover`.
+    /// - `plot`: The plot to be rendered, implementing `Hover` and `Drawer`.
     /// - `title`: The title of the window.
+    /// - `hover_mode`: What the hover shows once enabled with the `C` key.
     ///
     /// # Panics
     /// - If the window cannot be created.
-    pub fn display_interactive<T: Hover>(canvas: &mut PixelCanvas, plot: &T, title: &str) {
+    pub fn display_interactive<T: Hover + Drawer>(
+        canvas: &mut PixelCanvas,
+        plot: &mut T,
+        title: &str,
+        hover_mode: HoverMode,
+    ) {
         let width = canvas.width as usize;
         let height = canvas.height as usize;
 
@@ -218,24 +305,31 @@ impl Winop {
 
         let mut hover_enabled = false;
         let mut show_hints = false;
+        let mut fonts = FontCache::new();
+        let mut hover_buffer = HoverBuffer::new();
+        let mut mouse_was_down = false;
 
         while window.is_open() && !window.is_key_pressed(Key::Escape, minifb::KeyRepeat::No) {
-            // Render the canvas to a buffer.
-            let mut buffer: Vec<u32> = Self::canvas_to_buffer(canvas);
-
-            if show_hints {
-                Self::render_hints(canvas);
-            }
-
-            if hover_enabled {
-                if let Some(mouse_pos) = window.get_mouse_pos(MouseMode::Pass) {
-                    let (mouse_x, mouse_y) = (mouse_pos.0 as u32, mouse_pos.1 as u32);
-
-                    if let Some(updated_buffer) = plot.handle_hover(mouse_x, mouse_y, canvas) {
-                        buffer = updated_buffer;
+            // Toggle the dataset of a clicked legend entry once per click
+            let mouse_down = window.get_mouse_down(MouseButton::Left);
+            if mouse_down && !mouse_was_down {
+                if let Some((mouse_x, mouse_y)) = window.get_mouse_pos(MouseMode::Discard) {
+                    if Self::toggle_dataset_at(plot, canvas, mouse_x as u32, mouse_y as u32) {
+                        plot.draw(canvas);
                     }
                 }
             }
+            mouse_was_down = mouse_down;
+
+            let hovered = hover_enabled
+                && Self::hover(
+                    &window,
+                    canvas,
+                    plot,
+                    hover_mode,
+                    &mut fonts,
+                    &mut hover_buffer,
+                );
 
             if window.is_key_pressed(Key::H, minifb::KeyRepeat::No) {
                 show_hints = !show_hints;
@@ -245,18 +339,253 @@ impl Winop {
                 hover_enabled = !hover_enabled;
             }
 
-            window.update_with_buffer(&buffer, width, height).unwrap();
+            // Show the hovered frame, or the canvas itself, with the hints on top
+            let mut buffer;
+            let frame = if hovered {
+                &mut hover_buffer.pixels
+            } else {
+                buffer = Self::canvas_to_buffer(canvas);
+                &mut buffer
+            };
+            if window.is_key_pressed(Key::S, minifb::KeyRepeat::No) {
+                Self::save_frame(frame, canvas);
+            }
+            if show_hints {
+                let font = fonts.label_font(plot.get_figure_config());
+                Self::render_hints(frame, width, height, font);
+            }
+            window.update_with_buffer(frame, width, height).unwrap();
+        }
+    }
+
+    /// Saves a frame shown in the window to the current directory and reports the outcome.
+    ///
+    /// The frame includes the hover elements, but not the key binding hints.
+    ///
+    /// # Parameters
+    /// - `frame`: The frame in the `0RGB` format used by the window.
+    /// - `canvas`: The `PixelCanvas` the frame was made from.
+    fn save_frame(frame: &[u32], canvas: &PixelCanvas) {
+        let directory = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+        match Self::save_snapshot(frame, canvas, &directory) {
+            Ok(path) => println!("Saved current view to {}", path.display()),
+            Err(e) => eprintln!("Failed to save current view: {}", e),
         }
     }
 
-    /// Renders hints on the canvas for user guidance.
+    /// Saves a frame as a PNG with a timestamped file name.
+    ///
+    /// The frame is converted back to RGB and encoded like a `PixelCanvas` of the same size.
+    /// A counter is appended to the name if a file with the timestamp already exists, so every
+    /// save creates a new file.
     ///
     /// # Parameters
-    /// - `canvas`: The `PixelCanvas` to display hints on.
+    /// - `frame`: The frame in the `0RGB` format used by the window.
+    /// - `canvas`: The `PixelCanvas` the frame was made from, which gives its size.
+    /// - `directory`: The directory in which the file is created.
+    ///
+    /// # Returns
+    /// The path of the saved file, e.g. `dataviz_1700000000000.png`, or an
+    /// `image::ImageError` if the frame does not match the canvas size or the file cannot be
+    /// saved.
+    fn save_snapshot(
+        frame: &[u32],
+        canvas: &PixelCanvas,
+        directory: &Path,
+    ) -> Result<PathBuf, image::ImageError> {
+        let snapshot = PixelCanvas::from_buffer(
+            canvas.width,
+            canvas.height,
+            Self::buffer_to_rgb(frame),
+            canvas.background_color,
+            0,
+        )
+        .map_err(|_| {
+            image::ImageError::Parameter(ParameterError::from_kind(
+                ParameterErrorKind::DimensionMismatch,
+            ))
+        })?;
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or_default();
+        let mut path = directory.join(format!("dataviz_{}.png", timestamp));
+        let mut counter = 1;
+        while path.exists() {
+            path = directory.join(format!("dataviz_{}_{}.png", timestamp, counter));
+            counter += 1;
+        }
+        snapshot.save_as_image(&path.to_string_lossy())?;
+        Ok(path)
+    }
+
+    /// Renders the key bindings as a semi-transparent panel in the top-right corner of a frame.
+    ///
+    /// The hints are drawn into the frame shown in the window, so the chart canvas itself is
+    /// left untouched. Frames too small to hold the panel are not changed.
     ///
-    /// # Note
-    /// This function is a placeholder and needs to be implemented for specific hint rendering.
-    fn render_hints(_canvas: &mut PixelCanvas) {
-        // todo!();
+    /// # Parameters
+    /// - `frame`: The frame in the `0RGB` format used by the window, `width * height` pixels.
+    /// - `width`: The width of the frame in pixels.
+    /// - `height`: The height of the frame in pixels.
+    /// - `font`: The font the hints are written in.
+    fn render_hints(frame: &mut [u32], width: usize, height: usize, font: &FontArc) {
+        const HINTS: [&str; 4] = [
+            "C: toggle hover",
+            "H: toggle hints",
+            "S: save frame",
+            "Esc: quit",
+        ];
+        const PADDING: u32 = 6;
+        const LINE_HEIGHT: u32 = 16;
+        const OFFSET: u32 = 10;
+
+        let scale = PxScale::from(12.0);
+        let text_width = HINTS
+            .iter()
+            .map(|hint| text_size(scale, font, hint).0)
+            .max()
+            .unwrap_or(0);
+        let panel_width = text_width + PADDING * 2;
+        let panel_height = LINE_HEIGHT * HINTS.len() as u32 + PADDING * 2;
+        if frame.len() < width * height
+            || (panel_width + OFFSET * 2) as usize > width
+            || (panel_height + OFFSET * 2) as usize > height
+        {
+            return;
+        }
+        let left = width as u32 - panel_width - OFFSET;
+        let top = OFFSET;
+
+        // Blend the covered part of the frame towards white, then draw the border and text
+        let index = |x: u32, y: u32| (top + y) as usize * width + (left + x) as usize;
+        let mut panel = RgbImage::from_fn(panel_width, panel_height, |x, y| {
+            let pixel = frame[index(x, y)];
+            let blend = |channel: u32| (((channel & 0xFF) + 3 * 255) / 4) as u8;
+            Rgb([blend(pixel >> 16), blend(pixel >> 8), blend(pixel)])
+        });
+        draw_hollow_rect_mut(
+            &mut panel,
+            Rect::at(0, 0).of_size(panel_width, panel_height),
+            Rgb([128, 128, 128]),
+        );
+        for (line, hint) in HINTS.iter().enumerate() {
+            let y = PADDING + line as u32 * LINE_HEIGHT;
+            draw_text_mut(
+                &mut panel,
+                Rgb([0, 0, 0]),
+                PADDING as i32,
+                y as i32,
+                scale,
+                font,
+                hint,
+            );
+        }
+
+        for (x, y, pixel) in panel.enumerate_pixels() {
+            let [r, g, b] = pixel.0;
+            frame[index(x, y)] = ((r as u32) << 16) | ((g as u32) << 8) | b as u32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::{
+        configuration::figureconfig::DEFAULT_FONT,
+        datasets::{dataset::Dataset, scattergraphdataset::ScatterGraphDataset},
+        figuretypes::scattergraph::ScatterGraph,
+        utilities::scatterdottype::ScatterDotType,
+    };
+
+    #[test]
+    fn test_save_snapshot_writes_png() {
+        let mut canvas = PixelCanvas::new(30, 20, [255, 255, 255], 0);
+        canvas.clear();
+        let mut frame = Winop::canvas_to_buffer(&canvas);
+        // A hover element drawn into the frame only
+        frame[4 * 30 + 4] = 0x00FF_0000;
+
+        let path = Winop::save_snapshot(&frame, &canvas, &std::env::temp_dir()).unwrap();
+        let second = Winop::save_snapshot(&frame, &canvas, &std::env::temp_dir()).unwrap();
+
+        assert!(path.exists());
+        assert_ne!(path, second);
+        assert!(path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("dataviz_"));
+        let img = image::open(&path)
+            .expect("failed to open snapshot")
+            .to_rgb8();
+        assert_eq!(img.get_pixel(4, 4).0, [255, 0, 0]);
+        assert_eq!(img.get_pixel(5, 4).0, [255, 255, 255]);
+        std::fs::remove_file(path).unwrap();
+        std::fs::remove_file(second).unwrap();
+    }
+
+    #[test]
+    fn test_buffer_to_rgb_inverts_canvas_to_buffer() {
+        let canvas =
+            PixelCanvas::from_buffer(2, 1, vec![1, 2, 3, 250, 128, 0], [255, 255, 255], 0).unwrap();
+        let frame = Winop::canvas_to_buffer(&canvas);
+
+        assert_eq!(frame, vec![0x0001_0203, 0x00FA_8000]);
+        assert_eq!(Winop::buffer_to_rgb(&frame), canvas.buffer);
+        // The alpha byte of the window format is ignored
+        assert_eq!(Winop::buffer_to_rgb(&[0xFF12_3456]), vec![0x12, 0x34, 0x56]);
+    }
+
+    #[test]
+    fn test_clicking_a_legend_entry_hides_its_dataset() {
+        let mut graph = ScatterGraph::new("Toggle", "X", "Y", FigureConfig::default());
+        for (color, label, y) in [([255, 0, 0], "Red", 2.0), ([0, 0, 255], "Blue", 4.0)] {
+            let mut dataset = ScatterGraphDataset::new(color, label, ScatterDotType::Square(4));
+            dataset.add_points(&[(1.0, y), (3.0, y)]);
+            graph.add_dataset(dataset);
+        }
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 50);
+        graph.draw(&mut canvas);
+        let count = |canvas: &PixelCanvas, color: [u8; 3]| {
+            canvas
+                .buffer
+                .chunks_exact(3)
+                .filter(|pixel| *pixel == color)
+                .count()
+        };
+        assert!(count(&canvas, [255, 0, 0]) > 0);
+
+        let entry = graph.legend_entries(&canvas)[0];
+        let (x, y) = (entry.x + entry.width / 2, entry.y + entry.height / 2);
+        assert!(Winop::toggle_dataset_at(&mut graph, &canvas, x, y));
+        graph.draw(&mut canvas);
+
+        assert!(!graph.datasets[0].visible);
+        assert_eq!(count(&canvas, [255, 0, 0]), 0);
+        assert!(count(&canvas, [0, 0, 255]) > 0);
+        assert!(!Winop::toggle_dataset_at(&mut graph, &canvas, 0, 0));
+    }
+
+    #[test]
+    fn test_render_hints_draws_panel_in_top_right_corner() {
+        let font = FontArc::try_from_slice(DEFAULT_FONT).unwrap();
+        let (width, height) = (300, 200);
+        let mut frame = vec![0x00FF_FFFF; width * height];
+
+        Winop::render_hints(&mut frame, width, height, &font);
+
+        let changed: Vec<(usize, usize)> = frame
+            .iter()
+            .enumerate()
+            .filter(|(_, &pixel)| pixel != 0x00FF_FFFF)
+            .map(|(index, _)| (index % width, index / width))
+            .collect();
+        assert!(!changed.is_empty());
+        assert!(changed
+            .iter()
+            .all(|&(x, y)| x >= width / 2 && x < width - 10 && (10..height / 2).contains(&y)));
     }
 }
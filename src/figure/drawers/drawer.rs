@@ -1,31 +1,254 @@
 use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
 
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
-    utilities::{axistype::AxisType, linetype::LineType},
+    utilities::{annotation::Annotation, axistype::AxisType, linetype::LineType},
 };
 
 use std::any::Any;
 
+/// The message drawn in place of the data when a plot has nothing to show.
+pub const NO_DATA_MESSAGE: &str = "No data available";
+
+/// The space between the marker and the label of a legend entry, in pixels.
+pub const LEGEND_PADDING: u32 = 5;
+
+/// The RGB color of the legend entries of hidden datasets.
+pub const LEGEND_HIDDEN_COLOR: [u8; 3] = [190, 190, 190];
+
+/// The area a legend entry, its marker and its label, covers on the canvas.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LegendEntry {
+    /// The left edge of the entry, where the marker starts.
+    pub x: u32,
+    /// The top edge of the entry.
+    pub y: u32,
+    /// The width of the marker, the padding and the label.
+    pub width: u32,
+    /// The height of the taller of the marker and the label.
+    pub height: u32,
+}
+
+impl LegendEntry {
+    /// Returns whether the canvas position `(x, y)` lies within the entry.
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        (self.x..self.x + self.width).contains(&x) && (self.y..self.y + self.height).contains(&y)
+    }
+}
+
+/// Draws the marker of the legend entry at an index, given its position and color.
+pub type DrawLegendMarker<'a> = dyn Fn(&mut PixelCanvas, usize, LegendEntry, [u8; 3]) + 'a;
+
 /// A trait for rendering charts and graphs, supporting multiple output formats.
 pub trait Drawer: Any {
     fn as_any(&mut self) -> &mut (dyn Any + 'static);
     fn get_figure_config(&self) -> &FigureConfig;
 
+    /// Returns whether the plot has any data to draw.
+    ///
+    /// Plots without data are drawn as a placeholder, see [`Drawer::draw_no_data`].
+    /// The default implementation always returns `true`.
+    fn has_data(&self) -> bool {
+        true
+    }
+
     /// Draws the main content of the plot on a `PixelCanvas`.
     ///
     /// # Parameters
     /// - `canvas`: The `PixelCanvas` to draw the plot on.
     fn draw(&mut self, canvas: &mut PixelCanvas);
 
+    /// Redraws the plot after only its data changed, e.g. once per frame in a real-time window.
+    ///
+    /// Plots that cache their static layout (title, grid, axis values and legend) reuse it here
+    /// and only redraw the data. The default implementation performs a full [`Drawer::draw`].
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the plot on.
+    fn draw_incremental(&mut self, canvas: &mut PixelCanvas) {
+        self.draw(canvas);
+    }
+
     /// Draws the legend for the plot on a `PixelCanvas`.
     ///
     /// # Parameters
     /// - `canvas`: The `PixelCanvas` to draw the legend on.
     fn draw_legend(&self, canvas: &mut PixelCanvas);
 
+    /// Returns where [`Drawer::draw_legend`] places the entries of the datasets on a canvas.
+    ///
+    /// Interactive windows use the entries to find the dataset of a clicked legend entry, see
+    /// [`Drawer::toggle_dataset`]. The default implementation returns no entries.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` the legend is drawn on.
+    ///
+    /// # Returns
+    /// One `LegendEntry` per dataset, in the order of the datasets.
+    fn legend_entries(&self, _canvas: &PixelCanvas) -> Vec<LegendEntry> {
+        Vec::new()
+    }
+
+    /// Shows the dataset at `index` if it is hidden, or hides it if it is shown.
+    ///
+    /// Hidden datasets are skipped when drawing and greyed out in the legend. The default
+    /// implementation does nothing, for plots without toggleable datasets.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the dataset, as in [`Drawer::legend_entries`].
+    ///
+    /// # Returns
+    /// `true` if the visibility of a dataset changed and the plot needs to be redrawn.
+    fn toggle_dataset(&mut self, _index: usize) -> bool {
+        false
+    }
+
+    /// Lays out legend entries in rows at the configured legend position.
+    ///
+    /// Entries are placed side by side and wrap to a new row once they pass the right margin.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` the legend is drawn on.
+    /// - `marker_width`: The width of the marker drawn in front of each label.
+    /// - `labels`: The labels of the entries.
+    ///
+    /// # Returns
+    /// One `LegendEntry` per label, or none if the legend is hidden.
+    fn layout_legend(
+        &self,
+        canvas: &PixelCanvas,
+        marker_width: u32,
+        labels: &[&str],
+    ) -> Vec<LegendEntry> {
+        let config = self.get_figure_config();
+        let font_bytes = config.label_font_bytes();
+        let font = FontRef::try_from_slice(&font_bytes).unwrap();
+        let scale = PxScale { x: 10.0, y: 10.0 };
+
+        let marker_height = config.legend_marker_size;
+        let line_height = 20.max(marker_height + 10); // Vertical space for each legend row
+        let entry_offset = 20; // Offset of the entries below the legend row
+        let sizes: Vec<(u32, u32)> = labels
+            .iter()
+            .map(|label| canvas.text_size(scale, &font, label))
+            .collect();
+        let legend_width: u32 = sizes
+            .iter()
+            .map(|(w, _)| marker_width + LEGEND_PADDING + w + LEGEND_PADDING)
+            .sum();
+        let Some((x_start, y_start)) = config.legend_position.origin(
+            (canvas.width, canvas.height),
+            canvas.margins,
+            (legend_width, entry_offset + line_height),
+        ) else {
+            return Vec::new();
+        };
+
+        let mut x = x_start;
+        let mut y = y_start;
+        let mut entries = Vec::with_capacity(labels.len());
+        for (w, h) in sizes {
+            entries.push(LegendEntry {
+                x,
+                y: y + entry_offset + h,
+                width: marker_width + LEGEND_PADDING + w,
+                height: marker_height.max(h),
+            });
+
+            // Move to the next legend entry
+            x += marker_width + LEGEND_PADDING + w + LEGEND_PADDING;
+            if x > canvas.width - canvas.margins.right {
+                // If the width exceeds, wrap to the next row
+                x = x_start;
+                if config.legend_position.rows_grow_downwards() {
+                    y += line_height;
+                } else {
+                    y = y.saturating_sub(line_height);
+                }
+            }
+        }
+        entries
+    }
+
+    /// Draws a legend with a colored square in front of each label, see
+    /// [`Drawer::draw_legend_with_markers`].
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the legend on.
+    /// - `entries`: The `(label, color, hidden)` of each entry.
+    fn draw_square_legend(&self, canvas: &mut PixelCanvas, entries: &[(&str, [u8; 3], bool)]) {
+        let square_size = self.get_figure_config().legend_marker_size;
+        self.draw_legend_with_markers(canvas, square_size, entries, &|canvas, _, entry, color| {
+            for dy in 0..square_size {
+                for dx in 0..square_size {
+                    canvas.draw_pixel(entry.x + dx, entry.y + dy, color);
+                }
+            }
+        });
+    }
+
+    /// Draws legend entries at the positions from [`Drawer::layout_legend`], each a marker
+    /// followed by its label. Hidden entries are drawn in [`LEGEND_HIDDEN_COLOR`].
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the legend on.
+    /// - `marker_width`: The width of the marker drawn in front of each label.
+    /// - `entries`: The `(label, color, hidden)` of each entry.
+    /// - `draw_marker`: Draws the marker of the entry at an index, given its position and color.
+    fn draw_legend_with_markers(
+        &self,
+        canvas: &mut PixelCanvas,
+        marker_width: u32,
+        entries: &[(&str, [u8; 3], bool)],
+        draw_marker: &DrawLegendMarker<'_>,
+    ) {
+        let font_bytes = self.get_figure_config().label_font_bytes();
+        let font = FontRef::try_from_slice(&font_bytes).unwrap();
+        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
+
+        let labels: Vec<&str> = entries.iter().map(|&(label, _, _)| label).collect();
+        let positions = self.layout_legend(canvas, marker_width, &labels);
+        for (index, (&(label, color, hidden), entry)) in entries.iter().zip(positions).enumerate() {
+            let color = if hidden { LEGEND_HIDDEN_COLOR } else { color };
+            draw_marker(canvas, index, entry, color);
+
+            // Draw the label text next to the marker
+            let text_x = entry.x + marker_width + LEGEND_PADDING;
+            canvas.draw_text(text_x, entry.y, label, color, &font, scale);
+        }
+    }
+
+    /// Returns the top-left corner of the legend box of an `SvgCanvas` at the configured
+    /// legend position.
+    ///
+    /// Like the legend of a `PixelCanvas`, the box is placed 20 pixels below the position
+    /// returned by [`LegendPosition::origin`](crate::figure::utilities::legendposition::LegendPosition::origin),
+    /// leaving room for the tick labels under the plot area.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` the legend is drawn on.
+    /// - `legend_size`: The `(width, height)` of the legend box.
+    ///
+    /// # Returns
+    /// The `(x, y)` of the legend box, or `None` if the legend is hidden.
+    fn svg_legend_origin(
+        &self,
+        svg_canvas: &SvgCanvas,
+        (width, height): (f64, f64),
+    ) -> Option<(f64, f64)> {
+        let entry_offset = 20;
+        let (x, y) = self.get_figure_config().legend_position.origin(
+            (svg_canvas.width, svg_canvas.height),
+            svg_canvas.margins,
+            (width.ceil() as u32, entry_offset + height.ceil() as u32),
+        )?;
+        Some((x as f64, (y + entry_offset) as f64))
+    }
+
     /// Draws the plot content on an `SvgCanvas`.
     ///
     /// # Parameters
@@ -39,11 +262,42 @@ pub trait Drawer: Any {
     /// - `config`: The `FigureConfig` containing grid appearance settings.
     fn draw_grid(&self, canvas: &mut PixelCanvas, config: &FigureConfig) {
         canvas.draw_grid(
-            &[config.num_grid_horizontal, config.num_grid_vertical],
-            config.color_grid,
+            &[config.num_grid_vertical, config.num_grid_horizontal],
+            config.effective_grid_color(),
+            config.grid_line_type.clone(),
         );
     }
 
+    /// Draws the grid for the plot at the tick positions of the axes, so every grid line has
+    /// a tick label.
+    ///
+    /// If `config.grid_at_ticks` is off, the grid is drawn with the configured spacing instead,
+    /// see [`Drawer::draw_grid`].
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the grid on.
+    /// - `config`: The `FigureConfig` containing grid appearance settings.
+    /// - `x_positions`: The canvas x-coordinates of the X-axis ticks.
+    /// - `y_positions`: The canvas y-coordinates of the Y-axis ticks.
+    fn draw_tick_grid(
+        &self,
+        canvas: &mut PixelCanvas,
+        config: &FigureConfig,
+        x_positions: &[u32],
+        y_positions: &[u32],
+    ) {
+        if config.grid_at_ticks {
+            canvas.draw_grid_lines(
+                x_positions,
+                y_positions,
+                config.effective_grid_color(),
+                config.grid_line_type.clone(),
+            );
+        } else {
+            self.draw_grid(canvas, config);
+        }
+    }
+
     /// Draws an axis line on the canvas.
     ///
     /// # Parameters
@@ -60,7 +314,155 @@ pub trait Drawer: Any {
         x2: i32,
         y2: i32,
     ) {
-        canvas.draw_line(x1, y1, x2, y2, config.color_axis, LineType::Solid);
+        canvas.draw_line(
+            x1,
+            y1,
+            x2,
+            y2,
+            config.effective_axis_color(),
+            LineType::Solid,
+        );
+    }
+
+    /// Draws the emphasized zero lines (x = 0 and y = 0) of the plot.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the lines on.
+    /// - `config`: The `FigureConfig` containing the zero line color and width.
+    /// - `zero_x`: The canvas x-coordinate of x = 0, or `None` if the x-range does not span zero.
+    /// - `zero_y`: The canvas y-coordinate of y = 0, or `None` if the y-range does not span zero.
+    fn draw_zero_lines(
+        &self,
+        canvas: &mut PixelCanvas,
+        config: &FigureConfig,
+        zero_x: Option<u32>,
+        zero_y: Option<u32>,
+    ) {
+        // Spread the line width evenly around the zero coordinate
+        let line_width = config.zero_line_width.max(1) as i64;
+        let offsets = -((line_width - 1) / 2)..=(line_width / 2);
+
+        if let Some(x) = zero_x {
+            for offset in offsets.clone() {
+                let x = x as i64 + offset;
+                if x >= 0 && x < canvas.width as i64 {
+                    canvas.draw_vertical_line(x as u32, config.effective_zero_line_color());
+                }
+            }
+        }
+        if let Some(y) = zero_y {
+            for offset in offsets {
+                let y = y as i64 + offset;
+                if y >= 0 && y < canvas.height as i64 {
+                    canvas.draw_horizontal_line(y as u32, config.effective_zero_line_color());
+                }
+            }
+        }
+    }
+
+    /// Draws reference annotations (horizontal and vertical lines with labels) across the plot area.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the annotations on.
+    /// - `config`: The `FigureConfig` containing the label font settings.
+    /// - `annotations`: The annotations to draw.
+    /// - `to_canvas`: Maps a data point `(x, y)` to canvas coordinates, as used for the plot's data.
+    fn draw_annotations(
+        &self,
+        canvas: &mut PixelCanvas,
+        config: &FigureConfig,
+        annotations: &[Annotation],
+        to_canvas: &dyn Fn(f64, f64) -> (i32, i32),
+    ) {
+        if annotations.is_empty() {
+            return;
+        }
+
+        let font_bytes = config.label_font_bytes();
+        let font = FontRef::try_from_slice(&font_bytes).unwrap();
+        let scale = PxScale {
+            x: config.font_size_axis,
+            y: config.font_size_axis,
+        };
+
+        let (left, top, right, bottom) = canvas.plot_bounds();
+        let (left, top, right, bottom) = (left as i32, top as i32, right as i32, bottom as i32);
+
+        for annotation in annotations {
+            let color = annotation.color();
+            let (w, h) = canvas.text_size(scale, &font, annotation.label());
+            match annotation {
+                Annotation::HLine { y, .. } => {
+                    let (_, py) = to_canvas(0.0, *y);
+                    if py < top || py > bottom {
+                        continue;
+                    }
+                    canvas.draw_line(left, py, right, py, color, LineType::Solid);
+                    // Label right-aligned just above the line
+                    let text_x = (right - w as i32 - 5).max(0) as u32;
+                    let text_y = (py - h as i32 - 4).max(0) as u32;
+                    canvas.draw_text(text_x, text_y, annotation.label(), color, &font, scale);
+                }
+                Annotation::VLine { x, .. } => {
+                    let (px, _) = to_canvas(*x, 0.0);
+                    if px < left || px > right {
+                        continue;
+                    }
+                    canvas.draw_line(px, top, px, bottom, color, LineType::Solid);
+                    // Label at the top, just right of the line
+                    canvas.draw_text(
+                        (px + 5) as u32,
+                        (top + 5) as u32,
+                        annotation.label(),
+                        color,
+                        &font,
+                        scale,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Draws reference annotations (horizontal and vertical lines with labels) on an `SvgCanvas`.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` to draw the annotations on.
+    /// - `annotations`: The annotations to draw.
+    /// - `to_svg`: Maps a data point `(x, y)` to SVG coordinates, as used for the plot's data.
+    fn draw_annotations_svg(
+        &self,
+        svg_canvas: &mut SvgCanvas,
+        annotations: &[Annotation],
+        to_svg: &dyn Fn(f64, f64) -> (f64, f64),
+    ) {
+        let (left, top, right, bottom) = svg_canvas.plot_bounds();
+        let font_size = 12.0;
+
+        for annotation in annotations {
+            let color = annotation.color();
+            let (line, text) = match annotation {
+                Annotation::HLine { y, .. } => {
+                    let (_, sy) = to_svg(0.0, *y);
+                    ((left, sy, right, sy), (right - 5.0, sy - 4.0, "end"))
+                }
+                Annotation::VLine { x, .. } => {
+                    let (sx, _) = to_svg(*x, 0.0);
+                    ((sx, top, sx, bottom), (sx + 5.0, top + font_size, "start"))
+                }
+            };
+            svg_canvas.draw_line_rgb(line.0, line.1, line.2, line.3, color, 1.5);
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="{}" fill="rgb({},{},{})">{}</text>"#,
+                text.0,
+                text.1,
+                font_size,
+                text.2,
+                color[0],
+                color[1],
+                color[2],
+                escape_xml(annotation.label())
+            ));
+        }
     }
 
     /// Draws a text label on the canvas.
@@ -78,21 +480,20 @@ pub trait Drawer: Any {
         y: u32,
         text: &str,
     ) {
-        let font_path = config.font_label.as_ref().expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
+        let font_bytes = config.label_font_bytes();
         let font = FontRef::try_from_slice(&font_bytes).unwrap();
         let scale = ab_glyph::PxScale {
             x: config.font_size_label,
             y: config.font_size_label,
         };
 
-        let (w, h) = text_size(scale, &font, text);
+        let (w, h) = canvas.text_size(scale, &font, text);
 
         canvas.draw_text(
             x.saturating_sub(w / 2),
             y.saturating_sub(h / 2),
             text,
-            config.color_axis,
+            config.effective_axis_color(),
             &font,
             scale,
         );
@@ -113,26 +514,93 @@ pub trait Drawer: Any {
         y: u32,
         text: &str,
     ) {
-        let font_path = config.font_title.as_ref().expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
+        let font_bytes = config.title_font_bytes();
         let font = FontRef::try_from_slice(&font_bytes).unwrap();
         let scale = PxScale {
             x: config.font_size_title,
             y: config.font_size_title,
         };
 
-        let (w, h) = text_size(scale, &font, text);
+        let (w, h) = canvas.text_size(scale, &font, text);
 
         canvas.draw_text(
             x.saturating_sub(w / 2),
             y.saturating_sub(h / 2),
             text,
-            config.color_title,
+            config.effective_title_color(),
             &font,
             scale,
         );
     }
 
+    /// Draws the placeholder of a plot without data: the title, the axes and a centered
+    /// [`NO_DATA_MESSAGE`].
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the placeholder on.
+    /// - `config`: The `FigureConfig` containing the font and color settings.
+    /// - `title`: The plot title.
+    fn draw_no_data(&self, canvas: &mut PixelCanvas, config: &FigureConfig, title: &str) {
+        let width = canvas.width;
+        let height = canvas.height;
+        let (left, top, right, bottom) = canvas.plot_bounds();
+
+        self.draw_title(canvas, config, width / 2, top / 2, title);
+
+        // Axes along the left and bottom edge of the plot area
+        let (left, top, right, bottom) = (left as i32, top as i32, right as i32, bottom as i32);
+        self.draw_axis(canvas, config, left, bottom, right, bottom);
+        self.draw_axis(canvas, config, left, top, left, bottom);
+
+        self.draw_label(canvas, config, width / 2, height / 2, NO_DATA_MESSAGE);
+    }
+
+    /// Draws the placeholder of a plot without data on an `SvgCanvas`: the background, the
+    /// title, the axes and a centered [`NO_DATA_MESSAGE`], in the colors of the figure
+    /// configuration.
+    ///
+    /// # Parameters
+    /// - `svg_canvas`: The `SvgCanvas` to draw the placeholder on.
+    /// - `title`: The plot title.
+    fn draw_no_data_svg(&self, svg_canvas: &mut SvgCanvas, title: &str) {
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let (left, top, right, bottom) = svg_canvas.plot_bounds();
+        let font_size = 12.0;
+        let config = self.get_figure_config();
+        let rgb = |color: [u8; 3]| format!("rgb({},{},{})", color[0], color[1], color[2]);
+        let axis_color = rgb(config.effective_axis_color());
+
+        svg_canvas.draw_rect(
+            0.0,
+            0.0,
+            width,
+            height,
+            &rgb(config.color_background),
+            &axis_color,
+            2.0,
+            1.0,
+        );
+        svg_canvas.draw_title(
+            width / 2.0,
+            top / 2.0,
+            title,
+            font_size * 2.0,
+            &rgb(config.effective_title_color()),
+        );
+
+        svg_canvas.draw_line(left, bottom, right, bottom, &axis_color, 1.0);
+        svg_canvas.draw_line(left, top, left, bottom, &axis_color, 1.0);
+
+        svg_canvas.draw_title(
+            width / 2.0,
+            height / 2.0,
+            NO_DATA_MESSAGE,
+            font_size * 1.5,
+            "gray",
+        );
+    }
+
     /// Draws a value on the axis (tick label) based on its type.
     ///
     /// # Parameters
@@ -150,15 +618,14 @@ pub trait Drawer: Any {
         text: &str,
         axis: AxisType,
     ) {
-        let font_path = config.font_label.as_ref().expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
+        let font_bytes = config.label_font_bytes();
         let font = FontRef::try_from_slice(&font_bytes).unwrap();
         let scale = ab_glyph::PxScale {
             x: config.font_size_axis,
             y: config.font_size_axis,
         };
 
-        let (w, h) = text_size(scale, &font, text);
+        let (w, h) = canvas.text_size(scale, &font, text);
         let mut x = x;
         let mut y = y;
         match axis {
@@ -172,6 +639,79 @@ pub trait Drawer: Any {
             }
         }
 
-        canvas.draw_text(x, y, text, config.color_axis, &font, scale);
+        canvas.draw_text(x, y, text, config.effective_axis_color(), &font, scale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::{
+        canvas::margins::Margins,
+        datasets::{
+            areachartdataset::AreaChartDataset, bardataset::BarDataset,
+            cartesiangraphdataset::CartesianDataset, dataset::Dataset,
+            linegraphdataset::LineGraphDataset, scattergraphdataset::ScatterGraphDataset,
+        },
+        figuretypes::{
+            areachart::AreaChart, cartesiangraph::CartesianGraph, groupbarchart::GroupBarChart,
+            heatmap::Heatmap, histogram::Histogram, linegraph::LineGraph, piechart::PieChart,
+            quadrant1graph::Quadrant1Graph, scattergraph::ScatterGraph,
+        },
+        utilities::{orientation::Orientation, scatterdottype::ScatterDotType},
+    };
+
+    #[test]
+    fn test_charts_draw_with_a_left_margin_narrower_than_the_tick_labels() {
+        let config = FigureConfig::default();
+        let points = [(1.0, 1.0), (5.0, 3.0)];
+
+        let mut cartesian = CartesianGraph::new("Cartesian", "X", "Y", &config);
+        let mut dataset = CartesianDataset::new([255, 0, 0], "A", LineType::Solid);
+        dataset.add_points(&points);
+        cartesian.add_dataset(dataset);
+        let mut quadrant = Quadrant1Graph::new("Quadrant", "X", "Y", config.clone());
+        let mut dataset = CartesianDataset::new([255, 0, 0], "A", LineType::Solid);
+        dataset.add_points(&points);
+        quadrant.add_dataset(dataset);
+        let mut line = LineGraph::new("Line", "X", "Y", &config);
+        let mut dataset = LineGraphDataset::new([255, 0, 0], "A", LineType::Solid);
+        dataset.add_points(&points);
+        line.add_dataset(dataset);
+        let mut scatter = ScatterGraph::new("Scatter", "X", "Y", config.clone());
+        let mut dataset = ScatterGraphDataset::new([255, 0, 0], "A", ScatterDotType::Circle(2));
+        dataset.add_points(&points);
+        scatter.add_dataset(dataset);
+        let mut area = AreaChart::new("Area", "X", "Y", config.clone());
+        let mut dataset = AreaChartDataset::new([255, 0, 0], "A", 0.5);
+        dataset.add_points(&points);
+        area.add_dataset(dataset);
+        let mut histogram = Histogram::new("Histogram", "X", "Y", 4, [255, 0, 0], config.clone());
+        histogram.add_data_vec(vec![1.0, 2.0, 2.5, 4.0]);
+        let mut bars = GroupBarChart::new("Bars", "X", "Y", Orientation::Vertical, config.clone());
+        let mut dataset = BarDataset::new("A", [255, 0, 0]);
+        dataset.add_points(&points);
+        bars.add_dataset(dataset);
+        let mut pie = PieChart::new("Pie", config.clone());
+        pie.add_slice("A", 1.0, [255, 0, 0]);
+        let mut heatmap = Heatmap::new("Heatmap", "X", "Y", config);
+        heatmap.set_grid(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+
+        let charts: Vec<Box<dyn Drawer>> = vec![
+            Box::new(cartesian),
+            Box::new(quadrant),
+            Box::new(line),
+            Box::new(scatter),
+            Box::new(area),
+            Box::new(histogram),
+            Box::new(bars),
+            Box::new(pie),
+            Box::new(heatmap),
+        ];
+        for mut chart in charts {
+            let mut canvas =
+                PixelCanvas::with_margins(200, 200, [255, 255, 255], Margins::new(20, 20, 20, 5));
+            chart.draw(&mut canvas);
+        }
     }
 }
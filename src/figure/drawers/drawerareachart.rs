@@ -1,84 +1,75 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
-
-use super::drawer::Drawer;
+use super::drawer::{Drawer, LegendEntry, LEGEND_HIDDEN_COLOR};
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
     figuretypes::areachart::AreaChart,
-    utilities::axistype::AxisType,
+    utilities::{axistype::AxisType, ticks::nice_tick_values},
 };
 use std::any::Any;
 
 impl Drawer for AreaChart {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        if !self.has_data() {
+            self.draw_no_data_svg(svg_canvas, &self.title);
+            return;
+        }
+
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
-        let margin = svg_canvas.margin as f64;
+        let (left, top, right, bottom) = svg_canvas.plot_bounds();
         let font_size = 12.0;
 
-        // Draw background
-        svg_canvas.draw_rect(0.0, 0.0, width, height, "white", "black", 1.0, 1.0);
+        // Draw the frame, the background itself is added by the canvas
+        svg_canvas.draw_rect(0.0, 0.0, width, height, "none", "black", 1.0, 1.0);
 
         // Draw Title
         svg_canvas.draw_title(
             width / 2.0,
-            margin / 2.0,
+            top / 2.0,
             &self.title,
             font_size * 2.0,
             "black",
         );
 
-        // Determine dataset range
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
-                (min.min(x), max.max(x))
-            });
-
-        let (y_min, y_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
-                (min.min(y), max.max(y))
-            });
-
-        // Adjust limits to include (0, 0)
-        let x_min = x_min.min(0.0);
-        let y_min = y_min.min(0.0);
+        // Determine dataset range, including (0, 0), or the fixed limits
+        let (x_min, x_max, y_min, y_max) = self.plot_limits();
 
-        let scale_x = (width - 2.0 * margin) / (x_max - x_min);
-        let scale_y = (height - 2.0 * margin) / (y_max - y_min);
+        let scale_x = (right - left) / (x_max - x_min);
+        let scale_y = (bottom - top) / (y_max - y_min);
 
         // Draw grid
         let num_ticks = 10;
+        let grid_start = svg_canvas.elements.len();
         svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
+            left,
+            right,
+            top,
+            bottom,
             num_ticks,
             num_ticks,
             "lightgray",
+            self.config.grid_line_type.clone(),
         );
+        let grid = grid_start..svg_canvas.elements.len();
 
-        // Draw axes
-        let origin_x = margin + (0.0 - x_min) * scale_x;
-        let origin_y = height - margin - (0.0 - y_min) * scale_y;
+        // Draw axes, at the nearest plot edge if the origin is outside the limits
+        let origin_x = left + (0.0 - x_min) * scale_x;
+        let origin_y = bottom - (0.0 - y_min) * scale_y;
+        let axis_x = origin_x.clamp(left, right);
+        let axis_y = origin_y.clamp(top, bottom);
 
-        svg_canvas.draw_line(margin, origin_y, width - margin, origin_y, "black", 2.0); // X-axis
-        svg_canvas.draw_line(origin_x, margin, origin_x, height - margin, "black", 2.0); // Y-axis
+        svg_canvas.draw_line(left, axis_y, right, axis_y, "black", 2.0); // X-axis
+        svg_canvas.draw_line(axis_x, top, axis_x, bottom, "black", 2.0); // Y-axis
 
         // X-axis
         let mut x_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = x_min + i as f64 * (x_max - x_min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
-            let tick_start_y = origin_y - 5.0;
-            let tick_end_y = origin_y + 5.0;
+        for value in nice_tick_values(None, x_min, x_max, num_ticks) {
+            let x = left + (value - x_min) * scale_x;
+            let tick_start_y = axis_y - 5.0;
+            let tick_end_y = axis_y + 5.0;
 
             x_axis_ticks.push_str(&format!(
                 "M {:.2},{:.2} L {:.2},{:.2} ",
@@ -87,8 +78,8 @@ impl Drawer for AreaChart {
 
             // Draw value as text (fallback to basic SVG <text>)
             svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{:.1}</text>"#,
-            x, height - margin + font_size * 1.5, font_size, value));
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{}</text>"#,
+            x, bottom + font_size * 1.5, font_size, self.config.format_tick(value)));
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{}" stroke="black" stroke-width="1" fill="none"/>"#,
@@ -97,11 +88,10 @@ impl Drawer for AreaChart {
 
         // Y-axis
         let mut y_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = y_min + i as f64 * (y_max - y_min) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
-            let tick_start_x = origin_x - 5.0;
-            let tick_end_x = origin_x + 5.0;
+        for value in nice_tick_values(None, y_min, y_max, num_ticks) {
+            let y = bottom - (value - y_min) * scale_y;
+            let tick_start_x = axis_x - 5.0;
+            let tick_end_x = axis_x + 5.0;
 
             y_axis_ticks.push_str(&format!(
                 "M {:.2},{:.2} L {:.2},{:.2} ",
@@ -110,8 +100,8 @@ impl Drawer for AreaChart {
 
             // Draw value as text (fallback to basic SVG <text>)
             svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 5.0, y + font_size * 0.3, font_size, value
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{}</text>"#,
+            left - 5.0, y + font_size * 0.3, font_size, self.config.format_tick(value)
         ));
         }
         svg_canvas.elements.push(format!(
@@ -120,8 +110,8 @@ impl Drawer for AreaChart {
         ));
 
         svg_canvas.draw_text(
-            width - margin,
-            height - margin / 2.0,
+            right,
+            bottom + (height - bottom) / 2.0,
             &self.x_label,
             font_size * 1.5,
             "black",
@@ -130,37 +120,57 @@ impl Drawer for AreaChart {
         // Draw Y-axis label (rotated)
         svg_canvas.elements.push(format!(
             r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
-            margin / 3.0,
+            left / 3.0,
             height / 2.0,
             font_size * 1.5,
-            margin / 3.0,
+            left / 3.0,
             height / 2.0,
-            self.y_label
+            escape_xml(&self.y_label)
         ));
 
-        // Draw areas under the datasets
-        for dataset in &self.datasets {
-            let mut path_data = String::new();
-            let mut first_point = true;
-
-            // Move to the initial point
-            for &(x, y) in &dataset.points {
-                let svg_x = margin + (x - x_min) * scale_x;
-                let svg_y = height - margin - (y - y_min) * scale_y;
-
-                if first_point {
-                    path_data.push_str(&format!("M {:.2},{:.2} ", svg_x, origin_y));
-                    first_point = false;
-                }
+        // Clip the areas to the plot area, since they can extend past fixed limits
+        let clipped = self.x_limits.is_some() || self.y_limits.is_some();
+        if clipped {
+            let clip_id = svg_canvas.unique_id("plot-area");
+            svg_canvas.elements.push(format!(
+                r#"<clipPath id="{}"><rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}"/></clipPath>"#,
+                clip_id,
+                left,
+                top,
+                right - left,
+                bottom - top
+            ));
+            svg_canvas
+                .elements
+                .push(format!(r#"<g clip-path="url(#{})">"#, clip_id));
+        }
 
-                path_data.push_str(&format!("L {:.2},{:.2} ", svg_x, svg_y));
+        // Draw areas under the datasets, or between the stacked layers
+        let layers: Vec<Vec<(f64, f64, f64)>> = if self.stacked {
+            self.stacked_layers()
+        } else {
+            self.datasets
+                .iter()
+                .map(|dataset| dataset.points.iter().map(|&(x, y)| (x, 0.0, y)).collect())
+                .collect()
+        };
+        let svg_x = |x: f64| left + (x - x_min) * scale_x;
+        let svg_y = |y: f64| bottom - (y - y_min) * scale_y;
+        let visible_layers = self.datasets.iter().zip(&layers);
+        for (dataset, layer) in visible_layers.filter(|(dataset, _)| dataset.visible) {
+            let Some(&(first_x, first_base, _)) = layer.first() else {
+                continue;
+            };
+
+            // Follow the line from the start of the baseline, then the baseline back
+            let mut path_data = format!("M {:.2},{:.2} ", svg_x(first_x), svg_y(first_base));
+            for &(x, _, top) in layer {
+                path_data.push_str(&format!("L {:.2},{:.2} ", svg_x(x), svg_y(top)));
             }
-
-            // Close the path back to the x-axis
-            if let Some(&(last_x, _)) = dataset.points.last() {
-                let svg_x = margin + (last_x - x_min) * scale_x;
-                path_data.push_str(&format!("L {:.2},{:.2} Z", svg_x, origin_y));
+            for &(x, base, _) in layer.iter().rev() {
+                path_data.push_str(&format!("L {:.2},{:.2} ", svg_x(x), svg_y(base)));
             }
+            path_data.push('Z');
 
             svg_canvas.elements.push(format!(
                 r#"<path d="{}" fill="rgba({}, {}, {}, 0.5)" stroke="rgb({}, {}, {})" stroke-width="1"/>"#,
@@ -173,45 +183,66 @@ impl Drawer for AreaChart {
                 dataset.color[2],
             ));
         }
+        if clipped {
+            svg_canvas.elements.push("</g>".to_string());
+        }
+
+        // Move the grid over the data
+        if self.config.grid_on_top {
+            svg_canvas.raise_elements(grid);
+        }
 
         // Draw legend
-        let legend_x_start = 5.0; // Start at the very left with margin spacing
-        let legend_y = height - margin / 2.0; // Move to bottom-left corner
-        let mut legend_x = legend_x_start; // Reset starting position for legend items
+        let marker_size = self.config.legend_marker_size as f64;
+        let entry_width =
+            |label: &str| marker_size + font_size * 4.0 + label.len() as f64 * font_size * 0.6;
+        let legend_width = self
+            .datasets
+            .iter()
+            .map(|dataset| entry_width(&dataset.label))
+            .sum::<f64>()
+            + 5.0;
+        let legend_height = font_size.max(marker_size) + 10.0;
+        let Some((legend_left, legend_top)) =
+            self.svg_legend_origin(svg_canvas, (legend_width, legend_height))
+        else {
+            return;
+        };
+        let legend_x_start = legend_left + 5.0;
+        let legend_y = legend_top + 5.0;
+        let mut legend_x = legend_x_start;
         let mut elements = String::new();
 
         for dataset in &self.datasets {
+            let color = if dataset.visible {
+                dataset.color
+            } else {
+                LEGEND_HIDDEN_COLOR
+            };
+
             // Draw color square
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
-                legend_x,
-                legend_y,
-                font_size,
-                font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2]
+                legend_x, legend_y, marker_size, marker_size, color[0], color[1], color[2]
             ));
 
             // Draw label text next to the color square
             elements.push_str(&format!(
                 r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="rgb({},{},{})">{}</text>"#,
-                legend_x + font_size * 1.3,
+                legend_x + marker_size + font_size * 0.3,
                 legend_y + font_size - 2.0,
                 font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2],
-                dataset.label
+                color[0],
+                color[1],
+                color[2],
+                escape_xml(&dataset.label)
             ));
 
             // Update legend_x to position the next item
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
+            legend_x += entry_width(&dataset.label);
         }
 
         // Draw a background rectangle for the legend
-        let legend_width = legend_x - legend_x_start + 5.0;
-        let legend_height = font_size + 10.0;
         svg_canvas.draw_rect(
             legend_x_start - 5.0,
             legend_y - 5.0,
@@ -229,154 +260,153 @@ impl Drawer for AreaChart {
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
         canvas.clear();
+        canvas.antialias = self.config.antialias;
+        canvas.text_rendering = self.config.text_rendering;
+
+        if !self.has_data() {
+            self.draw_no_data(canvas, &self.config, &self.title);
+            return;
+        }
 
-        let margin = canvas.margin;
+        let (left, top, right, bottom) = canvas.plot_bounds();
         let width = canvas.width;
-        let height = canvas.height;
         let cfg = &self.config;
 
         // Draw the title
-        self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
+        self.draw_title(canvas, cfg, width / 2, top / 2, &self.title);
 
-        // Calculate dataset limits
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
-                (min.min(x), max.max(x))
-            });
-
-        let (y_min, y_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
-                (min.min(y), max.max(y))
-            });
-
-        // Adjust limits to include (0, 0)
-        let x_min = x_min.min(0.0);
-        let y_min = y_min.min(0.0);
+        // Calculate dataset limits, including (0, 0), or the fixed limits
+        let (x_min, x_max, y_min, y_max) = self.plot_limits();
 
         // Calculate scales
-        let scale_x = (width - 2 * margin) as f64 / (x_max - x_min);
-        let scale_y = (height - 2 * margin) as f64 / (y_max - y_min);
+        let scale_x = (right - left) as f64 / (x_max - x_min);
+        let scale_y = (bottom - top) as f64 / (y_max - y_min);
 
-        // Draw grids
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
-        );
+        // The data origin can lie outside the plot area for fixed limits, the axes are drawn
+        // at the nearest plot edge then
+        let data_origin_x = left as i32 + ((0.0 - x_min) * scale_x) as i32;
+        let data_origin_y = bottom as i32 - ((0.0 - y_min) * scale_y) as i32;
+        let origin_x = left + ((0.0_f64.clamp(x_min, x_max) - x_min) * scale_x) as u32;
+        let origin_y = bottom - ((0.0_f64.clamp(y_min, y_max) - y_min) * scale_y) as u32;
 
-        // Draw axes
-        let origin_x = canvas.margin + ((0.0 - x_min) * scale_x) as u32;
-        let origin_y = height - margin - ((0.0 - y_min) * scale_y) as u32;
+        // Tick positions, shared by the grid and the tick values
+        let num_ticks = cfg.num_axis_ticks;
+        let x_tick_values = nice_tick_values(None, x_min, x_max, num_ticks);
+        let x_tick_positions: Vec<u32> = x_tick_values
+            .iter()
+            .map(|value_x| left + ((value_x - x_min) * scale_x) as u32)
+            .collect();
+        let y_tick_values = nice_tick_values(None, y_min, y_max, num_ticks);
+        let y_tick_positions: Vec<u32> = y_tick_values
+            .iter()
+            .map(|value_y| bottom - ((value_y - y_min) * scale_y) as u32)
+            .collect();
 
-        self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.x_label);
-        self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
+        // Draw grids, unless they go on top of the data
+        if !cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &x_tick_positions, &y_tick_positions);
+        }
 
-        // Draw axis tick values
-        let num_ticks = cfg.num_axis_ticks;
+        self.draw_label(
+            canvas,
+            cfg,
+            right + (width - right) / 2,
+            origin_y,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, left, top / 2, &self.y_label);
 
+        // Draw axis tick values
         // X-axis ticks
-        let x_tick_step = (x_max - x_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_x = x_min + i as f64 * x_tick_step;
-            let tick_x = origin_x + ((value_x - x_min) * scale_x) as u32;
-
-            let value_label = format!("{:.2}", value_x);
+        for (&value_x, &tick_x) in x_tick_values.iter().zip(&x_tick_positions) {
+            let value_label = cfg.format_tick(value_x);
             self.draw_axis_value(canvas, cfg, tick_x, origin_y, &value_label, AxisType::AxisX);
         }
 
         // Y-axis ticks
-        let y_tick_step = (y_max - y_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_y = y_min + i as f64 * y_tick_step;
-            let tick_y = origin_y - ((value_y - y_min) * scale_y) as u32;
-
-            let value_label = format!("{:.2}", value_y);
+        for (&value_y, &tick_y) in y_tick_values.iter().zip(&y_tick_positions) {
+            let value_label = cfg.format_tick(value_y);
             self.draw_axis_value(
                 canvas,
                 cfg,
-                origin_x - 10,
+                origin_x.saturating_sub(10),
                 tick_y,
                 &value_label,
                 AxisType::AxisY,
             );
         }
 
-        // Draw areas under the curves
-        for dataset in &self.datasets {
-            self.draw_area(
-                canvas,
-                dataset,
-                origin_x as i32,
-                origin_y as i32,
-                scale_x,
-                scale_y,
-            );
+        // Draw areas under the curves, clipped to the plot area
+        let plot_area = (left as i32, top as i32, right as i32, bottom as i32);
+        if self.stacked {
+            let layers = self.datasets.iter().zip(self.stacked_layers());
+            for (dataset, layer) in layers.filter(|(dataset, _)| dataset.visible) {
+                self.draw_layer_clipped(
+                    canvas,
+                    dataset,
+                    &layer,
+                    (data_origin_x, data_origin_y),
+                    (scale_x, scale_y),
+                    plot_area,
+                );
+            }
+        } else {
+            for dataset in self.datasets.iter().filter(|dataset| dataset.visible) {
+                self.draw_area_clipped(
+                    canvas,
+                    dataset,
+                    (data_origin_x, data_origin_y),
+                    (scale_x, scale_y),
+                    plot_area,
+                );
+            }
         }
 
-        canvas.draw_vertical_line(canvas.margin, [0, 0, 0]);
-        canvas.draw_vertical_line(canvas.width - canvas.margin, [0, 0, 0]);
-        canvas.draw_horizontal_line(canvas.height - canvas.margin, [0, 0, 0]);
-        canvas.draw_horizontal_line(canvas.margin, [0, 0, 0]);
+        // Draw grids over the data
+        if cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &x_tick_positions, &y_tick_positions);
+        }
+
+        let axis_color = cfg.effective_axis_color();
+        canvas.draw_vertical_line(left, axis_color);
+        canvas.draw_vertical_line(right, axis_color);
+        canvas.draw_horizontal_line(bottom, axis_color);
+        canvas.draw_horizontal_line(top, axis_color);
 
         // Draw legend
         self.draw_legend(canvas);
     }
 
     fn draw_legend(&self, canvas: &mut PixelCanvas) {
-        let font_path = self
-            .config
-            .font_label
-            .as_ref()
-            .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
-
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
-        let legend_margin = canvas.margin; // Margin from the bottom of the canvas
-
-        let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
+        let entries: Vec<(&str, [u8; 3], bool)> = self
+            .datasets
+            .iter()
+            .map(|dataset| (dataset.label.as_str(), dataset.color, !dataset.visible))
+            .collect();
+        self.draw_square_legend(canvas, &entries);
+    }
 
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
-            // Draw the square
-            for dy in 0..square_size {
-                for dx in 0..square_size {
-                    canvas.draw_pixel(
-                        x + dx,
-                        y + square_size * 2 + dy + h, // Adjust to align above baseline
-                        dataset.color,
-                    );
-                }
-            }
+    fn legend_entries(&self, canvas: &PixelCanvas) -> Vec<LegendEntry> {
+        let labels: Vec<&str> = self
+            .datasets
+            .iter()
+            .map(|dataset| dataset.label.as_str())
+            .collect();
+        self.layout_legend(canvas, self.config.legend_marker_size, &labels)
+    }
 
-            // Draw the label text next to the square
-            let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
-                text_x,
-                y + 2 * square_size + h,
-                &dataset.label,
-                dataset.color,
-                &font,
-                scale,
-            );
+    fn toggle_dataset(&mut self, index: usize) -> bool {
+        let Some(dataset) = self.datasets.get_mut(index) else {
+            return false;
+        };
+        dataset.visible = !dataset.visible;
+        true
+    }
 
-            // Move to the next legend entry
-            x += square_size + padding + w + padding;
-            if x > canvas.width - canvas.margin {
-                // If the width exceeds, wrap to the next row
-                x = canvas.margin;
-                y -= line_height;
-            }
-        }
+    fn has_data(&self) -> bool {
+        self.datasets
+            .iter()
+            .any(|dataset| !dataset.points.is_empty())
     }
 
     fn as_any(&mut self) -> &mut (dyn Any + 'static) {
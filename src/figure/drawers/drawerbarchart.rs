@@ -1,80 +1,80 @@
-use super::drawer::Drawer;
+use super::drawer::{Drawer, LegendEntry, LEGEND_HIDDEN_COLOR, LEGEND_PADDING};
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
     figuretypes::groupbarchart::GroupBarChart,
-    utilities::orientation::Orientation,
+    utilities::{barmode::BarMode, orientation::Orientation},
 };
 use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
 use std::any::Any;
 
 impl Drawer for GroupBarChart {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        if !self.has_data() {
+            self.draw_no_data_svg(svg_canvas, &self.title);
+            return;
+        }
+
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
-        let margin = svg_canvas.margin as f64;
+        let (left, top, right, bottom) = svg_canvas.plot_bounds();
         let font_size = 12.0;
 
-        match self.orientation {
+        let grid = match self.orientation {
             Orientation::Vertical => {
-                // Draw background
-                svg_canvas.draw_rect(0.0, 0.0, width, height, "white", "black", 1.0, 1.0);
+                // Draw the frame, the background itself is added by the canvas
+                svg_canvas.draw_rect(0.0, 0.0, width, height, "none", "black", 1.0, 1.0);
 
                 // Draw Title
                 svg_canvas.draw_title(
                     width / 2.0,
-                    margin / 2.0,
+                    top / 2.0,
                     &self.title,
                     font_size * 2.0,
                     "black",
                 );
 
                 // Calculate unique axis values
-                let unique_x_values: Vec<u32> = self
-                    .datasets
-                    .iter()
-                    .flat_map(|dataset| dataset.data.iter().map(|(x, _)| *x as u32))
-                    .collect::<std::collections::BTreeSet<_>>()
-                    .into_iter()
-                    .collect();
+                let unique_x_values = self.category_keys();
 
                 let x_count = unique_x_values.len();
 
-                let y_max = self
-                    .datasets
-                    .iter()
-                    .flat_map(|dataset| dataset.data.iter().map(|(_, y)| *y))
-                    .fold(0.0_f64, |max, y| max.max(y));
+                let (_, y_max) = self.value_range();
 
                 // Calculate scales
-                let scale_x = (width - 2.0 * margin) / x_count as f64;
-                let scale_y = (height - 2.0 * margin) / y_max;
+                let scale_x = (right - left) / x_count as f64;
+                let scale_y = (bottom - top) / y_max;
 
                 // Draw grid
+                let grid_start = svg_canvas.elements.len();
                 svg_canvas.draw_grid(
-                    margin,
-                    width - margin,
-                    margin,
-                    height - margin,
+                    left,
+                    right,
+                    top,
+                    bottom,
                     10,
                     10,
                     "lightgray",
+                    self.config.grid_line_type.clone(),
                 );
+                let grid = grid_start..svg_canvas.elements.len();
 
                 // Draw axes
-                let origin_x = margin;
-                let origin_y = height - margin;
+                let origin_x = left;
+                let origin_y = bottom;
 
-                svg_canvas.draw_line(origin_x, margin, origin_x, origin_y, "black", 2.0); // Y-axis
-                svg_canvas.draw_line(origin_x, origin_y, width - margin, origin_y, "black", 2.0); // X-axis
+                svg_canvas.draw_line(origin_x, top, origin_x, origin_y, "black", 2.0); // Y-axis
+                svg_canvas.draw_line(origin_x, origin_y, right, origin_y, "black", 2.0); // X-axis
 
                 // Y-axis
                 let num_ticks = 10;
                 let mut y_axis_ticks = String::new();
                 for i in 0..=num_ticks {
                     let value = 0.0 + i as f64 * (y_max - 0.0) / num_ticks as f64;
-                    let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+                    let y = bottom - i as f64 * (bottom - top) / num_ticks as f64;
                     let tick_start_x = origin_x - 5.0;
                     let tick_end_x = origin_x + 5.0;
 
@@ -85,8 +85,8 @@ impl Drawer for GroupBarChart {
 
                     // Draw value as text (fallback to basic SVG <text>)
                     svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 10.0, y + font_size * 0.3, font_size, value
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{}</text>"#,
+            left - 10.0, y + font_size * 0.3, font_size, self.config.format_tick(value)
         ));
                 }
                 svg_canvas.elements.push(format!(
@@ -96,103 +96,111 @@ impl Drawer for GroupBarChart {
 
                 // Draw X-axis labels and grouped bars
                 let group_width = scale_x * 0.8; // Width of each group of bars
-                let bar_width = group_width / self.datasets.len() as f64; // Width of each bar
+                let bar_width = match self.bar_mode {
+                    BarMode::Grouped => group_width / self.datasets.len() as f64, // Width of each bar
+                    BarMode::Stacked => group_width,
+                };
 
                 for (group_index, x_label) in unique_x_values.iter().enumerate() {
                     let group_center_x = origin_x + (group_index as f64 + 0.4) * scale_x;
-                    // let x = margin + group_index as f64 * (width - 2.0 * margin) / unique_x_values.len() as f64;
+                    // let x = left + group_index as f64 * (right - left) / unique_x_values.len() as f64;
 
                     // Draw X-axis label
                     svg_canvas.draw_text(
                         group_center_x,
                         origin_y + font_size * 1.5,
-                        &x_label.to_string(),
+                        &self.category_label(*x_label),
                         font_size,
                         "black",
                     );
 
                     // Draw bars for each dataset in the group
-                    for (dataset_index, dataset) in self.datasets.iter().enumerate() {
-                        if let Some(&(_, value)) = dataset
-                            .data
-                            .iter()
-                            .find(|(x, _)| (*x as u32).to_string() == x_label.to_string())
-                        {
-                            let bar_height = value * scale_y;
-                            let bar_left = group_center_x - group_width / 2.0
-                                + dataset_index as f64 * bar_width;
-
-                            // Draw bar
-                            svg_canvas.elements.push(format!(
+                    let segments = self.bar_segments(*x_label);
+                    for (segment_index, &(dataset_index, start, end)) in segments.iter().enumerate()
+                    {
+                        let dataset = &self.datasets[dataset_index];
+                        let slot = match self.bar_mode {
+                            BarMode::Grouped => dataset_index,
+                            BarMode::Stacked => 0,
+                        };
+                        let bar_height = (end - start) * scale_y;
+                        let bar_left = group_center_x - group_width / 2.0 + slot as f64 * bar_width;
+
+                        // Draw bar
+                        svg_canvas.elements.push(format!(
                         r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})" stroke="black" stroke-width="1"/>"#,
                         bar_left,
-                        origin_y - bar_height,
+                        origin_y - end * scale_y,
                         bar_width,
                         bar_height,
                         dataset.color[0],
                         dataset.color[1],
                         dataset.color[2]
                     ));
+
+                        // Draw the value above the bar, below the upper edge of the canvas
+                        if self.labels_segment(&segments, segment_index) {
+                            svg_canvas.draw_text(
+                                bar_left + bar_width / 2.0,
+                                (origin_y - end * scale_y - 4.0).max(font_size),
+                                &self.config.number_format.format(end),
+                                font_size,
+                                "black",
+                            );
                         }
                     }
                 }
+                grid
             }
             Orientation::Horizontal => {
                 let width = svg_canvas.width as f64;
                 let height = svg_canvas.height as f64;
-                let margin = svg_canvas.margin as f64;
+                let (left, top, right, bottom) = svg_canvas.plot_bounds();
                 let font_size = 12.0;
 
-                // Draw background
-                svg_canvas.draw_rect(0.0, 0.0, width, height, "white", "black", 1.0, 1.0);
+                // Draw the frame, the background itself is added by the canvas
+                svg_canvas.draw_rect(0.0, 0.0, width, height, "none", "black", 1.0, 1.0);
 
                 // Draw Title
                 svg_canvas.draw_title(
                     width / 2.0,
-                    margin / 2.0,
+                    top / 2.0,
                     &self.title,
                     font_size * 2.0,
                     "black",
                 );
 
                 // Calculate unique axis values
-                let unique_y_values: Vec<u32> = self
-                    .datasets
-                    .iter()
-                    .flat_map(|dataset| dataset.data.iter().map(|(y, _)| *y as u32))
-                    .collect::<std::collections::BTreeSet<_>>()
-                    .into_iter()
-                    .collect();
+                let unique_y_values = self.category_keys();
 
                 let y_count = unique_y_values.len();
 
-                let x_max = self
-                    .datasets
-                    .iter()
-                    .flat_map(|dataset| dataset.data.iter().map(|(_, x)| *x))
-                    .fold(0.0_f64, |max, x| max.max(x));
+                let (_, x_max) = self.value_range();
 
                 // Calculate scales
-                let scale_y = (height - 2.0 * margin) / y_count as f64;
-                let scale_x = (width - 2.0 * margin) / x_max;
+                let scale_y = (bottom - top) / y_count as f64;
+                let scale_x = (right - left) / x_max;
 
                 // Draw grid
+                let grid_start = svg_canvas.elements.len();
                 svg_canvas.draw_grid(
-                    margin,
-                    width - margin,
-                    margin,
-                    height - margin,
+                    left,
+                    right,
+                    top,
+                    bottom,
                     10,
                     10,
                     "lightgray",
+                    self.config.grid_line_type.clone(),
                 );
+                let grid = grid_start..svg_canvas.elements.len();
 
                 // Draw axes
-                let origin_x = margin;
-                let origin_y = height - margin;
+                let origin_x = left;
+                let origin_y = bottom;
 
-                svg_canvas.draw_line(origin_x, margin, origin_x, origin_y, "black", 2.0); // Y-axis
-                svg_canvas.draw_line(origin_x, origin_y, width - margin, origin_y, "black", 2.0); // X-axis
+                svg_canvas.draw_line(origin_x, top, origin_x, origin_y, "black", 2.0); // Y-axis
+                svg_canvas.draw_line(origin_x, origin_y, right, origin_y, "black", 2.0); // X-axis
 
                 // Draw X-axis tick marks and labels
                 let num_ticks = 10;
@@ -200,7 +208,7 @@ impl Drawer for GroupBarChart {
                 for i in 0..=num_ticks {
                     let value_x = i as f64 * x_tick_step;
                     let tick_x = origin_x + (value_x * scale_x);
-                    let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+                    let x = left + i as f64 * (right - left) / num_ticks as f64;
                     // Draw tick line
                     svg_canvas.draw_line(tick_x, origin_y, tick_x, origin_y + 5.0, "black", 1.0);
 
@@ -208,7 +216,7 @@ impl Drawer for GroupBarChart {
                     svg_canvas.draw_text(
                         x,
                         origin_y + font_size * 1.5,
-                        &format!("{:.1}", value_x),
+                        &self.config.format_tick(value_x),
                         font_size,
                         "black",
                     );
@@ -216,7 +224,10 @@ impl Drawer for GroupBarChart {
 
                 // Draw Y-axis labels and grouped bars
                 let group_height = scale_y * 0.8; // Height of each group of bars
-                let bar_height = group_height / self.datasets.len() as f64; // Height of each bar
+                let bar_height = match self.bar_mode {
+                    BarMode::Grouped => group_height / self.datasets.len() as f64, // Height of each bar
+                    BarMode::Stacked => group_height,
+                };
 
                 for (group_index, y_label) in unique_y_values.iter().enumerate() {
                     let group_center_y = origin_y - (group_index as f64 + 0.5) * scale_y;
@@ -225,26 +236,28 @@ impl Drawer for GroupBarChart {
                     svg_canvas.draw_text(
                         origin_x - font_size * 3.0,
                         group_center_y,
-                        &y_label.to_string(),
+                        &self.category_label(*y_label),
                         font_size,
                         "black",
                     );
 
                     // Draw bars for each dataset in the group
-                    for (dataset_index, dataset) in self.datasets.iter().enumerate() {
-                        if let Some(&(_, value)) = dataset
-                            .data
-                            .iter()
-                            .find(|(y, _)| (*y as u32).to_string() == y_label.to_string())
-                        {
-                            let bar_length = value * scale_x;
-                            let bar_top = group_center_y - group_height / 2.0
-                                + dataset_index as f64 * bar_height;
-
-                            // Draw bar
-                            svg_canvas.elements.push(format!(
+                    let segments = self.bar_segments(*y_label);
+                    for (segment_index, &(dataset_index, start, end)) in segments.iter().enumerate()
+                    {
+                        let dataset = &self.datasets[dataset_index];
+                        let slot = match self.bar_mode {
+                            BarMode::Grouped => dataset_index,
+                            BarMode::Stacked => 0,
+                        };
+                        let bar_length = (end - start) * scale_x;
+                        let bar_top =
+                            group_center_y - group_height / 2.0 + slot as f64 * bar_height;
+
+                        // Draw bar
+                        svg_canvas.elements.push(format!(
                         r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})" stroke="black" stroke-width="1"/>"#,
-                        origin_x,
+                        origin_x + start * scale_x,
                         bar_top,
                         bar_length,
                         bar_height,
@@ -252,46 +265,77 @@ impl Drawer for GroupBarChart {
                         dataset.color[1],
                         dataset.color[2]
                     ));
+
+                        // Draw the value right of the bar
+                        if self.labels_segment(&segments, segment_index) {
+                            svg_canvas.elements.push(format!(
+                                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
+                                origin_x + end * scale_x + 4.0,
+                                bar_top + bar_height / 2.0 + font_size * 0.3,
+                                font_size,
+                                escape_xml(&self.config.number_format.format(end))
+                            ));
                         }
                     }
                 }
+                grid
             }
+        };
+
+        // Move the grid over the data
+        if self.config.grid_on_top {
+            svg_canvas.raise_elements(grid);
         }
-        // Draw legend in the bottom-left corner
-        let legend_x_start = 5.0; // Start at the very left with margin spacing
-        let legend_y = height - margin / 2.0; // Move to bottom-left corner
 
+        // Draw legend in the bottom-left corner
+        let marker_size = self.config.legend_marker_size as f64;
+        let entry_width =
+            |label: &str| marker_size + font_size * 4.0 + label.len() as f64 * font_size * 0.6;
+        let legend_width = self
+            .datasets
+            .iter()
+            .map(|dataset| entry_width(&dataset.label))
+            .sum::<f64>()
+            + 5.0;
+        let legend_height = font_size.max(marker_size) + 10.0;
+        let Some((legend_left, legend_top)) =
+            self.svg_legend_origin(svg_canvas, (legend_width, legend_height))
+        else {
+            return;
+        };
+        let legend_x_start = legend_left + 5.0;
+        let legend_y = legend_top + 5.0;
         let mut legend_x = legend_x_start;
         let mut elements = String::new();
 
         for dataset in &self.datasets {
+            let color = if dataset.visible {
+                dataset.color
+            } else {
+                LEGEND_HIDDEN_COLOR
+            };
+
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
-                legend_x,
-                legend_y,
-                font_size,
-                font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2]
+                legend_x, legend_y, marker_size, marker_size, color[0], color[1], color[2]
             ));
 
             elements.push_str(&format!(
                 r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
-                legend_x + font_size * 1.3,
+                legend_x + marker_size + font_size * 0.3,
                 legend_y + font_size - 2.0,
                 font_size,
-                dataset.label
+                escape_xml(&dataset.label)
             ));
 
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
+            legend_x += entry_width(&dataset.label);
         }
 
         svg_canvas.draw_rect(
             legend_x_start - 5.0,
             legend_y - 5.0,
-            legend_x - legend_x_start + 5.0,
-            font_size + 10.0,
+            legend_width,
+            legend_height,
             "white",
             "black",
             0.5,
@@ -302,6 +346,14 @@ impl Drawer for GroupBarChart {
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
+        if !self.has_data() {
+            canvas.clear();
+            canvas.antialias = self.config.antialias;
+            canvas.text_rendering = self.config.text_rendering;
+            self.draw_no_data(canvas, &self.config, &self.title);
+            return;
+        }
+
         match self.orientation {
             Orientation::Vertical => self.draw_vertical(canvas),
             Orientation::Horizontal => self.draw_horizontal(canvas),
@@ -309,57 +361,52 @@ impl Drawer for GroupBarChart {
     }
 
     fn draw_legend(&self, canvas: &mut PixelCanvas) {
-        let font_path = self
-            .config
-            .font_label
-            .as_ref()
-            .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
+        let font_bytes = self.config.label_font_bytes();
         let font = FontRef::try_from_slice(&font_bytes).unwrap();
         let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
 
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
-        let legend_margin = canvas.margin; // Margin from the bottom of the canvas
-
-        let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
+        let square_size = self.config.legend_marker_size; // Size of the colored square
+        for (dataset, entry) in self.datasets.iter().zip(self.legend_entries(canvas)) {
+            let color = if dataset.visible {
+                dataset.color
+            } else {
+                LEGEND_HIDDEN_COLOR
+            };
 
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
             // Draw the square
             for dy in 0..square_size {
                 for dx in 0..square_size {
-                    canvas.draw_pixel(
-                        x + dx,
-                        y + square_size * 2 + dy + h, // Adjust to align above baseline
-                        dataset.color,
-                    );
+                    canvas.draw_pixel(entry.x + dx, entry.y + dy, color);
                 }
             }
 
             // Draw the label text next to the square
-            let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
-                text_x,
-                y + 2 * square_size + h,
-                &dataset.label,
-                dataset.color,
-                &font,
-                scale,
-            );
-
-            // Move to the next legend entry
-            x += square_size + padding + w + padding;
-            if x > canvas.width - canvas.margin {
-                // If the width exceeds, wrap to the next row
-                x = canvas.margin;
-                y -= line_height;
-            }
+            let text_x: u32 = entry.x + square_size + LEGEND_PADDING;
+            canvas.draw_text(text_x, entry.y, &dataset.label, color, &font, scale);
         }
     }
 
+    fn legend_entries(&self, canvas: &PixelCanvas) -> Vec<LegendEntry> {
+        let labels: Vec<&str> = self
+            .datasets
+            .iter()
+            .map(|dataset| dataset.label.as_str())
+            .collect();
+        self.layout_legend(canvas, self.config.legend_marker_size, &labels)
+    }
+
+    fn toggle_dataset(&mut self, index: usize) -> bool {
+        let Some(dataset) = self.datasets.get_mut(index) else {
+            return false;
+        };
+        dataset.visible = !dataset.visible;
+        true
+    }
+
+    fn has_data(&self) -> bool {
+        self.datasets.iter().any(|dataset| !dataset.data.is_empty())
+    }
+
     fn as_any(&mut self) -> &mut (dyn Any + 'static) {
         self as &mut dyn Any
     }
@@ -1,32 +1,41 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
-
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
     figuretypes::cartesiangraph::CartesianGraph,
-    utilities::axistype::AxisType,
+    utilities::{
+        axisscale::AxisScale, axistype::AxisType, clipping::ClipRect, linetype::LineType,
+        simplify::simplify, ticks::nice_tick_values,
+    },
 };
 
-use super::drawer::Drawer;
+use super::drawer::{Drawer, LegendEntry, LEGEND_HIDDEN_COLOR};
 use std::any::Any;
+use std::sync::atomic::{AtomicBool, Ordering};
 impl Drawer for CartesianGraph {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        if !self.has_data() {
+            self.draw_no_data_svg(svg_canvas, &self.title);
+            return;
+        }
+
         // Clear existing SVG elements
         // svg_canvas.clear();
 
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
-        let margin = svg_canvas.margin as f64;
+        let (left, top, right, bottom) = svg_canvas.plot_bounds();
         let font_size = 12.0;
 
-        // Draw background
-        svg_canvas.draw_rect(0.0, 0.0, width, height, "white", "black", 2.0, 1.0);
+        // Draw the frame, the background itself is added by the canvas
+        svg_canvas.draw_rect(0.0, 0.0, width, height, "none", "black", 2.0, 1.0);
 
         // Draw Title
         svg_canvas.draw_title(
             width / 2.0,
-            margin / 2.0,
+            top / 2.0,
             &self.title,
             font_size * 2.0,
             "black",
@@ -35,36 +44,63 @@ impl Drawer for CartesianGraph {
         // Symmetric scaling
         self.update_range();
 
-        let scale_x = (svg_canvas.width - 2 * svg_canvas.margin) as f64 / (self.x_max - self.x_min);
-        let scale_y =
-            (svg_canvas.height - 2 * svg_canvas.margin) as f64 / (self.y_max - self.y_min);
+        let scale_x = (right - left) / (self.x_max - self.x_min);
+        let (y_lo, y_hi) = self.y_axis_range();
+        let scale_y = (bottom - top) / (y_hi - y_lo);
+        let y_position = |y: f64| {
+            self.y_scale
+                .transform(y)
+                .map(|y| bottom - (y - y_lo) * scale_y)
+        };
 
         // Draw grid
         let num_ticks = 20;
+        let grid_start = svg_canvas.elements.len();
         svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
+            left,
+            right,
+            top,
+            bottom,
             num_ticks,
             num_ticks,
             "lightgray",
+            self.config.grid_line_type.clone(),
         );
+        let grid = grid_start..svg_canvas.elements.len();
 
         // Draw axes
-        let center_x = margin + (0.0 - self.x_min) * scale_x;
-        let center_y = height - margin - (0.0 - self.y_min) * scale_y;
-
-        svg_canvas.draw_line(margin, center_y, width - margin, center_y, "black", 2.0);
-        svg_canvas.draw_line(center_x, margin, center_x, height - margin, "black", 2.0);
+        let center_x = left + (0.0 - self.x_min) * scale_x;
+        // The X-axis is at y = 0, or at the bottom of a logarithmic Y-axis
+        let center_y = y_position(0.0).unwrap_or(bottom);
+
+        let zero_line_width = self.config.zero_line_width as f64;
+        if self.y_scale == AxisScale::Linear && self.y_min <= 0.0 && self.y_max >= 0.0 {
+            svg_canvas.draw_line_rgb(
+                left,
+                center_y,
+                right,
+                center_y,
+                self.config.effective_zero_line_color(),
+                zero_line_width,
+            );
+        }
+        if self.x_min <= 0.0 && self.x_max >= 0.0 {
+            svg_canvas.draw_line_rgb(
+                center_x,
+                top,
+                center_x,
+                bottom,
+                self.config.effective_zero_line_color(),
+                zero_line_width,
+            );
+        }
 
         // Draw tick marks and labels
 
         // X-axis
         let mut x_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = self.x_min + i as f64 * (self.x_max - self.x_min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+        for value in nice_tick_values(self.x_ticks.as_deref(), self.x_min, self.x_max, num_ticks) {
+            let x = left + (value - self.x_min) * scale_x;
             let tick_start_y = center_y - 5.0;
             let tick_end_y = center_y + 5.0;
 
@@ -75,8 +111,8 @@ impl Drawer for CartesianGraph {
 
             // Draw value as text (fallback to basic SVG <text>)
             svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{:.1}</text>"#,
-            x, height - margin + font_size * 1.5, font_size, value));
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{}</text>"#,
+            x, bottom + font_size * 1.5, font_size, self.config.format_tick(value)));
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{}" stroke="black" stroke-width="1" fill="none"/>"#,
@@ -85,9 +121,10 @@ impl Drawer for CartesianGraph {
 
         // Y-axis
         let mut y_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = self.y_min + i as f64 * (self.y_max - self.y_min) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+        for value in self.y_tick_values(num_ticks) {
+            let Some(y) = y_position(value) else {
+                continue;
+            };
             let tick_start_x = center_x - 5.0;
             let tick_end_x = center_x + 5.0;
 
@@ -98,8 +135,8 @@ impl Drawer for CartesianGraph {
 
             // Draw value as text (fallback to basic SVG <text>)
             svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 5.0, y + font_size * 0.3, font_size, value
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{}</text>"#,
+            left - 5.0, y + font_size * 0.3, font_size, self.config.format_tick(value)
         ));
         }
         svg_canvas.elements.push(format!(
@@ -111,74 +148,103 @@ impl Drawer for CartesianGraph {
         svg_canvas.elements.push(format!(
         r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{}</text>"#,
         width / 2.0,
-        margin - 5.0,
+        top - 5.0,
         font_size * 1.5,
-        self.y_label
+        escape_xml(&self.y_label)
     ));
 
         // Draw Y-axis label (rotated)
         svg_canvas.elements.push(format!(
         r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
-        margin / 3.0,
+        left / 3.0,
         height / 2.0,
         font_size * 1.5,
-        margin / 3.0,
+        left / 3.0,
         height / 2.0,
-        self.x_label
+        escape_xml(&self.x_label)
     ));
 
-        // Plot datasets
-        for dataset in &self.datasets {
-            for window in dataset.points.windows(2) {
+        // Plot the visible datasets
+        for dataset in self.datasets.iter().filter(|dataset| dataset.visible) {
+            let points = simplify(self.visible_points(dataset), self.config.simplify_tolerance);
+            for window in self.interpolation.apply(&points).windows(2) {
                 if let [p1, p2] = window {
-                    let x1 = margin + (p1.0 - self.x_min) * scale_x;
-                    let y1 = height - margin - (p1.1 - self.y_min) * scale_y;
-                    let x2 = margin + (p2.0 - self.x_min) * scale_x;
-                    let y2 = height - margin - (p2.1 - self.y_min) * scale_y;
-
-                    svg_canvas.draw_line_rgb(x1, y1, x2, y2, dataset.color, 2.0);
+                    // Skip segments with a point that can't be shown on the Y-axis
+                    let (Some(y1), Some(y2)) = (y_position(p1.1), y_position(p2.1)) else {
+                        continue;
+                    };
+                    let x1 = left + (p1.0 - self.x_min) * scale_x;
+                    let x2 = left + (p2.0 - self.x_min) * scale_x;
+
+                    svg_canvas.draw_line_styled(
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        dataset.color,
+                        2.0 * dataset.line_width as f64,
+                        dataset.line_type.clone(),
+                    );
                 }
             }
         }
 
+        // Move the grid over the data
+        if self.config.grid_on_top {
+            svg_canvas.raise_elements(grid);
+        }
+
         // Draw legend
-        let legend_x_start = 5.0; // Start at the very left with margin spacing
-        let legend_y = height - margin / 2.0; // Move to bottom-left corner
-        let mut legend_x = legend_x_start; // Reset starting position for legend items
+        let marker_size = self.config.legend_marker_size as f64;
+        let entry_width =
+            |label: &str| marker_size + font_size * 4.0 + label.len() as f64 * font_size * 0.6;
+        let legend_width = self
+            .datasets
+            .iter()
+            .map(|dataset| entry_width(&dataset.label))
+            .sum::<f64>()
+            + 5.0;
+        let legend_height = font_size.max(marker_size) + 10.0;
+        let Some((legend_left, legend_top)) =
+            self.svg_legend_origin(svg_canvas, (legend_width, legend_height))
+        else {
+            return;
+        };
+        let legend_x_start = legend_left + 5.0;
+        let legend_y = legend_top + 5.0;
+        let mut legend_x = legend_x_start;
         let mut elements = String::new();
 
         for dataset in &self.datasets {
+            let color = if dataset.visible {
+                dataset.color
+            } else {
+                LEGEND_HIDDEN_COLOR
+            };
+
             // Draw color square
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
-                legend_x,
-                legend_y,
-                font_size,
-                font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2]
+                legend_x, legend_y, marker_size, marker_size, color[0], color[1], color[2]
             ));
 
             // Draw label text next to the color square
             elements.push_str(&format!(
                 r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="rgb({},{},{})">{}</text>"#,
-                legend_x + font_size * 1.3,
+                legend_x + marker_size + font_size * 0.3,
                 legend_y + font_size - 2.0,
                 font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2],
-                dataset.label
+                color[0],
+                color[1],
+                color[2],
+                escape_xml(&dataset.label)
             ));
 
             // Update legend_x to position the next item
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
+            legend_x += entry_width(&dataset.label);
         }
 
         // Draw a background rectangle for the legend
-        let legend_width = legend_x - legend_x_start + 5.0;
-        let legend_height = font_size + 10.0;
         svg_canvas.draw_rect(
             legend_x_start - 5.0,
             legend_y - 5.0,
@@ -194,23 +260,86 @@ impl Drawer for CartesianGraph {
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
+        self.draw_with_progress(canvas, |_| {}, &AtomicBool::new(false));
+    }
+
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let entries: Vec<(&str, [u8; 3], bool)> = self
+            .datasets
+            .iter()
+            .map(|dataset| (dataset.label.as_str(), dataset.color, !dataset.visible))
+            .collect();
+        self.draw_square_legend(canvas, &entries);
+    }
+
+    fn legend_entries(&self, canvas: &PixelCanvas) -> Vec<LegendEntry> {
+        let labels: Vec<&str> = self
+            .datasets
+            .iter()
+            .map(|dataset| dataset.label.as_str())
+            .collect();
+        self.layout_legend(canvas, self.config.legend_marker_size, &labels)
+    }
+
+    fn toggle_dataset(&mut self, index: usize) -> bool {
+        let Some(dataset) = self.datasets.get_mut(index) else {
+            return false;
+        };
+        dataset.visible = !dataset.visible;
+        true
+    }
+
+    fn has_data(&self) -> bool {
+        self.datasets
+            .iter()
+            .any(|dataset| !self.visible_points(dataset).is_empty())
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+}
+
+impl CartesianGraph {
+    /// Draws the graph on a pixel canvas while reporting progress and honoring a cancel flag.
+    ///
+    /// This is the variant of [`Drawer::draw`] intended for very large datasets.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` on which to draw the graph.
+    /// - `on_progress`: Called after each dataset with the fraction (`0.0..=1.0`) of points processed so far.
+    /// - `cancel`: Checked before each dataset. Once set, drawing stops and the remaining datasets,
+    ///   tick values and legend are skipped.
+    ///
+    /// # Returns
+    /// `true` if the graph was drawn completely, `false` if drawing was cancelled.
+    pub fn draw_with_progress(
+        &mut self,
+        canvas: &mut PixelCanvas,
+        mut on_progress: impl FnMut(f32),
+        cancel: &AtomicBool,
+    ) -> bool {
         canvas.clear();
+        canvas.antialias = self.config.antialias;
+        canvas.text_rendering = self.config.text_rendering;
 
-        let margin = canvas.margin;
+        if !self.has_data() {
+            self.draw_no_data(canvas, &self.config, &self.title);
+            return true;
+        }
+
+        let (left, top, right, bottom) = canvas.plot_bounds();
         let width = canvas.width;
-        let height = canvas.height;
         let cfg = &self.config;
-        let center_x = width / 2;
-        let center_y = height / 2;
+        let center_x = left + (right - left) / 2;
+        let center_y = top + (bottom - top) / 2;
 
         // Draw the title
-        self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
-
-        // Draw grids
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
-        );
+        self.draw_title(canvas, cfg, width / 2, top / 2, &self.title);
 
         // Ensure x_min and x_max are symmetric
         let abs_x_min = self.x_min.abs();
@@ -222,146 +351,594 @@ impl Drawer for CartesianGraph {
             self.x_min = -abs_x_max;
         }
 
-        // Draw X and Y axes
-        canvas.draw_vertical_line(center_x, [0, 0, 0]);
-        canvas.draw_horizontal_line(center_y, [0, 0, 0]);
+        let scale_x = (right - left) as f64 / (self.x_max - self.x_min);
+        let (y_lo, y_hi) = self.y_axis_range();
+        let scale_y = (bottom - top) as f64 / (y_hi - y_lo);
+        let y_position = |y: f64| {
+            self.y_scale
+                .transform(y)
+                .map(|y| bottom as f64 - (y - y_lo) * scale_y)
+        };
 
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (self.x_max - self.x_min);
-        let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (self.y_max - self.y_min); // Adjust y-range as needed
+        // Tick positions, shared by the grid and the tick values
+        let num_ticks = 10;
+        let x_tick_values =
+            nice_tick_values(self.x_ticks.as_deref(), self.x_min, self.x_max, num_ticks);
+        let x_tick_positions: Vec<u32> = x_tick_values
+            .iter()
+            .map(|value_x| left + ((value_x - self.x_min) * scale_x) as u32)
+            .collect();
+        let y_tick_values = self.y_tick_values(num_ticks);
+        let y_tick_positions: Vec<u32> = y_tick_values
+            .iter()
+            .filter_map(|&value_y| self.y_scale.transform(value_y))
+            .map(|value_y| bottom - ((value_y - y_lo) * scale_y) as u32)
+            .collect();
+
+        // Draw grids, unless they go on top of the data
+        if !cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &x_tick_positions, &y_tick_positions);
+        }
 
-        for dataset in &self.datasets {
-            for window in dataset.points.windows(2) {
+        // Draw X and Y axes as emphasized zero lines when the range spans zero
+        let zero_x = (self.x_min <= 0.0 && self.x_max >= 0.0).then_some(center_x);
+        let zero_y = (self.y_scale == AxisScale::Linear && self.y_min <= 0.0 && self.y_max >= 0.0)
+            .then_some(center_y);
+        self.draw_zero_lines(canvas, cfg, zero_x, zero_y);
+
+        let plot_area = ClipRect::new(
+            left as f64,
+            top as f64,
+            (right - 1) as f64,
+            (bottom - 1) as f64,
+        );
+
+        let total_points: usize = self
+            .datasets
+            .iter()
+            .filter(|d| d.visible)
+            .map(|d| self.visible_points(d).len())
+            .sum();
+        let mut processed_points = 0;
+
+        for dataset in self.datasets.iter().filter(|dataset| dataset.visible) {
+            if cancel.load(Ordering::Relaxed) {
+                return false;
+            }
+
+            let points = self.visible_points(dataset);
+            let simplified = simplify(points, self.config.simplify_tolerance);
+            for window in self.interpolation.apply(&simplified).windows(2) {
                 if let [p1, p2] = window {
-                    let x1 = center_x as i32 + (p1.0 * scale_x) as i32;
-                    let y1 = center_y as i32 - (p1.1 * scale_y) as i32;
-                    let x2 = center_x as i32 + (p2.0 * scale_x) as i32;
-                    let y2 = center_y as i32 - (p2.1 * scale_y) as i32;
-
-                    // Simple line drawing algorithm (Bresenham)
-                    let dx = (x2 - x1).abs();
-                    let sx = if x1 < x2 { 1 } else { -1 };
-                    let dy = -(y2 - y1).abs();
-                    let sy = if y1 < y2 { 1 } else { -1 };
-                    let mut err = dx + dy;
-
-                    let mut x = x1;
-                    let mut y = y1;
-
-                    while x != x2 || y != y2 {
-                        if x >= canvas.margin as i32
-                            && x < (canvas.width - canvas.margin) as i32
-                            && y >= canvas.margin as i32
-                            && y < (canvas.height - canvas.margin) as i32
-                        {
-                            canvas.draw_pixel(x as u32, y as u32, dataset.color);
-                        }
-
-                        let e2 = 2 * err;
-                        if e2 >= dy {
-                            err += dy;
-                            x += sx;
-                        }
-                        if e2 <= dx {
-                            err += dx;
-                            y += sy;
-                        }
+                    // Skip segments with a point that can't be shown on the Y-axis
+                    let (Some(y1), Some(y2)) = (y_position(p1.1), y_position(p2.1)) else {
+                        continue;
+                    };
+                    let x1 = center_x as f64 + p1.0 * scale_x;
+                    let x2 = center_x as f64 + p2.0 * scale_x;
+
+                    // Clip the segment at the plot boundary so partly visible segments are kept
+                    if let Some((x1, y1, x2, y2)) = plot_area.clip_line(x1, y1, x2, y2) {
+                        canvas.draw_line_thick(
+                            x1.round() as i32,
+                            y1.round() as i32,
+                            x2.round() as i32,
+                            y2.round() as i32,
+                            dataset.color,
+                            dataset.line_width,
+                            LineType::Solid,
+                        );
                     }
                 }
             }
+
+            processed_points += points.len();
+            on_progress(if total_points == 0 {
+                1.0
+            } else {
+                processed_points as f32 / total_points as f32
+            });
+        }
+
+        // Draw grids over the data
+        if cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &x_tick_positions, &y_tick_positions);
         }
 
-        // X-axis label
-        let origin_y = height - margin - ((0.0 - self.y_min) * scale_y) as u32;
-        self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.x_label);
-        self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
+        // X-axis label, at y = 0 or at the bottom of a logarithmic Y-axis
+        let axis_y = self.y_scale.transform(0.0).unwrap_or(y_lo);
+        let origin_y = bottom - ((axis_y - y_lo) * scale_y) as u32;
+        self.draw_label(
+            canvas,
+            cfg,
+            right + (width - right) / 2,
+            origin_y,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, left, top / 2, &self.y_label);
 
         // Draw X and Y axis tick values
-        let num_ticks = 10;
-        let x_tick_step = (canvas.width - 2 * canvas.margin) / num_ticks;
-        let y_tick_step = (canvas.height - 2 * canvas.margin) / num_ticks;
-
-        let y = canvas.height - canvas.margin;
-        for i in 0..=num_ticks {
-            // X-axis ticks
-            let x = canvas.margin + i * x_tick_step;
-            let value_x = self.x_min + ((self.x_max - self.x_min) / num_ticks as f64) * i as f64;
-            let label_x = format!("{:+.2}", value_x);
+        // X-axis ticks
+        let y = bottom;
+        for (&value_x, &x) in x_tick_values.iter().zip(&x_tick_positions) {
+            let label_x = cfg.format_tick(value_x);
             self.draw_axis_value(canvas, cfg, x, y, &label_x, AxisType::AxisX);
+        }
 
-            // Y-axis ticks
-            let y = canvas.margin + i * y_tick_step;
-            let value_y = self.y_min + ((self.y_max - self.y_min) / num_ticks as f64) * i as f64;
-            let label_y = format!("{:.2}", value_y);
+        // Y-axis ticks
+        for (&value_y, &y) in y_tick_values.iter().zip(&y_tick_positions) {
+            let label_y = cfg.format_tick(value_y);
             self.draw_axis_value(
                 canvas,
                 cfg,
-                margin - 10,
-                height - y,
+                left.saturating_sub(10),
+                y,
                 &label_y,
                 AxisType::AxisY,
             );
         }
 
         self.draw_legend(canvas);
+        true
     }
+}
 
-    fn draw_legend(&self, canvas: &mut PixelCanvas) {
-        let font_path = self
-            .config
-            .font_label
-            .as_ref()
-            .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
-
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
-        let legend_margin = canvas.margin; // Margin from the bottom of the canvas
-
-        let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::canvas::testing::pixel_at;
+    use crate::figure::datasets::{cartesiangraphdataset::CartesianDataset, dataset::Dataset};
+    use crate::figure::utilities::textrendering::TextRendering;
+
+    fn graph_with_datasets(count: usize) -> CartesianGraph {
+        let mut graph = CartesianGraph::new("Progress", "X", "Y", &FigureConfig::default());
+        for i in 0..count {
+            let mut dataset =
+                CartesianDataset::new([255, 0, 0], &format!("Dataset {}", i), LineType::Solid);
+            dataset.add_point((-10.0, -5.0));
+            dataset.add_point((10.0, 5.0));
+            graph.add_dataset(dataset);
+        }
+        graph
+    }
 
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
-            // Draw the square
-            for dy in 0..square_size {
-                for dx in 0..square_size {
-                    canvas.draw_pixel(
-                        x + dx,
-                        y + square_size * 2 + dy + h, // Adjust to align above baseline
-                        dataset.color,
-                    );
-                }
+    fn log_graph(points: &[(f64, f64)]) -> CartesianGraph {
+        let mut graph = graph_with_datasets(0);
+        graph.set_y_scale(AxisScale::Log10);
+        let mut dataset = CartesianDataset::new([0, 0, 255], "Growth", LineType::Solid);
+        for &point in points {
+            dataset.add_point(point);
+        }
+        graph.add_dataset(dataset);
+        graph
+    }
+
+    #[test]
+    fn test_log_scale_spaces_powers_of_ten_evenly() {
+        let mut graph = log_graph(&[(1.0, 1.0), (2.0, 10.0), (3.0, 100.0)]);
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+
+        graph.draw_svg(&mut svg_canvas);
+
+        let attribute = |element: &str, name: &str| -> f64 {
+            let start = element.find(&format!(r#" {}=""#, name)).unwrap() + name.len() + 3;
+            let end = start + element[start..].find('"').unwrap();
+            element[start..end].parse().unwrap()
+        };
+        let rows: Vec<(f64, f64)> = svg_canvas
+            .elements
+            .iter()
+            .filter(|element| element.starts_with("<line") && element.contains("rgb(0,0,255)"))
+            .map(|element| (attribute(element, "y1"), attribute(element, "y2")))
+            .collect();
+        // Decades 10^0..10^2 over the 220 px plot height
+        assert_eq!(rows, vec![(260.0, 150.0), (150.0, 40.0)]);
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut canvas);
+        // The middle point (2, 10) lies halfway up the plot
+        let x = 200 + (2.0 * 320.0 / 6.0_f64).round() as u32;
+        assert_eq!(pixel_at(&canvas, x, 150), [0, 0, 255]);
+    }
+
+    #[test]
+    fn test_log_scale_skips_non_positive_points() {
+        let mut graph = log_graph(&[(-2.0, -5.0), (-1.0, 0.0), (1.0, 1.0), (2.0, 10.0)]);
+        assert_eq!(graph.y_axis_range(), (0.0, 1.0));
+        assert_eq!(graph.y_tick_values(10), vec![1.0, 10.0]);
+
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+        graph.draw_svg(&mut svg_canvas);
+        let lines = svg_canvas
+            .elements
+            .iter()
+            .filter(|element| element.starts_with("<line") && element.contains("rgb(0,0,255)"))
+            .count();
+        assert_eq!(lines, 1);
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut canvas);
+    }
+
+    #[test]
+    fn test_dashed_dataset_has_dasharray_in_svg() {
+        let mut graph = graph_with_datasets(0);
+        let mut dataset = CartesianDataset::new([0, 0, 255], "Dashed", LineType::Dashed(8));
+        dataset.add_point((-10.0, -5.0));
+        dataset.add_point((10.0, 5.0));
+        graph.add_dataset(dataset);
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+
+        graph.draw_svg(&mut svg_canvas);
+
+        let line = svg_canvas
+            .elements
+            .iter()
+            .find(|element| element.starts_with("<line") && element.contains("rgb(0,0,255)"))
+            .expect("No line drawn for the dataset");
+        assert!(line.contains(r#"stroke-dasharray="8.00 8.00""#));
+    }
+
+    #[test]
+    fn test_draw_with_progress_reports_fraction() {
+        let mut graph = graph_with_datasets(4);
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        let mut reported = Vec::new();
+
+        let completed =
+            graph.draw_with_progress(&mut canvas, |p| reported.push(p), &AtomicBool::new(false));
+
+        assert!(completed);
+        assert_eq!(reported, vec![0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn test_draw_with_progress_stops_when_cancelled() {
+        let mut graph = graph_with_datasets(4);
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        let cancel = AtomicBool::new(false);
+        let mut calls = 0;
+
+        let completed = graph.draw_with_progress(
+            &mut canvas,
+            |_| {
+                calls += 1;
+                cancel.store(true, Ordering::Relaxed);
+            },
+            &cancel,
+        );
+
+        assert!(!completed);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_zero_lines_use_emphasis_color() {
+        let mut graph = graph_with_datasets(1);
+        graph.config.zero_line_color = [255, 0, 0];
+        graph.config.zero_line_width = 3;
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+
+        graph.draw(&mut canvas);
+
+        // The data spans -10..10 x -5..5, so both zero lines go through the center
+        for x in 199..=201 {
+            assert_eq!(pixel_at(&canvas, x, 100), [255, 0, 0]);
+        }
+        for y in 149..=151 {
+            assert_eq!(pixel_at(&canvas, 100, y), [255, 0, 0]);
+        }
+        // Regular gridlines keep the grid color, e.g. the one at the second X-axis tick
+        assert_eq!(pixel_at(&canvas, 72, 100), graph.config.color_grid);
+    }
+
+    #[test]
+    fn test_segment_leaving_plot_is_clipped_at_boundary() {
+        let mut graph = graph_with_datasets(0);
+        let mut dataset = CartesianDataset::new([0, 0, 255], "Clipped", LineType::Solid);
+        dataset.add_point((0.0, 0.0));
+        dataset.add_point((100.0, 100.0));
+        graph.add_dataset(dataset);
+        // Narrow the visible range so the second point lies far outside the plot
+        graph.x_min = -10.0;
+        graph.x_max = 10.0;
+        graph.y_min = -10.0;
+        graph.y_max = 10.0;
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+
+        graph.draw(&mut canvas);
+
+        // The segment runs from the center (200, 150) and leaves the plot at its right edge
+        assert_eq!(pixel_at(&canvas, 280, 95), [0, 0, 255]);
+        assert_eq!(pixel_at(&canvas, 359, 41), [0, 0, 255]);
+        // Nothing is drawn past the boundary
+        for x in 360..400 {
+            for y in 0..41 {
+                assert_ne!(pixel_at(&canvas, x, y), [0, 0, 255]);
             }
+        }
+    }
 
-            // Draw the label text next to the square
-            let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
-                text_x,
-                y + 2 * square_size + h,
-                &dataset.label,
-                dataset.color,
-                &font,
-                scale,
-            );
+    #[test]
+    fn test_set_config_applies_to_next_draw() {
+        let mut graph = graph_with_datasets(1);
+        let dark = FigureConfig {
+            color_background: [30, 30, 30],
+            color_grid: [80, 80, 80],
+            ..FigureConfig::default()
+        };
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+
+        graph.set_config(dark);
+        graph.draw(&mut canvas);
+
+        // The datasets survive the config change
+        assert_eq!(graph.datasets.len(), 1);
+        assert_eq!(graph.config.color_background, [30, 30, 30]);
+        assert_eq!(pixel_at(&canvas, 72, 100), [80, 80, 80]);
+    }
+
+    #[test]
+    fn test_title_renders_with_builtin_font() {
+        // No font paths are configured
+        let mut graph = CartesianGraph::new("Title", "X", "Y", &FigureConfig::default());
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+
+        graph.draw(&mut canvas);
 
-            // Move to the next legend entry
-            x += square_size + padding + w + padding;
-            if x > canvas.width - canvas.margin {
-                // If the width exceeds, wrap to the next row
-                x = canvas.margin;
-                y -= line_height;
+        let title_area_has_text =
+            (0..40).any(|y| (150..250).any(|x| pixel_at(&canvas, x, y) != [255, 255, 255]));
+        assert!(title_area_has_text);
+    }
+
+    #[test]
+    fn test_visible_range_limits_rendered_points() {
+        let mut graph = CartesianGraph::new("Paging", "X", "Y", &FigureConfig::default());
+        let mut dataset = CartesianDataset::new([0, 0, 255], "Series", LineType::Solid);
+        for i in 0..100 {
+            dataset.add_point((i as f64, i as f64));
+        }
+        graph.add_dataset(dataset);
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+
+        graph.set_visible_range(40, 60);
+        graph.draw(&mut canvas);
+
+        // The data is untouched and the range only covers the visible points
+        assert_eq!(graph.datasets[0].points.len(), 100);
+        assert_eq!(graph.x_max, 59.0);
+        assert_eq!(graph.y_max, 59.0);
+
+        // Only the segment from index 40 to 59 appears inside the plot area
+        let scale_x = 320.0 / (graph.x_max - graph.x_min);
+        let first_x = (200.0 + 40.0 * scale_x).floor() as u32;
+        let last_x = (200.0 + 59.0 * scale_x).ceil() as u32;
+        let mut blue_pixels = 0;
+        for y in canvas.margins.top..canvas.height - canvas.margins.bottom {
+            for x in 0..canvas.width {
+                if pixel_at(&canvas, x, y) == [0, 0, 255] {
+                    assert!((first_x..=last_x).contains(&x));
+                    blue_pixels += 1;
+                }
             }
         }
+        assert!(blue_pixels > 0);
     }
 
-    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
-        self as &mut dyn Any
+    #[test]
+    fn test_visible_range_past_the_data_draws_no_data_placeholder() {
+        let render = |points: &[(f64, f64)], visible_range: Option<(usize, usize)>| {
+            let mut graph = CartesianGraph::new("Paging", "X", "Y", &FigureConfig::default());
+            let mut dataset = CartesianDataset::new([0, 0, 255], "Series", LineType::Solid);
+            dataset.add_points(points);
+            graph.add_dataset(dataset);
+            if let Some((start, end)) = visible_range {
+                graph.set_visible_range(start, end);
+            }
+            let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+            graph.draw(&mut canvas);
+            canvas.buffer
+        };
+
+        let points = [(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)];
+        assert_eq!(render(&points, Some((10, 20))), render(&[], None));
+        assert_ne!(render(&points, Some((1, 20))), render(&[], None));
     }
 
-    fn get_figure_config(&self) -> &FigureConfig {
-        &self.config
+    #[test]
+    fn test_legend_marker_size_sets_swatch_size() {
+        let swatch_height = |marker_size: u32| {
+            let mut graph = graph_with_datasets(1);
+            graph.config.legend_marker_size = marker_size;
+            // The legend sits in the bottom margin, which must fit the larger swatch
+            let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 60);
+            canvas.clear();
+
+            graph.draw_legend(&mut canvas);
+
+            // The first column of the legend only holds the swatch, the label starts to its right
+            (0..canvas.height)
+                .filter(|&y| pixel_at(&canvas, canvas.margins.left, y) == [255, 0, 0])
+                .count()
+        };
+
+        assert_eq!(swatch_height(10), 10);
+        assert_eq!(swatch_height(16), 16);
+    }
+
+    #[test]
+    fn test_custom_ticks_replace_default_tick_labels() {
+        let mut graph = graph_with_datasets(1);
+        graph.set_ticks(Some(vec![-10.0, 0.0, 7.5]), Some(vec![-5.0, 2.0]));
+        let mut canvas = SvgCanvas::new(400, 300, "white", 40);
+
+        graph.draw_svg(&mut canvas);
+
+        let tick_labels = |anchor: &str| -> Vec<String> {
+            canvas
+                .elements
+                .iter()
+                .filter(|e| e.starts_with("<text") && e.contains(anchor))
+                .filter_map(|e| {
+                    let text = &e[e.find('>')? + 1..e.find("</text>")?];
+                    text.parse::<f64>().is_ok().then(|| text.to_string())
+                })
+                .collect()
+        };
+        let format = |values: &[f64]| -> Vec<String> {
+            values
+                .iter()
+                .map(|&v| graph.config.format_tick(v))
+                .collect()
+        };
+
+        assert_eq!(
+            tick_labels(r#"text-anchor="middle""#),
+            format(&[-10.0, 0.0, 7.5])
+        );
+        assert_eq!(tick_labels(r#"text-anchor="end""#), format(&[-5.0, 2.0]));
+    }
+
+    #[test]
+    fn test_box_placeholder_output_is_font_independent() {
+        let render = |config: &FigureConfig| {
+            let mut graph = CartesianGraph::new("Progress", "X", "Y", config);
+            let mut dataset = CartesianDataset::new([255, 0, 0], "Data", LineType::Solid);
+            dataset.add_point((-10.0, -5.0));
+            dataset.add_point((10.0, 5.0));
+            graph.add_dataset(dataset);
+            let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+            graph.draw(&mut canvas);
+            canvas.buffer
+        };
+        // The built-in font with every advance width doubled, so its text is twice as wide
+        let mut font = crate::figure::configuration::figureconfig::DEFAULT_FONT.to_vec();
+        let read_u16 = |font: &[u8], at: usize| u16::from_be_bytes([font[at], font[at + 1]]);
+        let table = |font: &[u8], tag: &[u8]| {
+            (0..read_u16(font, 4) as usize)
+                .map(|index| 12 + index * 16)
+                .find(|&record| &font[record..record + 4] == tag)
+                .map(|record| u32::from_be_bytes(font[record + 8..record + 12].try_into().unwrap()))
+                .unwrap() as usize
+        };
+        let num_metrics = read_u16(&font, table(&font, b"hhea") + 34) as usize;
+        let hmtx = table(&font, b"hmtx");
+        for at in (0..num_metrics).map(|index| hmtx + index * 4) {
+            let advance = read_u16(&font, at).saturating_mul(2);
+            font[at..at + 2].copy_from_slice(&advance.to_be_bytes());
+        }
+        let font = std::sync::Arc::new(font);
+
+        let builtin = FigureConfig {
+            text_rendering: TextRendering::BoxPlaceholder,
+            ..Default::default()
+        };
+        let scaled = FigureConfig {
+            font_label_bytes: Some(font.clone()),
+            font_title_bytes: Some(font),
+            ..builtin.clone()
+        };
+
+        let placeholder = render(&builtin);
+        assert_eq!(placeholder, render(&scaled));
+        assert_ne!(placeholder, render(&FigureConfig::default()));
+
+        // Drawing the glyphs, the two fonts give different images
+        let normal = |config: &FigureConfig| FigureConfig {
+            text_rendering: TextRendering::Normal,
+            ..config.clone()
+        };
+        assert_ne!(render(&normal(&builtin)), render(&normal(&scaled)));
+
+        // The title is a solid box of 8 characters, each half the 24 px font size wide
+        let (w, h) = TextRendering::placeholder_size("Progress", 24.0);
+        assert_eq!((w, h), (96, 24));
+        let (x, y) = (200 - w / 2, 20 - h / 2);
+        for (px, py) in [
+            (x, y),
+            (x + w - 1, y),
+            (x, y + h - 1),
+            (x + w - 1, y + h - 1),
+        ] {
+            let index = ((py * 400 + px) * 3) as usize;
+            assert_eq!(placeholder[index..index + 3], [0, 0, 0]);
+        }
+        let index = (((y + h) * 400 + x) * 3) as usize;
+        assert_eq!(placeholder[index..index + 3], [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_gridlines_coincide_with_tick_labels() {
+        let mut graph = graph_with_datasets(1);
+        graph.set_ticks(Some(vec![-10.0, -2.5, 5.0]), Some(vec![-5.0, 1.0]));
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        let grid_color = graph.config.effective_grid_color();
+        let is_grid = |canvas: &PixelCanvas, x: u32, y: u32| pixel_at(canvas, x, y) == grid_color;
+
+        graph.draw(&mut canvas);
+
+        // Inside the plot, vertical gridlines are exactly the columns of the X-axis ticks
+        // (16 px per unit) and horizontal ones the rows of the Y-axis ticks (22 px per unit)
+        let x_lines: Vec<u32> = (41..360).filter(|&x| is_grid(&canvas, x, 45)).collect();
+        assert_eq!(x_lines, vec![40 + 120, 40 + 240]);
+        let y_lines: Vec<u32> = (41..260).filter(|&y| is_grid(&canvas, 45, y)).collect();
+        assert_eq!(y_lines, vec![260 - 132]);
+
+        // Decoupled, the grid splits the plot into the configured number of columns again
+        graph.config.grid_at_ticks = false;
+        graph.draw(&mut canvas);
+        assert!(is_grid(&canvas, 40 + 32, 45));
+    }
+
+    #[test]
+    fn test_line_width_draws_thicker_band() {
+        let band = |line_width: u32| {
+            let mut graph = graph_with_datasets(1);
+            let mut dataset = CartesianDataset::new([0, 0, 255], "Wide", LineType::Solid);
+            dataset.set_line_width(line_width);
+            dataset.add_point((-8.0, 2.0));
+            dataset.add_point((8.0, 2.0));
+            graph.add_dataset(dataset);
+            let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+
+            graph.draw(&mut canvas);
+
+            let rows = (0..canvas.height)
+                .filter(|&y| pixel_at(&canvas, 250, y) == [0, 0, 255])
+                .count();
+            let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+            graph.draw_svg(&mut svg_canvas);
+            let stroke = format!(
+                r#"stroke="rgb(0,0,255)" stroke-width="{:.2}""#,
+                2.0 * line_width as f64
+            );
+            assert!(svg_canvas.elements.iter().any(|e| e.contains(&stroke)));
+            rows
+        };
+
+        assert_eq!(band(1), 1);
+        assert_eq!(band(4), 4);
+    }
+
+    #[test]
+    fn test_grid_on_top_is_drawn_over_data() {
+        let mut graph = graph_with_datasets(1);
+        let mut dataset = CartesianDataset::new([0, 0, 255], "Level", LineType::Solid);
+        dataset.add_point((-8.0, 2.0));
+        dataset.add_point((8.0, 2.0));
+        graph.add_dataset(dataset);
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        // The line at y = 2 crosses the vertical gridline of the X-axis tick at x = -6
+        let intersection = ((106 * 400 + 104) * 3) as usize;
+
+        graph.draw(&mut canvas);
+        assert_eq!(canvas.buffer[intersection..intersection + 3], [0, 0, 255]);
+
+        graph.config.grid_on_top = true;
+        graph.draw(&mut canvas);
+        assert_eq!(
+            canvas.buffer[intersection..intersection + 3],
+            graph.config.effective_grid_color()
+        );
     }
 }
@@ -0,0 +1,331 @@
+use crate::figure::{
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
+    configuration::figureconfig::FigureConfig,
+    figuretypes::heatmap::{cell_span, Heatmap},
+    utilities::axistype::AxisType,
+};
+
+use super::drawer::Drawer;
+use std::any::Any;
+
+impl Drawer for Heatmap {
+    fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        if !self.has_data() {
+            self.draw_no_data_svg(svg_canvas, &self.title);
+            return;
+        }
+
+        let width = svg_canvas.width as f64;
+        let height = svg_canvas.height as f64;
+        let (plot_left, plot_top, plot_right, plot_bottom) = svg_canvas.plot_bounds();
+        let font_size = 12.0;
+        let rgb = |color: [u8; 3]| format!("rgb({},{},{})", color[0], color[1], color[2]);
+
+        // Draw the frame, the background itself is added by the canvas
+        svg_canvas.draw_rect(0.0, 0.0, width, height, "none", "black", 2.0, 1.0);
+
+        // Draw Title
+        svg_canvas.draw_title(
+            width / 2.0,
+            plot_top / 2.0,
+            &self.title,
+            font_size * 2.0,
+            "black",
+        );
+
+        // Draw the cells, the first row at the top
+        let (rows, columns) = self.dimensions();
+        let (plot_width, plot_height) = (plot_right - plot_left, plot_bottom - plot_top);
+        for (row, values) in self.grid.iter().enumerate() {
+            let (top, bottom) = cell_span(row, rows, plot_top, plot_height);
+            for (column, &value) in values.iter().enumerate() {
+                let (left, right) = cell_span(column, columns, plot_left, plot_width);
+                svg_canvas.draw_rect(
+                    left,
+                    top,
+                    right - left,
+                    bottom - top,
+                    &rgb(self.color_for(value)),
+                    "none",
+                    0.0,
+                    1.0,
+                );
+            }
+        }
+        svg_canvas.draw_rect(
+            plot_left,
+            plot_top,
+            plot_width,
+            plot_height,
+            "none",
+            "black",
+            1.0,
+            1.0,
+        );
+
+        // Draw X-axis label below the plot and Y-axis label (rotated)
+        svg_canvas.draw_text(
+            width / 2.0,
+            plot_bottom + (height - plot_bottom) / 2.0,
+            &self.x_label,
+            font_size * 1.5,
+            "black",
+        );
+        svg_canvas.elements.push(format!(
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
+            plot_left / 3.0,
+            height / 2.0,
+            font_size * 1.5,
+            plot_left / 3.0,
+            height / 2.0,
+            escape_xml(&self.y_label)
+        ));
+
+        // Draw the color scale: the gradient from min at the bottom to max at the top
+        let (scale_x, scale_width) = Heatmap::color_scale_bounds(width, width - plot_right);
+        let gradient_id = svg_canvas.unique_id("heatmap-scale");
+        svg_canvas.elements.push(format!(
+            r#"<defs><linearGradient id="{}" x1="0" y1="1" x2="0" y2="0"><stop offset="0" stop-color="{}"/><stop offset="1" stop-color="{}"/></linearGradient></defs>"#,
+            gradient_id,
+            rgb(self.low_color),
+            rgb(self.high_color)
+        ));
+        svg_canvas.draw_rect(
+            scale_x,
+            plot_top,
+            scale_width,
+            plot_height,
+            &format!("url(#{})", gradient_id),
+            "black",
+            0.5,
+            1.0,
+        );
+        for (value, y) in [
+            (self.max, plot_top - 4.0),
+            (self.min, plot_bottom + font_size),
+        ] {
+            svg_canvas.draw_text(
+                scale_x + scale_width / 2.0,
+                y,
+                &self.config.format_tick(value),
+                font_size,
+                "black",
+            );
+        }
+    }
+
+    fn draw(&mut self, canvas: &mut PixelCanvas) {
+        canvas.clear();
+        canvas.antialias = self.config.antialias;
+        canvas.text_rendering = self.config.text_rendering;
+
+        if !self.has_data() {
+            self.draw_no_data(canvas, &self.config, &self.title);
+            return;
+        }
+
+        let (plot_left, plot_top, plot_right, plot_bottom) = canvas.plot_bounds();
+        let width = canvas.width;
+        let height = canvas.height;
+        let cfg = &self.config;
+
+        // Draw the title
+        self.draw_title(canvas, cfg, width / 2, plot_top / 2, &self.title);
+
+        // Draw the cells, the first row at the top
+        let (rows, columns) = self.dimensions();
+        let plot_width = (plot_right - plot_left) as f64;
+        let plot_height = (plot_bottom - plot_top) as f64;
+        for (row, values) in self.grid.iter().enumerate() {
+            let (top, bottom) = cell_span(row, rows, plot_top as f64, plot_height);
+            let (top, bottom) = (top.round() as u32, bottom.round() as u32);
+            for (column, &value) in values.iter().enumerate() {
+                let (left, right) = cell_span(column, columns, plot_left as f64, plot_width);
+                let (left, right) = (left.round() as u32, right.round() as u32);
+                canvas.draw_rect_filled(
+                    left,
+                    top,
+                    right - left,
+                    bottom - top,
+                    self.color_for(value),
+                );
+            }
+        }
+
+        // Draw the plot border
+        canvas.draw_rect_outline(
+            plot_left,
+            plot_top,
+            plot_right - plot_left + 1,
+            plot_bottom - plot_top + 1,
+            cfg.effective_axis_color(),
+        );
+
+        let x_label_y = plot_bottom + (height - plot_bottom) / 2;
+        self.draw_label(canvas, cfg, width / 2, x_label_y, &self.x_label);
+        self.draw_label(canvas, cfg, plot_left, plot_top / 2, &self.y_label);
+
+        self.draw_legend(canvas);
+    }
+
+    /// Draws the color scale in the right margin, with `max` above and `min` below it.
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let (_, top, _, bottom) = canvas.plot_bounds();
+        let (scale_x, scale_width) =
+            Heatmap::color_scale_bounds(canvas.width as f64, canvas.margins.right as f64);
+        let (scale_x, scale_width) = (scale_x as u32, scale_width as u32);
+        if bottom <= top {
+            return;
+        }
+
+        for y in top..bottom {
+            let t = (bottom - 1 - y) as f64 / (bottom - top).saturating_sub(1).max(1) as f64;
+            let value = self.min + (self.max - self.min) * t;
+            canvas.draw_rect_filled(scale_x, y, scale_width, 1, self.color_for(value));
+        }
+        canvas.draw_rect_outline(
+            scale_x,
+            top,
+            scale_width,
+            bottom - top,
+            self.config.effective_axis_color(),
+        );
+
+        let center = scale_x + scale_width / 2;
+        let cfg = &self.config;
+        self.draw_axis_value(
+            canvas,
+            cfg,
+            center,
+            top.saturating_sub(cfg.font_size_axis as u32 * 2),
+            &cfg.format_tick(self.max),
+            AxisType::AxisX,
+        );
+        self.draw_axis_value(
+            canvas,
+            cfg,
+            center,
+            bottom,
+            &cfg.format_tick(self.min),
+            AxisType::AxisX,
+        );
+    }
+
+    fn has_data(&self) -> bool {
+        self.grid.iter().any(|row| !row.is_empty())
+    }
+
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
+
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+}
+
+impl Heatmap {
+    /// Returns the color scale drawn in the right margin as `(x, width)`.
+    fn color_scale_bounds(width: f64, right_margin: f64) -> (f64, f64) {
+        (
+            width - right_margin + right_margin / 4.0,
+            (right_margin / 4.0).max(1.0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::canvas::margins::Margins;
+    use crate::figure::canvas::testing::pixel_at;
+
+    fn two_by_two() -> Heatmap {
+        let mut heatmap = Heatmap::new("Grid", "X", "Y", FigureConfig::default());
+        heatmap.set_grid(vec![vec![0.0, 1.0], vec![2.0, 3.0]]);
+        heatmap
+    }
+
+    #[test]
+    fn test_svg_output_is_identical_between_renders() {
+        let render = |heatmap: &mut Heatmap| {
+            let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+            heatmap.draw_svg(&mut svg_canvas);
+            svg_canvas.elements.concat()
+        };
+
+        let mut heatmap = two_by_two();
+        let svg = render(&mut heatmap);
+        let start = svg.find("<linearGradient id=\"").unwrap() + 20;
+        let id = &svg[start..start + svg[start..].find('"').unwrap()];
+        assert!(svg.contains(&format!("url(#{})", id)));
+        assert_eq!(svg, render(&mut heatmap));
+    }
+
+    #[test]
+    fn test_cells_are_colored_along_the_gradient() {
+        let mut heatmap = two_by_two();
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        heatmap.draw(&mut canvas);
+
+        // The centers of the four 160x110 cells of the 320x220 plot area
+        let cells = [
+            pixel_at(&canvas, 120, 95),
+            pixel_at(&canvas, 280, 95),
+            pixel_at(&canvas, 120, 205),
+            pixel_at(&canvas, 280, 205),
+        ];
+        assert_eq!(cells[0], [0, 0, 255]);
+        assert_eq!(cells[3], [255, 0, 0]);
+        for (i, cell) in cells.iter().enumerate() {
+            assert!(cells[i + 1..].iter().all(|other| other != cell));
+        }
+    }
+
+    #[test]
+    fn test_missing_cells_use_the_missing_color_in_svg() {
+        let mut heatmap = two_by_two();
+        heatmap.grid[0][1] = f64::NAN;
+        heatmap.set_missing_color([10, 20, 30]);
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+        heatmap.draw_svg(&mut svg_canvas);
+
+        let cell_fills: Vec<&str> = svg_canvas
+            .elements
+            .iter()
+            .filter(|element| element.starts_with("<rect") && element.contains("stroke=\"none\""))
+            .filter_map(|element| element.split("fill=\"").nth(1)?.split('"').next())
+            .collect();
+        assert_eq!(
+            cell_fills,
+            vec![
+                "rgb(0,0,255)",
+                "rgb(10,20,30)",
+                "rgb(170,0,85)",
+                "rgb(255,0,0)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_asymmetric_margins_offset_the_plot_area() {
+        let mut heatmap = two_by_two();
+        let margins = Margins::new(20, 80, 60, 40);
+        let mut canvas = PixelCanvas::with_margins(400, 300, [255, 255, 255], margins);
+        heatmap.draw(&mut canvas);
+
+        // The plot area spans 40..320 horizontally and 20..240 vertically
+        assert_eq!(pixel_at(&canvas, 41, 21), [0, 0, 255]);
+        assert_eq!(pixel_at(&canvas, 319, 239), [255, 0, 0]);
+        assert_eq!(pixel_at(&canvas, 30, 100), [255, 255, 255]);
+        assert_eq!(pixel_at(&canvas, 200, 250), [255, 255, 255]);
+
+        let mut svg_canvas = SvgCanvas::with_margins(400, 300, "white", margins);
+        heatmap.draw_svg(&mut svg_canvas);
+        assert!(svg_canvas.elements.iter().any(|element| element
+            .starts_with(r#"<rect x="40.00" y="20.00" width="280.00" height="220.00""#)));
+    }
+}
@@ -1,7 +1,10 @@
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
-    figuretypes::histogram::Histogram,
+    figuretypes::histogram::{count_in_edges, Histogram},
     utilities::axistype::AxisType,
 };
 
@@ -9,54 +12,67 @@ use super::drawer::Drawer;
 use std::any::Any;
 impl Drawer for Histogram {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        if !self.has_data() {
+            self.draw_no_data_svg(svg_canvas, &self.title);
+            return;
+        }
+
         // Clear existing SVG elements
 
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
-        let margin = svg_canvas.margin as f64;
+        let (left, top, right, bottom) = svg_canvas.plot_bounds();
         let font_size = 12.0;
 
-        // Draw background
-        svg_canvas.draw_rect(0.0, 0.0, width, height, "white", "black", 1.0, 1.0);
+        // Draw the frame, the background itself is added by the canvas
+        svg_canvas.draw_rect(0.0, 0.0, width, height, "none", "black", 1.0, 1.0);
 
         // Draw Title
         svg_canvas.draw_title(
             width / 2.0,
-            margin / 2.0,
+            top / 2.0,
             &self.title,
             font_size * 2.0,
             "black",
         );
 
+        // Bin layout and counts of every series (the histogram itself plus any overlays)
+        let (edges, series_counts) = self.series_bins();
+        let bin_min = edges[0];
+        let bin_max = edges[edges.len() - 1];
+
         // Calculate range and scales
-        let y_max = self.bin_counts.iter().cloned().fold(0.0, f64::max);
-        let scale_x = (width - 2.0 * margin) / (self.max - self.min);
-        let scale_y = (height - 2.0 * margin) / y_max;
+        let y_max = series_counts.iter().flatten().cloned().fold(0.0, f64::max);
+        let scale_x = (right - left) / (bin_max - bin_min);
+        let scale_y = (bottom - top) / y_max;
 
         // Draw grid
         let num_ticks = 10;
+        let grid_start = svg_canvas.elements.len();
         svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
+            left,
+            right,
+            top,
+            bottom,
             num_ticks,
             num_ticks,
             "lightgray",
+            self.config.grid_line_type.clone(),
         );
+        let grid = grid_start..svg_canvas.elements.len();
 
         // Draw axes
-        let origin_x = margin;
-        let origin_y = height - margin;
+        let origin_x = left;
+        let origin_y = bottom;
 
-        svg_canvas.draw_line(margin, origin_y, width - margin, origin_y, "black", 2.0); // X-axis
-        svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0); // Y-axis
+        svg_canvas.draw_line(left, origin_y, right, origin_y, "black", 2.0); // X-axis
+        svg_canvas.draw_line(left, top, left, bottom, "black", 2.0); // Y-axis
 
         // X-axis
         let mut x_axis_ticks = String::new();
         for i in 0..=num_ticks {
-            let value = self.max + i as f64 * (self.max - self.min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+            let value = bin_min + i as f64 * (bin_max - bin_min) / num_ticks as f64;
+            let x = left + i as f64 * (right - left) / num_ticks as f64;
             let tick_start_y = origin_y - 5.0;
             let tick_end_y = origin_y + 5.0;
 
@@ -67,8 +83,8 @@ impl Drawer for Histogram {
 
             // Draw value as text (fallback to basic SVG <text>)
             svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{:.1}</text>"#,
-            x, height - margin + font_size * 1.5, font_size, value));
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{}</text>"#,
+            x, bottom + font_size * 1.5, font_size, self.config.format_tick(value)));
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{}" stroke="black" stroke-width="1" fill="none"/>"#,
@@ -79,7 +95,7 @@ impl Drawer for Histogram {
         let mut y_axis_ticks = String::new();
         for i in 0..=num_ticks {
             let value = 0.0 + i as f64 * (y_max - 0.0) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+            let y = bottom - i as f64 * (bottom - top) / num_ticks as f64;
             let tick_start_x = origin_x - 5.0;
             let tick_end_x = origin_x + 5.0;
 
@@ -90,8 +106,8 @@ impl Drawer for Histogram {
 
             // Draw value as text (fallback to basic SVG <text>)
             svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 5.0, y + font_size * 0.3, font_size, value
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{}</text>"#,
+            left - 5.0, y + font_size * 0.3, font_size, self.config.format_tick(value)
         ));
         }
         svg_canvas.elements.push(format!(
@@ -102,7 +118,7 @@ impl Drawer for Histogram {
         // Draw X-axis label
         svg_canvas.draw_text(
             width / 2.0,
-            height - margin / 4.0,
+            bottom + (height - bottom) * 3.0 / 4.0,
             &self.x_label,
             font_size * 1.5,
             "black",
@@ -111,106 +127,204 @@ impl Drawer for Histogram {
         // Draw Y-axis label (rotated)
         svg_canvas.elements.push(format!(
             r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
-            margin / 3.0,
+            left / 3.0,
             height / 2.0,
             font_size * 1.5,
-            margin / 3.0,
+            left / 3.0,
             height / 2.0,
-            self.y_label
+            escape_xml(&self.y_label)
         ));
 
         // Draw histogram bars
-        for (i, &count) in self.bin_counts.iter().enumerate() {
-            let bin_start = self.min + i as f64 * self.bin_width;
-            let bin_end = bin_start + self.bin_width;
-
-            let x_start = margin + (bin_start - self.min) * scale_x;
-            let x_end = margin + (bin_end - self.min) * scale_x;
-            let bar_width = x_end - x_start;
-            let bar_height = count * scale_y;
-
-            svg_canvas.draw_rect(
-                x_start,
-                origin_y - bar_height,
-                bar_width,
-                bar_height,
-                &format!("rgb({},{},{})", self.color[0], self.color[1], self.color[2]),
-                "black",
-                1.0,
-                1.0,
-            );
+        let colors = std::iter::once(self.color).chain(self.overlays.iter().map(|o| o.color));
+        let opacity = if self.overlays.is_empty() {
+            1.0
+        } else {
+            self.overlay_alpha.clamp(0.0, 1.0)
+        };
+        for (counts, color) in series_counts.iter().zip(colors) {
+            for (bin, &count) in edges.windows(2).zip(counts) {
+                let (bin_start, bin_end) = (bin[0], bin[1]);
+
+                let x_start = left + (bin_start - bin_min) * scale_x;
+                let x_end = left + (bin_end - bin_min) * scale_x;
+                let bar_width = x_end - x_start;
+                let bar_height = count * scale_y;
+
+                svg_canvas.draw_rect(
+                    x_start,
+                    origin_y - bar_height,
+                    bar_width,
+                    bar_height,
+                    &format!("rgb({},{},{})", color[0], color[1], color[2]),
+                    "black",
+                    1.0,
+                    opacity,
+                );
+            }
+        }
+
+        // Move the grid over the data
+        if self.config.grid_on_top {
+            svg_canvas.raise_elements(grid);
+        }
+
+        // Draw legend for compared series
+        if self.overlays.is_empty() {
+            return;
         }
+        let entries: Vec<(&String, [u8; 3])> = std::iter::once((&self.label, self.color))
+            .chain(self.overlays.iter().map(|o| (&o.label, o.color)))
+            .collect();
+        let marker_size = self.config.legend_marker_size as f64;
+        let entry_width =
+            |label: &str| marker_size + font_size * 4.0 + label.len() as f64 * font_size * 0.6;
+        let legend_width = entries
+            .iter()
+            .map(|(label, _)| entry_width(label))
+            .sum::<f64>()
+            + 5.0;
+        let legend_height = font_size.max(marker_size) + 10.0;
+        let Some((legend_left, legend_top)) =
+            self.svg_legend_origin(svg_canvas, (legend_width, legend_height))
+        else {
+            return;
+        };
+        let legend_x_start = legend_left + 5.0;
+        let legend_y = legend_top + 5.0;
+        let mut legend_x = legend_x_start;
+        let mut elements = String::new();
+
+        for (label, color) in entries {
+            elements.push_str(&format!(
+                r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
+                legend_x, legend_y, marker_size, marker_size, color[0], color[1], color[2]
+            ));
+            elements.push_str(&format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="rgb({},{},{})">{}</text>"#,
+                legend_x + marker_size + font_size * 0.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                color[0],
+                color[1],
+                color[2],
+                escape_xml(label)
+            ));
+            legend_x += entry_width(label);
+        }
+
+        svg_canvas.draw_rect(
+            legend_x_start - 5.0,
+            legend_y - 5.0,
+            legend_width,
+            legend_height,
+            "white",
+            "black",
+            0.5,
+            0.5,
+        );
+        svg_canvas.elements.push(elements);
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
         canvas.clear();
+        canvas.antialias = self.config.antialias;
+        canvas.text_rendering = self.config.text_rendering;
+
+        if !self.has_data() {
+            self.draw_no_data(canvas, &self.config, &self.title);
+            return;
+        }
 
-        let margin = canvas.margin;
+        let (left, top, right, bottom) = canvas.plot_bounds();
         let width = canvas.width;
-        let height = canvas.height;
         let cfg = &self.config;
 
         // Draw the title
-        self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
-
-        let bin_data = self.calculate_bins();
-        let y_max = bin_data.iter().map(|&(_, freq)| freq).fold(0.0, f64::max);
-
-        let scale_x = (width - 2 * margin) as f64 / self.bins as f64;
-        let scale_y = (height - 2 * margin) as f64 / y_max;
-
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
-        );
+        self.draw_title(canvas, cfg, width / 2, top / 2, &self.title);
+
+        // Bin layout and counts of every series (the histogram itself plus any overlays)
+        let (edges, series_counts) = self.series_bins();
+        let colors: Vec<[u8; 3]> = std::iter::once(self.color)
+            .chain(self.overlays.iter().map(|overlay| overlay.color))
+            .collect();
+        let alpha = if self.overlays.is_empty() {
+            1.0
+        } else {
+            self.overlay_alpha.clamp(0.0, 1.0)
+        };
+        let y_max = series_counts.iter().flatten().cloned().fold(0.0, f64::max);
+
+        let scale_y = (bottom - top) as f64 / y_max;
 
         // Draw axes
-        let origin_x = margin as i32;
-        let origin_y = height as i32 - margin as i32;
+        let origin_x = left as i32;
+        let origin_y = bottom as i32;
+
+        // Tick positions at the bin edges and at even count steps, shared by the grid and the tick values
+        let edge_positions: Vec<u32> = self
+            .edge_offsets(&edges, (right - left) as f64)
+            .iter()
+            .map(|offset| (origin_x + *offset as i32) as u32)
+            .collect();
+        let num_y_ticks = 10;
+        let y_tick_values: Vec<f64> = (0..=num_y_ticks)
+            .map(|i| y_max * i as f64 / num_y_ticks as f64)
+            .collect();
+        let y_tick_positions: Vec<u32> = y_tick_values
+            .iter()
+            .map(|tick_value| (origin_y - (tick_value * scale_y) as i32) as u32)
+            .collect();
+
+        // Draw grids, unless they go on top of the data
+        if !cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &edge_positions, &y_tick_positions);
+        }
 
         // Draw bars with edges
-        let bin_start = bin_data[0].0; // First bin's start
-        let bin_width = (bin_data[1].0 - bin_start).abs(); // Width of each bin
-
-        for (i, &(_, freq)) in bin_data.iter().enumerate() {
-            let bar_height = (freq * scale_y) as i32;
-            let bar_left = origin_x + (i as f64 * scale_x) as i32;
-            let bar_right = bar_left + scale_x as i32;
+        for (counts, color) in series_counts.iter().zip(colors.iter()) {
+            for (bar_edges, &freq) in edge_positions.windows(2).zip(counts) {
+                let bar_height = (freq * scale_y) as i32;
+                let bar_left = bar_edges[0] as i32;
+                let bar_right = bar_edges[1] as i32;
+
+                // Fill the bar, blending overlapping series in overlay mode
+                for x in bar_left..=bar_right {
+                    for y in (origin_y - bar_height)..origin_y {
+                        canvas.blend_pixel(x as u32, y as u32, *color, alpha);
+                    }
+                }
 
-            // Fill the bar
-            for x in bar_left..=bar_right {
+                // Draw the edges (outline)
+                let edge_color = cfg.effective_axis_color();
+                // Left edge
                 for y in (origin_y - bar_height)..origin_y {
-                    canvas.draw_pixel(x as u32, y as u32, self.color);
+                    canvas.draw_pixel(bar_left as u32, y as u32, edge_color);
+                }
+                // Right edge
+                for y in (origin_y - bar_height)..origin_y {
+                    canvas.draw_pixel(bar_right as u32, y as u32, edge_color);
+                }
+                // Top edge
+                for x in bar_left..=bar_right {
+                    canvas.draw_pixel(x as u32, (origin_y - bar_height) as u32, edge_color);
                 }
             }
+        }
 
-            // Draw the edges (outline)
-            let edge_color = [0, 0, 0]; // Black color for edges
-                                        // Left edge
-            for y in (origin_y - bar_height)..origin_y {
-                canvas.draw_pixel(bar_left as u32, y as u32, edge_color);
-            }
-            // Right edge
-            for y in (origin_y - bar_height)..origin_y {
-                canvas.draw_pixel(bar_right as u32, y as u32, edge_color);
-            }
-            // Top edge
-            for x in bar_left..=bar_right {
-                canvas.draw_pixel(x as u32, (origin_y - bar_height) as u32, edge_color);
-            }
+        // Draw grids over the data
+        if cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &edge_positions, &y_tick_positions);
         }
 
         // Add x-axis ticks and labels at bin edges
-        for i in 0..=self.bins {
-            let edge_x = origin_x + (i as f64 * scale_x) as i32;
-            let edge_value = bin_start + i as f64 * bin_width;
-
-            canvas.draw_pixel(edge_x as u32, origin_y as u32, [0, 0, 0]); // Tick mark
-            let edge_label = format!("{:.1}", edge_value);
+        for (&edge_value, &edge_x) in edges.iter().zip(&edge_positions) {
+            canvas.draw_pixel(edge_x, origin_y as u32, cfg.effective_axis_color()); // Tick mark
+            let edge_label = cfg.format_tick(edge_value);
             self.draw_axis_value(
                 canvas,
                 cfg,
-                edge_x as u32,
+                edge_x,
                 origin_y as u32 + 10,
                 &edge_label,
                 AxisType::AxisX,
@@ -218,35 +332,58 @@ impl Drawer for Histogram {
         }
 
         // Add y-axis ticks and labels
-        let num_y_ticks = 10;
-        for i in 0..=num_y_ticks {
-            let tick_value = y_max * i as f64 / num_y_ticks as f64;
-            let tick_y = origin_y - (tick_value * scale_y) as i32;
-
-            canvas.draw_pixel(origin_x as u32, tick_y as u32, [0, 0, 0]); // Tick mark
-            let tick_label = format!("{:.1}", tick_value);
+        for (&tick_value, &tick_y) in y_tick_values.iter().zip(&y_tick_positions) {
+            canvas.draw_pixel(origin_x as u32, tick_y, cfg.effective_axis_color()); // Tick mark
+            let tick_label = cfg.format_tick(tick_value);
             self.draw_axis_value(
                 canvas,
                 cfg,
-                origin_x as u32 - 10,
-                tick_y as u32,
+                (origin_x as u32).saturating_sub(10),
+                tick_y,
                 &tick_label,
                 AxisType::AxisY,
             );
         }
 
-        let origin_y = height - margin;
-        self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.y_label);
-        self.draw_label(canvas, cfg, margin, margin / 2, &self.x_label);
+        let origin_y = bottom;
+        self.draw_label(
+            canvas,
+            cfg,
+            right + (width - right) / 2,
+            origin_y,
+            &self.y_label,
+        );
+        self.draw_label(canvas, cfg, left, top / 2, &self.x_label);
+
+        let axis_color = cfg.effective_axis_color();
+        canvas.draw_vertical_line(left, axis_color);
+        canvas.draw_vertical_line(right, axis_color);
+        canvas.draw_horizontal_line(bottom, axis_color);
+        canvas.draw_horizontal_line(top, axis_color);
 
-        canvas.draw_vertical_line(canvas.margin, [0, 0, 0]);
-        canvas.draw_vertical_line(canvas.width - canvas.margin, [0, 0, 0]);
-        canvas.draw_horizontal_line(canvas.height - canvas.margin, [0, 0, 0]);
-        canvas.draw_horizontal_line(canvas.margin, [0, 0, 0]);
+        self.draw_legend(canvas);
     }
 
-    fn draw_legend(&self, _canvas: &mut PixelCanvas) {
-        // Histogram does not have a legend
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        // A single histogram does not have a legend, only compared series do
+        if self.overlays.is_empty() {
+            return;
+        }
+
+        let entries: Vec<(&str, [u8; 3], bool)> =
+            std::iter::once((self.label.as_str(), self.color))
+                .chain(
+                    self.overlays
+                        .iter()
+                        .map(|overlay| (overlay.label.as_str(), overlay.color)),
+                )
+                .map(|(label, color)| (label, color, false))
+                .collect();
+        self.draw_square_legend(canvas, &entries);
+    }
+
+    fn has_data(&self) -> bool {
+        !self.data.is_empty() || self.overlays.iter().any(|overlay| !overlay.data.is_empty())
     }
 
     fn as_any(&mut self) -> &mut (dyn Any + 'static) {
@@ -257,3 +394,157 @@ impl Drawer for Histogram {
         &self.config
     }
 }
+
+impl Histogram {
+    /// Returns the bin edges and the bar heights of every drawn series, transformed by `mode`.
+    ///
+    /// Custom bin edges are shared by all series. Otherwise, without overlays this is the
+    /// histogram's cached binning, with overlays the shared layout computed by
+    /// [`Histogram::calculate_overlay_bins`].
+    fn series_bins(&self) -> (Vec<f64>, Vec<Vec<f64>>) {
+        let (edges, series) = self.series_counts();
+        let series = series
+            .iter()
+            .map(|counts| self.mode.apply(&edges, counts))
+            .collect();
+        (edges, series)
+    }
+
+    /// Returns the bin edges and the raw bin counts of every drawn series, see
+    /// [`Histogram::series_bins`].
+    fn series_counts(&self) -> (Vec<f64>, Vec<Vec<f64>>) {
+        if let Some(edges) = &self.bin_edges {
+            let series = std::iter::once(&self.data)
+                .chain(self.overlays.iter().map(|overlay| &overlay.data))
+                .map(|data| count_in_edges(data, edges))
+                .collect();
+            (edges.clone(), series)
+        } else if self.overlays.is_empty() {
+            (self.edges(), vec![self.bin_counts.clone()])
+        } else {
+            let (start, width, series) = self.calculate_overlay_bins();
+            let edges = (0..=self.bins).map(|i| start + i as f64 * width).collect();
+            (edges, series)
+        }
+    }
+
+    /// Returns the distance of each bin edge from the start of the first bin, in pixels.
+    ///
+    /// Uniform bins all get the same width, custom bins a width proportional to their range.
+    ///
+    /// # Parameters
+    /// - `edges`: The bin edges, as returned by [`Histogram::series_bins`].
+    /// - `plot_width`: The width of the plot area in pixels.
+    pub(crate) fn edge_offsets(&self, edges: &[f64], plot_width: f64) -> Vec<f64> {
+        match &self.bin_edges {
+            Some(_) => {
+                let span = edges[edges.len() - 1] - edges[0];
+                edges
+                    .iter()
+                    .map(|edge| (edge - edges[0]) * plot_width / span)
+                    .collect()
+            }
+            None => {
+                let scale_x = plot_width / self.bins as f64;
+                (0..edges.len()).map(|i| i as f64 * scale_x).collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::canvas::testing::pixel_at;
+
+    #[test]
+    fn test_overlay_blends_overlapping_bins() {
+        let mut histogram = Histogram::new(
+            "Compare",
+            "Value",
+            "Count",
+            3,
+            [255, 0, 0],
+            FigureConfig::default(),
+        );
+        histogram.label = "First".to_string();
+        histogram.add_data_vec(vec![1.0, 2.0, 2.5, 3.0]);
+        histogram.add_overlay("Second", vec![2.0, 2.2, 3.5, 4.0], [0, 0, 255]);
+
+        let mut canvas = PixelCanvas::new(300, 200, [255, 255, 255], 20);
+        histogram.draw(&mut canvas);
+
+        // Both series have two values in the middle bin, so its bars overlap completely:
+        // red blended over white, then blue blended over that
+        assert_eq!(pixel_at(&canvas, 149, 171), [127, 63, 191]);
+    }
+
+    #[test]
+    fn test_custom_bin_edges_draw_bars_proportional_to_their_range() {
+        let mut histogram = Histogram::new(
+            "Skewed",
+            "Value",
+            "Count",
+            10,
+            [255, 0, 0],
+            FigureConfig::default(),
+        );
+        histogram.set_bin_edges(vec![0.0, 1.0, 5.0, 10.0]);
+        histogram.add_data_vec(vec![0.5, 3.0, 3.0, 7.0]);
+
+        let mut canvas = PixelCanvas::new(300, 200, [255, 255, 255], 20);
+        histogram.draw(&mut canvas);
+
+        // The 260 px wide plot spans 0 to 10, so the [1, 5) bar runs from x = 46 to x = 150
+        assert_eq!(pixel_at(&canvas, 46, 150), [0, 0, 0]);
+        assert_eq!(pixel_at(&canvas, 100, 150), [255, 0, 0]);
+        assert_eq!(pixel_at(&canvas, 150, 150), [0, 0, 0]);
+        assert_eq!(pixel_at(&canvas, 200, 150), [255, 0, 0]);
+    }
+
+    #[test]
+    fn test_edges_and_frame_use_axis_color_on_dark_background() {
+        let config = FigureConfig {
+            color_background: [0, 0, 0],
+            ..FigureConfig::default()
+        };
+        let mut histogram = Histogram::new("Skewed", "Value", "Count", 10, [255, 0, 0], config);
+        histogram.set_bin_edges(vec![0.0, 1.0, 5.0, 10.0]);
+        histogram.add_data_vec(vec![0.5, 3.0, 3.0, 7.0]);
+
+        let mut canvas = PixelCanvas::new(300, 200, [0, 0, 0], 20);
+        histogram.draw(&mut canvas);
+
+        assert_eq!(pixel_at(&canvas, 46, 150), [255, 255, 255]);
+        assert_eq!(pixel_at(&canvas, 150, 150), [255, 255, 255]);
+        assert_eq!(pixel_at(&canvas, 280, 100), [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_svg_x_ticks_span_data_range() {
+        let mut histogram = Histogram::new(
+            "Range",
+            "Value",
+            "Count",
+            5,
+            [255, 0, 0],
+            FigureConfig::default(),
+        );
+        histogram.add_data_vec(vec![20.0, 25.0, 31.0, 40.0, 70.0]);
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+
+        histogram.draw_svg(&mut svg_canvas);
+
+        // The x tick labels sit below the x-axis, from the left to the right end of the plot
+        let label_y = format!(r#"y="{:.2}""#, 300.0 - 40.0 + 12.0 * 1.5);
+        let x_labels: Vec<&str> = svg_canvas
+            .elements
+            .iter()
+            .filter(|e| e.starts_with("<text") && e.contains(&label_y))
+            .map(|e| &e[e.find('>').unwrap() + 1..e.rfind("</text>").unwrap()])
+            .collect();
+        let config = &histogram.config;
+        assert_eq!(x_labels.first(), Some(&config.format_tick(20.0).as_str()));
+        assert_eq!(x_labels.last(), Some(&config.format_tick(70.0).as_str()));
+    }
+}
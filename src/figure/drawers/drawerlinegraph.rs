@@ -1,32 +1,37 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
-
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
     figuretypes::linegraph::LineGraph,
-    utilities::axistype::AxisType,
+    utilities::{axistype::AxisType, clipping::ClipRect, simplify::simplify, ticks::tick_values},
 };
 
-use super::drawer::Drawer;
+use super::drawer::{Drawer, LegendEntry, LEGEND_HIDDEN_COLOR};
 use std::any::Any;
 impl Drawer for LineGraph {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        if !self.has_data() {
+            self.draw_no_data_svg(svg_canvas, &self.title);
+            return;
+        }
+
         // Clear existing SVG elements
         // svg_canvas.clear();
 
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
-        let margin = svg_canvas.margin as f64;
+        let (left, top, right, bottom) = svg_canvas.plot_bounds();
         let font_size = 12.0;
 
-        // Draw background
-        svg_canvas.draw_rect(0.0, 0.0, width, height, "white", "black", 2.0, 1.0);
+        // Draw the frame, the background itself is added by the canvas
+        svg_canvas.draw_rect(0.0, 0.0, width, height, "none", "black", 2.0, 1.0);
 
         // Draw Title
         svg_canvas.draw_title(
             width / 2.0,
-            margin / 2.0,
+            top / 2.0,
             &self.title,
             font_size * 2.0,
             "black",
@@ -35,36 +40,56 @@ impl Drawer for LineGraph {
         // Symmetric scaling
         self.update_range();
 
-        let scale_x = (svg_canvas.width - 2 * svg_canvas.margin) as f64 / (self.x_max - self.x_min);
-        let scale_y =
-            (svg_canvas.height - 2 * svg_canvas.margin) as f64 / (self.y_max - self.y_min);
+        let scale_x = (right - left) / (self.x_max - self.x_min);
+        let scale_y = (bottom - top) / (self.y_max - self.y_min);
 
         // Draw grid
         let num_ticks = 20;
+        let grid_start = svg_canvas.elements.len();
         svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
+            left,
+            right,
+            top,
+            bottom,
             num_ticks,
             num_ticks,
             "lightgray",
+            self.config.grid_line_type.clone(),
         );
+        let grid = grid_start..svg_canvas.elements.len();
 
         // Draw axes
-        let center_x = margin + (0.0 - self.x_min) * scale_x;
-        let center_y = height - margin - (0.0 - self.y_min) * scale_y;
-
-        svg_canvas.draw_line(margin, center_y, width - margin, center_y, "black", 2.0);
-        svg_canvas.draw_line(center_x, margin, center_x, height - margin, "black", 2.0);
+        let center_x = left + (0.0 - self.x_min) * scale_x;
+        let center_y = bottom - (0.0 - self.y_min) * scale_y;
+
+        let zero_line_width = self.config.zero_line_width as f64;
+        if self.y_min <= 0.0 && self.y_max >= 0.0 {
+            svg_canvas.draw_line_rgb(
+                left,
+                center_y,
+                right,
+                center_y,
+                self.config.effective_zero_line_color(),
+                zero_line_width,
+            );
+        }
+        if self.x_min <= 0.0 && self.x_max >= 0.0 {
+            svg_canvas.draw_line_rgb(
+                center_x,
+                top,
+                center_x,
+                bottom,
+                self.config.effective_zero_line_color(),
+                zero_line_width,
+            );
+        }
 
         // Draw tick marks and labels
 
         // X-axis
         let mut x_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = self.x_min + i as f64 * (self.x_max - self.x_min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+        for value in tick_values(self.x_ticks.as_deref(), self.x_min, self.x_max, num_ticks) {
+            let x = left + (value - self.x_min) * scale_x;
             let tick_start_y = center_y - 5.0;
             let tick_end_y = center_y + 5.0;
 
@@ -75,8 +100,8 @@ impl Drawer for LineGraph {
 
             // Draw value as text (fallback to basic SVG <text>)
             svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{:.1}</text>"#,
-            x, height - margin + font_size * 1.5, font_size, value));
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{}</text>"#,
+            x, bottom + font_size * 1.5, font_size, self.config.format_tick(value)));
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{}" stroke="black" stroke-width="1" fill="none"/>"#,
@@ -85,9 +110,8 @@ impl Drawer for LineGraph {
 
         // Y-axis
         let mut y_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = self.y_min + i as f64 * (self.y_max - self.y_min) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+        for value in tick_values(self.y_ticks.as_deref(), self.y_min, self.y_max, num_ticks) {
+            let y = bottom - (value - self.y_min) * scale_y;
             let tick_start_x = center_x - 5.0;
             let tick_end_x = center_x + 5.0;
 
@@ -98,8 +122,8 @@ impl Drawer for LineGraph {
 
             // Draw value as text (fallback to basic SVG <text>)
             svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 5.0, y + font_size * 0.3, font_size, value
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{}</text>"#,
+            left - 5.0, y + font_size * 0.3, font_size, self.config.format_tick(value)
         ));
         }
         svg_canvas.elements.push(format!(
@@ -107,78 +131,83 @@ impl Drawer for LineGraph {
             y_axis_ticks
         ));
 
-        // Draw X-axis label
+        // Draw X-axis label, right-aligned below the end of the axis
         svg_canvas.elements.push(format!(
-        r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{}</text>"#,
-        width / 2.0,
-        margin - 5.0,
-        font_size * 1.5,
-        self.y_label
-    ));
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{}</text>"#,
+            right,
+            bottom + (height - bottom) / 2.0 + font_size,
+            font_size * 1.5,
+            escape_xml(&self.x_label)
+        ));
 
         // Draw Y-axis label (rotated)
         svg_canvas.elements.push(format!(
         r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
-        margin / 3.0,
+        left / 3.0,
         height / 2.0,
         font_size * 1.5,
-        margin / 3.0,
+        left / 3.0,
         height / 2.0,
-        self.x_label
+        escape_xml(&self.y_label)
     ));
 
-        // Plot datasets
-        for dataset in &self.datasets {
-            for window in dataset.points.windows(2) {
+        // Plot the visible datasets
+        for dataset in self.datasets.iter().filter(|dataset| dataset.visible) {
+            let points = simplify(&dataset.points, self.config.simplify_tolerance);
+            for window in self.interpolation.apply(&points).windows(2) {
                 if let [p1, p2] = window {
-                    let x1 = margin + (p1.0 - self.x_min) * scale_x;
-                    let y1 = height - margin - (p1.1 - self.y_min) * scale_y;
-                    let x2 = margin + (p2.0 - self.x_min) * scale_x;
-                    let y2 = height - margin - (p2.1 - self.y_min) * scale_y;
-
-                    svg_canvas.draw_line_rgb(x1, y1, x2, y2, dataset.color, 2.0);
+                    let x1 = left + (p1.0 - self.x_min) * scale_x;
+                    let y1 = bottom - (p1.1 - self.y_min) * scale_y;
+                    let x2 = left + (p2.0 - self.x_min) * scale_x;
+                    let y2 = bottom - (p2.1 - self.y_min) * scale_y;
+
+                    svg_canvas.draw_line_styled(
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        dataset.color,
+                        2.0 * dataset.line_width as f64,
+                        dataset.line_type.clone(),
+                    );
                 }
             }
         }
 
-        // Draw legend
-        let legend_x_start = 5.0; // Start at the very left with margin spacing
-        let legend_y = height - margin / 2.0; // Move to bottom-left corner
-        let mut legend_x = legend_x_start; // Reset starting position for legend items
-        let mut elements = String::new();
+        // Move the grid over the data
+        if self.config.grid_on_top {
+            svg_canvas.raise_elements(grid);
+        }
 
-        for dataset in &self.datasets {
-            // Draw color square
-            elements.push_str(&format!(
-                r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
-                legend_x,
-                legend_y,
-                font_size,
-                font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2]
-            ));
+        // Draw reference annotations
+        self.draw_annotations_svg(svg_canvas, &self.annotations, &|x, y| {
+            (
+                left + (x - self.x_min) * scale_x,
+                bottom - (y - self.y_min) * scale_y,
+            )
+        });
 
-            // Draw label text next to the color square
-            elements.push_str(&format!(
-                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="rgb({},{},{})">{}</text>"#,
-                legend_x + font_size * 1.3,
-                legend_y + font_size - 2.0,
-                font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2],
-                dataset.label
-            ));
-
-            // Update legend_x to position the next item
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
-        }
+        // Draw legend: a sample of each dataset's line followed by its label
+        let marker_size = self.config.legend_marker_size as f64;
+        let sample_length = marker_size * 2.0;
+        let entry_width =
+            |label: &str| sample_length + font_size * 4.0 + label.len() as f64 * font_size * 0.6;
 
         // Draw a background rectangle for the legend
-        let legend_width = legend_x - legend_x_start + 5.0;
-        let legend_height = font_size + 10.0;
+        let legend_width: f64 = self
+            .datasets
+            .iter()
+            .map(|dataset| entry_width(&dataset.label))
+            .sum::<f64>()
+            + 5.0;
+        let legend_height = font_size.max(marker_size) + 10.0;
+        let Some((legend_left, legend_top)) =
+            self.svg_legend_origin(svg_canvas, (legend_width, legend_height))
+        else {
+            return;
+        };
+        let legend_x_start = legend_left + 5.0;
+        let legend_y = legend_top + 5.0;
         svg_canvas.draw_rect(
             legend_x_start - 5.0,
             legend_y - 5.0,
@@ -190,27 +219,59 @@ impl Drawer for LineGraph {
             0.5,
         );
 
-        svg_canvas.elements.push(elements);
+        let mut legend_x = legend_x_start;
+        for dataset in &self.datasets {
+            let color = if dataset.visible {
+                dataset.color
+            } else {
+                LEGEND_HIDDEN_COLOR
+            };
+            let sample_y = legend_y + marker_size / 2.0;
+            svg_canvas.draw_line_styled(
+                legend_x,
+                sample_y,
+                legend_x + sample_length,
+                sample_y,
+                color,
+                2.0,
+                dataset.line_type.clone(),
+            );
+
+            // Draw label text next to the line sample
+            svg_canvas.elements.push(format!(
+                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="rgb({},{},{})">{}</text>"#,
+                legend_x + sample_length + font_size * 0.3,
+                legend_y + font_size - 2.0,
+                font_size,
+                color[0],
+                color[1],
+                color[2],
+                escape_xml(&dataset.label)
+            ));
+
+            // Update legend_x to position the next item
+            legend_x += entry_width(&dataset.label);
+        }
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
         canvas.clear();
+        canvas.antialias = self.config.antialias;
+        canvas.text_rendering = self.config.text_rendering;
 
-        let margin = canvas.margin;
+        if !self.has_data() {
+            self.draw_no_data(canvas, &self.config, &self.title);
+            return;
+        }
+
+        let (left, top, right, bottom) = canvas.plot_bounds();
         let width = canvas.width;
-        let height = canvas.height;
         let cfg = &self.config;
-        let center_x = width / 2;
-        let center_y = height / 2;
+        let center_x = left + (right - left) / 2;
+        let center_y = top + (bottom - top) / 2;
 
         // Draw the title
-        self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
-
-        // Draw grids
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
-        );
+        self.draw_title(canvas, cfg, width / 2, top / 2, &self.title);
 
         // Ensure x_min and x_max are symmetric
         let abs_x_min = self.x_min.abs();
@@ -222,81 +283,104 @@ impl Drawer for LineGraph {
             self.x_min = -abs_x_max;
         }
 
-        // Draw X and Y axes
-        canvas.draw_vertical_line(center_x, [0, 0, 0]);
-        canvas.draw_horizontal_line(center_y, [0, 0, 0]);
+        let scale_x = (right - left) as f64 / (self.x_max - self.x_min);
+        let scale_y = (bottom - top) as f64 / (self.y_max - self.y_min); // Adjust y-range as needed
 
-        let scale_x = (canvas.width - 2 * canvas.margin) as f64 / (self.x_max - self.x_min);
-        let scale_y = (canvas.height - 2 * canvas.margin) as f64 / (self.y_max - self.y_min); // Adjust y-range as needed
+        // Tick positions, shared by the grid and the tick values
+        let num_ticks = 10;
+        let x_tick_values = tick_values(self.x_ticks.as_deref(), self.x_min, self.x_max, num_ticks);
+        let x_tick_positions: Vec<u32> = x_tick_values
+            .iter()
+            .map(|value_x| left + ((value_x - self.x_min) * scale_x) as u32)
+            .collect();
+        let y_tick_values = tick_values(self.y_ticks.as_deref(), self.y_min, self.y_max, num_ticks);
+        let y_tick_positions: Vec<u32> = y_tick_values
+            .iter()
+            .map(|value_y| bottom - ((value_y - self.y_min) * scale_y) as u32)
+            .collect();
+
+        // Draw grids, unless they go on top of the data
+        if !cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &x_tick_positions, &y_tick_positions);
+        }
 
-        for dataset in &self.datasets {
-            for window in dataset.points.windows(2) {
+        // Draw X and Y axes as emphasized zero lines when the range spans zero
+        let zero_x = (self.x_min <= 0.0 && self.x_max >= 0.0).then_some(center_x);
+        let zero_y = (self.y_min <= 0.0 && self.y_max >= 0.0).then_some(center_y);
+        self.draw_zero_lines(canvas, cfg, zero_x, zero_y);
+
+        let plot_area = ClipRect::new(
+            left as f64,
+            top as f64,
+            (right - 1) as f64,
+            (bottom - 1) as f64,
+        );
+
+        for dataset in self.datasets.iter().filter(|dataset| dataset.visible) {
+            let points = simplify(&dataset.points, self.config.simplify_tolerance);
+            for window in self.interpolation.apply(&points).windows(2) {
                 if let [p1, p2] = window {
-                    let x1 = center_x as i32 + (p1.0 * scale_x) as i32;
-                    let y1 = center_y as i32 - (p1.1 * scale_y) as i32;
-                    let x2 = center_x as i32 + (p2.0 * scale_x) as i32;
-                    let y2 = center_y as i32 - (p2.1 * scale_y) as i32;
-
-                    // Simple line drawing algorithm (Bresenham)
-                    let dx = (x2 - x1).abs();
-                    let sx = if x1 < x2 { 1 } else { -1 };
-                    let dy = -(y2 - y1).abs();
-                    let sy = if y1 < y2 { 1 } else { -1 };
-                    let mut err = dx + dy;
-
-                    let mut x = x1;
-                    let mut y = y1;
-
-                    while x != x2 || y != y2 {
-                        if x >= canvas.margin as i32
-                            && x < (canvas.width - canvas.margin) as i32
-                            && y >= canvas.margin as i32
-                            && y < (canvas.height - canvas.margin) as i32
-                        {
-                            canvas.draw_pixel(x as u32, y as u32, dataset.color);
-                        }
-
-                        let e2 = 2 * err;
-                        if e2 >= dy {
-                            err += dy;
-                            x += sx;
-                        }
-                        if e2 <= dx {
-                            err += dx;
-                            y += sy;
-                        }
+                    let x1 = center_x as f64 + p1.0 * scale_x;
+                    let y1 = center_y as f64 - p1.1 * scale_y;
+                    let x2 = center_x as f64 + p2.0 * scale_x;
+                    let y2 = center_y as f64 - p2.1 * scale_y;
+
+                    // Clip the segment at the plot boundary so partly visible segments are kept
+                    if let Some((x1, y1, x2, y2)) = plot_area.clip_line(x1, y1, x2, y2) {
+                        canvas.draw_line_thick(
+                            x1.round() as i32,
+                            y1.round() as i32,
+                            x2.round() as i32,
+                            y2.round() as i32,
+                            dataset.color,
+                            dataset.line_width,
+                            dataset.line_type.clone(),
+                        );
                     }
                 }
             }
         }
 
+        // Draw grids over the data
+        if cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &x_tick_positions, &y_tick_positions);
+        }
+
+        // Draw reference annotations
+        self.draw_annotations(canvas, cfg, &self.annotations, &|x, y| {
+            (
+                center_x as i32 + (x * scale_x) as i32,
+                center_y as i32 - (y * scale_y) as i32,
+            )
+        });
+
         // X-axis label
-        let origin_y = height - margin - ((0.0 - self.y_min) * scale_y) as u32;
-        self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.x_label);
-        self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
+        let origin_y = bottom - ((0.0 - self.y_min) * scale_y) as u32;
+        self.draw_label(
+            canvas,
+            cfg,
+            right + (width - right) / 2,
+            origin_y,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, left, top / 2, &self.y_label);
 
         // Draw X and Y axis tick values
-        let num_ticks = 10;
-        let x_tick_step = (canvas.width - 2 * canvas.margin) / num_ticks;
-        let y_tick_step = (canvas.height - 2 * canvas.margin) / num_ticks;
-
-        let y = canvas.height - canvas.margin;
-        for i in 0..=num_ticks {
-            // X-axis ticks
-            let x = canvas.margin + i * x_tick_step;
-            let value_x = self.x_min + ((self.x_max - self.x_min) / num_ticks as f64) * i as f64;
-            let label_x = format!("{:+.2}", value_x);
+        // X-axis ticks
+        let y = bottom;
+        for (&value_x, &x) in x_tick_values.iter().zip(&x_tick_positions) {
+            let label_x = cfg.format_tick(value_x);
             self.draw_axis_value(canvas, cfg, x, y, &label_x, AxisType::AxisX);
+        }
 
-            // Y-axis ticks
-            let y = canvas.margin + i * y_tick_step;
-            let value_y = self.y_min + ((self.y_max - self.y_min) / num_ticks as f64) * i as f64;
-            let label_y = format!("{:.2}", value_y);
+        // Y-axis ticks
+        for (&value_y, &y) in y_tick_values.iter().zip(&y_tick_positions) {
+            let label_y = cfg.format_tick(value_y);
             self.draw_axis_value(
                 canvas,
                 cfg,
-                margin - 10,
-                height - y,
+                left.saturating_sub(10),
+                y,
                 &label_y,
                 AxisType::AxisY,
             );
@@ -306,55 +390,53 @@ impl Drawer for LineGraph {
     }
 
     fn draw_legend(&self, canvas: &mut PixelCanvas) {
-        let font_path = self
-            .config
-            .font_label
-            .as_ref()
-            .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
-
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
-        let legend_margin = canvas.margin; // Margin from the bottom of the canvas
-
-        let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
+        let square_size = self.config.legend_marker_size; // Height of the legend entry marker
+        let sample_length = square_size * 2; // Length of the line sample
+        let entries: Vec<(&str, [u8; 3], bool)> = self
+            .datasets
+            .iter()
+            .map(|dataset| (dataset.label.as_str(), dataset.color, !dataset.visible))
+            .collect();
+        self.draw_legend_with_markers(
+            canvas,
+            sample_length,
+            &entries,
+            &|canvas, index, entry, color| {
+                // Draw a sample of the line, centered on the marker height
+                let sample_y = (entry.y + square_size / 2) as i32;
+                canvas.draw_line(
+                    entry.x as i32,
+                    sample_y,
+                    (entry.x + sample_length) as i32,
+                    sample_y,
+                    color,
+                    self.datasets[index].line_type.clone(),
+                );
+            },
+        );
+    }
 
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
-            // Draw the square
-            for dy in 0..square_size {
-                for dx in 0..square_size {
-                    canvas.draw_pixel(
-                        x + dx,
-                        y + square_size * 2 + dy + h, // Adjust to align above baseline
-                        dataset.color,
-                    );
-                }
-            }
+    fn legend_entries(&self, canvas: &PixelCanvas) -> Vec<LegendEntry> {
+        let labels: Vec<&str> = self
+            .datasets
+            .iter()
+            .map(|dataset| dataset.label.as_str())
+            .collect();
+        self.layout_legend(canvas, self.config.legend_marker_size * 2, &labels)
+    }
 
-            // Draw the label text next to the square
-            let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
-                text_x,
-                y + 2 * square_size + h,
-                &dataset.label,
-                dataset.color,
-                &font,
-                scale,
-            );
+    fn toggle_dataset(&mut self, index: usize) -> bool {
+        let Some(dataset) = self.datasets.get_mut(index) else {
+            return false;
+        };
+        dataset.visible = !dataset.visible;
+        true
+    }
 
-            // Move to the next legend entry
-            x += square_size + padding + w + padding;
-            if x > canvas.width - canvas.margin {
-                // If the width exceeds, wrap to the next row
-                x = canvas.margin;
-                y -= line_height;
-            }
-        }
+    fn has_data(&self) -> bool {
+        self.datasets
+            .iter()
+            .any(|dataset| !dataset.points.is_empty())
     }
 
     fn as_any(&mut self) -> &mut (dyn Any + 'static) {
@@ -365,3 +447,324 @@ impl Drawer for LineGraph {
         &self.config
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::datasets::{dataset::Dataset, linegraphdataset::LineGraphDataset};
+    use crate::figure::drawers::drawer::NO_DATA_MESSAGE;
+    use crate::figure::utilities::{
+        interpolation::Interpolation, legendposition::LegendPosition, linetype::LineType,
+        textrendering::TextRendering,
+    };
+
+    #[test]
+    fn test_empty_graph_draws_no_data_placeholder() {
+        let config = FigureConfig {
+            text_rendering: TextRendering::BoxPlaceholder,
+            ..Default::default()
+        };
+        let mut graph = LineGraph::new("Loading", "X", "Y", &config);
+        graph.add_dataset(LineGraphDataset::new([255, 0, 0], "Empty", LineType::Solid));
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut canvas);
+        let center = ((150 * 400 + 200) * 3) as usize;
+        assert_eq!(
+            &canvas.buffer[center..center + 3],
+            &config.effective_axis_color()
+        );
+
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+        graph.draw_svg(&mut svg_canvas);
+        assert!(svg_canvas
+            .elements
+            .iter()
+            .any(|e| e.ends_with(&format!(">{}</text>", NO_DATA_MESSAGE))));
+    }
+
+    #[test]
+    fn test_svg_legend_follows_legend_position() {
+        let legend_box = |position: LegendPosition| {
+            let config = FigureConfig {
+                legend_position: position,
+                ..Default::default()
+            };
+            let mut graph = LineGraph::new("Legend", "X", "Y", &config);
+            let mut dataset = LineGraphDataset::new([255, 0, 0], "Data", LineType::Solid);
+            dataset.add_point((0.0, 0.0));
+            dataset.add_point((1.0, 1.0));
+            graph.add_dataset(dataset);
+
+            let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+            graph.draw_svg(&mut svg_canvas);
+            svg_canvas
+                .elements
+                .iter()
+                .find(|e| e.starts_with("<rect") && e.contains(r#"fill-opacity="0.5""#))
+                .map(|e| {
+                    let attribute = |name: &str| -> f64 {
+                        let start = e.find(&format!(" {}=\"", name)).unwrap() + name.len() + 3;
+                        e[start..start + e[start..].find('"').unwrap()]
+                            .parse()
+                            .unwrap()
+                    };
+                    (attribute("x"), attribute("y"))
+                })
+        };
+
+        assert_eq!(legend_box(LegendPosition::BottomLeft), Some((40.0, 280.0)));
+        let (x, y) = legend_box(LegendPosition::TopRight).unwrap();
+        assert!(x > 200.0 && y < 40.0);
+        assert_eq!(legend_box(LegendPosition::None), None);
+    }
+
+    #[test]
+    fn test_no_data_svg_uses_configured_colors() {
+        let config = FigureConfig {
+            color_background: [20, 20, 20],
+            ..Default::default()
+        };
+        let graph = LineGraph::new("Loading", "X", "Y", &config);
+
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+        graph.draw_no_data_svg(&mut svg_canvas, &graph.title);
+
+        assert!(svg_canvas
+            .elements
+            .iter()
+            .any(|e| e.starts_with("<rect") && e.contains(r#"fill="rgb(20,20,20)""#)));
+        assert!(svg_canvas
+            .elements
+            .iter()
+            .any(|e| e.contains(">Loading</text>") && e.contains("rgb(255,255,255)")));
+    }
+
+    #[test]
+    fn test_two_datasets_are_drawn_in_their_own_colors() {
+        let mut graph = LineGraph::new("Two Lines", "X", "Y", &FigureConfig::default());
+        for (label, color, offset) in [("Up", [255, 0, 0], 0.0), ("Down", [0, 0, 255], 5.0)] {
+            let mut dataset = LineGraphDataset::new(color, label, LineType::Solid);
+            for x in 0..4 {
+                dataset.add_point((x as f64, offset + x as f64));
+            }
+            graph.add_dataset(dataset);
+        }
+
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+        graph.draw_svg(&mut svg_canvas);
+        for color in ["rgb(255,0,0)", "rgb(0,0,255)"] {
+            let segments = svg_canvas
+                .elements
+                .iter()
+                .filter(|e| e.starts_with("<line") && e.contains(color))
+                .count();
+            // Three segments for four points, plus the legend sample
+            assert_eq!(segments, 4);
+        }
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut canvas);
+        // Only look inside the plot area, so the legend samples don't count
+        let plot_area = &canvas.buffer[40 * 400 * 3..260 * 400 * 3];
+        for color in [[255, 0, 0], [0, 0, 255]] {
+            assert!(plot_area.chunks_exact(3).any(|pixel| pixel == color));
+        }
+    }
+
+    #[test]
+    fn test_squared_dataset_has_square_stroke_pattern_in_svg() {
+        let mut graph = LineGraph::new("Squares", "X", "Y", &FigureConfig::default());
+        let mut dataset = LineGraphDataset::new([0, 128, 0], "Squared", LineType::Squared(10, 4));
+        dataset.add_point((0.0, 0.0));
+        dataset.add_point((10.0, 5.0));
+        graph.add_dataset(dataset);
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+
+        graph.draw_svg(&mut svg_canvas);
+
+        let line = svg_canvas
+            .elements
+            .iter()
+            .find(|element| element.starts_with("<line") && element.contains("rgb(0,128,0)"))
+            .expect("No line drawn for the dataset");
+        assert!(line.contains(r#"stroke-width="4.00""#));
+        assert!(line.contains(r#"stroke-dasharray="4.00 6.00""#));
+    }
+
+    #[test]
+    fn test_dashed_grid_config_adds_dasharray_to_svg_grid() {
+        let config = FigureConfig {
+            grid_line_type: LineType::Dashed(4),
+            ..Default::default()
+        };
+        let mut graph = LineGraph::new("Dashed Grid", "X", "Y", &config);
+        let mut dataset = LineGraphDataset::new([255, 0, 0], "Line", LineType::Solid);
+        dataset.add_point((0.0, 0.0));
+        dataset.add_point((10.0, 5.0));
+        graph.add_dataset(dataset);
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+
+        graph.draw_svg(&mut svg_canvas);
+
+        let grid_lines: Vec<&String> = svg_canvas
+            .elements
+            .iter()
+            .filter(|element| element.contains(r#"stroke="lightgray""#))
+            .collect();
+        assert!(!grid_lines.is_empty());
+        assert!(grid_lines
+            .iter()
+            .all(|line| line.contains(r#"stroke-dasharray="4.00 4.00""#)));
+    }
+
+    #[test]
+    fn test_svg_legend_has_line_sample_per_dataset() {
+        let mut graph = LineGraph::new("Legend", "Time", "Value", &FigureConfig::default());
+        for (label, color, line_type) in [
+            ("Measured", [255, 0, 0], LineType::Solid),
+            ("Forecast", [0, 0, 255], LineType::Dashed(6)),
+        ] {
+            let mut dataset = LineGraphDataset::new(color, label, line_type);
+            dataset.add_point((0.0, 0.0));
+            dataset.add_point((10.0, 5.0));
+            graph.add_dataset(dataset);
+        }
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+
+        graph.draw_svg(&mut svg_canvas);
+
+        let elements = &svg_canvas.elements;
+        for label in ["Measured", "Forecast"] {
+            let entries = elements
+                .iter()
+                .filter(|e| e.starts_with("<text") && e.ends_with(&format!(">{}</text>", label)))
+                .count();
+            assert_eq!(entries, 1);
+        }
+        // Each dataset is drawn once in the plot and once as legend sample, in its own style
+        let lines = |color: &str| -> Vec<&String> {
+            elements
+                .iter()
+                .filter(|e| e.starts_with("<line") && e.contains(color))
+                .collect()
+        };
+        assert_eq!(lines("rgb(0,0,255)").len(), 2);
+        assert!(lines("rgb(0,0,255)")
+            .iter()
+            .all(|line| line.contains(r#"stroke-dasharray="6.00 6.00""#)));
+        assert_eq!(lines("rgb(255,0,0)").len(), 2);
+        assert!(lines("rgb(255,0,0)")
+            .iter()
+            .all(|line| !line.contains("stroke-dasharray")));
+
+        // Axis labels are drawn with the matching axis
+        assert!(elements
+            .iter()
+            .any(|e| e.contains("rotate(-90") && e.ends_with(">Value</text>")));
+        assert!(elements
+            .iter()
+            .any(|e| !e.contains("rotate(") && e.ends_with(">Time</text>")));
+    }
+
+    #[test]
+    fn test_catmull_rom_draws_more_segments_than_linear() {
+        let render = |interpolation: Interpolation| {
+            let mut graph = LineGraph::new("Smooth", "X", "Y", &FigureConfig::default());
+            graph.set_interpolation(interpolation);
+            let mut dataset = LineGraphDataset::new([0, 128, 0], "Smooth", LineType::Solid);
+            for point in [(0.0, 0.0), (1.0, 2.0), (2.0, 1.0)] {
+                dataset.add_point(point);
+            }
+            graph.add_dataset(dataset);
+            let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+            graph.draw_svg(&mut svg_canvas);
+            svg_canvas
+                .elements
+                .iter()
+                .filter(|e| e.starts_with("<line") && e.contains("rgb(0,128,0)"))
+                .count()
+        };
+
+        // Two segments plus the legend sample
+        assert_eq!(render(Interpolation::Linear), 3);
+        assert!(render(Interpolation::CatmullRom) > 3);
+    }
+
+    #[test]
+    fn test_step_post_draws_horizontal_then_vertical_run() {
+        let mut graph = LineGraph::new("Steps", "X", "Y", &FigureConfig::default());
+        graph.set_interpolation(Interpolation::StepPost);
+        let mut dataset = LineGraphDataset::new([0, 128, 0], "State", LineType::Solid);
+        dataset.add_point((1.0, 1.0));
+        dataset.add_point((3.0, 4.0));
+        graph.add_dataset(dataset);
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+        graph.draw_svg(&mut svg_canvas);
+
+        let attribute = |line: &str, name: &str| -> String {
+            let start = line.find(&format!(r#" {}=""#, name)).unwrap() + name.len() + 3;
+            line[start..start + line[start..].find('"').unwrap()].to_string()
+        };
+        let lines: Vec<&String> = svg_canvas
+            .elements
+            .iter()
+            .filter(|e| e.starts_with("<line") && e.contains("rgb(0,128,0)"))
+            .collect();
+        // Two runs plus the legend sample
+        assert_eq!(lines.len(), 3);
+        let (horizontal, vertical) = (lines[0], lines[1]);
+        assert_eq!(attribute(horizontal, "y1"), attribute(horizontal, "y2"));
+        assert_ne!(attribute(horizontal, "x1"), attribute(horizontal, "x2"));
+        assert_eq!(attribute(vertical, "x1"), attribute(vertical, "x2"));
+        assert_eq!(attribute(vertical, "x1"), attribute(horizontal, "x2"));
+        assert_ne!(attribute(vertical, "y1"), attribute(vertical, "y2"));
+    }
+
+    #[test]
+    fn test_simplify_tolerance_reduces_svg_segments() {
+        let render = |tolerance: Option<f64>| {
+            let config = FigureConfig {
+                simplify_tolerance: tolerance,
+                ..FigureConfig::default()
+            };
+            let mut graph = LineGraph::new("Noisy", "X", "Y", &config);
+            let mut dataset = LineGraphDataset::new([0, 128, 0], "Noisy", LineType::Solid);
+            for i in 0..500 {
+                // A straight line with deterministic noise of at most 0.006
+                let noise = ((i * 7919) % 13) as f64 * 0.001 - 0.006;
+                dataset.add_point((i as f64 * 0.1, i as f64 * 0.05 + noise));
+            }
+            graph.add_dataset(dataset);
+            let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+            graph.draw_svg(&mut svg_canvas);
+            // The plotted segments, without the legend sample drawn last
+            let mut lines: Vec<String> = svg_canvas
+                .elements
+                .into_iter()
+                .filter(|e| e.starts_with("<line") && e.contains("rgb(0,128,0)"))
+                .collect();
+            lines.pop();
+            lines
+        };
+
+        assert_eq!(render(None).len(), 499);
+        let simplified = render(Some(0.1));
+        assert!(simplified.len() < 5);
+        // The line still starts and ends at the first and last data points
+        let full = render(None);
+        let attribute = |line: &str, name: &str| -> String {
+            let start = line.find(&format!(r#" {}=""#, name)).unwrap() + name.len() + 3;
+            line[start..start + line[start..].find('"').unwrap()].to_string()
+        };
+        for name in ["x1", "y1"] {
+            assert_eq!(attribute(&simplified[0], name), attribute(&full[0], name));
+        }
+        for name in ["x2", "y2"] {
+            assert_eq!(
+                attribute(simplified.last().unwrap(), name),
+                attribute(full.last().unwrap(), name)
+            );
+        }
+    }
+}
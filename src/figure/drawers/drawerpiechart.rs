@@ -1,9 +1,10 @@
 use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
-use std::f64::consts::PI;
 
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
     figuretypes::piechart::PieChart,
 };
@@ -12,18 +13,23 @@ use super::drawer::Drawer;
 use std::any::Any;
 impl Drawer for PieChart {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        if !self.has_data() {
+            self.draw_no_data_svg(svg_canvas, &self.title);
+            return;
+        }
+
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
-        let margin = svg_canvas.margin as f64;
+        let (left, top, right, bottom) = svg_canvas.plot_bounds();
         let font_size = 12.0;
 
-        // Draw background
-        svg_canvas.draw_rect(0.0, 0.0, width, height, "white", "black", 1.0, 1.0);
+        // Draw the frame, the background itself is added by the canvas
+        svg_canvas.draw_rect(0.0, 0.0, width, height, "none", "black", 1.0, 1.0);
 
         // Draw Title
         svg_canvas.draw_title(
             width / 2.0,
-            margin / 2.0,
+            top / 2.0,
             &self.title,
             font_size * 2.0,
             "black",
@@ -33,9 +39,9 @@ impl Drawer for PieChart {
         let total: f64 = self.datasets.iter().map(|dataset| dataset.1).sum();
 
         // Calculate center and radius
-        let cx = width / 2.0;
-        let cy = height / 2.0;
-        let radius = (width.min(height) - 2.0 * margin) / 2.0;
+        let cx = left + (right - left) / 2.0;
+        let cy = top + (bottom - top) / 2.0;
+        let radius = (right - left).min(bottom - top) / 2.0;
 
         // Begin group for pie chart with transformation
         svg_canvas.elements.push(format!(
@@ -43,20 +49,26 @@ impl Drawer for PieChart {
             cx, cy
         ));
 
-        // Track the starting angle in radians
-        let mut start_angle = 0.0;
-
-        // Draw pie slices
-        for dataset in &self.datasets {
+        // Without any value there are no slices, only the outline of the pie
+        let slice_angles = if total == 0.0 {
+            svg_canvas.draw_circle(0.0, 0.0, radius, "none");
+            Vec::new()
+        } else {
+            self.slice_angles()
+        };
+
+        // Draw pie slices. Angles are counterclockwise from 3 o'clock, so y is flipped for SVG
+        for (index, (dataset, (start_angle, end_angle))) in
+            self.datasets.iter().zip(slice_angles).enumerate()
+        {
             let value_ratio = dataset.1 / total; // Ratio of this slice to the total
-            let sweep_angle = value_ratio * 2.0 * std::f64::consts::PI; // Convert ratio to radians
-            let end_angle = start_angle + sweep_angle;
+            let sweep_angle = end_angle - start_angle;
 
             // Calculate start and end points of the slice
             let x1 = radius * start_angle.cos();
-            let y1 = radius * start_angle.sin();
+            let y1 = -radius * start_angle.sin();
             let x2 = radius * end_angle.cos();
-            let y2 = radius * end_angle.sin();
+            let y2 = -radius * end_angle.sin();
 
             // Determine if the slice is larger than 180 degrees
             let large_arc_flag = if sweep_angle > std::f64::consts::PI {
@@ -65,60 +77,87 @@ impl Drawer for PieChart {
                 0
             };
 
-            // Generate the path for the slice
+            // Exploded slices are moved outward, together with their label
+            let (explode_x, explode_y) = self.explode_offset(index, start_angle, end_angle);
+            let transform = if explode_x == 0.0 && explode_y == 0.0 {
+                String::new()
+            } else {
+                format!(
+                    r#" transform="translate({:.2},{:.2})""#,
+                    explode_x, explode_y
+                )
+            };
+
+            // Generate the path for the slice, sweeping counterclockwise on screen
             svg_canvas.elements.push(format!(
-               r#"<path d="M 0 0 L {:.2} {:.2} A {:.2} {:.2} 0 {} 1 {:.2} {:.2} Z" fill="rgb({},{},{})"/>"#,
-               x1, y1, radius, radius, large_arc_flag, x2, y2,
+               r#"<path d="M 0 0 L {:.2} {:.2} A {:.2} {:.2} 0 {} 0 {:.2} {:.2} Z"{} fill="rgb({},{},{})"/>"#,
+               x1, y1, radius, radius, large_arc_flag, x2, y2, transform,
                dataset.2[0], dataset.2[1], dataset.2[2]
            ));
 
             // Calculate label position (midpoint of the slice angle)
-            let mid_angle = start_angle + sweep_angle / 2.0;
-            let label_x = (radius * 0.6) * mid_angle.cos(); // 60% of radius for better placement
-            let label_y = (radius * 0.6) * mid_angle.sin();
+            let (label_x, label_y) = self.label_offset(start_angle, end_angle, radius);
+            let (label_x, label_y) = (label_x + explode_x, label_y + explode_y);
 
             // Draw percentage label
             svg_canvas.elements.push(format!(
                r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black" text-anchor="middle" alignment-baseline="middle">{:.1}%</text>"#,
-               label_x, label_y, font_size, value_ratio * 100.0
+               label_x, label_y, self.config.font_size_label, value_ratio * 100.0
            ));
-
-            // Update start angle for the next slice
-            start_angle = end_angle;
         }
 
         // Close group
         svg_canvas.elements.push("</g>".to_string());
 
         // Draw legend in the bottom-left corner
-        let legend_x_start = 5.0; // Start at the very left with margin spacing
-        let legend_y = height - margin / 2.0; // Move to bottom-left corner
-
+        let marker_size = self.config.legend_marker_size as f64;
+        let entry_width =
+            |label: &str| marker_size + font_size * 4.0 + label.len() as f64 * font_size * 0.6;
+        let legend_width = self
+            .datasets
+            .iter()
+            .map(|dataset| entry_width(&dataset.0))
+            .sum::<f64>()
+            + 5.0;
+        let legend_height = font_size.max(marker_size) + 10.0;
+        let Some((legend_left, legend_top)) =
+            self.svg_legend_origin(svg_canvas, (legend_width, legend_height))
+        else {
+            return;
+        };
+        let legend_x_start = legend_left + 5.0;
+        let legend_y = legend_top + 5.0;
         let mut legend_x = legend_x_start;
         let mut elements = String::new();
 
         for dataset in &self.datasets {
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
-                legend_x, legend_y, font_size, font_size, dataset.2[0], dataset.2[1], dataset.2[2]
+                legend_x,
+                legend_y,
+                marker_size,
+                marker_size,
+                dataset.2[0],
+                dataset.2[1],
+                dataset.2[2]
             ));
 
             elements.push_str(&format!(
                 r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="black">{}</text>"#,
-                legend_x + font_size * 1.3,
+                legend_x + marker_size + font_size * 0.3,
                 legend_y + font_size - 2.0,
                 font_size,
-                dataset.0
+                escape_xml(&dataset.0)
             ));
 
-            legend_x += font_size * 5.0 + dataset.0.len() as f64 * font_size * 0.6;
+            legend_x += entry_width(&dataset.0);
         }
 
         svg_canvas.draw_rect(
             legend_x_start - 5.0,
             legend_y - 5.0,
-            legend_x - legend_x_start + 5.0,
-            font_size + 10.0,
+            legend_width,
+            legend_height,
             "white",
             "black",
             0.5,
@@ -130,14 +169,20 @@ impl Drawer for PieChart {
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
         canvas.clear();
+        canvas.antialias = self.config.antialias;
+        canvas.text_rendering = self.config.text_rendering;
 
-        let margin = canvas.margin;
+        if !self.has_data() {
+            self.draw_no_data(canvas, &self.config, &self.title);
+            return;
+        }
+
+        let (left, top, right, bottom) = canvas.plot_bounds();
         let width = canvas.width;
-        let height = canvas.height;
         let cfg = &self.config;
 
         // Draw the title
-        self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
+        self.draw_title(canvas, cfg, width / 2, top / 2, &self.title);
 
         // Calculate total value
         let total: f64 = self.datasets.iter().map(|(_, value, _)| value).sum();
@@ -146,30 +191,35 @@ impl Drawer for PieChart {
         }
 
         // Center and radius of the pie chart
-        let center_x = width / 2;
-        let center_y = height / 2;
-        let radius = (width.min(height) / 2 - margin) as i32;
+        let center_x = left + (right - left) / 2;
+        let center_y = top + (bottom - top) / 2;
+        let radius = ((right - left).min(bottom - top) / 2) as i32;
 
-        let mut start_angle = 0.0;
-        for (_label, value, color) in &self.datasets {
+        for (index, ((_label, value, color), (start_angle, end_angle))) in
+            self.datasets.iter().zip(self.slice_angles()).enumerate()
+        {
             let percentage = value / total;
-            let sweep_angle = 2.0 * PI * percentage;
+
+            // Exploded slices are moved outward, together with their label
+            let (explode_x, explode_y) = self.explode_offset(index, start_angle, end_angle);
+            let slice_x = center_x as f64 + explode_x;
+            let slice_y = center_y as f64 + explode_y;
 
             // Draw the slice
             self.draw_slice(
                 canvas,
-                center_x as i32,
-                center_y as i32,
+                slice_x.round() as i32,
+                slice_y.round() as i32,
                 radius,
                 start_angle,
-                start_angle + sweep_angle,
+                end_angle,
                 *color,
             );
 
-            // Calculate mid-angle for label placement
-            let mid_angle = start_angle + sweep_angle / 2.0;
-            let label_x = center_x as f64 + (radius as f64 * 0.6 * mid_angle.cos());
-            let label_y = center_y as f64 - (radius as f64 * 0.6 * mid_angle.sin());
+            // Place the label at the mid-angle of the slice
+            let (offset_x, offset_y) = self.label_offset(start_angle, end_angle, radius as f64);
+            let label_x = (slice_x + offset_x).max(0.0);
+            let label_y = (slice_y + offset_y).max(0.0);
             self.draw_label(
                 canvas,
                 cfg,
@@ -177,8 +227,6 @@ impl Drawer for PieChart {
                 label_y as u32,
                 &format!("{:.1}%", percentage * 100.0),
             );
-
-            start_angle += sweep_angle;
         }
 
         // Draw legend
@@ -186,31 +234,39 @@ impl Drawer for PieChart {
     }
 
     fn draw_legend(&self, canvas: &mut PixelCanvas) {
-        let font_path = self
-            .config
-            .font_label
-            .as_ref()
-            .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
+        let font_bytes = self.config.label_font_bytes();
         let font = FontRef::try_from_slice(&font_bytes).unwrap();
         let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
 
-        let square_size = 10; // Size of the colored square
+        let square_size = self.config.legend_marker_size; // Size of the colored square
         let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
-        let legend_margin = canvas.margin; // Margin from the bottom of the canvas
+        let line_height = 20.max(square_size + 10); // Vertical space for each legend entry
+        let entry_offset = 20; // Offset of the entries below the legend row
+        let legend_width: u32 = self
+            .datasets
+            .iter()
+            .map(|dataset| dataset.0.as_str())
+            .map(|label| square_size + padding + canvas.text_size(scale, &font, label).0 + padding)
+            .sum();
+        let Some((x_start, y_start)) = self.config.legend_position.origin(
+            (canvas.width, canvas.height),
+            canvas.margins,
+            (legend_width, entry_offset + line_height),
+        ) else {
+            return;
+        };
 
-        let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
+        let mut x = x_start;
+        let mut y = y_start;
 
         for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.0);
+            let (w, h) = canvas.text_size(scale, &font, &dataset.0);
             // Draw the square
             for dy in 0..square_size {
                 for dx in 0..square_size {
                     canvas.draw_pixel(
                         x + dx,
-                        y + square_size * 2 + dy + h, // Adjust to align above baseline
+                        y + entry_offset + dy + h, // Adjust to align above baseline
                         dataset.2,
                     );
                 }
@@ -220,7 +276,7 @@ impl Drawer for PieChart {
             let text_x: u32 = x + square_size + padding;
             canvas.draw_text(
                 text_x,
-                y + 2 * square_size + h,
+                y + entry_offset + h,
                 &dataset.0,
                 dataset.2,
                 &font,
@@ -229,14 +285,22 @@ impl Drawer for PieChart {
 
             // Move to the next legend entry
             x += square_size + padding + w + padding;
-            if x > canvas.width - canvas.margin {
+            if x > canvas.width - canvas.margins.right {
                 // If the width exceeds, wrap to the next row
-                x = canvas.margin;
-                y -= line_height;
+                x = x_start;
+                if self.config.legend_position.rows_grow_downwards() {
+                    y += line_height;
+                } else {
+                    y = y.saturating_sub(line_height);
+                }
             }
         }
     }
 
+    fn has_data(&self) -> bool {
+        !self.datasets.is_empty()
+    }
+
     fn as_any(&mut self) -> &mut (dyn Any + 'static) {
         self as &mut dyn Any
     }
@@ -245,3 +309,166 @@ impl Drawer for PieChart {
         &self.config
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::canvas::testing::pixel_at;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn quarter_pie() -> PieChart {
+        let mut pie = PieChart::new("Pie", FigureConfig::default());
+        pie.add_slice("Quarter", 25.0, [255, 0, 0]);
+        pie.add_slice("Rest", 75.0, [0, 0, 255]);
+        pie
+    }
+
+    fn first_slice_endpoints(svg_canvas: &SvgCanvas) -> ((f64, f64), (f64, f64)) {
+        let path = svg_canvas
+            .elements
+            .iter()
+            .find(|element| element.starts_with("<path d=\"M 0 0"))
+            .expect("No slice path found");
+        let tokens: Vec<&str> = path.split_whitespace().collect();
+        let parse = |i: usize| tokens[i].parse::<f64>().unwrap();
+        ((parse(5), parse(6)), (parse(13), parse(14)))
+    }
+
+    #[test]
+    fn test_start_angle_and_direction_match_between_outputs() {
+        let mut pie = quarter_pie();
+        pie.set_orientation(FRAC_PI_2, true);
+
+        // Pixel output: the first slice runs clockwise from 12 to 3 o'clock
+        let mut canvas = PixelCanvas::new(400, 400, [255, 255, 255], 40);
+        pie.draw(&mut canvas);
+        assert_eq!(pixel_at(&canvas, 224, 62), [255, 0, 0]);
+        assert_eq!(pixel_at(&canvas, 331, 152), [255, 0, 0]);
+        assert_eq!(pixel_at(&canvas, 176, 62), [0, 0, 255]);
+
+        // SVG output: the first slice covers the same upper-right quadrant
+        let mut svg_canvas = SvgCanvas::new(400, 400, "white", 40);
+        pie.draw_svg(&mut svg_canvas);
+        let ((x1, y1), (x2, y2)) = first_slice_endpoints(&svg_canvas);
+        assert!((x1 - 160.0).abs() < 0.01 && y1.abs() < 0.01);
+        assert!(x2.abs() < 0.01 && (y2 + 160.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_pixel_and_svg_place_largest_slice_in_same_region() {
+        let mut pie = quarter_pie();
+        pie.datasets.clear();
+        pie.add_slice("A", 20.0, [255, 0, 0]);
+        pie.add_slice("B", 50.0, [0, 255, 0]);
+        pie.add_slice("C", 30.0, [0, 0, 255]);
+
+        let mut canvas = PixelCanvas::new(400, 400, [255, 255, 255], 40);
+        pie.draw(&mut canvas);
+        let mut svg_canvas = SvgCanvas::new(400, 400, "white", 40);
+        pie.draw_svg(&mut svg_canvas);
+
+        // Angular region of the largest slice in the SVG (second path, y pointing down)
+        let path = svg_canvas
+            .elements
+            .iter()
+            .filter(|element| element.starts_with("<path d=\"M 0 0"))
+            .nth(1)
+            .unwrap();
+        let tokens: Vec<&str> = path.split_whitespace().collect();
+        let parse = |i: usize| tokens[i].parse::<f64>().unwrap();
+        let from = (-parse(6)).atan2(parse(5));
+        let to = (-parse(14)).atan2(parse(13));
+        let mid = from + (to - from).rem_euclid(2.0 * std::f64::consts::PI) / 2.0;
+
+        // Clockwise from 12 o'clock puts the middle of the largest slice in the lower right
+        assert!(mid.cos() > 0.0 && mid.sin() < 0.0);
+
+        // The pixel output has the same slice at that angle
+        let x = (200.0 + 140.0 * mid.cos()) as u32;
+        let y = (200.0 - 140.0 * mid.sin()) as u32;
+        assert_eq!(pixel_at(&canvas, x, y), [0, 255, 0]);
+    }
+
+    #[test]
+    fn test_label_radius_ratio_moves_labels_outward() {
+        let label_position = |ratio: f64| {
+            let mut pie = quarter_pie();
+            pie.set_label_radius_ratio(ratio);
+            let mut svg_canvas = SvgCanvas::new(400, 400, "white", 40);
+            pie.draw_svg(&mut svg_canvas);
+
+            let label = svg_canvas
+                .elements
+                .iter()
+                .find(|element| element.starts_with("<text") && element.ends_with("%</text>"))
+                .expect("No percentage label found");
+            let attribute = |name: &str| {
+                let start = label.find(&format!("{}=\"", name)).unwrap() + name.len() + 2;
+                let end = start + label[start..].find('"').unwrap();
+                label[start..end].parse::<f64>().unwrap()
+            };
+            (attribute("x"), attribute("y"))
+        };
+
+        let (x_inner, y_inner) = label_position(0.6);
+        let (x_outer, y_outer) = label_position(1.2);
+
+        // The first slice is centered at 1:30, so its label moves up and to the right
+        assert!((x_inner.hypot(y_inner) - 96.0).abs() < 0.01);
+        assert!((x_outer.hypot(y_outer) - 192.0).abs() < 0.01);
+        assert!(x_outer > x_inner && y_outer < y_inner);
+    }
+
+    #[test]
+    fn test_zero_total_svg_is_valid() {
+        let mut pie = quarter_pie();
+        pie.datasets.clear();
+        pie.add_slice("A", 0.0, [255, 0, 0]);
+        pie.add_slice("B", 0.0, [0, 0, 255]);
+        let mut svg_canvas = SvgCanvas::new(400, 400, "white", 40);
+
+        pie.draw_svg(&mut svg_canvas);
+
+        let svg = svg_canvas.get_svg_as_text();
+        assert!(!svg.contains("NaN"));
+        assert!(!svg.contains("<path d=\"M 0 0"));
+        assert!(svg.contains("<circle"));
+        assert!(resvg::usvg::Tree::from_str(&svg, &resvg::usvg::Options::default()).is_ok());
+    }
+
+    #[test]
+    fn test_exploded_slice_is_moved_outward() {
+        let draw = |pie: &mut PieChart| {
+            let mut canvas = PixelCanvas::new(400, 400, [255, 255, 255], 40);
+            pie.draw(&mut canvas);
+            // Pixels along the mid-angle of the first slice, at 1:30, by distance from the center
+            move |distance: f64| {
+                let offset = distance * std::f64::consts::FRAC_1_SQRT_2;
+                let (x, y) = ((200.0 + offset) as u32, (200.0 - offset) as u32);
+                pixel_at(&canvas, x, y)
+            }
+        };
+
+        let mut pie = quarter_pie();
+        let baseline = draw(&mut pie);
+        pie.datasets.clear();
+        pie.add_slice_exploded("Quarter", 25.0, [255, 0, 0], 20.0);
+        pie.add_slice("Rest", 75.0, [0, 0, 255]);
+        let exploded = draw(&mut pie);
+
+        // The slice leaves a gap at the center and reaches past the radius of 160
+        assert_eq!(baseline(5.0), [255, 0, 0]);
+        assert_eq!(exploded(5.0), [255, 255, 255]);
+        assert_eq!(baseline(170.0), [255, 255, 255]);
+        assert_eq!(exploded(170.0), [255, 0, 0]);
+
+        // The SVG slice is moved by the same offset
+        let mut svg_canvas = SvgCanvas::new(400, 400, "white", 40);
+        pie.draw_svg(&mut svg_canvas);
+        let translated = r#"transform="translate(14.14,-14.14)" fill="rgb(255,0,0)""#;
+        assert!(svg_canvas
+            .elements
+            .iter()
+            .any(|element| element.contains(translated)));
+    }
+}
@@ -1,29 +1,35 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
-
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
+    datasets::dataset::combined_bounds,
     figuretypes::quadrant1graph::Quadrant1Graph,
-    utilities::axistype::AxisType,
+    utilities::{axistype::AxisType, ticks::tick_values},
 };
 
-use super::drawer::Drawer;
+use super::drawer::{Drawer, LegendEntry, LEGEND_HIDDEN_COLOR};
 use std::any::Any;
 impl Drawer for Quadrant1Graph {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        if !self.has_data() {
+            self.draw_no_data_svg(svg_canvas, &self.title);
+            return;
+        }
+
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
-        let margin = svg_canvas.margin as f64;
+        let (left, top, right, bottom) = svg_canvas.plot_bounds();
         let font_size = 12.0;
 
-        // Draw background
-        svg_canvas.draw_rect(0.0, 0.0, width, height, "white", "black", 1.0, 1.0);
+        // Draw the frame, the background itself is added by the canvas
+        svg_canvas.draw_rect(0.0, 0.0, width, height, "none", "black", 1.0, 1.0);
 
         // Draw Title
         svg_canvas.draw_title(
             width / 2.0,
-            margin / 2.0,
+            top / 2.0,
             &self.title,
             font_size * 2.0,
             "black",
@@ -33,71 +39,52 @@ impl Drawer for Quadrant1Graph {
         self.update_range();
 
         // Determine dataset range
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
-                (min.min(x), max.max(x))
-            });
-
-        let (y_min, y_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
-                (min.min(y), max.max(y))
-            });
+        let visible = self.datasets.iter().filter(|dataset| dataset.visible);
+        let ((x_min, x_max), (y_min, y_max)) = combined_bounds(visible).unwrap_or_default();
 
-        let scale_x = (width - 2.0 * margin) / (x_max - x_min);
-        let scale_y = (height - 2.0 * margin) / (y_max - y_min);
+        let scale_x = (right - left) / (x_max - x_min);
+        let scale_y = (bottom - top) / (y_max - y_min);
 
         // Draw grid
         let num_ticks = 10;
+        let grid_start = svg_canvas.elements.len();
         svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
+            left,
+            right,
+            top,
+            bottom,
             num_ticks,
             num_ticks,
             "lightgray",
+            self.config.grid_line_type.clone(),
         );
+        let grid = grid_start..svg_canvas.elements.len();
 
         // Draw axes (only positive X and Y axes for Quadrant 1)
-        svg_canvas.draw_line(
-            margin,
-            height - margin,
-            width - margin,
-            height - margin,
-            "black",
-            2.0,
-        ); // X-axis
-        svg_canvas.draw_line(margin, margin, margin, height - margin, "black", 2.0); // Y-axis
+        svg_canvas.draw_line(left, bottom, right, bottom, "black", 2.0); // X-axis
+        svg_canvas.draw_line(left, top, left, bottom, "black", 2.0); // Y-axis
 
         // Draw tick marks and values for X-axis
-        for i in 0..=num_ticks {
-            let value = x_min + i as f64 * (x_max - x_min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+        for value in tick_values(self.x_ticks.as_deref(), x_min, x_max, num_ticks) {
+            let x = left + (value - x_min) * scale_x;
 
             svg_canvas.draw_text(
                 x,
-                height - margin + font_size * 1.5,
-                &format!("{:.1}", value),
+                bottom + font_size * 1.5,
+                &self.config.format_tick(value),
                 font_size,
                 "black",
             );
         }
 
         // Draw tick marks and values for Y-axis
-        for i in 0..=num_ticks {
-            let value = y_min + i as f64 * (y_max - y_min) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+        for value in tick_values(self.y_ticks.as_deref(), y_min, y_max, num_ticks) {
+            let y = bottom - (value - y_min) * scale_y;
 
             svg_canvas.draw_text(
-                margin - font_size * 2.0,
+                left - font_size * 2.0,
                 y,
-                &format!("{:.1}", value),
+                &self.config.format_tick(value),
                 font_size,
                 "black",
             );
@@ -105,8 +92,8 @@ impl Drawer for Quadrant1Graph {
 
         // Draw X-axis label
         svg_canvas.draw_text(
-            width - margin,
-            height - margin / 2.0,
+            right,
+            bottom + (height - bottom) / 2.0,
             &self.x_label,
             font_size * 1.5,
             "black",
@@ -115,84 +102,100 @@ impl Drawer for Quadrant1Graph {
         // Draw Y-axis label (rotated)
         svg_canvas.elements.push(format!(
             r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
-            margin / 3.0,
+            left / 3.0,
             height / 2.0,
             font_size * 1.5,
-            margin / 3.0,
+            left / 3.0,
             height / 2.0,
-            self.y_label
+            escape_xml(&self.y_label)
         ));
 
-        // Draw datasets as points or lines
-        for dataset in &self.datasets {
+        // Draw the visible datasets as points or lines
+        for dataset in self.datasets.iter().filter(|dataset| dataset.visible) {
             for window in dataset.points.windows(2) {
                 if let [p1, p2] = window {
-                    let x1 = margin + (p1.0 - x_min) * scale_x;
-                    let y1 = height - margin - (p1.1 - y_min) * scale_y;
-                    let x2 = margin + (p2.0 - x_min) * scale_x;
-                    let y2 = height - margin - (p2.1 - y_min) * scale_y;
+                    let x1 = left + (p1.0 - x_min) * scale_x;
+                    let y1 = bottom - (p1.1 - y_min) * scale_y;
+                    let x2 = left + (p2.0 - x_min) * scale_x;
+                    let y2 = bottom - (p2.1 - y_min) * scale_y;
 
-                    svg_canvas.draw_line(
+                    svg_canvas.draw_line_styled(
                         x1,
                         y1,
                         x2,
                         y2,
-                        &format!(
-                            "rgb({},{},{})",
-                            dataset.color[0], dataset.color[1], dataset.color[2]
-                        ),
-                        1.5,
+                        dataset.color,
+                        1.5 * dataset.line_width as f64,
+                        dataset.line_type.clone(),
                     );
                 }
             }
 
             // Optionally draw points
             for &(x, y) in &dataset.points {
-                let svg_x = margin + (x - x_min) * scale_x;
-                let svg_y = height - margin - (y - y_min) * scale_y;
+                let svg_x = left + (x - x_min) * scale_x;
+                let svg_y = bottom - (y - y_min) * scale_y;
 
                 svg_canvas.draw_circle(svg_x, svg_y, 3.0, "black");
             }
         }
 
+        // Move the grid over the data
+        if self.config.grid_on_top {
+            svg_canvas.raise_elements(grid);
+        }
+
         // Draw legend in the bottom-left corner
-        let legend_x_start = 5.0; // Start at the very left with margin spacing
-        let legend_y = height - margin / 2.0; // Move to bottom-left corner
-        let mut legend_x = legend_x_start; // Reset starting position for legend items
+        let marker_size = self.config.legend_marker_size as f64;
+        let entry_width =
+            |label: &str| marker_size + font_size * 4.0 + label.len() as f64 * font_size * 0.6;
+        let legend_width = self
+            .datasets
+            .iter()
+            .map(|dataset| entry_width(&dataset.label))
+            .sum::<f64>()
+            + 5.0;
+        let legend_height = font_size.max(marker_size) + 10.0;
+        let Some((legend_left, legend_top)) =
+            self.svg_legend_origin(svg_canvas, (legend_width, legend_height))
+        else {
+            return;
+        };
+        let legend_x_start = legend_left + 5.0;
+        let legend_y = legend_top + 5.0;
+        let mut legend_x = legend_x_start;
         let mut elements = String::new();
 
         for dataset in &self.datasets {
+            let color = if dataset.visible {
+                dataset.color
+            } else {
+                LEGEND_HIDDEN_COLOR
+            };
+
             // Draw color square
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
-                legend_x,
-                legend_y,
-                font_size,
-                font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2]
+                legend_x, legend_y, marker_size, marker_size, color[0], color[1], color[2]
             ));
 
             // Draw label text next to the color square
             elements.push_str(&format!(
                 r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="rgb({},{},{})">{}</text>"#,
-                legend_x + font_size * 1.3,
+                legend_x + marker_size + font_size * 0.3,
                 legend_y + font_size - 2.0,
                 font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2],
-                dataset.label
+                color[0],
+                color[1],
+                color[2],
+                escape_xml(&dataset.label)
             ));
 
             // Update legend_x to position the next item
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
+            legend_x += entry_width(&dataset.label);
         }
 
         // Draw a background rectangle for the legend
-        let legend_width = legend_x - legend_x_start + 5.0;
-        let legend_height = font_size + 10.0;
         svg_canvas.draw_rect(
             legend_x_start - 5.0,
             legend_y - 5.0,
@@ -210,81 +213,82 @@ impl Drawer for Quadrant1Graph {
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
         canvas.clear();
+        canvas.antialias = self.config.antialias;
+        canvas.text_rendering = self.config.text_rendering;
 
-        let margin = canvas.margin;
+        if !self.has_data() {
+            self.draw_no_data(canvas, &self.config, &self.title);
+            return;
+        }
+
+        let (left, top, right, bottom) = canvas.plot_bounds();
         let width = canvas.width;
-        let height = canvas.height;
         let cfg = &self.config;
 
         // Draw the title
-        self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
+        self.draw_title(canvas, cfg, width / 2, top / 2, &self.title);
 
-        // Calculate dataset limits
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
-            .fold((0.0_f64, 0.0_f64), |(min, max), x| (min.min(x), max.max(x)));
+        // Calculate dataset limits, including (0, 0)
+        let (x_min, x_max, y_min, y_max) = self.plot_limits();
+        // Calculate scales
+        let scale_x = (right - left) as f64 / (x_max - x_min);
+        let scale_y = (bottom - top) as f64 / (y_max - y_min);
 
-        let (y_min, y_max) = self
-            .datasets
+        // Draw axes
+        let origin_x = left + ((0.0 - x_min) * scale_x) as u32;
+        let origin_y = bottom - ((0.0 - y_min) * scale_y) as u32;
+
+        // Tick positions, shared by the grid and the tick values
+        let num_ticks = cfg.num_axis_ticks;
+        let x_tick_values = tick_values(self.x_ticks.as_deref(), x_min, x_max, num_ticks);
+        let x_tick_positions: Vec<u32> = x_tick_values
+            .iter()
+            .map(|value_x| origin_x + ((value_x - x_min) * scale_x) as u32)
+            .collect();
+        let y_tick_values = tick_values(self.y_ticks.as_deref(), y_min, y_max, num_ticks);
+        let y_tick_positions: Vec<u32> = y_tick_values
             .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
-            .fold((0.0_f64, 0.0_f64), |(min, max), y| (min.min(y), max.max(y)));
+            .map(|value_y| origin_y - ((value_y - y_min) * scale_y) as u32)
+            .collect();
 
-        // Adjust limits to include (0, 0)
-        let x_min = x_min.min(0.0);
-        let y_min = y_min.min(0.0);
-        // Calculate scales
-        let scale_x = (width - 2 * margin) as f64 / (x_max - x_min);
-        let scale_y = (height - 2 * margin) as f64 / (y_max - y_min);
+        // Draw grids, unless they go on top of the data
+        if !cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &x_tick_positions, &y_tick_positions);
+        }
 
-        // Draw grids
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
+        self.draw_label(canvas, cfg, left, top / 2, &self.y_label);
+        self.draw_label(
+            canvas,
+            cfg,
+            right + (width - right) / 2,
+            origin_y,
+            &self.x_label,
         );
 
-        // Draw axes
-        let origin_x = margin + ((0.0 - x_min) * scale_x) as u32;
-        let origin_y = height - margin - ((0.0 - y_min) * scale_y) as u32;
-
-        self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
-        self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.x_label);
-
         // Draw axis tick values
-        let num_ticks = cfg.num_axis_ticks;
-
         // X-axis ticks
-        let x_tick_step = (x_max - x_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_x = x_min + i as f64 * x_tick_step;
-            let tick_x = origin_x + ((value_x - x_min) * scale_x) as u32;
-
-            let value_label = format!("{:.2}", value_x);
+        for (&value_x, &tick_x) in x_tick_values.iter().zip(&x_tick_positions) {
+            let value_label = cfg.format_tick(value_x);
 
             self.draw_axis_value(canvas, cfg, tick_x, origin_y, &value_label, AxisType::AxisX);
         }
 
         // Y-axis ticks
-        let y_tick_step = (y_max - y_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_y = y_min + i as f64 * y_tick_step;
-            let tick_y = origin_y - ((value_y - y_min) * scale_y) as u32;
-            let value_label = format!("{:.2}", value_y);
+        for (&value_y, &tick_y) in y_tick_values.iter().zip(&y_tick_positions) {
+            let value_label = cfg.format_tick(value_y);
 
             self.draw_axis_value(
                 canvas,
                 cfg,
-                origin_x - 10,
+                origin_x.saturating_sub(10),
                 tick_y,
                 &value_label,
                 AxisType::AxisY,
             );
         }
 
-        // Draw datasets
-        for dataset in &self.datasets {
+        // Draw the visible datasets
+        for dataset in self.datasets.iter().filter(|dataset| dataset.visible) {
             for window in dataset.points.windows(2) {
                 if let [p1, p2] = window {
                     let x1 = origin_x + ((p1.0 - x_min) * scale_x) as u32;
@@ -292,75 +296,62 @@ impl Drawer for Quadrant1Graph {
                     let x2 = origin_x + ((p2.0 - x_min) * scale_x) as u32;
                     let y2 = origin_y - ((p2.1 - y_min) * scale_y) as u32;
 
-                    canvas.draw_line(
+                    canvas.draw_line_thick(
                         x1 as i32,
                         y1 as i32,
                         x2 as i32,
                         y2 as i32,
                         dataset.color,
+                        dataset.line_width,
                         dataset.line_type.clone(),
                     );
                 }
             }
         }
-        canvas.draw_vertical_line(canvas.margin, [0, 0, 0]);
-        canvas.draw_vertical_line(canvas.width - canvas.margin, [0, 0, 0]);
-        canvas.draw_horizontal_line(canvas.height - canvas.margin, [0, 0, 0]);
-        canvas.draw_horizontal_line(canvas.margin, [0, 0, 0]);
+        // Draw grids over the data
+        if cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &x_tick_positions, &y_tick_positions);
+        }
+
+        let axis_color = cfg.effective_axis_color();
+        canvas.draw_vertical_line(left, axis_color);
+        canvas.draw_vertical_line(right, axis_color);
+        canvas.draw_horizontal_line(bottom, axis_color);
+        canvas.draw_horizontal_line(top, axis_color);
         // Draw legend
         self.draw_legend(canvas);
     }
 
     fn draw_legend(&self, canvas: &mut PixelCanvas) {
-        let font_path = self
-            .config
-            .font_label
-            .as_ref()
-            .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
-
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
-        let legend_margin = canvas.margin; // Margin from the bottom of the canvas
-
-        let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
+        let entries: Vec<(&str, [u8; 3], bool)> = self
+            .datasets
+            .iter()
+            .map(|dataset| (dataset.label.as_str(), dataset.color, !dataset.visible))
+            .collect();
+        self.draw_square_legend(canvas, &entries);
+    }
 
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
-            // Draw the square
-            for dy in 0..square_size {
-                for dx in 0..square_size {
-                    canvas.draw_pixel(
-                        x + dx,
-                        y + square_size * 2 + dy + h, // Adjust to align above baseline
-                        dataset.color,
-                    );
-                }
-            }
+    fn legend_entries(&self, canvas: &PixelCanvas) -> Vec<LegendEntry> {
+        let labels: Vec<&str> = self
+            .datasets
+            .iter()
+            .map(|dataset| dataset.label.as_str())
+            .collect();
+        self.layout_legend(canvas, self.config.legend_marker_size, &labels)
+    }
 
-            // Draw the label text next to the square
-            let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
-                text_x,
-                y + 2 * square_size + h,
-                &dataset.label,
-                dataset.color,
-                &font,
-                scale,
-            );
+    fn toggle_dataset(&mut self, index: usize) -> bool {
+        let Some(dataset) = self.datasets.get_mut(index) else {
+            return false;
+        };
+        dataset.visible = !dataset.visible;
+        true
+    }
 
-            // Move to the next legend entry
-            x += square_size + padding + w + padding;
-            if x > canvas.width - canvas.margin {
-                // If the width exceeds, wrap to the next row
-                x = canvas.margin;
-                y -= line_height;
-            }
-        }
+    fn has_data(&self) -> bool {
+        self.datasets
+            .iter()
+            .any(|dataset| !dataset.points.is_empty())
     }
 
     fn as_any(&mut self) -> &mut (dyn Any + 'static) {
@@ -1,79 +1,78 @@
-use ab_glyph::{FontRef, PxScale};
-use imageproc::drawing::text_size;
-
 use crate::figure::{
-    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    canvas::{
+        margins::Margins,
+        pixelcanvas::PixelCanvas,
+        svgcanvas::{escape_xml, SvgCanvas},
+    },
     configuration::figureconfig::FigureConfig,
-    figuretypes::scattergraph::ScatterGraph,
-    utilities::{axistype::AxisType, scatterdottype::ScatterDotType},
+    figuretypes::scattergraph::{error_bar_lines, within_limits, ScatterGraph, ScatterLayout},
+    utilities::{
+        axistype::AxisType, clipping::ClipRect, linetype::LineType, scatterdottype::ScatterDotType,
+        ticks::nice_tick_values,
+    },
 };
 
-use super::drawer::Drawer;
+use super::drawer::{Drawer, LegendEntry, LEGEND_HIDDEN_COLOR};
+#[cfg(feature = "rayon")]
+use crate::figure::datasets::scattergraphdataset::ScatterGraphDataset;
 use std::any::Any;
 impl Drawer for ScatterGraph {
     fn draw_svg(&mut self, svg_canvas: &mut SvgCanvas) {
+        if !self.has_data() {
+            self.draw_no_data_svg(svg_canvas, &self.title);
+            return;
+        }
+
         let width = svg_canvas.width as f64;
         let height = svg_canvas.height as f64;
-        let margin = svg_canvas.margin as f64;
+        let (left, top, right, bottom) = svg_canvas.plot_bounds();
         let font_size = 12.0;
 
-        // Draw background
-        svg_canvas.draw_rect(0.0, 0.0, width, height, "white", "black", 1.0, 1.0);
+        // Draw the frame, the background itself is added by the canvas
+        svg_canvas.draw_rect(0.0, 0.0, width, height, "none", "black", 1.0, 1.0);
 
         // Draw Title
         svg_canvas.draw_title(
             width / 2.0,
-            margin / 2.0,
+            top / 2.0,
             &self.title,
             font_size * 2.0,
             "black",
         );
 
-        // Determine dataset range
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
-                (min.min(x), max.max(x))
-            });
-
-        let (y_min, y_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
-                (min.min(y), max.max(y))
-            });
+        // Use the same limits as the pixel output
+        let (x_min, x_max, y_min, y_max) = self.plot_limits();
 
-        let scale_x = (width - 2.0 * margin) / (x_max - x_min);
-        let scale_y = (height - 2.0 * margin) / (y_max - y_min);
+        let scale_x = (right - left) / (x_max - x_min);
+        let scale_y = (bottom - top) / (y_max - y_min);
 
         // Draw grid
         let num_ticks = 10;
+        let grid_start = svg_canvas.elements.len();
         svg_canvas.draw_grid(
-            margin,
-            width - margin,
-            margin,
-            height - margin,
+            left,
+            right,
+            top,
+            bottom,
             num_ticks,
             num_ticks,
             "lightgray",
+            self.config.grid_line_type.clone(),
         );
+        let grid = grid_start..svg_canvas.elements.len();
 
         // Draw axes
-        let origin_x = margin - x_min * scale_x; // Adjust for negative X values
-        let origin_y = height - margin + y_min * scale_y; // Adjust for negative Y values
+        let origin_x = left - x_min * scale_x; // Adjust for negative X values
+        let origin_y = bottom + y_min * scale_y; // Adjust for negative Y values
 
-        svg_canvas.draw_line(margin, origin_y, width - margin, origin_y, "black", 2.0); // X-axis
-        svg_canvas.draw_line(origin_x, margin, origin_x, height - margin, "black", 2.0); // Y-axis
+        svg_canvas.draw_line(left, origin_y, right, origin_y, "black", 2.0); // X-axis
+        svg_canvas.draw_line(origin_x, top, origin_x, bottom, "black", 2.0); // Y-axis
 
         // Draw tick marks and values for X-axis
         // X-axis
         let mut x_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = x_min + i as f64 * (x_max - x_min) / num_ticks as f64;
-            let x = margin + i as f64 * (width - 2.0 * margin) / num_ticks as f64;
+        for value in nice_tick_values(self.x_ticks.as_deref(), x_min, x_max, num_ticks) {
+            let x = left + (value - x_min) * scale_x;
             let tick_start_y = origin_y - 5.0;
             let tick_end_y = origin_y + 5.0;
 
@@ -84,8 +83,8 @@ impl Drawer for ScatterGraph {
 
             // Draw value as text (fallback to basic SVG <text>)
             svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{:.1}</text>"#,
-            x, height - margin + font_size * 1.5, font_size, value));
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black">{}</text>"#,
+            x, bottom + font_size * 1.5, font_size, self.config.format_tick(value)));
         }
         svg_canvas.elements.push(format!(
             r#"<path d="{}" stroke="black" stroke-width="1" fill="none"/>"#,
@@ -94,9 +93,8 @@ impl Drawer for ScatterGraph {
 
         // Y-axis
         let mut y_axis_ticks = String::new();
-        for i in 0..=num_ticks {
-            let value = y_min + i as f64 * (y_max - y_min) / num_ticks as f64;
-            let y = height - margin - i as f64 * (height - 2.0 * margin) / num_ticks as f64;
+        for value in nice_tick_values(self.y_ticks.as_deref(), y_min, y_max, num_ticks) {
+            let y = bottom - (value - y_min) * scale_y;
             let tick_start_x = origin_x - 5.0;
             let tick_end_x = origin_x + 5.0;
 
@@ -107,8 +105,8 @@ impl Drawer for ScatterGraph {
 
             // Draw value as text (fallback to basic SVG <text>)
             svg_canvas.elements.push(format!(
-            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{:.1}</text>"#,
-            margin - 5.0, y + font_size * 0.3, font_size, value
+            r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="end" fill="black">{}</text>"#,
+            left - 5.0, y + font_size * 0.3, font_size, self.config.format_tick(value)
         ));
         }
         svg_canvas.elements.push(format!(
@@ -118,8 +116,8 @@ impl Drawer for ScatterGraph {
 
         // Draw X-axis label
         svg_canvas.draw_text(
-            width - margin,
-            height - margin / 2.0,
+            right,
+            bottom + (height - bottom) / 2.0,
             &self.x_label,
             font_size * 1.5,
             "black",
@@ -128,20 +126,33 @@ impl Drawer for ScatterGraph {
         // Draw Y-axis label (rotated)
         svg_canvas.elements.push(format!(
             r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" text-anchor="middle" fill="black" transform="rotate(-90 {:.2} {:.2})">{}</text>"#,
-            margin / 3.0,
+            left / 3.0,
             height / 2.0,
             font_size * 1.5,
-            margin / 3.0,
+            left / 3.0,
             height / 2.0,
-            self.y_label
+            escape_xml(&self.y_label)
         ));
 
-        // Plot datasets with scatter dot types
-        for dataset in &self.datasets {
-            for &(x, y) in &dataset.points {
-                let dot_type = &dataset.dot_type;
-                let svg_x = margin + (x - x_min) * scale_x;
-                let svg_y = height - margin - (y - y_min) * scale_y;
+        // Plot the visible datasets with scatter dot types
+        for dataset in self.datasets.iter().filter(|dataset| dataset.visible) {
+            for (index, &(x, y)) in dataset.points.iter().enumerate() {
+                if !within_limits((x, y), (x_min, x_max, y_min, y_max)) {
+                    continue;
+                }
+                let dot_type = &dataset.dot_type_at(index);
+                let svg_x = left + (x - x_min) * scale_x;
+                let svg_y = bottom - (y - y_min) * scale_y;
+
+                // Draw error bars below the dot
+                for (x1, y1, x2, y2) in error_bar_lines(
+                    (svg_x, svg_y),
+                    dataset.x_error(index).map(|error| error * scale_x),
+                    dataset.y_error(index).map(|error| error * scale_y),
+                    ClipRect::new(left, top, right, bottom),
+                ) {
+                    svg_canvas.draw_line_rgb(x1, y1, x2, y2, dataset.color, 1.0);
+                }
 
                 match dot_type {
                     ScatterDotType::Circle(radius) => {
@@ -198,61 +209,99 @@ impl Drawer for ScatterGraph {
                     ScatterDotType::Triangle(base_size) => {
                         let half_base = *base_size as f64 / 2.0;
                         let height = *base_size as f64 * 0.866; // Height of an equilateral triangle
-                        svg_canvas.elements.push(format!(
-                            r#"<polygon points="{:.2},{:.2} {:.2},{:.2} {:.2},{:.2}" fill="rgb({},{},{})"/>"#,
-                            svg_x,
-                            svg_y - height / 2.0,
-                            svg_x - half_base,
-                            svg_y + height / 2.0,
-                            svg_x + half_base,
-                            svg_y + height / 2.0,
-                            dataset.color[0],
-                            dataset.color[1],
-                            dataset.color[2]
-                        ));
+                        svg_canvas.draw_polygon(
+                            &[
+                                (svg_x, svg_y - height / 2.0),
+                                (svg_x - half_base, svg_y + height / 2.0),
+                                (svg_x + half_base, svg_y + height / 2.0),
+                            ],
+                            &format!(
+                                "rgb({},{},{})",
+                                dataset.color[0], dataset.color[1], dataset.color[2]
+                            ),
+                            "none",
+                            0.0,
+                        );
                     }
                 }
             }
         }
 
+        // Move the grid over the data
+        if self.config.grid_on_top {
+            svg_canvas.raise_elements(grid);
+        }
+
+        // Draw trend lines across the visible x-range
+        for line in &self.trend_lines {
+            if let Some((x1, y1, x2, y2)) = line.segment((x_min, x_max, y_min, y_max)) {
+                svg_canvas.draw_line_rgb(
+                    left + (x1 - x_min) * scale_x,
+                    bottom - (y1 - y_min) * scale_y,
+                    left + (x2 - x_min) * scale_x,
+                    bottom - (y2 - y_min) * scale_y,
+                    line.color,
+                    1.5,
+                );
+            }
+        }
+
+        // Draw reference annotations
+        self.draw_annotations_svg(svg_canvas, &self.annotations, &|x, y| {
+            (left + (x - x_min) * scale_x, bottom - (y - y_min) * scale_y)
+        });
+
         // Draw legend
-        let legend_x_start = 5.0; // Start at the very left with margin spacing
-        let legend_y = height - margin / 2.0; // Move to bottom-left corner
-        let mut legend_x = legend_x_start; // Reset starting position for legend items
+        let marker_size = self.config.legend_marker_size as f64;
+        let entry_width =
+            |label: &str| marker_size + font_size * 4.0 + label.len() as f64 * font_size * 0.6;
+        let legend_width = self
+            .datasets
+            .iter()
+            .map(|dataset| entry_width(&dataset.label))
+            .sum::<f64>()
+            + 5.0;
+        let legend_height = font_size.max(marker_size) + 10.0;
+        let Some((legend_left, legend_top)) =
+            self.svg_legend_origin(svg_canvas, (legend_width, legend_height))
+        else {
+            return;
+        };
+        let legend_x_start = legend_left + 5.0;
+        let legend_y = legend_top + 5.0;
+        let mut legend_x = legend_x_start;
         let mut elements = String::new();
 
         for dataset in &self.datasets {
+            let color = if dataset.visible {
+                dataset.color
+            } else {
+                LEGEND_HIDDEN_COLOR
+            };
+
             // Draw color square
             elements.push_str(&format!(
                 r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="rgb({},{},{})"/>"#,
-                legend_x,
-                legend_y,
-                font_size,
-                font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2]
+                legend_x, legend_y, marker_size, marker_size, color[0], color[1], color[2]
             ));
 
             // Draw label text next to the color square
             elements.push_str(&format!(
                 r#"<text x="{:.2}" y="{:.2}" font-size="{:.2}" fill="rgb({},{},{})">{}</text>"#,
-                legend_x + font_size * 1.3,
+                legend_x + marker_size + font_size * 0.3,
                 legend_y + font_size - 2.0,
                 font_size,
-                dataset.color[0],
-                dataset.color[1],
-                dataset.color[2],
-                dataset.label
+                color[0],
+                color[1],
+                color[2],
+                escape_xml(&dataset.label)
             ));
 
             // Update legend_x to position the next item
-            legend_x += font_size * 5.0 + dataset.label.len() as f64 * font_size * 0.6;
+            legend_x += entry_width(&dataset.label);
         }
 
         // Draw a background rectangle for the legend
-        let legend_width = legend_x - legend_x_start + 5.0;
-        let legend_height = font_size + 10.0;
         svg_canvas.draw_rect(
             legend_x_start - 5.0,
             legend_y - 5.0,
@@ -269,172 +318,453 @@ impl Drawer for ScatterGraph {
     }
 
     fn draw(&mut self, canvas: &mut PixelCanvas) {
-        canvas.clear();
+        self.draw_with(canvas, Self::draw_points);
+    }
 
-        let margin = canvas.margin;
-        let width = canvas.width;
-        let height = canvas.height;
-        let cfg = &self.config;
+    fn draw_incremental(&mut self, canvas: &mut PixelCanvas) {
+        let limits = self.plot_limits();
+        match &self.layout_cache {
+            Some(layout)
+                if self.has_data() && layout.matches(canvas, limits, self.datasets.len()) =>
+            {
+                // Restore the static layout in place instead of redrawing text
+                canvas.buffer.copy_from_slice(&layout.buffer);
+            }
+            _ => {
+                self.draw(canvas);
+                return;
+            }
+        }
 
-        // Draw the title
-        self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
+        self.draw_points(canvas, limits);
+        if self.config.grid_on_top {
+            if let Some(layout) = &self.layout_cache {
+                let (x_positions, y_positions) = &layout.grid_positions;
+                self.draw_tick_grid(canvas, &self.config, x_positions, y_positions);
+            }
+        }
+        self.draw_overlay(canvas, limits);
+    }
 
-        // Calculate dataset limits
-        let (x_min, x_max) = self
+    fn draw_legend(&self, canvas: &mut PixelCanvas) {
+        let entries: Vec<(&str, [u8; 3], bool)> = self
             .datasets
             .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
-                (min.min(x), max.max(x))
-            });
+            .map(|dataset| (dataset.label.as_str(), dataset.color, !dataset.visible))
+            .collect();
+        self.draw_square_legend(canvas, &entries);
+    }
 
-        let (y_min, y_max) = self
+    fn legend_entries(&self, canvas: &PixelCanvas) -> Vec<LegendEntry> {
+        let labels: Vec<&str> = self
             .datasets
             .iter()
-            .flat_map(|dataset| dataset.points.iter().map(|&(_, y)| y))
-            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| {
-                (min.min(y), max.max(y))
-            });
+            .map(|dataset| dataset.label.as_str())
+            .collect();
+        self.layout_legend(canvas, self.config.legend_marker_size, &labels)
+    }
+
+    fn toggle_dataset(&mut self, index: usize) -> bool {
+        let Some(dataset) = self.datasets.get_mut(index) else {
+            return false;
+        };
+        dataset.visible = !dataset.visible;
+        // The cached layout holds the legend, which greys out hidden datasets
+        self.layout_cache = None;
+        true
+    }
 
-        // Adjust limits to include (0, 0)
-        let x_min = x_min.min(0.0);
-        let y_min = y_min.min(0.0);
+    fn has_data(&self) -> bool {
+        self.datasets
+            .iter()
+            .any(|dataset| !dataset.points.is_empty())
+    }
 
-        // Calculate scales
-        let scale_x = (width - 2 * margin) as f64 / (x_max - x_min);
-        let scale_y = (height - 2 * margin) as f64 / (y_max - y_min);
+    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
+        self as &mut dyn Any
+    }
 
-        // Draw grids
-        canvas.draw_grid(
-            &[cfg.num_grid_horizontal, cfg.num_grid_vertical],
-            cfg.color_grid,
-        );
+    fn get_figure_config(&self) -> &FigureConfig {
+        &self.config
+    }
+}
 
-        let origin_x = canvas.margin + ((0.0 - x_min) * scale_x) as u32;
-        let origin_y = height - margin - ((0.0 - y_min) * scale_y) as u32;
+/// Draws the data points of a scatter graph within the given plot limits.
+type PointsRenderer = fn(&ScatterGraph, &mut PixelCanvas, (f64, f64, f64, f64));
 
-        self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.x_label);
-        self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
+impl ScatterGraph {
+    /// Draws the whole graph, using `draw_points` for the data points.
+    fn draw_with(&mut self, canvas: &mut PixelCanvas, draw_points: PointsRenderer) {
+        canvas.clear();
+        canvas.antialias = self.config.antialias;
+        canvas.text_rendering = self.config.text_rendering;
 
-        // Draw axis tick values
+        if !self.has_data() {
+            self.draw_no_data(canvas, &self.config, &self.title);
+            self.layout_cache = None;
+            return;
+        }
+
+        let (left, top, right, bottom) = canvas.plot_bounds();
+        let width = canvas.width;
+        let cfg = &self.config;
+
+        // Draw the title
+        self.draw_title(canvas, cfg, width / 2, top / 2, &self.title);
+
+        // Calculate dataset limits, including (0, 0)
+        let limits = self.plot_limits();
+        let (x_min, x_max, y_min, y_max) = limits;
+
+        // Calculate scales
+        let scale_x = (right - left) as f64 / (x_max - x_min);
+        let scale_y = (bottom - top) as f64 / (y_max - y_min);
+
+        // The axes cross at the origin, or at the nearest plot edge if it is outside the limits
+        let origin_x = left + ((0.0_f64.clamp(x_min, x_max) - x_min) * scale_x) as u32;
+        let origin_y = bottom - ((0.0_f64.clamp(y_min, y_max) - y_min) * scale_y) as u32;
+
+        // Tick positions, shared by the grid and the tick values
         let num_ticks = 10;
+        let x_tick_values = nice_tick_values(self.x_ticks.as_deref(), x_min, x_max, num_ticks);
+        let x_tick_positions: Vec<u32> = x_tick_values
+            .iter()
+            .map(|value_x| left + ((value_x - x_min) * scale_x) as u32)
+            .collect();
+        let y_tick_values = nice_tick_values(self.y_ticks.as_deref(), y_min, y_max, num_ticks);
+        let y_tick_positions: Vec<u32> = y_tick_values
+            .iter()
+            .map(|value_y| bottom - ((value_y - y_min) * scale_y) as u32)
+            .collect();
 
-        // X-axis ticks
-        let x_tick_step = (x_max - x_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_x = x_min + i as f64 * x_tick_step;
-            let tick_x = origin_x + ((value_x - x_min) * scale_x) as u32;
+        // Draw grids, unless they go on top of the data
+        if !cfg.grid_on_top {
+            self.draw_tick_grid(canvas, cfg, &x_tick_positions, &y_tick_positions);
+        }
+
+        self.draw_label(
+            canvas,
+            cfg,
+            right + (width - right) / 2,
+            origin_y,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, left, top / 2, &self.y_label);
 
-            let value_label = format!("{:.2}", value_x);
+        // Draw axis tick values
+        // X-axis ticks
+        for (&value_x, &tick_x) in x_tick_values.iter().zip(&x_tick_positions) {
+            let value_label = cfg.format_tick(value_x);
 
             self.draw_axis_value(canvas, cfg, tick_x, origin_y, &value_label, AxisType::AxisX);
         }
 
         // Y-axis ticks
-        let y_tick_step = (y_max - y_min) / num_ticks as f64;
-        for i in 0..=num_ticks {
-            let value_y = y_min + i as f64 * y_tick_step;
-            let tick_y = origin_y - ((value_y - y_min) * scale_y) as u32;
-
-            let value_label = format!("{:.2}", value_y);
+        for (&value_y, &tick_y) in y_tick_values.iter().zip(&y_tick_positions) {
+            let value_label = cfg.format_tick(value_y);
 
             self.draw_axis_value(
                 canvas,
                 cfg,
-                origin_x - 10,
+                origin_x.saturating_sub(10),
                 tick_y,
                 &value_label,
                 AxisType::AxisY,
             );
         }
 
+        // Draw legend
+        self.draw_legend(canvas);
+
+        // Remember the static layout so draw_incremental only has to redraw the data
+        self.cache_layout(canvas, limits, (&x_tick_positions, &y_tick_positions));
+
         // Draw scatter points
-        for dataset in &self.datasets {
-            for &(_x, _y) in &dataset.points {
-                // Draw a small square or circle to represent the point
-                for dataset in &self.datasets {
-                    for &(x, y) in &dataset.points {
-                        let px = origin_x + ((x - x_min) * scale_x) as u32;
-                        let py = origin_y - ((y - y_min) * scale_y) as u32;
-
-                        self.draw_dot(
-                            canvas,
-                            px as i32,
-                            py as i32,
-                            dataset.dot_type.clone(),
-                            dataset.color,
-                        );
-                    }
-                }
-            }
+        draw_points(self, canvas, limits);
+
+        // Draw grids over the data
+        if self.config.grid_on_top {
+            self.draw_tick_grid(canvas, &self.config, &x_tick_positions, &y_tick_positions);
         }
 
-        canvas.draw_vertical_line(canvas.margin, [0, 0, 0]);
-        canvas.draw_vertical_line(canvas.width - canvas.margin, [0, 0, 0]);
-        canvas.draw_horizontal_line(canvas.height - canvas.margin, [0, 0, 0]);
-        canvas.draw_horizontal_line(canvas.margin, [0, 0, 0]);
+        self.draw_overlay(canvas, limits);
+    }
 
-        // Draw legend
-        self.draw_legend(canvas);
+    /// Returns the scales for the given plot limits.
+    fn layout_scales(&self, canvas: &PixelCanvas, limits: (f64, f64, f64, f64)) -> (f64, f64) {
+        let (x_min, x_max, y_min, y_max) = limits;
+        let scale_x = canvas.plot_width() as f64 / (x_max - x_min);
+        let scale_y = canvas.plot_height() as f64 / (y_max - y_min);
+        (scale_x, scale_y)
     }
 
-    fn draw_legend(&self, canvas: &mut PixelCanvas) {
-        let font_path = self
-            .config
-            .font_label
-            .as_ref()
-            .expect("Font path is not set");
-        let font_bytes = std::fs::read(font_path).expect("Failed to read font file");
-        let font = FontRef::try_from_slice(&font_bytes).unwrap();
-        let scale = PxScale { x: 10.0, y: 10.0 }; // Font size
-
-        let square_size = 10; // Size of the colored square
-        let padding = 5; // Space between the square and text
-        let line_height = 20; // Vertical space for each legend entry
-        let legend_margin = canvas.margin; // Margin from the bottom of the canvas
-
-        let mut x = canvas.margin;
-        let mut y = canvas.height - legend_margin; // Legend starts from the bottom
+    /// Stores the current canvas contents as the static layout, reusing the previous allocation.
+    ///
+    /// The grid positions are kept as well, to redraw the grid over the data if
+    /// `grid_on_top` is set.
+    fn cache_layout(
+        &mut self,
+        canvas: &PixelCanvas,
+        limits: (f64, f64, f64, f64),
+        grid_positions: (&[u32], &[u32]),
+    ) {
+        let layout = self.layout_cache.get_or_insert_with(|| ScatterLayout {
+            width: 0,
+            height: 0,
+            margins: Margins::default(),
+            limits,
+            dataset_count: 0,
+            buffer: Vec::new(),
+            grid_positions: (Vec::new(), Vec::new()),
+        });
+        layout.width = canvas.width;
+        layout.height = canvas.height;
+        layout.margins = canvas.margins;
+        layout.limits = limits;
+        layout.dataset_count = self.datasets.len();
+        layout.buffer.clear();
+        layout.buffer.extend_from_slice(&canvas.buffer);
+        layout.grid_positions = (grid_positions.0.to_vec(), grid_positions.1.to_vec());
+    }
 
-        for dataset in &self.datasets {
-            let (w, h) = text_size(scale, &font, &dataset.label);
-            // Draw the square
-            for dy in 0..square_size {
-                for dx in 0..square_size {
-                    canvas.draw_pixel(
-                        x + dx,
-                        y + square_size * 2 + dy + h, // Adjust to align above baseline
+    /// Draws every data point within the plot limits once.
+    fn draw_points(&self, canvas: &mut PixelCanvas, limits: (f64, f64, f64, f64)) {
+        let (scale_x, scale_y) = self.layout_scales(canvas, limits);
+        let (left, top, right, bottom) = canvas.plot_bounds();
+        let clip = ClipRect::new(left as f64, top as f64, right as f64, bottom as f64);
+
+        for dataset in self.datasets.iter().filter(|dataset| dataset.visible) {
+            for (index, &point) in dataset.points.iter().enumerate() {
+                if !within_limits(point, limits) {
+                    continue;
+                }
+                let (px, py) =
+                    self.pixel_position(point, limits, canvas.width, canvas.height, canvas.margins);
+
+                // Draw error bars below the dot
+                for (x1, y1, x2, y2) in error_bar_lines(
+                    (px as f64, py as f64),
+                    dataset.x_error(index).map(|error| error * scale_x),
+                    dataset.y_error(index).map(|error| error * scale_y),
+                    clip,
+                ) {
+                    canvas.draw_line(
+                        x1.round() as i32,
+                        y1.round() as i32,
+                        x2.round() as i32,
+                        y2.round() as i32,
                         dataset.color,
+                        LineType::Solid,
                     );
                 }
+
+                self.draw_dot(
+                    canvas,
+                    px as i32,
+                    py as i32,
+                    dataset.dot_type_at(index),
+                    dataset.color,
+                );
             }
+        }
+    }
 
-            // Draw the label text next to the square
-            let text_x: u32 = x + square_size + padding;
-            canvas.draw_text(
-                text_x,
-                y + 2 * square_size + h,
-                &dataset.label,
-                dataset.color,
-                &font,
-                scale,
-            );
+    /// Draws the graph like [`Drawer::draw`], rendering the data points on several threads.
+    ///
+    /// The canvas is split into horizontal bands whose points are drawn in parallel into
+    /// separate buffers, which are then stitched back together. The result is pixel-identical
+    /// to `draw`; it pays off for datasets with many thousands of points.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw on.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::datasets::{dataset::Dataset, scattergraphdataset::ScatterGraphDataset};
+    /// use dataviz::figure::figuretypes::scattergraph::ScatterGraph;
+    /// use dataviz::figure::utilities::scatterdottype::ScatterDotType;
+    ///
+    /// let mut graph = ScatterGraph::new("Samples", "X", "Y", FigureConfig::default());
+    /// let mut dataset = ScatterGraphDataset::new([255, 0, 0], "Noise", ScatterDotType::Circle(1));
+    /// let points: Vec<(f64, f64)> = (0..100_000).map(|i| (i as f64, (i % 977) as f64)).collect();
+    /// dataset.add_points(&points);
+    /// graph.add_dataset(dataset);
+    ///
+    /// let mut canvas = PixelCanvas::new(800, 600, [255, 255, 255], 50);
+    /// graph.draw_parallel(&mut canvas);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn draw_parallel(&mut self, canvas: &mut PixelCanvas) {
+        self.draw_with(canvas, Self::draw_points_parallel);
+    }
+
+    /// Draws the data points like `draw_points`, splitting the canvas into horizontal bands
+    /// that are drawn in parallel.
+    #[cfg(feature = "rayon")]
+    fn draw_points_parallel(&self, canvas: &mut PixelCanvas, limits: (f64, f64, f64, f64)) {
+        use rayon::prelude::*;
+
+        let (scale_x, scale_y) = self.layout_scales(canvas, limits);
+        let (left, top, right, bottom) = canvas.plot_bounds();
+        let clip = ClipRect::new(left as f64, top as f64, right as f64, bottom as f64);
+        let (width, height, margins) = (canvas.width, canvas.height, canvas.margins);
+        if canvas.buffer.is_empty() {
+            return;
+        }
 
-            // Move to the next legend entry
-            x += square_size + padding + w + padding;
-            if x > canvas.width - canvas.margin {
-                // If the width exceeds, wrap to the next row
-                x = canvas.margin;
-                y -= line_height;
+        // Every dot with its error bars, in drawing order
+        let dots: Vec<BandDot> = self
+            .datasets
+            .iter()
+            .filter(|dataset| dataset.visible)
+            .flat_map(|dataset| {
+                dataset
+                    .points
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &point)| within_limits(point, limits))
+                    .map(move |(index, &point)| {
+                        let (px, py) = self.pixel_position(point, limits, width, height, margins);
+                        let error_bars: Vec<(i32, i32, i32, i32)> = error_bar_lines(
+                            (px as f64, py as f64),
+                            dataset.x_error(index).map(|error| error * scale_x),
+                            dataset.y_error(index).map(|error| error * scale_y),
+                            clip,
+                        )
+                        .into_iter()
+                        .map(|(x1, y1, x2, y2)| {
+                            (
+                                x1.round() as i32,
+                                y1.round() as i32,
+                                x2.round() as i32,
+                                y2.round() as i32,
+                            )
+                        })
+                        .collect();
+                        BandDot::new(px as i32, py as i32, dataset, index, error_bars)
+                    })
+            })
+            .collect();
+
+        // Each band draws the dots reaching into it on a copy of its rows
+        let threads = rayon::current_num_threads() as u32;
+        let band_height = height.div_ceil(threads * 4).max(1);
+        let row_length = width as usize * 3;
+        let background_color = canvas.background_color;
+        canvas
+            .buffer
+            .par_chunks_mut(band_height as usize * row_length)
+            .enumerate()
+            .for_each(|(band, pixels)| {
+                let band_top = (band as u32 * band_height) as i32;
+                let rows = (pixels.len() / row_length) as u32;
+                let mut band_canvas =
+                    PixelCanvas::from_buffer(width, rows, pixels.to_vec(), background_color, 0)
+                        .expect("band buffer matches its size");
+
+                let band_rows = band_top..band_top + rows as i32;
+                for dot in dots.iter().filter(|dot| dot.overlaps(&band_rows)) {
+                    for &(x1, y1, x2, y2) in &dot.error_bars {
+                        band_canvas.draw_line(
+                            x1,
+                            y1 - band_top,
+                            x2,
+                            y2 - band_top,
+                            dot.color,
+                            LineType::Solid,
+                        );
+                    }
+                    self.draw_dot(
+                        &mut band_canvas,
+                        dot.x,
+                        dot.y - band_top,
+                        dot.dot_type.clone(),
+                        dot.color,
+                    );
+                }
+                pixels.copy_from_slice(&band_canvas.buffer);
+            });
+    }
+
+    /// Draws the parts on top of the data: trend lines, reference annotations and the plot border.
+    fn draw_overlay(&self, canvas: &mut PixelCanvas, limits: (f64, f64, f64, f64)) {
+        let (x_min, _, y_min, _) = limits;
+        let (scale_x, scale_y) = self.layout_scales(canvas, limits);
+        let (left, top, right, bottom) = canvas.plot_bounds();
+
+        // Draw trend lines across the visible x-range
+        for line in &self.trend_lines {
+            if let Some((x1, y1, x2, y2)) = line.segment(limits) {
+                canvas.draw_line(
+                    (left as f64 + (x1 - x_min) * scale_x) as i32,
+                    (bottom as f64 - (y1 - y_min) * scale_y) as i32,
+                    (left as f64 + (x2 - x_min) * scale_x) as i32,
+                    (bottom as f64 - (y2 - y_min) * scale_y) as i32,
+                    line.color,
+                    LineType::Solid,
+                );
             }
         }
+
+        // Draw reference annotations
+        self.draw_annotations(canvas, &self.config, &self.annotations, &|x, y| {
+            (
+                (left as f64 + (x - x_min) * scale_x) as i32,
+                (bottom as f64 - (y - y_min) * scale_y) as i32,
+            )
+        });
+
+        let axis_color = self.config.effective_axis_color();
+        canvas.draw_vertical_line(left, axis_color);
+        canvas.draw_vertical_line(right, axis_color);
+        canvas.draw_horizontal_line(bottom, axis_color);
+        canvas.draw_horizontal_line(top, axis_color);
     }
+}
 
-    fn as_any(&mut self) -> &mut (dyn Any + 'static) {
-        self as &mut dyn Any
+/// A data point prepared for drawing by [`ScatterGraph::draw_parallel`].
+#[cfg(feature = "rayon")]
+struct BandDot {
+    x: i32,
+    y: i32,
+    dot_type: ScatterDotType,
+    color: [u8; 3],
+    /// The error bars as `(x1, y1, x2, y2)` canvas segments.
+    error_bars: Vec<(i32, i32, i32, i32)>,
+    /// The first and last row the dot and its error bars may cover.
+    rows: (i32, i32),
+}
+
+#[cfg(feature = "rayon")]
+impl BandDot {
+    fn new(
+        x: i32,
+        y: i32,
+        dataset: &ScatterGraphDataset,
+        index: usize,
+        error_bars: Vec<(i32, i32, i32, i32)>,
+    ) -> Self {
+        let dot_type = dataset.dot_type_at(index);
+        // Every dot shape stays within its size of the center
+        let reach = dot_type.size().min(i32::MAX as u32) as i32;
+        let rows = error_bars.iter().fold(
+            (y.saturating_sub(reach), y.saturating_add(reach)),
+            |(first, last), &(_, y1, _, y2)| (first.min(y1).min(y2), last.max(y1).max(y2)),
+        );
+        Self {
+            x,
+            y,
+            dot_type,
+            color: dataset.color,
+            error_bars,
+            rows,
+        }
     }
 
-    fn get_figure_config(&self) -> &FigureConfig {
-        &self.config
+    /// Returns `true` if the dot may cover any of the `rows`.
+    fn overlaps(&self, rows: &std::ops::Range<i32>) -> bool {
+        self.rows.0 < rows.end && self.rows.1 >= rows.start
     }
 }
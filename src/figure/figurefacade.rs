@@ -0,0 +1,174 @@
+use std::io;
+
+use super::{
+    canvas::{pixelcanvas::PixelCanvas, svgcanvas::SvgCanvas},
+    display::{hover::Hover, winop::Winop},
+    drawers::drawer::Drawer,
+    utilities::hovermode::HoverMode,
+};
+
+/// A chart bundled with the canvas settings needed to render it.
+///
+/// `Figure` takes care of creating a canvas of matching size, wiring up the chart's
+/// configuration and drawing the chart, so saving or showing a chart is a single call.
+pub struct Figure<T: Drawer> {
+    /// The chart to render.
+    pub chart: T,
+    /// Margin around the plot area in pixels.
+    pub margin: u32,
+    /// Width of the window opened by [`Figure::show`] in pixels.
+    pub width: u32,
+    /// Height of the window opened by [`Figure::show`] in pixels.
+    pub height: u32,
+    /// What the window opened by [`Figure::show`] shows when hovering.
+    pub hover_mode: HoverMode,
+}
+
+impl<T: Drawer> Figure<T> {
+    /// Creates a new `Figure` for a chart, with a margin of 50 pixels and an 800x600 window.
+    ///
+    /// # Parameters
+    /// - `chart`: The chart to render.
+    ///
+    /// # Returns
+    /// A new `Figure` instance.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figurefacade::Figure;
+    /// use dataviz::figure::figuretypes::piechart::PieChart;
+    ///
+    /// let mut pie_chart = PieChart::new("Market Share", FigureConfig::default());
+    /// pie_chart.add_slice("Product A", 60.0, [255, 0, 0]);
+    /// pie_chart.add_slice("Product B", 40.0, [0, 0, 255]);
+    ///
+    /// let mut figure = Figure::new(pie_chart);
+    /// let path = std::env::temp_dir().join("figure_example.svg");
+    /// figure.save_svg(path.to_str().unwrap(), 400, 300).unwrap();
+    /// ```
+    pub fn new(chart: T) -> Self {
+        Self {
+            chart,
+            margin: 50,
+            width: 800,
+            height: 600,
+            hover_mode: HoverMode::default(),
+        }
+    }
+
+    /// Sets the margin around the plot area.
+    ///
+    /// # Parameters
+    /// - `margin`: The margin in pixels.
+    pub fn set_margin(&mut self, margin: u32) {
+        self.margin = margin;
+    }
+
+    /// Sets the size of the window opened by [`Figure::show`].
+    ///
+    /// # Parameters
+    /// - `width`: The width of the window in pixels.
+    /// - `height`: The height of the window in pixels.
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Sets what the window opened by [`Figure::show`] shows when hovering.
+    ///
+    /// # Parameters
+    /// - `hover_mode`: The nearest point hover, or crosshair guides for graphs that support them.
+    pub fn set_hover_mode(&mut self, hover_mode: HoverMode) {
+        self.hover_mode = hover_mode;
+    }
+
+    /// Draws the chart on a new `PixelCanvas` with the chart's background color.
+    ///
+    /// # Parameters
+    /// - `width`: The width of the canvas in pixels.
+    /// - `height`: The height of the canvas in pixels.
+    ///
+    /// # Returns
+    /// The `PixelCanvas` holding the drawn chart.
+    pub fn render(&mut self, width: u32, height: u32) -> PixelCanvas {
+        let background_color = self.chart.get_figure_config().color_background;
+        let mut canvas = PixelCanvas::new(width, height, background_color, self.margin);
+        self.chart.draw(&mut canvas);
+        canvas
+    }
+
+    /// Draws the chart and saves it as a PNG file.
+    ///
+    /// # Parameters
+    /// - `path`: The path of the PNG file.
+    /// - `width`: The width of the image in pixels.
+    /// - `height`: The height of the image in pixels.
+    ///
+    /// # Errors
+    /// Returns an `image::ImageError` if saving fails.
+    pub fn save_png(
+        &mut self,
+        path: &str,
+        width: u32,
+        height: u32,
+    ) -> Result<(), image::ImageError> {
+        self.render(width, height).save_as_image(path)
+    }
+
+    /// Draws the chart on an `SvgCanvas` and saves it as an SVG file.
+    ///
+    /// # Parameters
+    /// - `path`: The path of the SVG file.
+    /// - `width`: The width of the SVG in pixels.
+    /// - `height`: The height of the SVG in pixels.
+    ///
+    /// # Errors
+    /// Returns an `io::Result` if saving fails.
+    pub fn save_svg(&mut self, path: &str, width: u32, height: u32) -> io::Result<()> {
+        let [r, g, b] = self.chart.get_figure_config().color_background;
+        let background_color = format!("rgb({},{},{})", r, g, b);
+        let mut svg_canvas = SvgCanvas::new(width, height, &background_color, self.margin);
+        self.chart.draw_svg(&mut svg_canvas);
+        svg_canvas.save(path)
+    }
+}
+
+impl<T: Drawer + Hover> Figure<T> {
+    /// Draws the chart and shows it in an interactive window with hover functionality.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the window.
+    ///
+    /// # Panics
+    /// Panics if the window cannot be created.
+    pub fn show(&mut self, title: &str) {
+        let mut canvas = self.render(self.width, self.height);
+        Winop::display_interactive(&mut canvas, &mut self.chart, title, self.hover_mode);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::{
+        configuration::figureconfig::FigureConfig, figuretypes::piechart::PieChart,
+    };
+
+    #[test]
+    fn test_save_png_writes_image_of_requested_size() {
+        let mut pie_chart = PieChart::new("Market Share", FigureConfig::default());
+        pie_chart.add_slice("Product A", 60.0, [255, 0, 0]);
+        pie_chart.add_slice("Product B", 40.0, [0, 0, 255]);
+        let path = std::env::temp_dir().join("figurefacade_test_pie.png");
+
+        Figure::new(pie_chart)
+            .save_png(path.to_str().unwrap(), 320, 240)
+            .unwrap();
+
+        assert!(path.exists());
+        let img = image::open(&path).expect("failed to open saved figure");
+        assert_eq!((img.width(), img.height()), (320, 240));
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -3,7 +3,7 @@ use super::{
     drawers::drawer::Drawer,
     figuretypes::{
         areachart::AreaChart, cartesiangraph::CartesianGraph, groupbarchart::GroupBarChart,
-        histogram::Histogram, piechart::PieChart, scattergraph::ScatterGraph,
+        heatmap::Heatmap, histogram::Histogram, piechart::PieChart, scattergraph::ScatterGraph,
     },
 };
 
@@ -23,6 +23,8 @@ pub enum FigureType {
     AreaChart,
     /// A histogram, which shows the frequency distribution of data.
     Histogram,
+    /// A heatmap, which colors a grid of values along a gradient.
+    Heatmap,
 }
 
 /// Represents the output format for the generated plots.
@@ -50,9 +52,9 @@ impl FigureFactory {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::figurefactory::{FigureFactory, FigureType};
+    /// use dataviz::figure::figurefactory::{FigureFactory, FigureType};
     ///
-    /// let bar_chart = FigureFactory::create_figure(FigureType::BarChart);
+    /// let bar_chart = FigureFactory::create_figure(FigureType::GroupBarChartVertical);
     /// ```
     pub fn create_figure(plot_type: FigureType) -> Box<dyn Drawer> {
         match plot_type {
@@ -97,6 +99,12 @@ impl FigureFactory {
                 [0, 0, 255],
                 FigureConfig::default(),
             )),
+            FigureType::Heatmap => Box::new(Heatmap::new(
+                "Heatmap",
+                "X Axis",
+                "Y Axis",
+                FigureConfig::default(),
+            )),
         }
     }
 }
@@ -1,6 +1,7 @@
 use crate::figure::{
-    canvas::pixelcanvas::PixelCanvas, configuration::figureconfig::FigureConfig,
-    datasets::areachartdataset::AreaChartDataset,
+    canvas::pixelcanvas::PixelCanvas,
+    configuration::figureconfig::FigureConfig,
+    datasets::{areachartdataset::AreaChartDataset, dataset::combined_bounds},
 };
 
 /// Represents an area chart, including its title, axis labels, datasets, and configuration.
@@ -23,6 +24,12 @@ pub struct AreaChart {
     pub y_min: f64,
     /// Maximum y-value
     pub y_max: f64,
+    /// Fixed X-axis range `(min, max)` replacing the range fitted to the data, or `None`.
+    pub x_limits: Option<(f64, f64)>,
+    /// Fixed Y-axis range `(min, max)` replacing the range fitted to the data, or `None`.
+    pub y_limits: Option<(f64, f64)>,
+    /// Whether the datasets are stacked on top of each other instead of all starting at zero.
+    pub stacked: bool,
 }
 
 impl AreaChart {
@@ -39,8 +46,8 @@ impl AreaChart {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::configuration::figureconfig::FigureConfig;
-    /// use crate::figure::areachart::AreaChart;
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::areachart::AreaChart;
     ///
     /// let config = FigureConfig::default();
     /// let area_chart = AreaChart::new("Example Chart", "X Axis", "Y Axis", config);
@@ -56,9 +63,56 @@ impl AreaChart {
             x_max: f64::NEG_INFINITY, // Initialize to min range
             y_min: f64::INFINITY,     // Initialize to max range
             y_max: f64::NEG_INFINITY, // Initialize to min range
+            x_limits: None,
+            y_limits: None,
+            stacked: false,
         }
     }
 
+    /// Replaces the configuration of the area chart, which the next draw uses.
+    pub fn set_config(&mut self, config: FigureConfig) {
+        self.config = config;
+    }
+
+    /// Pins the X-axis to a fixed range instead of fitting it to the data.
+    ///
+    /// Areas extending past the range are clipped at the plot edge. Invalid ranges, where
+    /// `min` is not less than `max`, are ignored.
+    ///
+    /// # Parameters
+    /// - `min`: The value at the left edge of the plot area.
+    /// - `max`: The value at the right edge of the plot area.
+    pub fn set_x_limits(&mut self, min: f64, max: f64) {
+        if min.is_finite() && max.is_finite() && min < max {
+            self.x_limits = Some((min, max));
+        }
+    }
+
+    /// Pins the Y-axis to a fixed range instead of fitting it to the data.
+    ///
+    /// Areas extending past the range are clipped at the plot edge. Invalid ranges, where
+    /// `min` is not less than `max`, are ignored.
+    ///
+    /// # Parameters
+    /// - `min`: The value at the bottom edge of the plot area.
+    /// - `max`: The value at the top edge of the plot area.
+    pub fn set_y_limits(&mut self, min: f64, max: f64) {
+        if min.is_finite() && max.is_finite() && min < max {
+            self.y_limits = Some((min, max));
+        }
+    }
+
+    /// Sets whether the datasets are stacked on top of each other.
+    ///
+    /// Stacked areas start where the areas of the datasets added before them end, so they
+    /// don't obscure each other and the top of the stack shows the total.
+    ///
+    /// # Parameters
+    /// - `stacked`: `true` to stack the datasets, `false` to fill each from zero.
+    pub fn set_stacked(&mut self, stacked: bool) {
+        self.stacked = stacked;
+    }
+
     /// Adds a dataset to the area chart.
     ///
     /// # Parameters
@@ -66,7 +120,10 @@ impl AreaChart {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::datasets::areachartdataset::AreaChartDataset;
+    /// use dataviz::figure::datasets::areachartdataset::AreaChartDataset;
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::areachart::AreaChart;
+    /// # let mut area_chart = AreaChart::new("Example Chart", "X Axis", "Y Axis", FigureConfig::default());
     /// let dataset = AreaChartDataset::new([255, 0, 0], "Example Dataset", 0.5);
     /// area_chart.add_dataset(dataset);
     /// ```
@@ -90,10 +147,21 @@ impl AreaChart {
     ///
     /// # Details
     /// The method interpolates between adjacent points in the dataset to fill the area
-    /// under the line segment and blend it into the canvas using the dataset's color and transparency.
+    /// between the line segment and the zero baseline (`origin_y`) and blend it into the canvas
+    /// using the dataset's color and transparency. Positive values are filled downwards to the
+    /// baseline, negative values upwards to it.
     ///
     /// # Example
     /// ```rust
+    /// # use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::datasets::{areachartdataset::AreaChartDataset, dataset::Dataset};
+    /// # use dataviz::figure::figuretypes::areachart::AreaChart;
+    /// # let area_chart = AreaChart::new("Example Chart", "X Axis", "Y Axis", FigureConfig::default());
+    /// # let mut canvas = PixelCanvas::new(800, 600, [255, 255, 255], 50);
+    /// # let mut dataset = AreaChartDataset::new([255, 0, 0], "Example Dataset", 0.5);
+    /// # dataset.add_point((0.0, 1.0));
+    /// # dataset.add_point((1.0, 2.0));
     /// let scale_x = 10.0;
     /// let scale_y = 10.0;
     /// area_chart.draw_area(&mut canvas, &dataset, 50, 400, scale_x, scale_y);
@@ -106,29 +174,195 @@ impl AreaChart {
         origin_y: i32,
         scale_x: f64,
         scale_y: f64,
+    ) {
+        // Keep every pixel inside the canvas
+        let canvas_area = (0, 0, canvas.width as i32 - 1, canvas.height as i32 - 1);
+        self.draw_area_clipped(
+            canvas,
+            dataset,
+            (origin_x, origin_y),
+            (scale_x, scale_y),
+            canvas_area,
+        );
+    }
+
+    /// Draws the area under a dataset like [`AreaChart::draw_area`], keeping it within `clip`.
+    ///
+    /// `clip` is the `(left, top, right, bottom)` of the visible region, inclusive, and must lie
+    /// within the canvas.
+    pub(crate) fn draw_area_clipped(
+        &self,
+        canvas: &mut PixelCanvas,
+        dataset: &AreaChartDataset,
+        (origin_x, origin_y): (i32, i32),
+        (scale_x, scale_y): (f64, f64),
+        clip: (i32, i32, i32, i32),
     ) {
         let mut points = dataset.points.clone();
         points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        let layer: Vec<(f64, f64, f64)> = points.iter().map(|&(x, y)| (x, 0.0, y)).collect();
+
+        self.draw_layer_clipped(
+            canvas,
+            dataset,
+            &layer,
+            (origin_x, origin_y),
+            (scale_x, scale_y),
+            clip,
+        );
+    }
+
+    /// Fills the area between the base and the top of a layer, keeping it within `clip`.
+    ///
+    /// `layer` holds `(x, base, top)` sorted by `x`, as returned by
+    /// [`AreaChart::stacked_layers`]. The other parameters are those of
+    /// [`AreaChart::draw_area_clipped`].
+    pub(crate) fn draw_layer_clipped(
+        &self,
+        canvas: &mut PixelCanvas,
+        dataset: &AreaChartDataset,
+        layer: &[(f64, f64, f64)],
+        (origin_x, origin_y): (i32, i32),
+        (scale_x, scale_y): (f64, f64),
+        (min_x, min_y, max_x, max_y): (i32, i32, i32, i32),
+    ) {
+        // Keep the blend factor within [0, 1]
+        let alpha = dataset.alpha.clamp(0.0, 1.0);
 
-        for window in points.windows(2) {
+        // Project in f64 and bound the coordinates far outside the canvas, so extreme values
+        // neither overflow nor lose the slope of the visible part of a segment
+        const BOUND: f64 = 1e9;
+        let to_x = |x: f64| (origin_x as f64 + (x * scale_x).trunc()).clamp(-BOUND, BOUND);
+        let to_y = |y: f64| (origin_y as f64 - (y * scale_y).trunc()).clamp(-BOUND, BOUND);
+
+        for window in layer.windows(2) {
             if let [p1, p2] = window {
-                let x1 = origin_x + ((p1.0) * scale_x) as i32;
-                let y1 = origin_y - ((p1.1) * scale_y) as i32;
-                let x2 = origin_x + ((p2.0) * scale_x) as i32;
-                let y2 = origin_y - ((p2.1) * scale_y) as i32;
-
-                // Fill the area under the line
-                for x in x1.min(x2)..=x1.max(x2) {
-                    let interpolated_y =
-                        y1 + ((x - x1) as f64 * (y2 - y1) as f64 / (x2 - x1).abs() as f64) as i32;
-                    for y in interpolated_y..=origin_y {
-                        canvas.blend_pixel(x as u32, y as u32, dataset.color, dataset.alpha);
+                let (x1, y1, base1) = (to_x(p1.0), to_y(p1.2), to_y(p1.1));
+                let (x2, y2, base2) = (to_x(p2.0), to_y(p2.2), to_y(p2.1));
+                if [x1, y1, base1, x2, y2, base2].iter().any(|v| v.is_nan()) {
+                    continue;
+                }
+                let dx = x2 - x1;
+
+                // Fill the area between the line and the baseline
+                let first = x1.min(x2).max(min_x as f64);
+                let last = x1.max(x2).min(max_x as f64);
+                if first > last {
+                    continue;
+                }
+                for x in first as i32..=last as i32 {
+                    let (top, bottom) = if dx == 0.0 {
+                        // Vertical segment (duplicate x): fill a straight column covering both points
+                        (
+                            y1.min(y2).min(base1).min(base2),
+                            y1.max(y2).max(base1).max(base2),
+                        )
+                    } else {
+                        let interpolate = |from: f64, to: f64| {
+                            from + ((x as f64 - x1) * (to - from) / dx.abs()).trunc()
+                        };
+                        let interpolated_y = interpolate(y1, y2);
+                        let baseline = interpolate(base1, base2);
+                        // Values above the baseline fill down to it, values below fill up to it
+                        (interpolated_y.min(baseline), interpolated_y.max(baseline))
+                    };
+
+                    let top = top.clamp(min_y as f64, max_y as f64) as i32;
+                    let bottom = bottom.clamp(min_y as f64, max_y as f64) as i32;
+                    for y in top..=bottom {
+                        canvas.blend_pixel(x as u32, y as u32, dataset.color, alpha);
                     }
                 }
             }
         }
     }
 
+    /// Returns the stacked layers of the datasets, from the first dataset at the bottom up.
+    ///
+    /// Every dataset is linearly interpolated onto the sorted x-values of all datasets, and
+    /// counts as zero outside its own x-range. Each layer starts where the layer below it ends.
+    /// Hidden datasets count as zero everywhere, so the layers above them close the gap.
+    ///
+    /// # Returns
+    /// One layer per dataset, holding `(x, base, top)` for every shared x-value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::datasets::{areachartdataset::AreaChartDataset, dataset::Dataset};
+    /// # use dataviz::figure::figuretypes::areachart::AreaChart;
+    /// let mut area_chart = AreaChart::new("Example Chart", "X Axis", "Y Axis", FigureConfig::default());
+    /// let mut lower = AreaChartDataset::new([255, 0, 0], "Lower", 0.5);
+    /// lower.add_point((0.0, 1.0));
+    /// lower.add_point((2.0, 3.0));
+    /// let mut upper = AreaChartDataset::new([0, 0, 255], "Upper", 0.5);
+    /// upper.add_point((1.0, 5.0));
+    /// area_chart.add_dataset(lower);
+    /// area_chart.add_dataset(upper);
+    ///
+    /// let layers = area_chart.stacked_layers();
+    /// assert_eq!(layers[1], vec![(0.0, 1.0, 1.0), (1.0, 2.0, 7.0), (2.0, 3.0, 3.0)]);
+    /// ```
+    pub fn stacked_layers(&self) -> Vec<Vec<(f64, f64, f64)>> {
+        let mut xs: Vec<f64> = self
+            .datasets
+            .iter()
+            .flat_map(|dataset| dataset.points.iter().map(|&(x, _)| x))
+            .collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        xs.dedup();
+
+        let mut bases = vec![0.0; xs.len()];
+        self.datasets
+            .iter()
+            .map(|dataset| {
+                let mut points = dataset.points.clone();
+                points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+                xs.iter()
+                    .zip(bases.iter_mut())
+                    .map(|(&x, base)| {
+                        let start = *base;
+                        if dataset.visible {
+                            *base += interpolate(&points, x);
+                        }
+                        (x, start, *base)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the axis limits used to map data onto the canvas.
+    ///
+    /// The limits span all dataset points, with the minimums extended to include the origin.
+    /// Stacked charts span all layers of [`AreaChart::stacked_layers`] instead.
+    /// Axes pinned with [`AreaChart::set_x_limits`] or [`AreaChart::set_y_limits`] use their
+    /// fixed range instead. Both the drawer and the hover functionality use these limits, so hovered points line up
+    /// with the drawn areas.
+    ///
+    /// # Returns
+    /// A tuple `(x_min, x_max, y_min, y_max)`.
+    pub fn plot_limits(&self) -> (f64, f64, f64, f64) {
+        let unbounded = (f64::INFINITY, f64::NEG_INFINITY);
+        let ((x_min, x_max), (y_min, y_max)) =
+            combined_bounds(&self.datasets).unwrap_or((unbounded, unbounded));
+        // The layers share the x-values of the datasets, but stack their y-values
+        let (y_min, y_max) = if self.stacked {
+            self.stacked_layers()
+                .into_iter()
+                .flatten()
+                .fold(unbounded, |(min, max), (_, base, top)| {
+                    (min.min(base).min(top), max.max(base).max(top))
+                })
+        } else {
+            (y_min, y_max)
+        };
+
+        let (x_min, x_max) = self.x_limits.unwrap_or((x_min.min(0.0), x_max));
+        let (y_min, y_max) = self.y_limits.unwrap_or((y_min.min(0.0), y_max));
+        (x_min, x_max, y_min, y_max)
+    }
+
     pub fn update_range(&mut self) {
         for dataset in &self.datasets {
             for &(x, y) in &dataset.points {
@@ -176,3 +410,149 @@ impl AreaChart {
         }
     }
 }
+
+/// Returns the value of the sorted `points` at `x`, linearly interpolated between its
+/// neighbors, or zero if `x` lies outside their range.
+fn interpolate(points: &[(f64, f64)], x: f64) -> f64 {
+    if let Some(&(_, y)) = points.iter().find(|&&(point_x, _)| point_x == x) {
+        return y;
+    }
+    points
+        .windows(2)
+        .find(|window| window[0].0 < x && x < window[1].0)
+        .map(|window| {
+            let ((x1, y1), (x2, y2)) = (window[0], window[1]);
+            y1 + (x - x1) * (y2 - y1) / (x2 - x1)
+        })
+        .unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::canvas::testing::pixel_at;
+    use crate::figure::{datasets::dataset::Dataset, drawers::drawer::Drawer};
+
+    #[test]
+    fn test_draw_area_with_duplicate_x_does_not_panic() {
+        let chart = AreaChart::new("Area", "X", "Y", FigureConfig::default());
+        let mut canvas = PixelCanvas::new(100, 100, [255, 255, 255], 10);
+        let mut dataset = AreaChartDataset::new([255, 0, 0], "Vertical", 1.0);
+        dataset.add_point((2.0, 1.0));
+        dataset.add_point((2.0, 5.0));
+
+        chart.draw_area(&mut canvas, &dataset, 10, 90, 10.0, 10.0);
+
+        // The vertical segment is filled as a single column from the top point down to the origin
+        assert_eq!(pixel_at(&canvas, 30, 60), [255, 0, 0]);
+    }
+
+    #[test]
+    fn test_draw_area_clamps_out_of_canvas_values() {
+        let chart = AreaChart::new("Area", "X", "Y", FigureConfig::default());
+        let mut canvas = PixelCanvas::new(100, 100, [255, 255, 255], 10);
+        let mut dataset = AreaChartDataset::new([0, 0, 255], "Huge", 2.0);
+        dataset.add_point((0.0, 0.0));
+        dataset.add_point((1.0, 1000.0));
+
+        chart.draw_area(&mut canvas, &dataset, 10, 90, 50.0, 10.0);
+
+        let index = 60 * 3;
+        assert_eq!(&canvas.buffer[index..index + 3], &[0, 0, 255]);
+    }
+
+    #[test]
+    fn test_draw_area_with_extreme_values_does_not_overflow() {
+        let chart = AreaChart::new("Area", "X", "Y", FigureConfig::default());
+        let mut canvas = PixelCanvas::new(100, 100, [255, 255, 255], 10);
+        let mut dataset = AreaChartDataset::new([0, 0, 255], "Extreme", 1.0);
+        dataset.add_point((0.0, 1e308));
+        dataset.add_point((1.0, f64::MAX));
+        dataset.add_point((2.0, -1e308));
+
+        chart.draw_area(&mut canvas, &dataset, 10, 50, 20.0, 10.0);
+
+        // Above the axis the first segment fills the plot area, below it the falling segment
+        assert_eq!(pixel_at(&canvas, 20, 20), [0, 0, 255]);
+        assert_eq!(pixel_at(&canvas, 45, 80), [0, 0, 255]);
+    }
+
+    #[test]
+    fn test_fixed_y_limits_clip_area_at_plot_edge() {
+        let mut chart = AreaChart::new("", "X", "Y", FigureConfig::default());
+        let mut dataset = AreaChartDataset::new([0, 200, 0], "Peak", 1.0);
+        for point in [(0.0, 0.0), (1.0, 10.0), (2.0, 0.0)] {
+            dataset.add_point(point);
+        }
+        chart.add_dataset(dataset);
+        chart.set_y_limits(0.0, 5.0);
+        assert_eq!(chart.plot_limits(), (0.0, 2.0, 0.0, 5.0));
+
+        let mut canvas = PixelCanvas::new(100, 100, [255, 255, 255], 10);
+        chart.draw(&mut canvas);
+
+        // The peak is filled up to the top of the plot area, but not into the margin above it
+        assert_eq!(pixel_at(&canvas, 50, 11), [0, 200, 0]);
+        assert!((0..10).all(|y| (0..100).all(|x| pixel_at(&canvas, x, y) != [0, 200, 0])));
+    }
+
+    #[test]
+    fn test_draw_area_fills_toward_zero_baseline() {
+        let chart = AreaChart::new("Area", "X", "Y", FigureConfig::default());
+        let mut canvas = PixelCanvas::new(100, 100, [255, 255, 255], 10);
+        let mut dataset = AreaChartDataset::new([0, 200, 0], "Crossing", 1.0);
+        dataset.add_point((0.0, 5.0));
+        dataset.add_point((2.0, -5.0));
+
+        // Zero baseline at y = 50
+        chart.draw_area(&mut canvas, &dataset, 10, 50, 10.0, 10.0);
+
+        // Positive part: filled above the axis only
+        assert_eq!(pixel_at(&canvas, 12, 30), [0, 200, 0]);
+        assert_ne!(pixel_at(&canvas, 12, 70), [0, 200, 0]);
+
+        // Negative part: filled below the axis only
+        assert_eq!(pixel_at(&canvas, 28, 70), [0, 200, 0]);
+        assert_ne!(pixel_at(&canvas, 28, 30), [0, 200, 0]);
+    }
+
+    #[test]
+    fn test_stacked_areas_start_on_top_of_lower_datasets() {
+        let mut chart = AreaChart::new("", "X", "Y", FigureConfig::default());
+        let mut lower = AreaChartDataset::new([200, 0, 0], "Lower", 1.0);
+        lower.add_point((0.0, 4.0));
+        lower.add_point((10.0, 6.0));
+        // Sampled at different x-values than the lower dataset
+        let mut upper = AreaChartDataset::new([0, 0, 200], "Upper", 1.0);
+        for x in [0.0, 5.0, 10.0] {
+            upper.add_point((x, 5.0));
+        }
+        chart.add_dataset(lower);
+        chart.add_dataset(upper);
+        chart.set_stacked(true);
+
+        let layers = chart.stacked_layers();
+        assert_eq!(layers[0][1], (5.0, 0.0, 5.0));
+        assert_eq!(
+            layers[1],
+            vec![(0.0, 4.0, 9.0), (5.0, 5.0, 10.0), (10.0, 6.0, 11.0)]
+        );
+        assert_eq!(chart.plot_limits(), (0.0, 10.0, 0.0, 11.0));
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        chart.draw(&mut canvas);
+
+        // In a column through both areas, the upper area lies entirely above the lower one
+        let rows_with = |color: [u8; 3]| -> Vec<u32> {
+            (41..260)
+                .filter(|&y| pixel_at(&canvas, 210, y) == color)
+                .collect()
+        };
+        let lower_rows = rows_with([200, 0, 0]);
+        let upper_rows = rows_with([0, 0, 200]);
+        assert!(!lower_rows.is_empty() && !upper_rows.is_empty());
+        assert!(upper_rows.iter().max() < lower_rows.iter().min());
+        // The lower area still reaches down to the x-axis
+        assert!(lower_rows.contains(&258));
+    }
+}
@@ -1,5 +1,11 @@
 use crate::figure::{
-    configuration::figureconfig::FigureConfig, datasets::cartesiangraphdataset::CartesianDataset,
+    configuration::figureconfig::FigureConfig,
+    datasets::{cartesiangraphdataset::CartesianDataset, dataset::point_bounds},
+    utilities::{
+        axisscale::AxisScale,
+        interpolation::Interpolation,
+        ticks::{nice_tick_values, tick_values},
+    },
 };
 
 pub struct CartesianGraph {
@@ -12,6 +18,17 @@ pub struct CartesianGraph {
     pub y_min: f64, // Minimum y-value
     pub y_max: f64, // Maximum y-value
     pub config: FigureConfig,
+    /// Half-open index range `start..end` of the points plotted from each dataset, or `None` to plot all points.
+    pub visible_range: Option<(usize, usize)>,
+    /// Custom X-axis tick positions, in data units, replacing the evenly spaced default ticks.
+    pub x_ticks: Option<Vec<f64>>,
+    /// Custom Y-axis tick positions, in data units, replacing the evenly spaced default ticks.
+    pub y_ticks: Option<Vec<f64>>,
+    /// Scale of the Y-axis. Points that can't be shown on it, e.g. non-positive values on a
+    /// logarithmic axis, are skipped.
+    pub y_scale: AxisScale,
+    /// How consecutive points of each dataset are connected.
+    pub interpolation: Interpolation,
 }
 
 impl CartesianGraph {
@@ -26,9 +43,138 @@ impl CartesianGraph {
             y_min: f64::INFINITY,     // Initialize to max range
             y_max: f64::NEG_INFINITY, // Initialize to min range
             config: config.clone(),
+            visible_range: None,
+            x_ticks: None,
+            y_ticks: None,
+            y_scale: AxisScale::Linear,
+            interpolation: Interpolation::Linear,
         }
     }
 
+    /// Replaces the configuration of the graph, which the next draw uses.
+    pub fn set_config(&mut self, config: FigureConfig) {
+        self.config = config;
+    }
+
+    /// Sets how consecutive points of each dataset are connected.
+    ///
+    /// # Parameters
+    /// - `interpolation`: `Interpolation::Linear` for straight segments, or
+    ///   `Interpolation::CatmullRom` for a smooth curve through the points.
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Sets the positions of the ticks on both sides of the origin instead of the round
+    /// numbers chosen by [`nice_tick_values`].
+    ///
+    /// # Parameters
+    /// - `x_ticks`, `y_ticks`: Tick positions in data units, or `None` for round numbers.
+    pub fn set_ticks(&mut self, x_ticks: Option<Vec<f64>>, y_ticks: Option<Vec<f64>>) {
+        self.x_ticks = x_ticks;
+        self.y_ticks = y_ticks;
+    }
+
+    /// Sets the scale of the Y-axis.
+    ///
+    /// On a logarithmic axis the range is extended to whole powers of ten, which are also
+    /// used as the default ticks.
+    ///
+    /// # Parameters
+    /// - `y_scale`: The new scale of the Y-axis.
+    pub fn set_y_scale(&mut self, y_scale: AxisScale) {
+        self.y_scale = y_scale;
+    }
+
+    /// Returns the range of the Y-axis in axis units, see [`AxisScale::transform`].
+    ///
+    /// # Returns
+    /// The `(min, max)` of the Y-axis: `y_min` and `y_max` on a linear axis, the exponents of
+    /// the powers of ten around the positive visible values on a logarithmic axis.
+    pub fn y_axis_range(&self) -> (f64, f64) {
+        match self.y_scale {
+            AxisScale::Linear => (self.y_min, self.y_max),
+            AxisScale::Log10 => {
+                let transformed = self
+                    .datasets
+                    .iter()
+                    .flat_map(|dataset| self.visible_points(dataset))
+                    .filter_map(|&(x, y)| Some((x, self.y_scale.transform(y)?)));
+                let Some((_, (min, max))) = point_bounds(transformed) else {
+                    return (0.0, 1.0);
+                };
+                let (min, max) = (min.floor(), max.ceil());
+                (min, if max > min { max } else { min + 1.0 })
+            }
+        }
+    }
+
+    /// Returns the values at which the Y-axis ticks are drawn, in data units.
+    ///
+    /// On a logarithmic axis the default ticks are the powers of ten of its range, and custom
+    /// ticks that can't be shown on it are skipped.
+    ///
+    /// # Parameters
+    /// - `num_ticks`: The targeted number of intervals of the default ticks on a linear axis,
+    ///   which are placed at round numbers.
+    pub fn y_tick_values(&self, num_ticks: usize) -> Vec<f64> {
+        match self.y_scale {
+            AxisScale::Linear => {
+                nice_tick_values(self.y_ticks.as_deref(), self.y_min, self.y_max, num_ticks)
+            }
+            AxisScale::Log10 => {
+                let (min, max) = self.y_axis_range();
+                match self.y_ticks.as_deref() {
+                    Some(ticks) => {
+                        tick_values(Some(ticks), 10f64.powf(min), 10f64.powf(max), num_ticks)
+                    }
+                    None => (min as i32..=max as i32).map(|e| 10f64.powi(e)).collect(),
+                }
+            }
+        }
+    }
+
+    /// Restricts plotting to the points with indices `start_index..end_index` of each dataset.
+    ///
+    /// The datasets are left untouched, and the axis ranges are recomputed from the visible points only,
+    /// e.g. to page through a long time series. Indices past the end of a dataset are ignored.
+    ///
+    /// # Parameters
+    /// - `start_index`: Index of the first visible point.
+    /// - `end_index`: Index one past the last visible point.
+    pub fn set_visible_range(&mut self, start_index: usize, end_index: usize) {
+        self.visible_range = Some((start_index, end_index));
+        self.reset_range();
+    }
+
+    /// Plots all points of each dataset again after [`CartesianGraph::set_visible_range`].
+    pub fn clear_visible_range(&mut self) {
+        self.visible_range = None;
+        self.reset_range();
+    }
+
+    /// Returns the points of `dataset` within the visible range.
+    ///
+    /// # Parameters
+    /// - `dataset`: One of the datasets of this graph.
+    pub fn visible_points<'a>(&self, dataset: &'a CartesianDataset) -> &'a [(f64, f64)] {
+        match self.visible_range {
+            Some((start, end)) => {
+                let end = end.min(dataset.points.len());
+                &dataset.points[start.min(end)..end]
+            }
+            None => &dataset.points,
+        }
+    }
+
+    fn reset_range(&mut self) {
+        self.x_min = f64::INFINITY;
+        self.x_max = f64::NEG_INFINITY;
+        self.y_min = f64::INFINITY;
+        self.y_max = f64::NEG_INFINITY;
+        self.update_range();
+    }
+
     pub fn add_dataset(&mut self, dataset: CartesianDataset) {
         self.datasets.push(dataset);
         self.update_range();
@@ -36,7 +182,7 @@ impl CartesianGraph {
 
     pub fn update_range(&mut self) {
         for dataset in &self.datasets {
-            for &(x, y) in &dataset.points {
+            for &(x, y) in self.visible_points(dataset) {
                 if x < self.x_min {
                     self.x_min = x;
                 }
@@ -54,7 +200,7 @@ impl CartesianGraph {
         let mut is_empty = self.datasets.is_empty();
 
         for dataset in &self.datasets {
-            if dataset.points.is_empty() {
+            if self.visible_points(dataset).is_empty() {
                 is_empty = true;
                 break;
             }
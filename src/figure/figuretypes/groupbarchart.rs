@@ -1,9 +1,14 @@
+use ab_glyph::{FontRef, PxScale};
+
 use crate::figure::{
     canvas::pixelcanvas::PixelCanvas,
     configuration::figureconfig::FigureConfig,
     datasets::bardataset::BarDataset,
     drawers::drawer::Drawer,
-    utilities::{axistype::AxisType, orientation::Orientation},
+    utilities::{
+        axistype::AxisType, barlabeloverflow::BarLabelLayout, barmode::BarMode,
+        orientation::Orientation,
+    },
 };
 
 /// A grouped bar chart representation, supporting horizontal and vertical orientations.
@@ -18,10 +23,29 @@ pub struct GroupBarChart {
     pub y_label: String,
     /// Orientation of the bar chart (`Horizontal` or `Vertical`).
     pub orientation: Orientation,
+    /// Arrangement of the bars within a category (`Grouped` or `Stacked`).
+    pub bar_mode: BarMode,
+    /// Whether the value of each bar is drawn next to its outer end. Defaults to `true`.
+    pub show_values: bool,
     /// Configuration settings for rendering the chart (e.g., colors, fonts, grid).
     pub config: FigureConfig,
+    /// Names of the categories, indexed by the category key of the data points.
+    /// Keys without a name are labeled with the key itself.
+    pub categories: Vec<String>,
 }
 
+/// Bar colors assigned in order to the series of [`GroupBarChart::from_table`].
+const SERIES_COLORS: [[u8; 3]; 8] = [
+    [31, 119, 180],
+    [255, 127, 14],
+    [44, 160, 44],
+    [214, 39, 40],
+    [148, 103, 189],
+    [140, 86, 75],
+    [227, 119, 194],
+    [127, 127, 127],
+];
+
 impl GroupBarChart {
     /// Creates a new `GroupBarChart` instance with the specified title, labels, orientation, and configuration.
     ///
@@ -37,9 +61,9 @@ impl GroupBarChart {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::configuration::figureconfig::FigureConfig;
-    /// use crate::figure::groupbarchart::GroupBarChart;
-    /// use crate::figure::utilities::orientation::Orientation;
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::groupbarchart::GroupBarChart;
+    /// use dataviz::figure::utilities::orientation::Orientation;
     ///
     /// let config = FigureConfig::default();
     /// let bar_chart = GroupBarChart::new("Sales Chart", "Year", "Revenue", Orientation::Vertical, config);
@@ -57,8 +81,163 @@ impl GroupBarChart {
             x_label: x_label.to_string(),
             y_label: y_label.to_string(),
             orientation,
+            bar_mode: BarMode::default(),
+            show_values: true,
             config,
+            categories: Vec::new(),
+        }
+    }
+
+    /// Creates a vertical `GroupBarChart` from a table of values with named categories.
+    ///
+    /// Each series becomes one `BarDataset` holding a value for every category, so the bars of all
+    /// series line up per category. Series colors are taken in order from a built-in palette.
+    /// Title, axis labels, orientation and configuration can be changed on the returned chart.
+    ///
+    /// # Parameters
+    /// - `categories`: The category names shown along the category axis.
+    /// - `series`: The series as `(label, values)`, with the values in the order of `categories`.
+    ///   Values beyond the number of categories are ignored.
+    ///
+    /// # Returns
+    /// A new `GroupBarChart` instance with one dataset per series.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::figuretypes::groupbarchart::GroupBarChart;
+    ///
+    /// let mut bar_chart = GroupBarChart::from_table(
+    ///     vec!["Q1".to_string(), "Q2".to_string()],
+    ///     vec![
+    ///         ("Company A".to_string(), vec![1500.0, 2000.0]),
+    ///         ("Company B".to_string(), vec![1200.0, 1800.0]),
+    ///     ],
+    /// );
+    /// bar_chart.title = "Sales Chart".to_string();
+    /// ```
+    pub fn from_table(categories: Vec<String>, series: Vec<(String, Vec<f64>)>) -> Self {
+        let mut chart = Self::new("", "", "", Orientation::Vertical, FigureConfig::default());
+        for (index, (label, values)) in series.into_iter().enumerate() {
+            let mut dataset = BarDataset::new(&label, SERIES_COLORS[index % SERIES_COLORS.len()]);
+            for (key, value) in values.into_iter().take(categories.len()).enumerate() {
+                dataset.add_data(key as f64, value);
+            }
+            chart.add_dataset(dataset);
         }
+        chart.categories = categories;
+        chart
+    }
+
+    /// Returns the label shown for a category key: its name from `categories`, or the key itself.
+    ///
+    /// # Parameters
+    /// - `key`: The category key of a data point.
+    pub fn category_label(&self, key: u32) -> String {
+        self.categories
+            .get(key as usize)
+            .cloned()
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    /// Replaces the configuration of the bar chart, which the next draw uses.
+    pub fn set_config(&mut self, config: FigureConfig) {
+        self.config = config;
+    }
+
+    /// Sets whether the value of each bar is drawn next to its outer end.
+    ///
+    /// # Parameters
+    /// - `show_values`: `true` to label the bars with their values, `false` to hide the labels.
+    pub fn set_show_values(&mut self, show_values: bool) {
+        self.show_values = show_values;
+    }
+
+    /// Sets how the bars of the datasets are arranged within a category.
+    ///
+    /// # Parameters
+    /// - `bar_mode`: `BarMode::Grouped` to draw the bars side by side, `BarMode::Stacked` to
+    ///   draw them on top of each other.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::groupbarchart::GroupBarChart;
+    /// # use dataviz::figure::utilities::orientation::Orientation;
+    /// use dataviz::figure::utilities::barmode::BarMode;
+    /// # let mut bar_chart = GroupBarChart::new("Sales Chart", "Year", "Revenue", Orientation::Vertical, FigureConfig::default());
+    ///
+    /// bar_chart.set_bar_mode(BarMode::Stacked);
+    /// ```
+    pub fn set_bar_mode(&mut self, bar_mode: BarMode) {
+        self.bar_mode = bar_mode;
+    }
+
+    /// Returns the bar segments of a category, in the order of the datasets.
+    ///
+    /// In grouped mode every bar starts at zero. In stacked mode positive values start at the
+    /// running total of the positive values before them and negative values at the running
+    /// total of the negative values before them.
+    ///
+    /// # Parameters
+    /// - `key`: The category key of the data points.
+    ///
+    /// # Returns
+    /// The segments as `(dataset_index, start, end)` in data units. Hidden datasets and datasets
+    /// without a value for the category are skipped.
+    pub fn bar_segments(&self, key: u32) -> Vec<(usize, f64, f64)> {
+        let mut positive_total = 0.0;
+        let mut negative_total = 0.0;
+        self.datasets
+            .iter()
+            .enumerate()
+            .filter(|(_, dataset)| dataset.visible)
+            .filter_map(|(index, dataset)| {
+                dataset
+                    .data
+                    .iter()
+                    .find(|(x, _)| *x as u32 == key)
+                    .map(|&(_, value)| (index, value))
+            })
+            .map(|(index, value)| match self.bar_mode {
+                BarMode::Grouped => (index, 0.0, value),
+                BarMode::Stacked => {
+                    let total = if value < 0.0 {
+                        &mut negative_total
+                    } else {
+                        &mut positive_total
+                    };
+                    let start = *total;
+                    *total += value;
+                    (index, start, *total)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the range of the value axis, which always includes zero.
+    ///
+    /// In stacked mode the range covers the stacked totals of every category rather than the
+    /// single values.
+    ///
+    /// # Returns
+    /// The `(min, max)` of all bar ends.
+    pub fn value_range(&self) -> (f64, f64) {
+        self.category_keys()
+            .into_iter()
+            .flat_map(|key| self.bar_segments(key))
+            .fold((0.0_f64, 0.0_f64), |(min, max), (_, _, end)| {
+                (min.min(end), max.max(end))
+            })
+    }
+
+    /// Returns the sorted, unique category keys of all datasets.
+    pub(crate) fn category_keys(&self) -> Vec<u32> {
+        self.datasets
+            .iter()
+            .flat_map(|dataset| dataset.data.iter().map(|(key, _)| *key as u32))
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect()
     }
 
     /// Adds a dataset to the grouped bar chart.
@@ -68,7 +247,11 @@ impl GroupBarChart {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::datasets::bardataset::BarDataset;
+    /// use dataviz::figure::datasets::bardataset::BarDataset;
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::groupbarchart::GroupBarChart;
+    /// # use dataviz::figure::utilities::orientation::Orientation;
+    /// # let mut bar_chart = GroupBarChart::new("Sales Chart", "Year", "Revenue", Orientation::Vertical, FigureConfig::default());
     ///
     /// let dataset = BarDataset::new("Company A", [255, 0, 0]);
     /// bar_chart.add_dataset(dataset);
@@ -87,53 +270,59 @@ impl GroupBarChart {
     /// It uses the `FigureConfig` settings for appearance and adjusts the canvas accordingly.
     ///
     /// # Example
-    /// ```rust
+    /// ```rust,no_run
+    /// # use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::groupbarchart::GroupBarChart;
+    /// # use dataviz::figure::utilities::orientation::Orientation;
+    /// # let bar_chart = GroupBarChart::new("Sales Chart", "Year", "Revenue", Orientation::Horizontal, FigureConfig::default());
+    /// # let mut canvas = PixelCanvas::new(800, 600, [255, 255, 255], 50);
     /// bar_chart.draw_horizontal(&mut canvas);
     /// ```
     pub fn draw_horizontal(&self, canvas: &mut PixelCanvas) {
         canvas.clear();
+        canvas.antialias = self.config.antialias;
+        canvas.text_rendering = self.config.text_rendering;
 
-        let margin = canvas.margin;
+        let (left, top, right, bottom) = canvas.plot_bounds();
         let width = canvas.width;
-        let height = canvas.height;
         let cfg = &self.config;
 
         // Draw the title
-        self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
+        self.draw_title(canvas, cfg, width / 2, top / 2, &self.title);
 
         // Get unique y-axis values
-        let unique_y_values: Vec<u32> = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.data.iter().map(|(y, _)| *y as u32))
-            .collect::<std::collections::BTreeSet<_>>()
-            .into_iter()
-            .collect();
+        let unique_y_values = self.category_keys();
 
         let y_count = unique_y_values.len();
 
-        let (x_min, x_max) = self
-            .datasets
-            .iter()
-            .flat_map(|dataset| dataset.data.iter().map(|&(_, y)| y))
-            .fold((0.0_f64, 0.0_f64), |(min, max), y| (min.min(y), max.max(y)));
-
-        // Adjust limits to include (0, 0)
-        let x_min = x_min.min(0.0);
+        // The range includes 0, and the stacked totals in stacked mode
+        let (x_min, x_max) = self.value_range();
 
         // Calculate scales
-        let scale_y = (height - 2 * margin) as f64 / y_count as f64;
-        let scale_x = (width - 2 * margin) as f64 / x_max;
+        let scale_y = (bottom - top) as f64 / y_count as f64;
+        let x_range = if x_max > x_min { x_max - x_min } else { 1.0 };
+        let scale_x = (right - left) as f64 / x_range;
 
-        // Draw grids
-        self.draw_grid(canvas, cfg);
+        // Draw grids, unless they go on top of the data
+        if !cfg.grid_on_top {
+            self.draw_grid(canvas, cfg);
+        }
 
         // Draw axes
-        let origin_x = margin;
-        let origin_y = height - margin;
-
-        self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.y_label);
-        self.draw_label(canvas, cfg, margin, margin / 2, &self.x_label);
+        let origin_x = left;
+        let origin_y = bottom;
+        // Bars start at the zero baseline, which moves right when there are negative values
+        let zero_x = origin_x + (-x_min * scale_x) as u32;
+
+        self.draw_label(
+            canvas,
+            cfg,
+            right + (width - right) / 2,
+            origin_y,
+            &self.y_label,
+        );
+        self.draw_label(canvas, cfg, left, top / 2, &self.x_label);
 
         // X-axis ticks
         let x_tick_step = (x_max - x_min) / cfg.num_axis_ticks as f64;
@@ -141,14 +330,25 @@ impl GroupBarChart {
             let value_x = x_min + i as f64 * x_tick_step;
             let tick_x = origin_x + ((value_x - x_min) * scale_x) as u32;
 
-            let value_label = format!("{:.1}", value_x);
+            let value_label = cfg.format_tick(value_x);
 
             self.draw_axis_value(canvas, cfg, tick_x, origin_y, &value_label, AxisType::AxisX);
         }
 
         // Draw grouped horizontal bars
         let group_height = scale_y * 0.8; // Height of each group
-        let bar_height = group_height / self.datasets.len() as f64; // Height of each bar
+        let bar_height = match self.bar_mode {
+            BarMode::Grouped => group_height / self.datasets.len() as f64, // Height of each bar
+            BarMode::Stacked => group_height,
+        };
+        // Canvas x-coordinate of a value, relative to the zero baseline
+        let position_x = |value: f64| {
+            if value < 0.0 {
+                zero_x.saturating_sub((-value * scale_x) as u32)
+            } else {
+                zero_x + (value * scale_x) as u32
+            }
+        };
 
         for (group_index, y_label) in unique_y_values.iter().enumerate() {
             let group_center_y = origin_y - ((group_index as f64 + 0.5) * scale_y) as u32;
@@ -156,35 +356,50 @@ impl GroupBarChart {
             self.draw_axis_value(
                 canvas,
                 cfg,
-                origin_x - 10,
+                origin_x.saturating_sub(10),
                 group_center_y,
-                &y_label.to_string(),
+                &self.category_label(*y_label),
                 AxisType::AxisY,
             );
 
             // Draw bars for each company in the group
-            for (company_index, dataset) in self.datasets.iter().enumerate() {
-                if let Some(&(_, value)) = dataset
-                    .data
-                    .iter()
-                    .find(|(y, _)| (*y as u32).to_string() == y_label.to_string())
-                {
-                    let bar_length = (value * scale_x) as u32;
-                    let bar_top = group_center_y - (group_height / 2.0) as u32
-                        + (company_index as f64 * bar_height) as u32;
-                    let bar_bottom = bar_top + bar_height as u32;
-
-                    for x in origin_x..(origin_x + bar_length) {
-                        for y in bar_top..bar_bottom {
-                            canvas.draw_pixel(x, y, dataset.color);
-                        }
+            let segments = self.bar_segments(*y_label);
+            for (segment_index, &(company_index, start, end)) in segments.iter().enumerate() {
+                let slot = match self.bar_mode {
+                    BarMode::Grouped => company_index,
+                    BarMode::Stacked => 0,
+                };
+                let bar_top = group_center_y - (group_height / 2.0) as u32
+                    + (slot as f64 * bar_height) as u32;
+                let bar_bottom = bar_top + bar_height as u32;
+                let (bar_start, bar_end) = if end < start {
+                    (position_x(end), position_x(start))
+                } else {
+                    (position_x(start), position_x(end))
+                };
+
+                for x in bar_start..bar_end {
+                    for y in bar_top..bar_bottom {
+                        canvas.draw_pixel(x, y, self.datasets[company_index].color);
                     }
                 }
+
+                if self.labels_segment(&segments, segment_index) {
+                    self.draw_bar_value(canvas, end, (bar_start, bar_top, bar_end, bar_bottom));
+                }
             }
         }
 
-        canvas.draw_vertical_line(margin, cfg.color_axis);
-        canvas.draw_horizontal_line(height - margin, cfg.color_axis);
+        // Draw grids over the data
+        if cfg.grid_on_top {
+            self.draw_grid(canvas, cfg);
+        }
+
+        canvas.draw_vertical_line(left, cfg.effective_axis_color());
+        canvas.draw_horizontal_line(bottom, cfg.effective_axis_color());
+        if x_min < 0.0 {
+            canvas.draw_vertical_line(zero_x, cfg.effective_axis_color());
+        }
         // Draw legend
         self.draw_legend(canvas);
     }
@@ -199,53 +414,58 @@ impl GroupBarChart {
     /// It uses the `FigureConfig` settings for appearance and adjusts the canvas accordingly.
     ///
     /// # Example
-    /// ```rust
+    /// ```rust,no_run
+    /// # use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::groupbarchart::GroupBarChart;
+    /// # use dataviz::figure::utilities::orientation::Orientation;
+    /// # let bar_chart = GroupBarChart::new("Sales Chart", "Year", "Revenue", Orientation::Vertical, FigureConfig::default());
+    /// # let mut canvas = PixelCanvas::new(800, 600, [255, 255, 255], 50);
     /// bar_chart.draw_vertical(&mut canvas);
     /// ```
     pub fn draw_vertical(&self, canvas: &mut PixelCanvas) {
         canvas.clear();
-        let margin = canvas.margin;
+        canvas.antialias = self.config.antialias;
+        canvas.text_rendering = self.config.text_rendering;
+        let (left, top, right, bottom) = canvas.plot_bounds();
         let width = canvas.width;
-        let height = canvas.height;
         let cfg = &self.config;
 
         // Draw the title
-        self.draw_title(canvas, cfg, width / 2, margin / 2, &self.title);
+        self.draw_title(canvas, cfg, width / 2, top / 2, &self.title);
 
         // Get unique x-axis values
-        let unique_x_values: Vec<u32> = self
-            .datasets
-            .iter()
-            .flat_map(|d| d.data.iter().map(|(x, _)| *x as u32))
-            .collect::<std::collections::BTreeSet<_>>()
-            .into_iter()
-            .collect();
+        let unique_x_values = self.category_keys();
 
         let x_count = unique_x_values.len();
-        let y_max = self
-            .datasets
-            .iter()
-            .flat_map(|d| d.data.iter().map(|(_, y)| *y))
-            .fold(0.0_f64, |max, y| max.max(y));
+        let (_, y_max) = self.value_range();
 
         // Calculate scales
-        let scale_x = (width - 2 * margin) as f64 / x_count as f64;
-        let scale_y = (height - 2 * margin) as f64 / y_max;
-        let origin_x = margin;
-        let origin_y = height - margin;
-
-        // Draw grids
-        self.draw_grid(canvas, cfg);
+        let scale_x = (right - left) as f64 / x_count as f64;
+        let scale_y = (bottom - top) as f64 / y_max;
+        let origin_x = left;
+        let origin_y = bottom;
+
+        // Draw grids, unless they go on top of the data
+        if !cfg.grid_on_top {
+            self.draw_grid(canvas, cfg);
+        }
 
         // Draw axes
-        canvas.draw_vertical_line(margin, cfg.color_axis);
-        canvas.draw_vertical_line(width - margin, cfg.color_axis);
-        canvas.draw_horizontal_line(height - margin, cfg.color_axis);
-        canvas.draw_horizontal_line(margin, cfg.color_axis);
+        canvas.draw_vertical_line(left, cfg.effective_axis_color());
+        canvas.draw_vertical_line(right, cfg.effective_axis_color());
+        canvas.draw_horizontal_line(bottom, cfg.effective_axis_color());
+        canvas.draw_horizontal_line(top, cfg.effective_axis_color());
 
         // Draw axis labels
-        self.draw_label(canvas, cfg, width - margin / 2, origin_y, &self.x_label);
-        self.draw_label(canvas, cfg, margin, margin / 2, &self.y_label);
+        self.draw_label(
+            canvas,
+            cfg,
+            right + (width - right) / 2,
+            origin_y,
+            &self.x_label,
+        );
+        self.draw_label(canvas, cfg, left, top / 2, &self.y_label);
 
         // Y-axis ticks
         let y_tick_step = y_max / cfg.num_axis_ticks as f64;
@@ -253,12 +473,12 @@ impl GroupBarChart {
             let value_y = i as f64 * y_tick_step;
             let tick_y = origin_y - (value_y * scale_y) as u32;
 
-            let value_label = format!("{:.2}", value_y);
+            let value_label = cfg.format_tick(value_y);
 
             self.draw_axis_value(
                 canvas,
                 cfg,
-                origin_x - 5,
+                origin_x.saturating_sub(5),
                 tick_y,
                 &value_label,
                 AxisType::AxisY,
@@ -267,7 +487,10 @@ impl GroupBarChart {
 
         // Draw x-axis labels and grouped bars
         let group_width = scale_x * 0.8; // Width of each group of bars
-        let bar_width = group_width / self.datasets.len() as f64; // Width of each bar
+        let bar_width = match self.bar_mode {
+            BarMode::Grouped => group_width / self.datasets.len() as f64, // Width of each bar
+            BarMode::Stacked => group_width,
+        };
 
         for (group_index, x_label) in unique_x_values.iter().enumerate() {
             let group_center_x = origin_x + (((group_index as f64 + 0.5) * scale_x) as u32);
@@ -277,32 +500,371 @@ impl GroupBarChart {
                 cfg,
                 group_center_x,
                 origin_y,
-                &x_label.to_string(),
+                &self.category_label(*x_label),
                 AxisType::AxisX,
             );
 
             // Draw bars for each company in the group
-            for (company_index, dataset) in self.datasets.iter().enumerate() {
-                if let Some(&(_, income)) = dataset
-                    .data
-                    .iter()
-                    .find(|(x, _)| (*x as u32).to_string() == x_label.to_string())
-                {
-                    let bar_height = (income * scale_y) as u32;
-                    let bar_left = group_center_x - (group_width / 2.0) as u32
-                        + (company_index as f64 * bar_width) as u32;
-                    let bar_right = bar_left + bar_width as u32;
-
-                    for x in bar_left..=bar_right {
-                        for y in (origin_y - bar_height)..origin_y {
-                            canvas.draw_pixel(x, y, dataset.color);
-                        }
-                    }
+            let segments = self.bar_segments(*x_label);
+            for (segment_index, &(company_index, start, end)) in segments.iter().enumerate() {
+                let slot = match self.bar_mode {
+                    BarMode::Grouped => company_index,
+                    BarMode::Stacked => 0,
+                };
+                let bar_left =
+                    group_center_x - (group_width / 2.0) as u32 + (slot as f64 * bar_width) as u32;
+                let bar_right = bar_left + bar_width as u32;
+
+                let bar_bottom = origin_y - (start * scale_y) as u32;
+                let bar_top = origin_y - (end * scale_y) as u32;
+                canvas.draw_rect_filled(
+                    bar_left,
+                    bar_top,
+                    bar_right - bar_left + 1,
+                    bar_bottom.saturating_sub(bar_top),
+                    self.datasets[company_index].color,
+                );
+
+                if self.labels_segment(&segments, segment_index) {
+                    self.draw_bar_value(canvas, end, (bar_left, bar_top, bar_right, bar_bottom));
                 }
             }
         }
 
+        // Draw grids over the data
+        if cfg.grid_on_top {
+            self.draw_grid(canvas, cfg);
+        }
+
         // Draw legend
         self.draw_legend(canvas);
     }
+
+    /// Returns whether the value of a bar segment is labeled.
+    ///
+    /// Without `show_values` no bar is labeled. Otherwise grouped bars are all labeled, and
+    /// stacks only at their outer end, with their total, so the labels don't cover the
+    /// segments stacked on top.
+    ///
+    /// # Parameters
+    /// - `segments`: The segments of a category, as returned by [`Self::bar_segments`].
+    /// - `index`: The index of the segment in `segments`.
+    pub(crate) fn labels_segment(&self, segments: &[(usize, f64, f64)], index: usize) -> bool {
+        let is_negative = |&(_, start, end): &(usize, f64, f64)| end < start;
+        if !self.show_values {
+            return false;
+        }
+        match self.bar_mode {
+            BarMode::Grouped => true,
+            BarMode::Stacked => !segments[index + 1..]
+                .iter()
+                .any(|segment| is_negative(segment) == is_negative(&segments[index])),
+        }
+    }
+
+    /// Draws the value of a bar, formatted with `config.number_format`, next to its outer end.
+    ///
+    /// Labels wider than the bar (taller, for horizontal bars) are handled according to
+    /// `config.bar_label_overflow`.
+    ///
+    /// # Parameters
+    /// - `canvas`: The `PixelCanvas` to draw the value on.
+    /// - `value`: The value of the bar.
+    /// - `bar`: The canvas coordinates `(left, top, right, bottom)` of the bar.
+    fn draw_bar_value(&self, canvas: &mut PixelCanvas, value: f64, bar: (u32, u32, u32, u32)) {
+        let (left, top, right, bottom) = bar;
+        let font_bytes = self.config.label_font_bytes();
+        let font = FontRef::try_from_slice(&font_bytes).unwrap();
+        let scale = PxScale {
+            x: self.config.font_size_axis,
+            y: self.config.font_size_axis,
+        };
+        let color = self.config.effective_axis_color();
+        let text = self.config.number_format.format(value);
+        let (w, h) = canvas.text_size(scale, &font, &text);
+        let (rotated_w, rotated_h) = vertical_text_size(canvas, scale, &font, &text);
+
+        match self.orientation {
+            Orientation::Horizontal => {
+                let center_y = (top + bottom) / 2;
+                let layout = self
+                    .config
+                    .bar_label_overflow
+                    .layout(h, rotated_h, bottom - top);
+                let (label_w, label_h) = match layout {
+                    BarLabelLayout::Horizontal => (w, h),
+                    BarLabelLayout::Vertical => (rotated_w, rotated_h),
+                    BarLabelLayout::Hidden => return,
+                };
+                // Negative bars grow to the left, so their value goes left of the bar
+                let x = if value < 0.0 {
+                    left.saturating_sub(label_w + 3)
+                } else {
+                    right + 3
+                };
+                let y = center_y.saturating_sub(label_h / 2);
+                match layout {
+                    BarLabelLayout::Vertical => {
+                        canvas.draw_text_vertical(x, y, &text, color, &font, scale)
+                    }
+                    _ => canvas.draw_text(x, y, &text, color, &font, scale),
+                }
+            }
+            Orientation::Vertical => {
+                let center_x = (left + right) / 2;
+                match self
+                    .config
+                    .bar_label_overflow
+                    .layout(w, rotated_w, right - left)
+                {
+                    BarLabelLayout::Horizontal => canvas.draw_text(
+                        center_x.saturating_sub(w / 2),
+                        top.saturating_sub(h + 3),
+                        &text,
+                        color,
+                        &font,
+                        scale,
+                    ),
+                    BarLabelLayout::Vertical => canvas.draw_text_vertical(
+                        center_x.saturating_sub(rotated_w / 2),
+                        top.saturating_sub(rotated_h + 3),
+                        &text,
+                        color,
+                        &font,
+                        scale,
+                    ),
+                    BarLabelLayout::Hidden => {}
+                }
+            }
+        }
+    }
+}
+
+/// Returns the size of `text` drawn with [`PixelCanvas::draw_text_vertical`]: the width of the
+/// widest character and the height of the stacked characters.
+fn vertical_text_size(
+    canvas: &PixelCanvas,
+    scale: PxScale,
+    font: &FontRef,
+    text: &str,
+) -> (u32, u32) {
+    text.chars()
+        .map(|ch| canvas.text_size(scale, font, &ch.to_string()))
+        .fold((0, 0), |(width, height), (char_width, char_height)| {
+            (width.max(char_width), height + char_height + 5)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::canvas::svgcanvas::SvgCanvas;
+    use crate::figure::canvas::testing::pixel_at;
+    use crate::figure::utilities::barlabeloverflow::BarLabelOverflow;
+    use crate::figure::utilities::barmode::BarMode;
+    use crate::figure::utilities::{
+        numberformat::NumberFormat, textrendering::TextRendering, tickformat::TickFormat,
+    };
+
+    #[test]
+    fn test_from_table_aligns_series_by_category() {
+        let chart = GroupBarChart::from_table(
+            vec!["North".to_string(), "South".to_string(), "West".to_string()],
+            vec![
+                ("2023".to_string(), vec![1.0, 2.0, 3.0]),
+                ("2024".to_string(), vec![4.0, 5.0, 6.0]),
+            ],
+        );
+
+        assert_eq!(chart.datasets.len(), 2);
+        assert_eq!(chart.datasets[0].label, "2023");
+        assert_eq!(chart.datasets[1].label, "2024");
+        assert_ne!(chart.datasets[0].color, chart.datasets[1].color);
+        assert_eq!(
+            chart.datasets[0].data,
+            vec![(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]
+        );
+        assert_eq!(
+            chart.datasets[1].data,
+            vec![(0.0, 4.0), (1.0, 5.0), (2.0, 6.0)]
+        );
+        assert_eq!(chart.category_label(1), "South");
+        assert_eq!(chart.category_label(7), "7");
+    }
+
+    #[test]
+    fn test_horizontal_chart_with_small_margin_draws_value_labels() {
+        let mut chart = GroupBarChart::new(
+            "Sales",
+            "Revenue",
+            "Year",
+            Orientation::Horizontal,
+            FigureConfig::default(),
+        );
+        let mut dataset = BarDataset::new("Company A", [255, 0, 0]);
+        dataset.add_data(2020.0, 50.0);
+        dataset.add_data(2021.0, -20.0);
+        dataset.add_data(2022.0, 30.0);
+        chart.add_dataset(dataset);
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 5);
+
+        chart.draw_horizontal(&mut canvas);
+
+        // Zero sits at 2/7 of the plot width, the 50.0 bar of the 2020 group ends at its right edge
+        let scale_x = 390.0 / 70.0;
+        let zero_x = 5 + (20.0 * scale_x) as u32;
+        let bar_end = zero_x + (50.0 * scale_x) as u32;
+        assert_eq!(pixel_at(&canvas, zero_x + 1, 247), [255, 0, 0]);
+        assert_eq!(pixel_at(&canvas, bar_end - 1, 247), [255, 0, 0]);
+        // The negative bar of the 2021 group grows left of zero
+        assert_eq!(pixel_at(&canvas, zero_x - 1, 152), [255, 0, 0]);
+        assert_eq!(pixel_at(&canvas, zero_x + 1, 152), [255, 255, 255]);
+
+        // The value label of the 2022 bar is drawn right of its end, in dark anti-aliased text
+        let bar_end = zero_x + (30.0 * scale_x) as u32;
+        let label_drawn =
+            (40..70).any(|y| (bar_end + 1..bar_end + 40).any(|x| pixel_at(&canvas, x, y)[0] < 128));
+        assert!(label_drawn);
+    }
+
+    #[test]
+    fn test_bar_values_use_number_format_not_tick_format() {
+        let render = |number_format: NumberFormat, show_values: bool| {
+            let config = FigureConfig {
+                number_format,
+                tick_format: TickFormat::Integer,
+                text_rendering: TextRendering::BoxPlaceholder,
+                ..Default::default()
+            };
+            let mut chart =
+                GroupBarChart::new("Sales", "Year", "Revenue", Orientation::Vertical, config);
+            let mut dataset = BarDataset::new("Company A", [255, 0, 0]);
+            dataset.add_data(1.0, 2.5);
+            chart.add_dataset(dataset);
+            chart.set_show_values(show_values);
+            let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+            chart.draw(&mut canvas);
+            canvas.buffer
+        };
+
+        // Only the bar value labels depend on the number format, the ticks use the tick format
+        assert_eq!(
+            render(NumberFormat::Fixed(0), false),
+            render(NumberFormat::Fixed(4), false)
+        );
+        assert_ne!(
+            render(NumberFormat::Fixed(0), true),
+            render(NumberFormat::Fixed(4), true)
+        );
+    }
+
+    #[test]
+    fn test_overflowing_bar_labels_are_rotated_or_hidden() {
+        let gap_has_text = |overflow: BarLabelOverflow| {
+            // 8 categories on a 320 px wide plot: 32 px wide bars, too narrow for "12345.25"
+            let mut values = vec![12345.25; 8];
+            values[0] = 50000.0;
+            let mut chart = GroupBarChart::from_table(
+                (0..8).map(|i| i.to_string()).collect(),
+                vec![("Sales".to_string(), values)],
+            );
+            chart.config.bar_label_overflow = overflow;
+            let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+
+            chart.draw_vertical(&mut canvas);
+
+            let dark = |x: u32, y: u32| pixel_at(&canvas, x, y).iter().all(|&c| c < 128);
+            // The bar of category i spans x = 44 + 40 * i ..= 76 + 40 * i
+            let in_gaps =
+                (1..7).any(|i| (78 + 40 * i..=82 + 40 * i).any(|x| (41..260).any(|y| dark(x, y))));
+            let above_bars =
+                (1..7).any(|i| (44 + 40 * i..=76 + 40 * i).any(|x| (41..200).any(|y| dark(x, y))));
+            (in_gaps, above_bars)
+        };
+
+        assert_eq!(gap_has_text(BarLabelOverflow::Overlap), (true, true));
+        assert_eq!(gap_has_text(BarLabelOverflow::Rotate), (false, true));
+        assert_eq!(gap_has_text(BarLabelOverflow::Hide), (false, false));
+    }
+
+    #[test]
+    fn test_stacked_bars_end_at_the_category_total() {
+        let mut chart = GroupBarChart::from_table(
+            vec!["Q1".to_string(), "Q2".to_string()],
+            vec![
+                ("A".to_string(), vec![10.0, 5.0]),
+                ("B".to_string(), vec![20.0, 5.0]),
+                ("C".to_string(), vec![30.0, 5.0]),
+            ],
+        );
+        chart.set_bar_mode(BarMode::Stacked);
+
+        assert_eq!(
+            chart.bar_segments(0),
+            vec![(0, 0.0, 10.0), (1, 10.0, 30.0), (2, 30.0, 60.0)]
+        );
+        // The axis reaches the largest stack, not the largest single value
+        assert_eq!(chart.value_range(), (0.0, 60.0));
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        chart.draw_vertical(&mut canvas);
+
+        // The Q1 stack is centered at x = 120 and fills the plot height of 220 px
+        let scale_y = 220.0 / 60.0;
+        assert_eq!(pixel_at(&canvas, 120, 258), chart.datasets[0].color);
+        assert_eq!(
+            pixel_at(&canvas, 120, 260 - (20.0 * scale_y) as u32),
+            chart.datasets[1].color
+        );
+        assert_eq!(pixel_at(&canvas, 120, 42), chart.datasets[2].color);
+        // The Q2 stack of 15 ends a quarter of the way up
+        assert_eq!(
+            pixel_at(&canvas, 280, 260 - (14.0 * scale_y) as u32),
+            chart.datasets[2].color
+        );
+        assert_eq!(
+            pixel_at(&canvas, 280, 260 - (16.0 * scale_y) as u32),
+            [255, 255, 255]
+        );
+    }
+
+    #[test]
+    fn test_svg_shows_bar_values_within_canvas() {
+        let mut chart = GroupBarChart::from_table(
+            vec!["Q1".to_string(), "Q2".to_string()],
+            vec![("Sales".to_string(), vec![12.5, 40.0])],
+        );
+        // Bar values keep the number format when ticks are formatted differently
+        chart.config.tick_format = TickFormat::Integer;
+        let value_labels = |chart: &mut GroupBarChart| {
+            // A small margin leaves no room above the tallest bar
+            let mut svg_canvas = SvgCanvas::new(400, 300, "white", 5);
+            chart.draw_svg(&mut svg_canvas);
+            let labels: Vec<String> = [12.5, 40.0]
+                .iter()
+                .map(|&value| format!(">{}</text>", chart.config.number_format.format(value)))
+                .collect();
+            svg_canvas
+                .elements
+                .into_iter()
+                // Axis values are anchored at their end, bar values at their middle
+                .filter(|element| element.contains(r#"text-anchor="middle""#))
+                .filter(|element| labels.iter().any(|label| element.ends_with(label.as_str())))
+                .collect::<Vec<_>>()
+        };
+
+        let labels = value_labels(&mut chart);
+        assert_eq!(labels.len(), 2);
+        // The label of the tallest bar is moved down until it fits on the canvas
+        let y = |element: &str| {
+            let start = element.find(r#"y=""#).unwrap() + 3;
+            element[start..start + element[start..].find('"').unwrap()]
+                .parse::<f64>()
+                .unwrap()
+        };
+        assert!(y(&labels[1]) >= 12.0);
+
+        chart.set_show_values(false);
+        assert!(value_labels(&mut chart).is_empty());
+    }
 }
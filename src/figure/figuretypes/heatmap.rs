@@ -0,0 +1,199 @@
+use crate::figure::configuration::figureconfig::FigureConfig;
+
+/// Represents a heatmap: a grid of values drawn as cells colored along a gradient.
+pub struct Heatmap {
+    /// Title of the heatmap.
+    pub title: String,
+    /// Label for the X-axis (the columns of the grid).
+    pub x_label: String,
+    /// Label for the Y-axis (the rows of the grid).
+    pub y_label: String,
+    /// The values of the cells, one `Vec` per row. The first row is drawn at the top.
+    /// `NaN` marks a missing value.
+    pub grid: Vec<Vec<f64>>,
+    /// The value mapped to `low_color`. Smaller values are drawn in `low_color` too.
+    pub min: f64,
+    /// The value mapped to `high_color`. Larger values are drawn in `high_color` too.
+    pub max: f64,
+    /// The RGB color of cells at `min`.
+    pub low_color: [u8; 3],
+    /// The RGB color of cells at `max`.
+    pub high_color: [u8; 3],
+    /// The RGB color of cells with a missing (`NaN`) value.
+    pub missing_color: [u8; 3],
+    /// Configuration settings for rendering the heatmap (e.g., fonts, colors).
+    pub config: FigureConfig,
+}
+
+impl Heatmap {
+    /// Creates a new `Heatmap` instance without data.
+    ///
+    /// # Parameters
+    /// - `title`: The title of the heatmap.
+    /// - `x_label`: The label for the X-axis.
+    /// - `y_label`: The label for the Y-axis.
+    /// - `config`: The `FigureConfig` containing appearance and behavior settings.
+    ///
+    /// # Returns
+    /// A new `Heatmap` with an empty grid and a blue to red gradient over `0.0..=1.0`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::heatmap::Heatmap;
+    ///
+    /// let heatmap = Heatmap::new("Temperatures", "Hour", "Day", FigureConfig::default());
+    /// ```
+    pub fn new(title: &str, x_label: &str, y_label: &str, config: FigureConfig) -> Self {
+        Self {
+            title: title.to_string(),
+            x_label: x_label.to_string(),
+            y_label: y_label.to_string(),
+            grid: Vec::new(),
+            min: 0.0,
+            max: 1.0,
+            low_color: [0, 0, 255],
+            high_color: [255, 0, 0],
+            missing_color: [200, 200, 200],
+            config,
+        }
+    }
+
+    /// Replaces the configuration of the heatmap, which the next draw uses.
+    pub fn set_config(&mut self, config: FigureConfig) {
+        self.config = config;
+    }
+
+    /// Replaces the values of the heatmap and fits `min` and `max` to them.
+    ///
+    /// Missing (`NaN`) and infinite values are ignored for the range. Use
+    /// [`Heatmap::set_range`] afterwards to use a fixed range instead.
+    ///
+    /// # Parameters
+    /// - `grid`: The values of the cells, one `Vec` per row, top row first.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::heatmap::Heatmap;
+    /// let mut heatmap = Heatmap::new("Grid", "X", "Y", FigureConfig::default());
+    /// heatmap.set_grid(vec![vec![1.0, 2.0], vec![f64::NAN, 5.0]]);
+    /// assert_eq!((heatmap.min, heatmap.max), (1.0, 5.0));
+    /// ```
+    pub fn set_grid(&mut self, grid: Vec<Vec<f64>>) {
+        let (min, max) = grid
+            .iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        if min <= max {
+            self.min = min;
+            self.max = max;
+        }
+        self.grid = grid;
+    }
+
+    /// Sets the range of values covered by the color gradient.
+    ///
+    /// Invalid ranges, where `min` is greater than `max` or either is not finite, are ignored.
+    ///
+    /// # Parameters
+    /// - `min`: The value drawn in `low_color`.
+    /// - `max`: The value drawn in `high_color`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::heatmap::Heatmap;
+    /// let mut heatmap = Heatmap::new("Grid", "X", "Y", FigureConfig::default());
+    /// heatmap.set_range(0.0, 10.0);
+    /// heatmap.set_range(10.0, 0.0);
+    /// heatmap.set_range(f64::NAN, 5.0);
+    /// assert_eq!((heatmap.min, heatmap.max), (0.0, 10.0));
+    /// ```
+    pub fn set_range(&mut self, min: f64, max: f64) {
+        if min.is_finite() && max.is_finite() && min <= max {
+            self.min = min;
+            self.max = max;
+        }
+    }
+
+    /// Sets the colors of the gradient.
+    ///
+    /// # Parameters
+    /// - `low_color`: The RGB color of cells at `min`.
+    /// - `high_color`: The RGB color of cells at `max`.
+    pub fn set_gradient(&mut self, low_color: [u8; 3], high_color: [u8; 3]) {
+        self.low_color = low_color;
+        self.high_color = high_color;
+    }
+
+    /// Sets the color of cells with a missing (`NaN`) value.
+    ///
+    /// # Parameters
+    /// - `missing_color`: The RGB color of missing cells.
+    pub fn set_missing_color(&mut self, missing_color: [u8; 3]) {
+        self.missing_color = missing_color;
+    }
+
+    /// Returns the number of rows and columns of the grid.
+    ///
+    /// Rows may have different lengths; the number of columns is that of the longest row.
+    pub fn dimensions(&self) -> (usize, usize) {
+        let columns = self.grid.iter().map(Vec::len).max().unwrap_or(0);
+        (self.grid.len(), columns)
+    }
+
+    /// Returns the color of a cell with the given value.
+    ///
+    /// The color is interpolated linearly between `low_color` and `high_color`, values outside
+    /// `min..=max` get the color of the nearest end.
+    ///
+    /// # Parameters
+    /// - `value`: The value of the cell.
+    ///
+    /// # Returns
+    /// The RGB color of the cell, or `missing_color` for `NaN`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::heatmap::Heatmap;
+    /// let heatmap = Heatmap::new("Grid", "X", "Y", FigureConfig::default());
+    /// assert_eq!(heatmap.color_for(0.0), [0, 0, 255]);
+    /// assert_eq!(heatmap.color_for(0.5), [128, 0, 128]);
+    /// assert_eq!(heatmap.color_for(f64::NAN), heatmap.missing_color);
+    /// ```
+    pub fn color_for(&self, value: f64) -> [u8; 3] {
+        if value.is_nan() {
+            return self.missing_color;
+        }
+
+        let range = self.max - self.min;
+        let t = if range > 0.0 {
+            ((value - self.min) / range).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let mut color = [0; 3];
+        for (channel, (&low, &high)) in color
+            .iter_mut()
+            .zip(self.low_color.iter().zip(&self.high_color))
+        {
+            *channel = (low as f64 + (high as f64 - low as f64) * t).round() as u8;
+        }
+        color
+    }
+}
+
+/// Returns the `(start, end)` of the cell at `index` when `length` is divided into `count`
+/// equal cells beginning at `start`.
+pub(crate) fn cell_span(index: usize, count: usize, start: f64, length: f64) -> (f64, f64) {
+    let size = length / count as f64;
+    (
+        start + index as f64 * size,
+        start + (index + 1) as f64 * size,
+    )
+}
@@ -1,4 +1,6 @@
-use crate::figure::configuration::figureconfig::FigureConfig;
+use crate::figure::{
+    configuration::figureconfig::FigureConfig, utilities::histogrammode::HistogramMode,
+};
 
 /// Represents a histogram, including title, axis labels, bin configuration, and cached data.
 pub struct Histogram {
@@ -22,8 +24,29 @@ pub struct Histogram {
     pub bin_counts: Vec<f64>,
     /// Cached width of each bin.
     pub bin_width: f64,
+    /// Explicit bin boundaries replacing the uniform bins between the minimum and maximum,
+    /// or `None`. Set with [`Histogram::set_bin_edges`].
+    pub bin_edges: Option<Vec<f64>>,
+    /// Quantity shown by the bars: counts, densities or cumulative counts.
+    pub mode: HistogramMode,
     /// Configuration settings for rendering the histogram.
     pub config: FigureConfig,
+    /// Legend label of the histogram's own data, used when overlays are present.
+    pub label: String,
+    /// Additional data series blended on top of the histogram using a shared bin layout.
+    pub overlays: Vec<HistogramOverlay>,
+    /// Opacity (0.0 to 1.0) of the bars when overlays are present.
+    pub overlay_alpha: f64,
+}
+
+/// A data series drawn on top of a `Histogram` to compare distributions.
+pub struct HistogramOverlay {
+    /// Legend label of the series.
+    pub label: String,
+    /// Raw data values of the series.
+    pub data: Vec<f64>,
+    /// Color of the series bars in RGB format.
+    pub color: [u8; 3],
 }
 
 impl Histogram {
@@ -42,8 +65,8 @@ impl Histogram {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::configuration::figureconfig::FigureConfig;
-    /// use crate::figure::histogram::Histogram;
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::histogram::Histogram;
     ///
     /// let config = FigureConfig::default();
     /// let histogram = Histogram::new("Data Distribution", "Values", "Frequency", 10, [255, 0, 0], config);
@@ -67,7 +90,82 @@ impl Histogram {
             max: f64::NEG_INFINITY,
             bin_counts: vec![0.0; bins],
             bin_width: 0.0,
+            bin_edges: None,
+            mode: HistogramMode::default(),
             config,
+            label: String::new(),
+            overlays: Vec::new(),
+            overlay_alpha: 0.5,
+        }
+    }
+
+    /// Replaces the configuration of the histogram, which the next draw uses.
+    pub fn set_config(&mut self, config: FigureConfig) {
+        self.config = config;
+    }
+
+    /// Replaces the uniform bins with bins between explicit boundaries, e.g. for skewed data.
+    ///
+    /// Each bin includes its lower edge, the last bin also its upper edge. Values outside the
+    /// edges are not counted. The number of bins becomes one less than the number of edges.
+    /// Edges that are not finite and strictly increasing, or fewer than two edges, are ignored.
+    ///
+    /// # Parameters
+    /// - `edges`: The bin boundaries in ascending order.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::histogram::Histogram;
+    /// # let mut histogram = Histogram::new("Data Distribution", "Values", "Frequency", 10, [255, 0, 0], FigureConfig::default());
+    /// histogram.set_bin_edges(vec![0.0, 1.0, 5.0, 10.0]);
+    /// histogram.add_data(3.0);
+    /// assert_eq!(histogram.calculate_bins()[1], (1.0, 1.0));
+    /// ```
+    pub fn set_bin_edges(&mut self, edges: Vec<f64>) {
+        let is_valid = edges.len() >= 2
+            && edges.iter().all(|edge| edge.is_finite())
+            && edges.windows(2).all(|pair| pair[0] < pair[1]);
+        if !is_valid {
+            return;
+        }
+
+        self.bins = edges.len() - 1;
+        self.bin_counts = count_in_edges(&self.data, &edges);
+        self.bin_edges = Some(edges);
+    }
+
+    /// Sets which quantity the bars show.
+    ///
+    /// The mode is applied by [`Histogram::calculate_bins`] and by the drawers, to the bar
+    /// heights as well as the Y-axis values.
+    ///
+    /// # Parameters
+    /// - `mode`: `HistogramMode::Count`, `HistogramMode::Density` or `HistogramMode::Cumulative`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::histogram::Histogram;
+    /// use dataviz::figure::utilities::histogrammode::HistogramMode;
+    /// # let mut histogram = Histogram::new("Data Distribution", "Values", "Frequency", 10, [255, 0, 0], FigureConfig::default());
+    ///
+    /// histogram.set_mode(HistogramMode::Density);
+    /// ```
+    pub fn set_mode(&mut self, mode: HistogramMode) {
+        self.mode = mode;
+    }
+
+    /// Returns the boundaries of all bins, from the start of the first to the end of the last.
+    ///
+    /// These are the edges set with [`Histogram::set_bin_edges`], or `bins + 1` evenly spaced
+    /// edges from the cached minimum otherwise.
+    pub fn edges(&self) -> Vec<f64> {
+        match &self.bin_edges {
+            Some(edges) => edges.clone(),
+            None => (0..=self.bins)
+                .map(|i| self.min + i as f64 * self.bin_width)
+                .collect(),
         }
     }
 
@@ -78,6 +176,9 @@ impl Histogram {
     ///
     /// # Example
     /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::histogram::Histogram;
+    /// # let mut histogram = Histogram::new("Data Distribution", "Values", "Frequency", 10, [255, 0, 0], FigureConfig::default());
     /// histogram.add_data_vec(vec![1.2, 2.5, 3.1, 4.8]);
     /// ```
     pub fn add_data_vec(&mut self, values: Vec<f64>) {
@@ -98,6 +199,9 @@ impl Histogram {
     ///
     /// # Example
     /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::histogram::Histogram;
+    /// # let mut histogram = Histogram::new("Data Distribution", "Values", "Frequency", 10, [255, 0, 0], FigureConfig::default());
     /// histogram.add_data(3.5);
     /// ```
     pub fn add_data(&mut self, value: f64) {
@@ -111,6 +215,13 @@ impl Histogram {
             self.max = value;
         }
 
+        if let Some(edges) = &self.bin_edges {
+            if let Some(bin_index) = edge_bin_index(edges, value) {
+                self.bin_counts[bin_index] += 1.0;
+            }
+            return;
+        }
+
         // Recalculate bin width and update bin counts
         self.bin_width = (self.max - self.min) / self.bins as f64;
         if self.bin_width > 0.0 {
@@ -126,20 +237,199 @@ impl Histogram {
     /// # Returns
     /// A vector of tuples where each tuple contains:
     /// - The starting value of the bin.
-    /// - The frequency count for that bin.
+    /// - The value of that bin according to `mode`: its count, density or cumulative count.
     ///
     /// # Example
     /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::histogram::Histogram;
+    /// # let mut histogram = Histogram::new("Data Distribution", "Values", "Frequency", 10, [255, 0, 0], FigureConfig::default());
+    /// # histogram.add_data_vec(vec![1.2, 2.5, 3.1, 4.8]);
     /// let bins = histogram.calculate_bins();
     /// for (start, count) in bins {
     ///     println!("Bin starts at {}, count is {}", start, count);
     /// }
     /// ```
     pub fn calculate_bins(&self) -> Vec<(f64, f64)> {
-        self.bin_counts
+        let edges = self.edges();
+        let values = self.mode.apply(&edges, &self.bin_counts);
+        edges.into_iter().zip(values).collect()
+    }
+
+    /// Adds a data series that is overlaid on the histogram for comparison.
+    ///
+    /// With overlays present the histogram switches to overlay mode: the histogram's own data and
+    /// every overlay are counted on one shared bin layout, drawn with `overlay_alpha` so that
+    /// overlapping bars blend, and listed in a legend.
+    ///
+    /// # Parameters
+    /// - `label`: The legend label of the series.
+    /// - `data`: The raw data values of the series.
+    /// - `color`: The RGB color of the series bars.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::histogram::Histogram;
+    /// # let mut histogram = Histogram::new("Data Distribution", "Values", "Frequency", 10, [255, 0, 0], FigureConfig::default());
+    /// histogram.label = "Before".to_string();
+    /// histogram.add_data_vec(vec![1.2, 2.5, 3.1, 4.8]);
+    /// histogram.add_overlay("After", vec![2.2, 3.5, 4.1, 5.8], [0, 0, 255]);
+    /// ```
+    pub fn add_overlay(&mut self, label: &str, data: Vec<f64>, color: [u8; 3]) {
+        self.overlays.push(HistogramOverlay {
+            label: label.to_string(),
+            data,
+            color,
+        });
+    }
+
+    /// Calculates the bin counts of the histogram and all overlays on one shared bin layout.
+    ///
+    /// The layout has `bins` bins of equal width spanning the values of all series. Custom
+    /// edges set with [`Histogram::set_bin_edges`] are not applied here.
+    ///
+    /// # Returns
+    /// A tuple containing:
+    /// - The starting value of the first bin.
+    /// - The shared bin width.
+    /// - The bin counts per series: the histogram's own data first, followed by the overlays.
+    pub fn calculate_overlay_bins(&self) -> (f64, f64, Vec<Vec<f64>>) {
+        let all_values = self
+            .data
+            .iter()
+            .chain(self.overlays.iter().flat_map(|overlay| overlay.data.iter()));
+        let (min, max) = all_values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+        if !min.is_finite() || !max.is_finite() {
+            return (
+                0.0,
+                0.0,
+                vec![vec![0.0; self.bins]; self.overlays.len() + 1],
+            );
+        }
+
+        let bin_width = (max - min) / self.bins as f64;
+        let count = |values: &[f64]| {
+            let mut counts = vec![0.0; self.bins];
+            for &value in values {
+                let index = if bin_width > 0.0 {
+                    ((value - min) / bin_width).floor() as usize
+                } else {
+                    0
+                };
+                // The maximum value belongs to the last bin
+                if let Some(slot) = counts.get_mut(index.min(self.bins.saturating_sub(1))) {
+                    *slot += 1.0;
+                }
+            }
+            counts
+        };
+
+        let mut series = vec![count(&self.data)];
+        series.extend(self.overlays.iter().map(|overlay| count(&overlay.data)));
+        (min, bin_width, series)
+    }
+}
+
+/// Returns the index of the bin between consecutive `edges` that holds `value`, or `None` if
+/// it lies outside the edges. The last bin includes its upper edge.
+pub(crate) fn edge_bin_index(edges: &[f64], value: f64) -> Option<usize> {
+    let last = edges.len().checked_sub(1)?;
+    if value == edges[last] {
+        return last.checked_sub(1);
+    }
+    let index = edges.partition_point(|&edge| edge <= value);
+    (1..=last).contains(&index).then(|| index - 1)
+}
+
+/// Counts the `values` in each bin between consecutive `edges`, see [`edge_bin_index`].
+pub(crate) fn count_in_edges(values: &[f64], edges: &[f64]) -> Vec<f64> {
+    let mut counts = vec![0.0; edges.len().saturating_sub(1)];
+    for &value in values {
+        if let Some(index) = edge_bin_index(edges, value) {
+            counts[index] += 1.0;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_overlay_bins_shares_layout() {
+        let mut histogram =
+            Histogram::new("Compare", "X", "Y", 3, [255, 0, 0], FigureConfig::default());
+        histogram.add_data_vec(vec![1.0, 2.0, 2.5, 3.0]);
+        histogram.add_overlay("Other", vec![2.0, 2.2, 3.5, 4.0], [0, 0, 255]);
+
+        let (start, width, series) = histogram.calculate_overlay_bins();
+
+        assert_eq!(start, 1.0);
+        assert_eq!(width, 1.0);
+        assert_eq!(series, vec![vec![1.0, 2.0, 1.0], vec![0.0, 2.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_custom_bin_edges_bucket_values_into_variable_width_bins() {
+        let mut histogram =
+            Histogram::new("Skewed", "X", "Y", 10, [255, 0, 0], FigureConfig::default());
+        histogram.add_data(0.5);
+        histogram.set_bin_edges(vec![0.0, 1.0, 5.0, 10.0]);
+        histogram.add_data_vec(vec![3.0, 10.0, 12.0]);
+
+        assert_eq!(histogram.bins, 3);
+        // 3 lands in [1, 5), 10 in the last bin, and 12 outside all bins
+        assert_eq!(
+            histogram.calculate_bins(),
+            vec![(0.0, 1.0), (1.0, 1.0), (5.0, 1.0)]
+        );
+
+        // Edges that don't increase are ignored
+        histogram.set_bin_edges(vec![0.0, 5.0, 5.0]);
+        assert_eq!(histogram.edges(), vec![0.0, 1.0, 5.0, 10.0]);
+    }
+
+    #[test]
+    fn test_density_bins_integrate_to_one() {
+        let mut histogram =
+            Histogram::new("Density", "X", "Y", 4, [255, 0, 0], FigureConfig::default());
+        histogram.set_bin_edges(vec![0.0, 1.0, 2.0, 4.0, 8.0]);
+        histogram.add_data_vec(vec![0.5, 1.5, 1.7, 3.0, 5.0, 6.0, 7.5]);
+        histogram.set_mode(HistogramMode::Density);
+
+        let edges = histogram.edges();
+        let area: f64 = histogram
+            .calculate_bins()
+            .iter()
+            .zip(edges.windows(2))
+            .map(|(&(_, density), bin)| density * (bin[1] - bin[0]))
+            .sum();
+        assert!((area - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_cumulative_bins_end_at_total_count() {
+        let mut histogram = Histogram::new(
+            "Cumulative",
+            "X",
+            "Y",
+            3,
+            [255, 0, 0],
+            FigureConfig::default(),
+        );
+        histogram.set_bin_edges(vec![0.0, 1.0, 2.0, 3.0]);
+        histogram.add_data_vec(vec![0.5, 1.5, 1.7, 2.5, 3.0]);
+        histogram.set_mode(HistogramMode::Cumulative);
+
+        let values: Vec<f64> = histogram
+            .calculate_bins()
             .iter()
-            .enumerate()
-            .map(|(i, &freq)| (self.min + i as f64 * self.bin_width, freq))
-            .collect()
+            .map(|&(_, value)| value)
+            .collect();
+        assert_eq!(values, vec![1.0, 3.0, 5.0]);
     }
 }
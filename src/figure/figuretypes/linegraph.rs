@@ -1,5 +1,11 @@
 use crate::figure::{
-    configuration::figureconfig::FigureConfig, datasets::linegraphdataset::LineGraphDataset,
+    configuration::figureconfig::FigureConfig,
+    datasets::linegraphdataset::LineGraphDataset,
+    utilities::{
+        annotation::{mean_line, Annotation},
+        axistype::AxisType,
+        interpolation::Interpolation,
+    },
 };
 
 pub struct LineGraph {
@@ -12,6 +18,13 @@ pub struct LineGraph {
     pub y_min: f64, // Minimum y-value
     pub y_max: f64, // Maximum y-value
     pub config: FigureConfig,
+    pub annotations: Vec<Annotation>, // Reference lines drawn on top of the data
+    /// Custom X-axis tick positions, in data units, replacing the evenly spaced default ticks.
+    pub x_ticks: Option<Vec<f64>>,
+    /// Custom Y-axis tick positions, in data units, replacing the evenly spaced default ticks.
+    pub y_ticks: Option<Vec<f64>>,
+    /// How consecutive points of each dataset are connected.
+    pub interpolation: Interpolation,
 }
 
 impl LineGraph {
@@ -26,14 +39,72 @@ impl LineGraph {
             y_min: f64::INFINITY,     // Initialize to max range
             y_max: f64::NEG_INFINITY, // Initialize to min range
             config: config.clone(),
+            annotations: Vec::new(),
+            x_ticks: None,
+            y_ticks: None,
+            interpolation: Interpolation::Linear,
         }
     }
 
+    /// Replaces the configuration of the graph, which the next draw uses.
+    pub fn set_config(&mut self, config: FigureConfig) {
+        self.config = config;
+    }
+
+    /// Sets how consecutive points of each dataset are connected.
+    ///
+    /// # Parameters
+    /// - `interpolation`: `Interpolation::Linear` for straight segments,
+    ///   `Interpolation::CatmullRom` for a smooth curve through the points, or
+    ///   `Interpolation::StepPre`/`Interpolation::StepPost` for steps, e.g. for state changes.
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Sets the positions of the ticks along both axes of the line graph, filtered as
+    /// described in [`tick_values`](crate::figure::utilities::ticks::tick_values).
+    ///
+    /// # Parameters
+    /// - `x_ticks`, `y_ticks`: Tick positions in data units, or `None` for even spacing.
+    pub fn set_ticks(&mut self, x_ticks: Option<Vec<f64>>, y_ticks: Option<Vec<f64>>) {
+        self.x_ticks = x_ticks;
+        self.y_ticks = y_ticks;
+    }
+
     pub fn add_dataset(&mut self, dataset: LineGraphDataset) {
         self.datasets.push(dataset);
         self.update_range();
     }
 
+    /// Adds a reference annotation to the line graph.
+    ///
+    /// # Parameters
+    /// - `annotation`: The `Annotation` (horizontal or vertical line) to draw on top of the data.
+    pub fn add_annotation(&mut self, annotation: Annotation) {
+        self.annotations.push(annotation);
+    }
+
+    /// Adds a line marking the mean of all data points on the chosen axis.
+    ///
+    /// For `AxisType::AxisY` a horizontal line is drawn at the average y-value, for
+    /// `AxisType::AxisX` a vertical line at the average x-value. The line is labeled with its value.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis whose values are averaged.
+    /// - `color`: The RGB color of the line and its label.
+    ///
+    /// # Returns
+    /// The computed mean, or `None` if the line graph has no data points (no line is added).
+    pub fn add_mean_line(&mut self, axis: AxisType, color: [u8; 3]) -> Option<f64> {
+        let points = self
+            .datasets
+            .iter()
+            .flat_map(|dataset| dataset.points.iter());
+        let (value, line) = mean_line(points, axis, color, &self.config.number_format)?;
+        self.add_annotation(line);
+        Some(value)
+    }
+
     pub fn update_range(&mut self) {
         for dataset in &self.datasets {
             for &(x, y) in &dataset.points {
@@ -1,3 +1,5 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
 use crate::figure::{canvas::pixelcanvas::PixelCanvas, configuration::figureconfig::FigureConfig};
 
 /// Represents a pie chart with title, datasets, and configuration settings.
@@ -11,6 +13,17 @@ pub struct PieChart {
     pub datasets: Vec<(String, f64, [u8; 3])>,
     /// Configuration settings for rendering the chart (e.g., fonts, colors, grid).
     pub config: FigureConfig,
+    /// Angle in radians where the first slice starts, measured counterclockwise from the
+    /// 3 o'clock position. Defaults to 12 o'clock (`π/2`).
+    pub start_angle: f64,
+    /// Whether consecutive slices are laid out clockwise (`true`, default) or counterclockwise (`false`).
+    pub clockwise: bool,
+    /// Distance of the percentage labels from the center as a fraction of the radius.
+    /// Defaults to `0.6`; values above `1.0` place the labels outside the pie.
+    pub label_radius_ratio: f64,
+    /// Distance in pixels by which each slice is pulled out from the center, indexed like
+    /// `datasets`. Slices without an entry are not pulled out.
+    pub explode: Vec<f64>,
 }
 
 impl PieChart {
@@ -21,12 +34,12 @@ impl PieChart {
     /// - `config`: The `FigureConfig` containing appearance and behavior settings.
     ///
     /// # Returns
-    /// A new `PieChart` instance with no datasets.
+    /// A new `PieChart` instance with no datasets, laying out slices clockwise from 12 o'clock.
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::configuration::figureconfig::FigureConfig;
-    /// use crate::figure::piechart::PieChart;
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::piechart::PieChart;
     ///
     /// let config = FigureConfig::default();
     /// let pie_chart = PieChart::new("Market Share", config);
@@ -36,9 +49,18 @@ impl PieChart {
             title: title.to_string(),
             datasets: Vec::new(),
             config,
+            start_angle: FRAC_PI_2,
+            clockwise: true,
+            label_radius_ratio: 0.6,
+            explode: Vec::new(),
         }
     }
 
+    /// Replaces the configuration of the pie chart, which the next draw uses.
+    pub fn set_config(&mut self, config: FigureConfig) {
+        self.config = config;
+    }
+
     /// Adds a slice to the pie chart.
     ///
     /// # Parameters
@@ -48,6 +70,9 @@ impl PieChart {
     ///
     /// # Example
     /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::piechart::PieChart;
+    /// # let mut pie_chart = PieChart::new("Market Share", FigureConfig::default());
     /// pie_chart.add_slice("Product A", 30.0, [255, 0, 0]);
     /// pie_chart.add_slice("Product B", 50.0, [0, 255, 0]);
     /// pie_chart.add_slice("Product C", 20.0, [0, 0, 255]);
@@ -56,6 +81,125 @@ impl PieChart {
         self.datasets.push((label.to_string(), value, color));
     }
 
+    /// Adds a slice to the pie chart that is pulled out from the center for emphasis.
+    ///
+    /// # Parameters
+    /// - `label`: The label for the slice.
+    /// - `value`: The value representing the proportion of the slice.
+    /// - `color`: The RGB color of the slice.
+    /// - `offset`: The distance in pixels the slice is moved outward along its mid-angle.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::piechart::PieChart;
+    /// # let mut pie_chart = PieChart::new("Market Share", FigureConfig::default());
+    /// pie_chart.add_slice("Product A", 30.0, [255, 0, 0]);
+    /// pie_chart.add_slice_exploded("Product B", 50.0, [0, 255, 0], 15.0);
+    /// ```
+    pub fn add_slice_exploded(&mut self, label: &str, value: f64, color: [u8; 3], offset: f64) {
+        self.add_slice(label, value, color);
+        self.explode.resize(self.datasets.len(), 0.0);
+        self.explode[self.datasets.len() - 1] = offset;
+    }
+
+    /// Sets where the first slice starts and in which direction the slices are laid out.
+    ///
+    /// # Parameters
+    /// - `start_angle`: The angle in radians, measured counterclockwise from the 3 o'clock position.
+    /// - `clockwise`: `true` to lay out the slices clockwise, `false` for counterclockwise.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::piechart::PieChart;
+    /// # let mut pie_chart = PieChart::new("Market Share", FigureConfig::default());
+    /// // Start at 12 o'clock and continue clockwise
+    /// pie_chart.set_orientation(std::f64::consts::FRAC_PI_2, true);
+    /// ```
+    pub fn set_orientation(&mut self, start_angle: f64, clockwise: bool) {
+        self.start_angle = start_angle;
+        self.clockwise = clockwise;
+    }
+
+    /// Sets how far from the center the percentage labels are placed.
+    ///
+    /// The labels are drawn with the configured `font_size_label`.
+    ///
+    /// # Parameters
+    /// - `ratio`: The distance from the center as a fraction of the radius, e.g. `1.15` to place
+    ///   the labels just outside the pie.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::piechart::PieChart;
+    /// # let mut pie_chart = PieChart::new("Market Share", FigureConfig::default());
+    /// pie_chart.set_label_radius_ratio(0.8);
+    /// ```
+    pub fn set_label_radius_ratio(&mut self, ratio: f64) {
+        self.label_radius_ratio = ratio;
+    }
+
+    /// Computes the position of a slice's percentage label relative to the pie center.
+    ///
+    /// # Parameters
+    /// - `start_angle`, `end_angle`: The angular range of the slice, as returned by [`PieChart::slice_angles`].
+    /// - `radius`: The radius of the pie.
+    ///
+    /// # Returns
+    /// The `(x, y)` offset from the center, with y pointing down as on screen.
+    pub fn label_offset(&self, start_angle: f64, end_angle: f64, radius: f64) -> (f64, f64) {
+        let mid_angle = (start_angle + end_angle) / 2.0;
+        let distance = radius * self.label_radius_ratio;
+        (distance * mid_angle.cos(), -distance * mid_angle.sin())
+    }
+
+    /// Computes how far a slice is moved out of the center, see [`PieChart::explode`].
+    ///
+    /// # Parameters
+    /// - `index`: The index of the slice in `datasets`.
+    /// - `start_angle`, `end_angle`: The angular range of the slice, as returned by [`PieChart::slice_angles`].
+    ///
+    /// # Returns
+    /// The `(x, y)` offset of the slice, with y pointing down as on screen.
+    pub fn explode_offset(&self, index: usize, start_angle: f64, end_angle: f64) -> (f64, f64) {
+        let offset = self.explode.get(index).copied().unwrap_or(0.0);
+        let mid_angle = (start_angle + end_angle) / 2.0;
+        (offset * mid_angle.cos(), -offset * mid_angle.sin())
+    }
+
+    /// Computes the angular range covered by each slice.
+    ///
+    /// Angles are in radians, measured counterclockwise from the 3 o'clock position, and honor
+    /// `start_angle` and `clockwise`. All drawers and the hover functionality use these ranges so
+    /// the pixel and SVG outputs share the same orientation.
+    ///
+    /// # Returns
+    /// One `(from, to)` pair per slice with `from <= to`.
+    pub fn slice_angles(&self) -> Vec<(f64, f64)> {
+        let total: f64 = self.datasets.iter().map(|(_, value, _)| value).sum();
+        let mut angle = self.start_angle;
+
+        self.datasets
+            .iter()
+            .map(|(_, value, _)| {
+                let sweep = if total == 0.0 {
+                    0.0
+                } else {
+                    value / total * 2.0 * PI
+                };
+                if self.clockwise {
+                    angle -= sweep;
+                    (angle, angle + sweep)
+                } else {
+                    angle += sweep;
+                    (angle - sweep, angle)
+                }
+            })
+            .collect()
+    }
+
     /// Draws a slice of the pie chart on the canvas.
     ///
     /// # Parameters
@@ -63,8 +207,8 @@ impl PieChart {
     /// - `center_x`: The x-coordinate of the center of the pie chart.
     /// - `center_y`: The y-coordinate of the center of the pie chart.
     /// - `radius`: The radius of the pie chart.
-    /// - `start_angle`: The starting angle of the slice in radians.
-    /// - `end_angle`: The ending angle of the slice in radians.
+    /// - `start_angle`: The starting angle of the slice in radians, counterclockwise from 3 o'clock.
+    /// - `end_angle`: The ending angle of the slice in radians (`end_angle >= start_angle`).
     /// - `color`: The RGB color of the slice.
     ///
     /// # Details
@@ -73,6 +217,11 @@ impl PieChart {
     ///
     /// # Example
     /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::piechart::PieChart;
+    /// # let mut pie_chart = PieChart::new("Market Share", FigureConfig::default());
+    /// # use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    /// # let mut canvas = PixelCanvas::new(400, 400, [255, 255, 255], 20);
     /// pie_chart.draw_slice(&mut canvas, 200, 200, 100, 0.0, 1.0, [255, 0, 0]);
     /// ```
     #[allow(clippy::too_many_arguments)]
@@ -86,8 +235,7 @@ impl PieChart {
         end_angle: f64,
         color: [u8; 3],
     ) {
-        let start_angle_rad = start_angle;
-        let end_angle_rad = end_angle;
+        let sweep = end_angle - start_angle;
 
         for y in -radius..=radius {
             for x in -radius..=radius {
@@ -96,14 +244,9 @@ impl PieChart {
                 if distance <= (radius * radius) as f64 {
                     // Calculate the angle of the point
                     let angle = (y as f64).atan2(x as f64);
-                    let normalized_angle = if angle < 0.0 {
-                        angle + 2.0 * std::f64::consts::PI
-                    } else {
-                        angle
-                    };
 
-                    // Check if the angle is within the slice range
-                    if normalized_angle >= start_angle_rad && normalized_angle < end_angle_rad {
+                    // Check if the angle is within the slice range, independent of full turns
+                    if (angle - start_angle).rem_euclid(2.0 * PI) < sweep {
                         canvas.draw_pixel((center_x + x) as u32, (center_y - y) as u32, color);
                     }
                 }
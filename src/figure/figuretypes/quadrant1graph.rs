@@ -1,5 +1,6 @@
 use crate::figure::{
-    configuration::figureconfig::FigureConfig, datasets::cartesiangraphdataset::CartesianDataset,
+    configuration::figureconfig::FigureConfig,
+    datasets::{cartesiangraphdataset::CartesianDataset, dataset::combined_bounds},
 };
 
 /// Represents a graph limited to the first quadrant (x >= 0, y >= 0),
@@ -19,6 +20,10 @@ pub struct Quadrant1Graph {
     pub x_max: f64, // Maximum x-value
     pub y_min: f64, // Minimum y-value
     pub y_max: f64, // Maximum y-value
+    /// Custom X-axis tick positions, in data units, replacing the evenly spaced default ticks.
+    pub x_ticks: Option<Vec<f64>>,
+    /// Custom Y-axis tick positions, in data units, replacing the evenly spaced default ticks.
+    pub y_ticks: Option<Vec<f64>>,
 }
 
 impl Quadrant1Graph {
@@ -35,8 +40,8 @@ impl Quadrant1Graph {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::configuration::figureconfig::FigureConfig;
-    /// use crate::figure::quadrant1graph::Quadrant1Graph;
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::quadrant1graph::Quadrant1Graph;
     ///
     /// let config = FigureConfig::default();
     /// let graph = Quadrant1Graph::new("First Quadrant Graph", "X Axis", "Y Axis", config);
@@ -52,9 +57,26 @@ impl Quadrant1Graph {
             x_max: f64::NEG_INFINITY, // Initialize to min range
             y_min: f64::INFINITY,     // Initialize to max range
             y_max: f64::NEG_INFINITY, // Initialize to min range
+            x_ticks: None,
+            y_ticks: None,
         }
     }
 
+    /// Replaces the configuration of the graph, which the next draw uses.
+    pub fn set_config(&mut self, config: FigureConfig) {
+        self.config = config;
+    }
+
+    /// Sets the positions of the ticks along the positive axes, filtered as described in
+    /// [`tick_values`](crate::figure::utilities::ticks::tick_values).
+    ///
+    /// # Parameters
+    /// - `x_ticks`, `y_ticks`: Tick positions in data units, or `None` for even spacing.
+    pub fn set_ticks(&mut self, x_ticks: Option<Vec<f64>>, y_ticks: Option<Vec<f64>>) {
+        self.x_ticks = x_ticks;
+        self.y_ticks = y_ticks;
+    }
+
     /// Adds a dataset to the graph after filtering out points not in the first quadrant.
     ///
     /// # Parameters
@@ -65,8 +87,11 @@ impl Quadrant1Graph {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::datasets::cartesiangraphdataset::CartesianDataset;
-    /// use crate::figure::utilities::linetype::LineType;
+    /// use dataviz::figure::datasets::cartesiangraphdataset::CartesianDataset;
+    /// use dataviz::figure::utilities::linetype::LineType;
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::quadrant1graph::Quadrant1Graph;
+    /// # let config = FigureConfig::default();
     ///
     /// let mut graph = Quadrant1Graph::new("Example Graph", "X Axis", "Y Axis", config);
     /// let dataset = CartesianDataset {
@@ -74,6 +99,8 @@ impl Quadrant1Graph {
     ///     color: [255, 0, 0],
     ///     label: "Dataset 1".to_string(),
     ///     line_type: LineType::Solid,
+    ///     line_width: 1,
+    ///     visible: true,
     /// };
     /// graph.add_dataset(dataset);
     /// ```
@@ -87,6 +114,8 @@ impl Quadrant1Graph {
             color: dataset.color,
             label: dataset.label.clone(),
             line_type: dataset.line_type,
+            line_width: dataset.line_width,
+            visible: dataset.visible,
         };
         self.datasets.push(filtered_dataset);
         self.update_range();
@@ -138,4 +167,20 @@ impl Quadrant1Graph {
             }
         }
     }
+    /// Returns the axis limits used to map data onto the canvas.
+    ///
+    /// The limits span all visible dataset points and always include the origin.
+    ///
+    /// # Returns
+    /// A tuple `(x_min, x_max, y_min, y_max)`.
+    pub fn plot_limits(&self) -> (f64, f64, f64, f64) {
+        let visible = self.datasets.iter().filter(|dataset| dataset.visible);
+        let ((x_min, x_max), (y_min, y_max)) = combined_bounds(visible).unwrap_or_default();
+        (
+            x_min.min(0.0),
+            x_max.max(0.0),
+            y_min.min(0.0),
+            y_max.max(0.0),
+        )
+    }
 }
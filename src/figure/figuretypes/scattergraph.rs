@@ -1,6 +1,14 @@
 use crate::figure::{
-    canvas::pixelcanvas::PixelCanvas, configuration::figureconfig::FigureConfig,
-    datasets::scattergraphdataset::ScatterGraphDataset, utilities::scatterdottype::ScatterDotType,
+    canvas::{margins::Margins, pixelcanvas::PixelCanvas},
+    configuration::figureconfig::FigureConfig,
+    datasets::{dataset::combined_bounds, scattergraphdataset::ScatterGraphDataset},
+    utilities::{
+        annotation::{mean_line, Annotation},
+        axistype::AxisType,
+        clipping::ClipRect,
+        scatterdottype::ScatterDotType,
+        trendline::TrendLine,
+    },
 };
 
 /// Represents a scatter graph, including title, axis labels, datasets, and configuration settings.
@@ -15,6 +23,49 @@ pub struct ScatterGraph {
     pub datasets: Vec<ScatterGraphDataset>,
     /// Configuration settings for rendering the graph (e.g., colors, fonts, grid).
     pub config: FigureConfig,
+    /// Reference lines drawn on top of the data points.
+    pub annotations: Vec<Annotation>,
+    /// Least-squares trend lines drawn across the visible x-range.
+    pub trend_lines: Vec<TrendLine>,
+    /// Custom X-axis tick positions, in data units, replacing the evenly spaced default ticks.
+    pub x_ticks: Option<Vec<f64>>,
+    /// Custom Y-axis tick positions, in data units, replacing the evenly spaced default ticks.
+    pub y_ticks: Option<Vec<f64>>,
+    /// Fixed X-axis range `(min, max)` replacing the range fitted to the data, or `None`.
+    pub x_limits: Option<(f64, f64)>,
+    /// Fixed Y-axis range `(min, max)` replacing the range fitted to the data, or `None`.
+    pub y_limits: Option<(f64, f64)>,
+    /// Static layout of the last full draw, reused by `draw_incremental`.
+    pub(crate) layout_cache: Option<ScatterLayout>,
+}
+
+/// The canvas contents of a scatter graph without its data, together with the settings they
+/// were drawn for.
+pub(crate) struct ScatterLayout {
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    pub(crate) margins: Margins,
+    pub(crate) limits: (f64, f64, f64, f64),
+    pub(crate) dataset_count: usize,
+    pub(crate) buffer: Vec<u8>,
+    /// The canvas positions of the vertical and horizontal grid lines.
+    pub(crate) grid_positions: (Vec<u32>, Vec<u32>),
+}
+
+impl ScatterLayout {
+    /// Returns `true` if the cached layout can be reused for the given canvas and limits.
+    pub(crate) fn matches(
+        &self,
+        canvas: &PixelCanvas,
+        limits: (f64, f64, f64, f64),
+        dataset_count: usize,
+    ) -> bool {
+        self.width == canvas.width
+            && self.height == canvas.height
+            && self.margins == canvas.margins
+            && self.limits == limits
+            && self.dataset_count == dataset_count
+    }
 }
 
 impl ScatterGraph {
@@ -31,8 +82,8 @@ impl ScatterGraph {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::configuration::figureconfig::FigureConfig;
-    /// use crate::figure::scattergraph::ScatterGraph;
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::scattergraph::ScatterGraph;
     ///
     /// let config = FigureConfig::default();
     /// let scatter_graph = ScatterGraph::new("Data Points", "X Axis", "Y Axis", config);
@@ -44,6 +95,70 @@ impl ScatterGraph {
             y_label: y_label.to_string(),
             datasets: Vec::new(),
             config,
+            annotations: Vec::new(),
+            trend_lines: Vec::new(),
+            x_ticks: None,
+            y_ticks: None,
+            x_limits: None,
+            y_limits: None,
+            layout_cache: None,
+        }
+    }
+
+    /// Replaces the configuration of the graph, which the next draw uses.
+    pub fn set_config(&mut self, config: FigureConfig) {
+        self.config = config;
+        self.layout_cache = None;
+    }
+
+    /// Sets the positions of the ticks instead of the round numbers chosen by
+    /// [`nice_tick_values`](crate::figure::utilities::ticks::nice_tick_values), and
+    /// invalidates the cached layout.
+    ///
+    /// # Parameters
+    /// - `x_ticks`, `y_ticks`: Tick positions in data units, or `None` for round numbers.
+    pub fn set_ticks(&mut self, x_ticks: Option<Vec<f64>>, y_ticks: Option<Vec<f64>>) {
+        self.x_ticks = x_ticks;
+        self.y_ticks = y_ticks;
+        self.layout_cache = None;
+    }
+
+    /// Pins the X-axis to a fixed range instead of fitting it to the data.
+    ///
+    /// Points outside the range are not drawn. Invalid ranges, where `min` is not less than
+    /// `max`, are ignored.
+    ///
+    /// # Parameters
+    /// - `min`: The value at the left edge of the plot area.
+    /// - `max`: The value at the right edge of the plot area.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::scattergraph::ScatterGraph;
+    ///
+    /// let mut scatter_graph = ScatterGraph::new("Zoomed", "X", "Y", FigureConfig::default());
+    /// scatter_graph.set_x_limits(2.0, 8.0);
+    /// scatter_graph.set_x_limits(5.0, 5.0);
+    /// assert_eq!(scatter_graph.x_limits, Some((2.0, 8.0)));
+    /// ```
+    pub fn set_x_limits(&mut self, min: f64, max: f64) {
+        if min.is_finite() && max.is_finite() && min < max {
+            self.x_limits = Some((min, max));
+        }
+    }
+
+    /// Pins the Y-axis to a fixed range instead of fitting it to the data.
+    ///
+    /// Points outside the range are not drawn. Invalid ranges, where `min` is not less than
+    /// `max`, are ignored.
+    ///
+    /// # Parameters
+    /// - `min`: The value at the bottom edge of the plot area.
+    /// - `max`: The value at the top edge of the plot area.
+    pub fn set_y_limits(&mut self, min: f64, max: f64) {
+        if min.is_finite() && max.is_finite() && min < max {
+            self.y_limits = Some((min, max));
         }
     }
 
@@ -54,14 +169,22 @@ impl ScatterGraph {
     ///
     /// # Example
     /// ```rust
-    /// use crate::figure::datasets::scattergraphdataset::ScatterGraphDataset;
-    /// use crate::figure::utilities::scatterdottype::ScatterDotType;
+    /// use dataviz::figure::datasets::scattergraphdataset::ScatterGraphDataset;
+    /// use dataviz::figure::utilities::scatterdottype::ScatterDotType;
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::scattergraph::ScatterGraph;
+    /// # let mut scatter_graph = ScatterGraph::new("Data Points", "X Axis", "Y Axis", FigureConfig::default());
     ///
     /// let dataset = ScatterGraphDataset {
     ///     points: vec![(1.0, 2.0), (3.0, 4.0)],
     ///     color: [255, 0, 0],
     ///     label: "Dataset 1".to_string(),
     ///     dot_type: ScatterDotType::Circle(5),
+    ///     sizes: None,
+    ///     size_range: (1, 50),
+    ///     x_errors: None,
+    ///     y_errors: None,
+    ///     visible: true,
     /// };
     /// scatter_graph.add_dataset(dataset);
     /// ```
@@ -69,6 +192,131 @@ impl ScatterGraph {
         self.datasets.push(dataset);
     }
 
+    /// Returns the axis limits used to map data onto the canvas.
+    ///
+    /// The limits span all visible dataset points, with the minimums extended to include the origin.
+    /// Axes pinned with [`ScatterGraph::set_x_limits`] or [`ScatterGraph::set_y_limits`] use
+    /// their fixed range instead.
+    ///
+    /// # Returns
+    /// A tuple `(x_min, x_max, y_min, y_max)`.
+    pub fn plot_limits(&self) -> (f64, f64, f64, f64) {
+        let unbounded = (f64::INFINITY, f64::NEG_INFINITY);
+        let visible = self.datasets.iter().filter(|dataset| dataset.visible);
+        let ((x_min, x_max), (y_min, y_max)) =
+            combined_bounds(visible).unwrap_or((unbounded, unbounded));
+
+        let (x_min, x_max) = self.x_limits.unwrap_or((x_min.min(0.0), x_max));
+        let (y_min, y_max) = self.y_limits.unwrap_or((y_min.min(0.0), y_max));
+        (x_min, x_max, y_min, y_max)
+    }
+
+    /// Returns the pixel bounding box of a dataset's points for a canvas of the given size.
+    ///
+    /// The points are mapped with the same layout as [`Drawer::draw`](crate::figure::drawers::drawer::Drawer::draw),
+    /// e.g. to hit-test selection rectangles against a rendered graph. The box spans the
+    /// centers of the points within the plot limits; the extent of the dots themselves is not
+    /// included.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the dataset in `datasets`.
+    /// - `width`: The width of the canvas in pixels.
+    /// - `height`: The height of the canvas in pixels.
+    /// - `margins`: The margins of the canvas in pixels.
+    ///
+    /// # Returns
+    /// A tuple `(left, top, right, bottom)` in pixel coordinates, or `(0, 0, 0, 0)` if the dataset has no points
+    /// within the plot limits.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn dataset_bbox(
+        &self,
+        index: usize,
+        width: u32,
+        height: u32,
+        margins: Margins,
+    ) -> (u32, u32, u32, u32) {
+        let limits = self.plot_limits();
+        self.datasets[index]
+            .points
+            .iter()
+            .filter(|&&point| within_limits(point, limits))
+            .map(|&point| self.pixel_position(point, limits, width, height, margins))
+            .fold(None, |bbox, (x, y)| match bbox {
+                None => Some((x, y, x, y)),
+                Some((left, top, right, bottom)) => {
+                    Some((left.min(x), top.min(y), right.max(x), bottom.max(y)))
+                }
+            })
+            .unwrap_or((0, 0, 0, 0))
+    }
+
+    /// Maps a data point within `limits` to the pixel position at which its dot is drawn.
+    pub(crate) fn pixel_position(
+        &self,
+        (x, y): (f64, f64),
+        limits: (f64, f64, f64, f64),
+        width: u32,
+        height: u32,
+        margins: Margins,
+    ) -> (u32, u32) {
+        let (x_min, x_max, y_min, y_max) = limits;
+        let scale_x = (width - margins.horizontal()) as f64 / (x_max - x_min);
+        let scale_y = (height - margins.vertical()) as f64 / (y_max - y_min);
+        (
+            margins.left + ((x - x_min) * scale_x) as u32,
+            height - margins.bottom - ((y - y_min) * scale_y) as u32,
+        )
+    }
+
+    /// Adds a reference annotation to the scatter graph.
+    ///
+    /// # Parameters
+    /// - `annotation`: The `Annotation` (horizontal or vertical line) to draw on top of the data.
+    pub fn add_annotation(&mut self, annotation: Annotation) {
+        self.annotations.push(annotation);
+    }
+
+    /// Adds a line marking the mean of all data points on the chosen axis.
+    ///
+    /// For `AxisType::AxisY` a horizontal line is drawn at the average y-value, for
+    /// `AxisType::AxisX` a vertical line at the average x-value. The line is labeled with its value.
+    ///
+    /// # Parameters
+    /// - `axis`: The axis whose values are averaged.
+    /// - `color`: The RGB color of the line and its label.
+    ///
+    /// # Returns
+    /// The computed mean, or `None` if the scatter graph has no data points (no line is added).
+    pub fn add_mean_line(&mut self, axis: AxisType, color: [u8; 3]) -> Option<f64> {
+        let points = self
+            .datasets
+            .iter()
+            .flat_map(|dataset| dataset.points.iter());
+        let (value, line) = mean_line(points, axis, color, &self.config.number_format)?;
+        self.add_annotation(line);
+        Some(value)
+    }
+
+    /// Adds a linear trend line fitted to the points of one dataset by ordinary least squares.
+    ///
+    /// The line is drawn in both the pixel and the SVG output, spanning the visible x-range.
+    ///
+    /// # Parameters
+    /// - `dataset_index`: The index of the dataset whose points are fitted.
+    /// - `color`: The RGB color of the line.
+    ///
+    /// # Returns
+    /// The fitted `TrendLine`, or `None` if the dataset doesn't exist, has fewer than two points
+    /// or all its points share the same x-value (no line is added).
+    pub fn add_trend_line(&mut self, dataset_index: usize, color: [u8; 3]) -> Option<TrendLine> {
+        let dataset = self.datasets.get(dataset_index)?;
+        let line = TrendLine::fit(&dataset.points, color)?;
+        self.trend_lines.push(line);
+        Some(line)
+    }
+
     /// Draws a single dot on the canvas using the specified dot type and color.
     ///
     /// # Parameters
@@ -87,6 +335,12 @@ impl ScatterGraph {
     ///
     /// # Example
     /// ```rust
+    /// # use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// # use dataviz::figure::figuretypes::scattergraph::ScatterGraph;
+    /// # let mut scatter_graph = ScatterGraph::new("Data Points", "X Axis", "Y Axis", FigureConfig::default());
+    /// # use dataviz::figure::canvas::pixelcanvas::PixelCanvas;
+    /// # use dataviz::figure::utilities::scatterdottype::ScatterDotType;
+    /// # let mut canvas = PixelCanvas::new(400, 400, [255, 255, 255], 20);
     /// scatter_graph.draw_dot(&mut canvas, 100, 100, ScatterDotType::Circle(5), [255, 0, 0]);
     /// ```
     pub fn draw_dot(
@@ -98,15 +352,7 @@ impl ScatterGraph {
         color: [u8; 3],
     ) {
         match dot_type {
-            ScatterDotType::Circle(radius) => {
-                for dy in -(radius as i32)..=radius as i32 {
-                    for dx in -(radius as i32)..=radius as i32 {
-                        if dx * dx + dy * dy <= (radius * radius) as i32 {
-                            canvas.draw_pixel((x + dx) as u32, (y + dy) as u32, color);
-                        }
-                    }
-                }
-            }
+            ScatterDotType::Circle(radius) => canvas.draw_circle_filled(x, y, radius, color),
             ScatterDotType::Square(size) => {
                 for dy in -(size as i32) / 2..=(size as i32) / 2 {
                     for dx in -(size as i32) / 2..=(size as i32) / 2 {
@@ -131,3 +377,370 @@ impl ScatterGraph {
         }
     }
 }
+
+/// Half the width of the caps at the ends of error bars, in pixels.
+const ERROR_BAR_CAP: f64 = 3.0;
+
+/// Returns the line segments of the error bars of one point in canvas coordinates.
+///
+/// Each bar runs through `center` and gets a cap at every end that isn't cut off by `clip`.
+///
+/// # Parameters
+/// - `center`: The canvas position of the point.
+/// - `x_extent`: The horizontal error in pixels, or `None` for no horizontal bar.
+/// - `y_extent`: The vertical error in pixels, or `None` for no vertical bar.
+/// - `clip`: The plot area the segments are clipped to.
+///
+/// # Returns
+/// The visible segments as `(x1, y1, x2, y2)`.
+pub(crate) fn error_bar_lines(
+    (cx, cy): (f64, f64),
+    x_extent: Option<f64>,
+    y_extent: Option<f64>,
+    clip: ClipRect,
+) -> Vec<(f64, f64, f64, f64)> {
+    let mut lines = Vec::new();
+    let inside = |x: f64, y: f64| clip.clip_line(x, y, x, y).is_some();
+
+    if let Some(extent) = y_extent {
+        lines.push((cx, cy - extent, cx, cy + extent));
+        for y in [cy - extent, cy + extent] {
+            if inside(cx, y) {
+                lines.push((cx - ERROR_BAR_CAP, y, cx + ERROR_BAR_CAP, y));
+            }
+        }
+    }
+    if let Some(extent) = x_extent {
+        lines.push((cx - extent, cy, cx + extent, cy));
+        for x in [cx - extent, cx + extent] {
+            if inside(x, cy) {
+                lines.push((x, cy - ERROR_BAR_CAP, x, cy + ERROR_BAR_CAP));
+            }
+        }
+    }
+
+    lines
+        .into_iter()
+        .filter_map(|(x1, y1, x2, y2)| clip.clip_line(x1, y1, x2, y2))
+        .collect()
+}
+
+/// Returns `true` if `point` lies within the plot `limits` `(x_min, x_max, y_min, y_max)`.
+pub(crate) fn within_limits((x, y): (f64, f64), limits: (f64, f64, f64, f64)) -> bool {
+    let (x_min, x_max, y_min, y_max) = limits;
+    (x_min..=x_max).contains(&x) && (y_min..=y_max).contains(&y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::canvas::testing::pixel_at;
+    use crate::figure::{
+        canvas::svgcanvas::SvgCanvas, datasets::dataset::Dataset, drawers::drawer::Drawer,
+        utilities::legendposition::LegendPosition,
+    };
+
+    #[test]
+    fn test_add_mean_line_draws_at_average_y() {
+        let mut graph = ScatterGraph::new("Mean", "X", "Y", FigureConfig::default());
+        let mut dataset = ScatterGraphDataset::new([0, 0, 255], "Data", ScatterDotType::Circle(2));
+        for point in [(1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (4.0, 6.0)] {
+            dataset.add_point(point);
+        }
+        graph.add_dataset(dataset);
+
+        let mean_y = graph.add_mean_line(AxisType::AxisY, [255, 0, 0]);
+        assert_eq!(mean_y, Some(3.0));
+        assert_eq!(
+            graph.annotations,
+            vec![Annotation::HLine {
+                y: 3.0,
+                color: [255, 0, 0],
+                label: "Mean: 3".to_string()
+            }]
+        );
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut canvas);
+
+        // y-range 0..6 over 220 px with the origin at y = 260: the mean 3 maps to y = 150
+        assert_eq!(pixel_at(&canvas, 250, 150), [255, 0, 0]);
+    }
+
+    #[test]
+    fn test_points_are_drawn_in_their_own_dataset_color() {
+        let mut graph = ScatterGraph::new("Two Sets", "X", "Y", FigureConfig::default());
+        for (color, points) in [
+            ([255, 0, 0], [(1.0, 2.0), (3.0, 7.0)]),
+            ([0, 0, 255], [(6.0, 1.0), (8.0, 5.0)]),
+        ] {
+            let mut dataset = ScatterGraphDataset::new(color, "Data", ScatterDotType::Circle(3));
+            for point in points {
+                dataset.add_point(point);
+            }
+            graph.add_dataset(dataset);
+        }
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut canvas);
+
+        let limits = graph.plot_limits();
+        for (dataset, other) in [
+            (&graph.datasets[0], &graph.datasets[1]),
+            (&graph.datasets[1], &graph.datasets[0]),
+        ] {
+            for &point in &dataset.points {
+                let (px, py) = graph.pixel_position(point, limits, 400, 300, Margins::uniform(40));
+                // One pixel towards the plot center, so the plot border doesn't cover it
+                assert_eq!(pixel_at(&canvas, px - 1, py + 1), dataset.color);
+
+                // No pixel of the dot is in the color of the other dataset
+                for y in py.saturating_sub(3)..=py + 3 {
+                    for x in px.saturating_sub(3)..=px + 3 {
+                        assert_ne!(pixel_at(&canvas, x, y), other.color);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_top_right_legend_is_drawn_in_top_right_quadrant() {
+        let config = FigureConfig {
+            legend_position: LegendPosition::TopRight,
+            ..FigureConfig::default()
+        };
+        let mut graph = ScatterGraph::new("Legend", "X", "Y", config);
+        let mut dataset = ScatterGraphDataset::new([0, 200, 0], "Data", ScatterDotType::Circle(2));
+        dataset.add_point((1.0, 1.0));
+        dataset.add_point((4.0, 3.0));
+        graph.add_dataset(dataset);
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut canvas);
+
+        // The points are inside the plot area, only the legend is drawn in the margins
+        let legend_pixels: Vec<(u32, u32)> = (0..canvas.height)
+            .flat_map(|y| (0..canvas.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| !(40..260).contains(&y) || !(40..360).contains(&x))
+            .filter(|&(x, y)| pixel_at(&canvas, x, y) == [0, 200, 0])
+            .collect();
+        assert!(!legend_pixels.is_empty());
+        assert!(legend_pixels.iter().all(|&(x, y)| x >= 200 && y < 150));
+    }
+
+    #[test]
+    fn test_fixed_limits_clip_outliers_instead_of_rescaling() {
+        let mut graph = ScatterGraph::new("Zoomed", "X", "Y", FigureConfig::default());
+        let mut dataset = ScatterGraphDataset::new([0, 0, 255], "Data", ScatterDotType::Circle(2));
+        for point in [(1.0, 1.0), (2.0, 2.0), (3.0, 3.0), (100.0, 100.0)] {
+            dataset.add_point(point);
+        }
+        graph.add_dataset(dataset);
+        graph.set_x_limits(0.0, 4.0);
+        graph.set_y_limits(0.0, 4.0);
+        // Invalid ranges are ignored
+        graph.set_x_limits(4.0, 0.0);
+        graph.set_y_limits(1.0, f64::NAN);
+        assert_eq!(graph.plot_limits(), (0.0, 4.0, 0.0, 4.0));
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut canvas);
+
+        // 320 / 4 and 220 / 4 px per unit: (2, 2) maps to (200, 150)
+        assert_eq!(pixel_at(&canvas, 200, 150), [0, 0, 255]);
+        assert_eq!(
+            graph.dataset_bbox(0, 400, 300, Margins::uniform(40)),
+            (120, 95, 280, 205)
+        );
+    }
+
+    #[test]
+    fn test_hidden_datasets_do_not_widen_the_plot_limits() {
+        let mut graph = ScatterGraph::new("Hidden", "X", "Y", FigureConfig::default());
+        let mut shown = ScatterGraphDataset::new([0, 0, 255], "Shown", ScatterDotType::Circle(2));
+        shown.add_point((2.0, 3.0));
+        let mut hidden = ScatterGraphDataset::new([255, 0, 0], "Hidden", ScatterDotType::Circle(2));
+        hidden.add_point((50.0, 80.0));
+        hidden.set_visible(false);
+        graph.add_dataset(shown);
+        graph.add_dataset(hidden);
+        assert_eq!(graph.plot_limits(), (0.0, 2.0, 0.0, 3.0));
+
+        let mut svg_canvas = SvgCanvas::new(300, 200, "white", 20);
+        graph.draw_svg(&mut svg_canvas);
+        // The SVG uses the same limits: (2, 3) lands in the top right corner of the plot
+        let dot = r#"<circle cx="280.00" cy="20.00" r="2.00" fill="rgb(0,0,255)"/>"#;
+        assert!(svg_canvas.elements.iter().any(|element| element == dot));
+    }
+
+    #[test]
+    fn test_dataset_bbox_spans_pixel_positions() {
+        let mut graph = ScatterGraph::new("Bbox", "X", "Y", FigureConfig::default());
+        let mut dataset = ScatterGraphDataset::new([0, 0, 255], "Data", ScatterDotType::Circle(2));
+        dataset.add_point((2.0, 8.0));
+        dataset.add_point((6.0, 4.0));
+        graph.add_dataset(dataset);
+
+        // Limits 0..6 x 0..8 on a 300x260 canvas with margin 20: 260 / 6 and 220 / 8 px per unit
+        let left = 20 + (2.0 * 260.0 / 6.0) as u32;
+        let right = 20 + (6.0 * 260.0 / 6.0) as u32;
+        let top = 240 - (8.0 * 220.0 / 8.0) as u32;
+        let bottom = 240 - (4.0 * 220.0 / 8.0) as u32;
+        assert_eq!(
+            graph.dataset_bbox(0, 300, 260, Margins::uniform(20)),
+            (left, top, right, bottom)
+        );
+    }
+
+    #[test]
+    fn test_add_trend_line_fits_points_on_a_line() {
+        let mut graph = ScatterGraph::new("Trend", "X", "Y", FigureConfig::default());
+        let mut dataset = ScatterGraphDataset::new([0, 0, 255], "Data", ScatterDotType::Circle(2));
+        for point in [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)] {
+            dataset.add_point(point);
+        }
+        graph.add_dataset(dataset);
+
+        let line = graph.add_trend_line(0, [255, 0, 0]).unwrap();
+        assert!((line.slope - 1.0).abs() < 1e-9);
+        assert!(line.intercept.abs() < 1e-9);
+        assert_eq!(graph.trend_lines, vec![line]);
+
+        // Unknown datasets and vertical point clouds don't get a line
+        assert_eq!(graph.add_trend_line(1, [255, 0, 0]), None);
+        let mut vertical = ScatterGraphDataset::new([0, 0, 255], "Data", ScatterDotType::Circle(2));
+        vertical.add_point((1.0, 0.0));
+        vertical.add_point((1.0, 5.0));
+        graph.add_dataset(vertical);
+        assert_eq!(graph.add_trend_line(1, [255, 0, 0]), None);
+        assert_eq!(graph.trend_lines.len(), 1);
+    }
+
+    #[test]
+    fn test_bubble_sizes_scale_the_drawn_circles() {
+        let mut graph = ScatterGraph::new("Bubbles", "X", "Y", FigureConfig::default());
+        let mut dataset = ScatterGraphDataset::new([0, 0, 255], "Data", ScatterDotType::Circle(3));
+        dataset.add_point((2.0, 2.0));
+        dataset.add_point((4.0, 4.0));
+        dataset.set_sizes(vec![1.0, 4.0]);
+        graph.add_dataset(dataset);
+        graph.set_x_limits(0.0, 6.0);
+        graph.set_y_limits(0.0, 6.0);
+
+        let mut svg_canvas = SvgCanvas::new(400, 300, "white", 40);
+        graph.draw_svg(&mut svg_canvas);
+        let radii: Vec<&str> = svg_canvas
+            .elements
+            .iter()
+            .filter(|element| element.starts_with("<circle"))
+            .filter_map(|element| element.split(" r=\"").nth(1)?.split('"').next())
+            .collect();
+        assert_eq!(radii, vec!["3.00", "12.00"]);
+
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut canvas);
+        let limits = graph.plot_limits();
+        // 8 pixels left of the center lies outside the small dot but inside the large one
+        let left_of_center = |point| {
+            let (px, py) = graph.pixel_position(point, limits, 400, 300, Margins::uniform(40));
+            pixel_at(&canvas, px - 8, py) == [0, 0, 255]
+        };
+        assert!(!left_of_center((2.0, 2.0)));
+        assert!(left_of_center((4.0, 4.0)));
+    }
+
+    #[test]
+    fn test_y_error_bars_extend_by_the_scaled_error() {
+        let mut graph = ScatterGraph::new("Errors", "X", "Y", FigureConfig::default());
+        let mut dataset = ScatterGraphDataset::new([0, 0, 255], "Data", ScatterDotType::Circle(2));
+        dataset.add_point((5.0, 5.0));
+        dataset.set_y_errors(vec![2.0]);
+        graph.add_dataset(dataset);
+        graph.set_x_limits(0.0, 10.0);
+        graph.set_y_limits(0.0, 10.0);
+
+        // 220 / 10 = 22 pixels per unit, so the bar spans 44 pixels on either side
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut canvas);
+        let (px, py) = graph.pixel_position(
+            (5.0, 5.0),
+            graph.plot_limits(),
+            400,
+            300,
+            Margins::uniform(40),
+        );
+        let is_blue = |x: u32, y: u32| pixel_at(&canvas, x, y) == [0, 0, 255];
+        assert!(is_blue(px, py - 44) && is_blue(px, py + 44));
+        assert!(!is_blue(px, py - 46) && !is_blue(px, py + 46));
+        // Caps are a few pixels wide
+        assert!(is_blue(px - 3, py - 44) && is_blue(px + 3, py + 44));
+    }
+
+    #[test]
+    fn test_add_mean_line_without_points() {
+        let mut graph = ScatterGraph::new("Mean", "X", "Y", FigureConfig::default());
+        assert_eq!(graph.add_mean_line(AxisType::AxisX, [255, 0, 0]), None);
+        assert!(graph.annotations.is_empty());
+    }
+
+    #[test]
+    fn test_draw_incremental_reuses_buffers() {
+        let mut graph = ScatterGraph::new("Real-time", "X", "Y", FigureConfig::default());
+        let mut dataset = ScatterGraphDataset::new([0, 0, 255], "Data", ScatterDotType::Square(3));
+        for point in [(1.0, 1.0), (5.0, 5.0), (10.0, 10.0)] {
+            dataset.add_point(point);
+        }
+        graph.add_dataset(dataset);
+        let mut canvas = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+
+        // The first draw builds the cached layout
+        graph.draw_incremental(&mut canvas);
+        let buffer_ptr = canvas.buffer.as_ptr();
+        let cache_ptr = graph.layout_cache.as_ref().unwrap().buffer.as_ptr();
+
+        for frame in 0..200 {
+            // Only the data changes, the limits stay at 0..10
+            graph.datasets[0].points[1] = ((frame % 10) as f64, ((frame * 3) % 10) as f64);
+            graph.draw_incremental(&mut canvas);
+
+            assert_eq!(canvas.buffer.as_ptr(), buffer_ptr);
+            assert_eq!(canvas.buffer.len(), 400 * 300 * 3);
+            assert_eq!(
+                graph.layout_cache.as_ref().unwrap().buffer.as_ptr(),
+                cache_ptr
+            );
+        }
+
+        // The incremental result matches a full redraw
+        let mut full = PixelCanvas::new(400, 300, [255, 255, 255], 40);
+        graph.draw(&mut full);
+        assert!(canvas.buffer == full.buffer);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_draw_matches_serial_draw() {
+        let mut graph = ScatterGraph::new("Parallel", "X", "Y", FigureConfig::default());
+        for (color, dot_type) in [
+            ([255, 0, 0], ScatterDotType::Circle(4)),
+            ([0, 0, 255], ScatterDotType::Triangle(5)),
+        ] {
+            let mut dataset = ScatterGraphDataset::new(color, "Data", dot_type);
+            // A deterministic cloud of 5000 points per dataset, overlapping across bands
+            let points: Vec<(f64, f64)> = (0..5000)
+                .map(|i| ((i * 7919 % 1000) as f64, (i * 104_729 % 997) as f64 - 300.0))
+                .collect();
+            dataset.add_points(&points);
+            dataset.set_y_errors((0..5000).map(|i| (i % 40) as f64).collect());
+            graph.add_dataset(dataset);
+        }
+
+        let mut serial = PixelCanvas::new(640, 480, [255, 255, 255], 40);
+        graph.draw(&mut serial);
+        let mut parallel = PixelCanvas::new(640, 480, [255, 255, 255], 40);
+        graph.draw_parallel(&mut parallel);
+
+        assert!(serial.buffer == parallel.buffer);
+    }
+}
@@ -0,0 +1,429 @@
+use super::{canvas::pixelcanvas::PixelCanvas, drawers::drawer::Drawer};
+
+/// Several charts arranged in a grid of rows and columns, rendered into one image.
+///
+/// Every chart is drawn on a canvas of its own, sized to its cell, and then copied into the
+/// image. Cells share the available space in proportion to the weights of their row and
+/// column, separated by a gutter.
+pub struct SubplotGrid {
+    /// Number of rows of the grid.
+    rows: usize,
+    /// Number of columns of the grid.
+    cols: usize,
+    /// The charts of the cells in row-major order; `None` leaves a cell empty.
+    cells: Vec<Option<Box<dyn Drawer>>>,
+    /// Relative heights of the rows, one per row.
+    row_weights: Vec<f64>,
+    /// Relative widths of the columns, one per column.
+    col_weights: Vec<f64>,
+    /// Space between neighbouring cells in pixels.
+    pub gutter: u32,
+    /// Margin around the plot area of every chart in pixels, reduced to a quarter of the
+    /// smaller side of cells that are too small for it.
+    pub margin: u32,
+    /// The RGB color of the gutters and empty cells.
+    pub background_color: [u8; 3],
+}
+
+impl SubplotGrid {
+    /// Creates an empty grid with equally sized cells, a gutter of 10 pixels and a margin of
+    /// 50 pixels around every chart.
+    ///
+    /// # Parameters
+    /// - `rows`: The number of rows.
+    /// - `cols`: The number of columns.
+    ///
+    /// # Returns
+    /// A new `SubplotGrid` without charts.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::configuration::figureconfig::FigureConfig;
+    /// use dataviz::figure::figuretypes::piechart::PieChart;
+    /// use dataviz::figure::layout::SubplotGrid;
+    ///
+    /// let mut pie_chart = PieChart::new("Market Share", FigureConfig::default());
+    /// pie_chart.add_slice("Product A", 60.0, [255, 0, 0]);
+    /// pie_chart.add_slice("Product B", 40.0, [0, 0, 255]);
+    ///
+    /// let mut grid = SubplotGrid::new(1, 2);
+    /// grid.set(0, 0, Box::new(pie_chart));
+    /// let path = std::env::temp_dir().join("subplot_grid_example.png");
+    /// grid.save_png(path.to_str().unwrap(), 800, 300).unwrap();
+    /// ```
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: (0..rows * cols).map(|_| None).collect(),
+            row_weights: vec![1.0; rows],
+            col_weights: vec![1.0; cols],
+            gutter: 10,
+            margin: 50,
+            background_color: [255, 255, 255],
+        }
+    }
+
+    /// Returns the number of rows of the grid.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns of the grid.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the chart of a cell.
+    ///
+    /// # Parameters
+    /// - `row`: The 0-based row of the cell.
+    /// - `col`: The 0-based column of the cell.
+    ///
+    /// # Returns
+    /// The chart, or `None` if the cell is empty or outside the grid.
+    pub fn cell(&self, row: usize, col: usize) -> Option<&dyn Drawer> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.cells[row * self.cols + col].as_deref()
+    }
+
+    /// Returns the relative heights of the rows, one per row.
+    pub fn row_weights(&self) -> &[f64] {
+        &self.row_weights
+    }
+
+    /// Returns the relative widths of the columns, one per column.
+    pub fn col_weights(&self) -> &[f64] {
+        &self.col_weights
+    }
+
+    /// Places a chart in a cell, replacing the chart that was there.
+    ///
+    /// # Parameters
+    /// - `row`: The 0-based row of the cell.
+    /// - `col`: The 0-based column of the cell.
+    /// - `chart`: The chart to draw in the cell.
+    ///
+    /// # Panics
+    /// Panics if the cell is outside the grid.
+    pub fn set(&mut self, row: usize, col: usize, chart: Box<dyn Drawer>) {
+        assert!(
+            row < self.rows && col < self.cols,
+            "cell ({}, {}) is outside the {}x{} grid",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        self.cells[row * self.cols + col] = Some(chart);
+    }
+
+    /// Sets the relative heights of the rows.
+    ///
+    /// # Parameters
+    /// - `weights`: One positive weight per row, e.g. `[2.0, 1.0]` for a top row twice as
+    ///   high as the bottom row.
+    ///
+    /// # Panics
+    /// Panics if there is not one weight per row, or if a weight is negative or not finite.
+    pub fn set_row_weights(&mut self, weights: Vec<f64>) {
+        assert_eq!(weights.len(), self.rows, "expected one weight per row");
+        assert_valid_weights(&weights);
+        self.row_weights = weights;
+    }
+
+    /// Sets the relative widths of the columns.
+    ///
+    /// # Parameters
+    /// - `weights`: One positive weight per column.
+    ///
+    /// # Panics
+    /// Panics if there is not one weight per column, or if a weight is negative or not
+    /// finite.
+    pub fn set_col_weights(&mut self, weights: Vec<f64>) {
+        assert_eq!(weights.len(), self.cols, "expected one weight per column");
+        assert_valid_weights(&weights);
+        self.col_weights = weights;
+    }
+
+    /// Sets the space between neighbouring cells.
+    ///
+    /// # Parameters
+    /// - `gutter`: The gutter in pixels.
+    pub fn set_gutter(&mut self, gutter: u32) {
+        self.gutter = gutter;
+    }
+
+    /// Sets the margin around the plot area of every chart.
+    ///
+    /// Cells too small for the margin use a quarter of their smaller side instead, so that
+    /// half of the cell is left for the plot area.
+    ///
+    /// # Parameters
+    /// - `margin`: The margin in pixels.
+    pub fn set_margin(&mut self, margin: u32) {
+        self.margin = margin;
+    }
+
+    /// Sets the color of the gutters and empty cells.
+    ///
+    /// # Parameters
+    /// - `background_color`: The RGB background color.
+    pub fn set_background_color(&mut self, background_color: [u8; 3]) {
+        self.background_color = background_color;
+    }
+
+    /// Computes the region of a cell in an image of the given size.
+    ///
+    /// # Parameters
+    /// - `row`, `col`: The 0-based cell position.
+    /// - `width`, `height`: The size of the whole image in pixels.
+    ///
+    /// # Returns
+    /// The `(x, y, width, height)` of the cell in pixels.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::layout::SubplotGrid;
+    ///
+    /// let mut grid = SubplotGrid::new(1, 2);
+    /// grid.set_col_weights(vec![1.0, 3.0]);
+    /// assert_eq!(grid.cell_bounds(0, 1, 410, 100), (110, 0, 300, 100));
+    /// ```
+    pub fn cell_bounds(
+        &self,
+        row: usize,
+        col: usize,
+        width: u32,
+        height: u32,
+    ) -> (u32, u32, u32, u32) {
+        let (x, cell_width) = split(width, self.gutter, &self.col_weights)[col];
+        let (y, cell_height) = split(height, self.gutter, &self.row_weights)[row];
+        (x, y, cell_width, cell_height)
+    }
+
+    /// Draws every chart into its cell of a new `PixelCanvas`.
+    ///
+    /// # Parameters
+    /// - `width`: The width of the image in pixels.
+    /// - `height`: The height of the image in pixels.
+    ///
+    /// # Returns
+    /// The `PixelCanvas` holding all charts.
+    pub fn render(&mut self, width: u32, height: u32) -> PixelCanvas {
+        let mut canvas = PixelCanvas::new(width, height, self.background_color, 0);
+        canvas.clear();
+
+        for index in 0..self.cells.len() {
+            let (row, col) = (index / self.cols, index % self.cols);
+            let (x, y, cell_width, cell_height) = self.cell_bounds(row, col, width, height);
+            let Some(chart) = self.cells[index].as_mut() else {
+                continue;
+            };
+            if cell_width == 0 || cell_height == 0 {
+                continue;
+            }
+
+            let margin = self.margin.min(cell_width.min(cell_height) / 4);
+            let background_color = chart.get_figure_config().color_background;
+            let mut cell = PixelCanvas::new(cell_width, cell_height, background_color, margin);
+            chart.draw(&mut cell);
+            blit(&mut canvas, &cell, x, y);
+        }
+        canvas
+    }
+
+    /// Draws every chart and saves the grid as a PNG file.
+    ///
+    /// # Parameters
+    /// - `path`: The path of the PNG file.
+    /// - `width`: The width of the image in pixels.
+    /// - `height`: The height of the image in pixels.
+    ///
+    /// # Errors
+    /// Returns an `image::ImageError` if saving fails.
+    pub fn save_png(
+        &mut self,
+        path: &str,
+        width: u32,
+        height: u32,
+    ) -> Result<(), image::ImageError> {
+        self.render(width, height).save_as_image(path)
+    }
+}
+
+/// Divides `length` pixels into spans proportional to `weights`, with `gutter` pixels between
+/// neighbouring spans.
+///
+/// The span edges are rounded from the exact proportional positions, so the spans always add
+/// up to the available length.
+///
+/// # Returns
+/// The `(start, length)` of every span.
+fn split(length: u32, gutter: u32, weights: &[f64]) -> Vec<(u32, u32)> {
+    let gutters = gutter.saturating_mul(weights.len().saturating_sub(1) as u32);
+    let available = length.saturating_sub(gutters) as f64;
+    let total: f64 = weights.iter().sum();
+
+    let mut spans = Vec::with_capacity(weights.len());
+    let mut sum = 0.0;
+    for (index, weight) in weights.iter().enumerate() {
+        let edge = |sum: f64| {
+            if total > 0.0 {
+                (available * sum / total).round() as u32
+            } else {
+                0
+            }
+        };
+        let start = edge(sum);
+        sum += weight;
+        let end = edge(sum);
+        spans.push((start + index as u32 * gutter, end.saturating_sub(start)));
+    }
+    spans
+}
+
+/// Panics with a message if a weight is negative or not finite.
+fn assert_valid_weights(weights: &[f64]) {
+    assert!(
+        weights
+            .iter()
+            .all(|weight| weight.is_finite() && *weight >= 0.0),
+        "weights must be finite and not negative, got {:?}",
+        weights
+    );
+}
+
+/// Copies `source` into `target` with its top-left corner at `(x, y)`, clipping it to
+/// `target`.
+fn blit(target: &mut PixelCanvas, source: &PixelCanvas, x: u32, y: u32) {
+    let columns = source.width.min(target.width.saturating_sub(x)) as usize;
+    for row in 0..source.height.min(target.height.saturating_sub(y)) {
+        let from = (row * source.width) as usize * 3;
+        let to = ((y + row) * target.width + x) as usize * 3;
+        target.buffer[to..to + columns * 3]
+            .copy_from_slice(&source.buffer[from..from + columns * 3]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::figure::canvas::testing::pixel_at;
+    use crate::figure::{
+        configuration::figureconfig::FigureConfig,
+        datasets::{
+            bardataset::BarDataset, cartesiangraphdataset::CartesianDataset, dataset::Dataset,
+            scattergraphdataset::ScatterGraphDataset,
+        },
+        figuretypes::{
+            cartesiangraph::CartesianGraph, groupbarchart::GroupBarChart, piechart::PieChart,
+            scattergraph::ScatterGraph,
+        },
+        utilities::{linetype::LineType, orientation::Orientation, scatterdottype::ScatterDotType},
+    };
+
+    #[test]
+    fn test_one_by_two_grid_draws_both_charts() {
+        let mut pie_chart = PieChart::new("Share", FigureConfig::default());
+        pie_chart.add_slice("A", 60.0, [255, 0, 0]);
+        pie_chart.add_slice("B", 40.0, [0, 0, 255]);
+        let mut bar_chart = GroupBarChart::new(
+            "Sales",
+            "Year",
+            "Revenue",
+            Orientation::Vertical,
+            FigureConfig::default(),
+        );
+        let mut dataset = BarDataset::new("Company", [0, 200, 0]);
+        dataset.add_data(1.0, 3.0);
+        dataset.add_data(2.0, 5.0);
+        bar_chart.add_dataset(dataset);
+
+        let mut grid = SubplotGrid::new(1, 2);
+        grid.set(0, 0, Box::new(pie_chart));
+        grid.set(0, 1, Box::new(bar_chart));
+        let canvas = grid.render(810, 300);
+
+        let colors_in = |columns: std::ops::Range<u32>| {
+            let mut colors = std::collections::HashSet::new();
+            for y in 0..canvas.height {
+                for x in columns.clone() {
+                    colors.insert(pixel_at(&canvas, x, y));
+                }
+            }
+            colors
+        };
+        let (left, gutter, right) = (colors_in(0..400), colors_in(400..410), colors_in(410..810));
+        assert!(left.contains(&[255, 0, 0]) && left.contains(&[0, 0, 255]));
+        assert!(!left.contains(&[0, 200, 0]));
+        assert!(right.contains(&[0, 200, 0]) && !right.contains(&[255, 0, 0]));
+        assert_eq!(
+            gutter.into_iter().collect::<Vec<_>>(),
+            vec![[255, 255, 255]]
+        );
+    }
+
+    #[test]
+    fn test_small_grid_shrinks_margins_to_fit_the_cells() {
+        let config = FigureConfig::default();
+        let mut grid = SubplotGrid::new(2, 2);
+        for (row, col) in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            let mut bar_chart = GroupBarChart::new(
+                "Sales",
+                "Year",
+                "Revenue",
+                Orientation::Vertical,
+                config.clone(),
+            );
+            let mut dataset = BarDataset::new("Company", [0, 200, 0]);
+            dataset.add_data(1.0, 3.0);
+            bar_chart.add_dataset(dataset);
+            grid.set(row, col, Box::new(bar_chart));
+        }
+
+        // Cells of 70 pixels can't hold the default margin of 50 pixels on both sides
+        let canvas = grid.render(150, 150);
+        assert!(canvas.buffer.chunks(3).any(|pixel| pixel == [0, 200, 0]));
+        assert!(grid.cell(1, 1).is_some() && grid.cell(2, 0).is_none());
+
+        // Down to cells of a single pixel, with charts whose labels sit left of the plot area
+        let mut pie_chart = PieChart::new("Share", config.clone());
+        pie_chart.add_slice("A", 60.0, [255, 0, 0]);
+        pie_chart.add_slice("B", 40.0, [0, 0, 255]);
+        grid.set(0, 1, Box::new(pie_chart));
+        let mut scatter = ScatterGraph::new("Spread", "X", "Y", config.clone());
+        let mut dataset = ScatterGraphDataset::new([255, 0, 0], "A", ScatterDotType::Circle(2));
+        dataset.add_points(&[(1.0, 1.0), (5.0, 3.0)]);
+        scatter.add_dataset(dataset);
+        grid.set(1, 0, Box::new(scatter));
+        let mut cartesian = CartesianGraph::new("Trend", "X", "Y", &config);
+        let mut dataset = CartesianDataset::new([0, 0, 255], "B", LineType::Solid);
+        dataset.add_points(&[(-2.0, 1.0), (4.0, -3.0)]);
+        cartesian.add_dataset(dataset);
+        grid.set(1, 1, Box::new(cartesian));
+        for size in (1..150).step_by(5) {
+            grid.render(size, size);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "weights must be finite and not negative")]
+    fn test_negative_weights_are_rejected() {
+        SubplotGrid::new(1, 2).set_col_weights(vec![1.0, -0.5]);
+    }
+
+    #[test]
+    fn test_cells_are_sized_by_their_weights() {
+        let mut grid = SubplotGrid::new(2, 3);
+        grid.set_gutter(5);
+        grid.set_row_weights(vec![2.0, 1.0]);
+        grid.set_col_weights(vec![1.0, 1.0, 2.0]);
+
+        assert_eq!(grid.cell_bounds(0, 0, 410, 305), (0, 0, 100, 200));
+        assert_eq!(grid.cell_bounds(1, 1, 410, 305), (105, 205, 100, 100));
+        assert_eq!(grid.cell_bounds(1, 2, 410, 305), (210, 205, 200, 100));
+    }
+}
@@ -0,0 +1,124 @@
+use super::{axistype::AxisType, numberformat::NumberFormat};
+
+/// Represents a reference annotation drawn on top of the data of a chart.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Annotation {
+    /// A horizontal line spanning the plot area at a fixed y-value.
+    HLine {
+        /// The y-value (in data coordinates) of the line.
+        y: f64,
+        /// The RGB color of the line and its label.
+        color: [u8; 3],
+        /// The label drawn next to the line.
+        label: String,
+    },
+    /// A vertical line spanning the plot area at a fixed x-value.
+    VLine {
+        /// The x-value (in data coordinates) of the line.
+        x: f64,
+        /// The RGB color of the line and its label.
+        color: [u8; 3],
+        /// The label drawn next to the line.
+        label: String,
+    },
+}
+
+impl Annotation {
+    /// Returns the color of the annotation.
+    pub fn color(&self) -> [u8; 3] {
+        match self {
+            Annotation::HLine { color, .. } | Annotation::VLine { color, .. } => *color,
+        }
+    }
+
+    /// Returns the label of the annotation.
+    pub fn label(&self) -> &str {
+        match self {
+            Annotation::HLine { label, .. } | Annotation::VLine { label, .. } => label,
+        }
+    }
+}
+
+/// Builds a line marking the mean of the given points on the chosen axis.
+///
+/// For `AxisType::AxisY` the line is horizontal at the average y-value, for `AxisType::AxisX`
+/// vertical at the average x-value. It is labeled with the mean formatted by `number_format`.
+///
+/// # Parameters
+/// - `points`: The `(x, y)` data points to average.
+/// - `axis`: The axis whose values are averaged.
+/// - `color`: The RGB color of the line and its label.
+/// - `number_format`: The format of the value in the label.
+///
+/// # Returns
+/// The mean and its line, or `None` if there are no points.
+pub fn mean_line<'a>(
+    points: impl Iterator<Item = &'a (f64, f64)>,
+    axis: AxisType,
+    color: [u8; 3],
+    number_format: &NumberFormat,
+) -> Option<(f64, Annotation)> {
+    let value = match axis {
+        AxisType::AxisX => mean(points.map(|&(x, _)| x)),
+        AxisType::AxisY => mean(points.map(|&(_, y)| y)),
+    }?;
+
+    let label = format!("Mean: {}", number_format.format(value));
+    let annotation = match axis {
+        AxisType::AxisX => Annotation::VLine {
+            x: value,
+            color,
+            label,
+        },
+        AxisType::AxisY => Annotation::HLine {
+            y: value,
+            color,
+            label,
+        },
+    };
+    Some((value, annotation))
+}
+
+/// Computes the arithmetic mean of a sequence of values.
+///
+/// # Parameters
+/// - `values`: The values to average.
+///
+/// # Returns
+/// The mean, or `None` if there are no values.
+pub fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(mean([1.0, 2.0, 6.0].into_iter()), Some(3.0));
+        assert_eq!(mean(std::iter::empty()), None);
+    }
+
+    #[test]
+    fn test_mean_line_is_vertical_for_x_axis() {
+        let points = [(1.0, 5.0), (3.0, 7.0)];
+        let (value, line) = mean_line(
+            points.iter(),
+            AxisType::AxisX,
+            [255, 0, 0],
+            &NumberFormat::Auto,
+        )
+        .unwrap();
+        assert_eq!(value, 2.0);
+        assert_eq!(
+            line,
+            Annotation::VLine {
+                x: 2.0,
+                color: [255, 0, 0],
+                label: "Mean: 2".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,36 @@
+/// The scale along which values are laid out on an axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AxisScale {
+    /// Values are spaced proportionally to their difference.
+    #[default]
+    Linear,
+    /// Values are spaced proportionally to the difference of their base-10 logarithms, so
+    /// every power of ten takes the same space. Only positive values can be shown.
+    Log10,
+}
+
+impl AxisScale {
+    /// Maps a value from data units to axis units.
+    ///
+    /// # Parameters
+    /// - `value`: The value in data units.
+    ///
+    /// # Returns
+    /// The value in axis units, or `None` if it can't be shown on this scale, i.e. for
+    /// non-positive values on a logarithmic axis.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::axisscale::AxisScale;
+    ///
+    /// assert_eq!(AxisScale::Linear.transform(-5.0), Some(-5.0));
+    /// assert_eq!(AxisScale::Log10.transform(100.0), Some(2.0));
+    /// assert_eq!(AxisScale::Log10.transform(0.0), None);
+    /// ```
+    pub fn transform(&self, value: f64) -> Option<f64> {
+        match self {
+            AxisScale::Linear => Some(value),
+            AxisScale::Log10 => (value > 0.0).then(|| value.log10()),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+/// Controls how bar value labels are drawn when they are wider than their bar and would
+/// overlap the neighboring bars.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BarLabelOverflow {
+    /// Labels are always drawn as they are, even if they overlap their neighbors.
+    Overlap,
+    /// Labels that don't fit are drawn vertically, one character below the other.
+    /// Labels that don't fit even then are hidden.
+    #[default]
+    Rotate,
+    /// Labels that don't fit are hidden.
+    Hide,
+}
+
+/// How a single bar value label is drawn, as decided by [`BarLabelOverflow::layout`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BarLabelLayout {
+    /// The label is drawn as regular horizontal text.
+    Horizontal,
+    /// The label is drawn vertically, one character below the other.
+    Vertical,
+    /// The label is not drawn.
+    Hidden,
+}
+
+impl BarLabelOverflow {
+    /// Decides how a bar value label is drawn.
+    ///
+    /// All extents are measured across the bar, i.e. widths for vertical bars and heights for
+    /// horizontal bars.
+    ///
+    /// # Parameters
+    /// - `label_extent`: The extent of the label drawn horizontally, in pixels.
+    /// - `rotated_extent`: The extent of the label drawn vertically, in pixels.
+    /// - `bar_extent`: The extent of the bar, in pixels.
+    ///
+    /// # Returns
+    /// The `BarLabelLayout` to draw the label with.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::barlabeloverflow::{BarLabelLayout, BarLabelOverflow};
+    ///
+    /// assert_eq!(BarLabelOverflow::Rotate.layout(20, 6, 30), BarLabelLayout::Horizontal);
+    /// assert_eq!(BarLabelOverflow::Rotate.layout(40, 6, 30), BarLabelLayout::Vertical);
+    /// assert_eq!(BarLabelOverflow::Hide.layout(40, 6, 30), BarLabelLayout::Hidden);
+    /// ```
+    pub fn layout(self, label_extent: u32, rotated_extent: u32, bar_extent: u32) -> BarLabelLayout {
+        if label_extent <= bar_extent {
+            return BarLabelLayout::Horizontal;
+        }
+        match self {
+            BarLabelOverflow::Overlap => BarLabelLayout::Horizontal,
+            BarLabelOverflow::Rotate if rotated_extent <= bar_extent => BarLabelLayout::Vertical,
+            BarLabelOverflow::Rotate | BarLabelOverflow::Hide => BarLabelLayout::Hidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_labels_that_fit_are_drawn_horizontally() {
+        for policy in [
+            BarLabelOverflow::Overlap,
+            BarLabelOverflow::Rotate,
+            BarLabelOverflow::Hide,
+        ] {
+            assert_eq!(policy.layout(30, 6, 30), BarLabelLayout::Horizontal);
+        }
+    }
+
+    #[test]
+    fn test_rotated_labels_that_still_overflow_are_hidden() {
+        assert_eq!(
+            BarLabelOverflow::Rotate.layout(40, 35, 30),
+            BarLabelLayout::Hidden
+        );
+        assert_eq!(
+            BarLabelOverflow::Overlap.layout(40, 35, 30),
+            BarLabelLayout::Horizontal
+        );
+    }
+}
@@ -0,0 +1,10 @@
+/// Controls how the bars of several datasets are arranged within a category.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BarMode {
+    /// The bars of the datasets are drawn side by side.
+    #[default]
+    Grouped,
+    /// The bars of the datasets are drawn on top of each other, so the stack shows the total
+    /// of the category. Negative values are stacked separately below zero.
+    Stacked,
+}
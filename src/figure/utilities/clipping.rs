@@ -0,0 +1,149 @@
+// Region codes used by the Cohen–Sutherland algorithm
+const INSIDE: u8 = 0;
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const BOTTOM: u8 = 4;
+const TOP: u8 = 8;
+
+/// An axis-aligned rectangle that line segments are clipped against.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClipRect {
+    /// Minimum x-coordinate (inclusive).
+    pub x_min: f64,
+    /// Minimum y-coordinate (inclusive).
+    pub y_min: f64,
+    /// Maximum x-coordinate (inclusive).
+    pub x_max: f64,
+    /// Maximum y-coordinate (inclusive).
+    pub y_max: f64,
+}
+
+impl ClipRect {
+    /// Creates a new clipping rectangle.
+    ///
+    /// # Parameters
+    /// - `x_min`, `y_min`: The minimum corner of the rectangle.
+    /// - `x_max`, `y_max`: The maximum corner of the rectangle.
+    pub fn new(x_min: f64, y_min: f64, x_max: f64, y_max: f64) -> Self {
+        Self {
+            x_min,
+            y_min,
+            x_max,
+            y_max,
+        }
+    }
+
+    fn region_code(&self, x: f64, y: f64) -> u8 {
+        let mut code = INSIDE;
+        if x < self.x_min {
+            code |= LEFT;
+        } else if x > self.x_max {
+            code |= RIGHT;
+        }
+        if y < self.y_min {
+            code |= TOP;
+        } else if y > self.y_max {
+            code |= BOTTOM;
+        }
+        code
+    }
+
+    /// Clips a line segment to the rectangle using the Cohen–Sutherland algorithm.
+    ///
+    /// # Parameters
+    /// - `x1`, `y1`: The start point of the segment.
+    /// - `x2`, `y2`: The end point of the segment.
+    ///
+    /// # Returns
+    /// The visible part of the segment as `(x1, y1, x2, y2)`, or `None` if the segment lies
+    /// completely outside the rectangle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::clipping::ClipRect;
+    ///
+    /// let rect = ClipRect::new(0.0, 0.0, 10.0, 10.0);
+    /// assert_eq!(rect.clip_line(5.0, 5.0, 20.0, 5.0), Some((5.0, 5.0, 10.0, 5.0)));
+    /// assert_eq!(rect.clip_line(20.0, 5.0, 30.0, 5.0), None);
+    /// ```
+    pub fn clip_line(
+        &self,
+        mut x1: f64,
+        mut y1: f64,
+        mut x2: f64,
+        mut y2: f64,
+    ) -> Option<(f64, f64, f64, f64)> {
+        let mut code1 = self.region_code(x1, y1);
+        let mut code2 = self.region_code(x2, y2);
+
+        loop {
+            if code1 | code2 == INSIDE {
+                // Both points inside
+                return Some((x1, y1, x2, y2));
+            }
+            if code1 & code2 != INSIDE {
+                // Both points share an outside region, so the segment is invisible
+                return None;
+            }
+
+            // Move the outside point onto the boundary it crosses
+            let code_out = if code1 != INSIDE { code1 } else { code2 };
+            let (x, y) = if code_out & TOP != 0 {
+                (x1 + (x2 - x1) * (self.y_min - y1) / (y2 - y1), self.y_min)
+            } else if code_out & BOTTOM != 0 {
+                (x1 + (x2 - x1) * (self.y_max - y1) / (y2 - y1), self.y_max)
+            } else if code_out & RIGHT != 0 {
+                (self.x_max, y1 + (y2 - y1) * (self.x_max - x1) / (x2 - x1))
+            } else {
+                (self.x_min, y1 + (y2 - y1) * (self.x_min - x1) / (x2 - x1))
+            };
+
+            if code_out == code1 {
+                x1 = x;
+                y1 = y;
+                code1 = self.region_code(x1, y1);
+            } else {
+                x2 = x;
+                y2 = y;
+                code2 = self.region_code(x2, y2);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clip_line_inside_is_unchanged() {
+        let rect = ClipRect::new(0.0, 0.0, 100.0, 100.0);
+        assert_eq!(
+            rect.clip_line(10.0, 20.0, 30.0, 40.0),
+            Some((10.0, 20.0, 30.0, 40.0))
+        );
+    }
+
+    #[test]
+    fn test_clip_line_to_far_outside() {
+        let rect = ClipRect::new(0.0, 0.0, 100.0, 100.0);
+        let (x1, y1, x2, y2) = rect.clip_line(50.0, 50.0, 1050.0, 550.0).unwrap();
+        assert_eq!((x1, y1), (50.0, 50.0));
+        assert_eq!((x2, y2), (100.0, 75.0));
+    }
+
+    #[test]
+    fn test_clip_line_crossing_both_sides() {
+        let rect = ClipRect::new(0.0, 0.0, 100.0, 100.0);
+        assert_eq!(
+            rect.clip_line(-50.0, 50.0, 150.0, 50.0),
+            Some((0.0, 50.0, 100.0, 50.0))
+        );
+    }
+
+    #[test]
+    fn test_clip_line_outside_is_rejected() {
+        let rect = ClipRect::new(0.0, 0.0, 100.0, 100.0);
+        assert_eq!(rect.clip_line(-50.0, -10.0, 150.0, -20.0), None);
+    }
+}
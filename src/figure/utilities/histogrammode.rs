@@ -0,0 +1,59 @@
+/// Controls which quantity the bars of a histogram show.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum HistogramMode {
+    /// Bars show the number of values in each bin.
+    #[default]
+    Count,
+    /// Bars show the probability density, so the bar areas sum to one.
+    Density,
+    /// Bars show the number of values in the bin and all bins before it.
+    Cumulative,
+}
+
+impl HistogramMode {
+    /// Transforms the raw counts of a series of bins into the values shown by this mode.
+    ///
+    /// # Parameters
+    /// - `edges`: The bin edges, one more than the number of bins.
+    /// - `counts`: The number of values in each bin.
+    ///
+    /// # Returns
+    /// One value per bin. Densities are zero if there are no values at all.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::histogrammode::HistogramMode;
+    ///
+    /// let edges = [0.0, 1.0, 3.0];
+    /// let counts = [2.0, 2.0];
+    /// assert_eq!(HistogramMode::Density.apply(&edges, &counts), vec![0.5, 0.25]);
+    /// assert_eq!(HistogramMode::Cumulative.apply(&edges, &counts), vec![2.0, 4.0]);
+    /// ```
+    pub fn apply(&self, edges: &[f64], counts: &[f64]) -> Vec<f64> {
+        match self {
+            HistogramMode::Count => counts.to_vec(),
+            HistogramMode::Density => {
+                let total: f64 = counts.iter().sum();
+                counts
+                    .iter()
+                    .zip(edges.windows(2))
+                    .map(|(count, bin)| {
+                        let width = bin[1] - bin[0];
+                        if total > 0.0 && width > 0.0 {
+                            count / (total * width)
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect()
+            }
+            HistogramMode::Cumulative => counts
+                .iter()
+                .scan(0.0, |sum, count| {
+                    *sum += count;
+                    Some(*sum)
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// Controls what interactive windows show for the data under the mouse.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum HoverMode {
+    /// A line from the cursor to the nearest data point, with a tooltip for that point.
+    #[default]
+    Nearest,
+    /// Dashed guides across the plot area through the data x-value nearest to the cursor, with
+    /// a tooltip listing the y-values of every dataset at that x.
+    ///
+    /// Plots without crosshair support show the `Nearest` hover instead.
+    Crosshair,
+}
@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+
+/// Number of segments each segment of the data is divided into by smooth interpolation.
+const SUBDIVISIONS: usize = 8;
+
+/// Controls how consecutive data points of a line are connected.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Interpolation {
+    /// Points are connected by straight segments.
+    #[default]
+    Linear,
+    /// Points are connected by a smooth Catmull–Rom spline passing through every point.
+    CatmullRom,
+    /// Points are connected by steps that change to the next value at the start of the
+    /// interval: a vertical segment followed by a horizontal one.
+    StepPre,
+    /// Points are connected by steps that keep the value until the end of the interval:
+    /// a horizontal segment followed by a vertical one.
+    StepPost,
+}
+
+impl Interpolation {
+    /// Returns the points to draw a line through `points` with.
+    ///
+    /// For `Interpolation::CatmullRom`, every segment is divided into several shorter segments
+    /// along a uniform Catmull–Rom spline. The first and last points are duplicated to get the
+    /// control points of the outer segments. For the step variants, a corner point is inserted
+    /// between every two consecutive points.
+    ///
+    /// # Parameters
+    /// - `points`: The data points, in drawing order.
+    ///
+    /// # Returns
+    /// The points of the polyline to draw, or `points` itself if it is drawn with straight
+    /// segments.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::interpolation::Interpolation;
+    ///
+    /// let points = [(0.0, 0.0), (1.0, 1.0), (2.0, 0.0)];
+    /// assert_eq!(Interpolation::Linear.apply(&points).len(), 3);
+    /// let smooth = Interpolation::CatmullRom.apply(&points);
+    /// assert_eq!(smooth.len(), 17);
+    /// assert_eq!(smooth[8], (1.0, 1.0));
+    /// assert_eq!(
+    ///     Interpolation::StepPost.apply(&points[..2]).as_ref(),
+    ///     &[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]
+    /// );
+    /// ```
+    pub fn apply<'a>(&self, points: &'a [(f64, f64)]) -> Cow<'a, [(f64, f64)]> {
+        if points.len() < 2 {
+            return Cow::Borrowed(points);
+        }
+
+        match self {
+            Interpolation::Linear => Cow::Borrowed(points),
+            // Two points are connected by a straight segment either way
+            Interpolation::CatmullRom if points.len() < 3 => Cow::Borrowed(points),
+            Interpolation::CatmullRom => Cow::Owned(catmull_rom_points(points)),
+            Interpolation::StepPre => Cow::Owned(step_points(points, |(x1, _), (_, y2)| (x1, y2))),
+            Interpolation::StepPost => Cow::Owned(step_points(points, |(_, y1), (x2, _)| (x2, y1))),
+        }
+    }
+}
+
+/// Divides every segment between `points` along a uniform Catmull–Rom spline.
+fn catmull_rom_points(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let last = points.len() - 1;
+    let mut smooth = Vec::with_capacity(last * SUBDIVISIONS + 1);
+    for i in 0..last {
+        let p0 = points[i.saturating_sub(1)];
+        let (p1, p2) = (points[i], points[i + 1]);
+        let p3 = points[(i + 2).min(last)];
+        smooth.extend(
+            (0..SUBDIVISIONS)
+                .map(|step| catmull_rom(p0, p1, p2, p3, step as f64 / SUBDIVISIONS as f64)),
+        );
+    }
+    smooth.push(points[last]);
+    smooth
+}
+
+/// Inserts the corner returned by `corner` between every two consecutive `points`.
+fn step_points(
+    points: &[(f64, f64)],
+    corner: impl Fn((f64, f64), (f64, f64)) -> (f64, f64),
+) -> Vec<(f64, f64)> {
+    let mut steps = Vec::with_capacity(points.len() * 2 - 1);
+    steps.push(points[0]);
+    for window in points.windows(2) {
+        steps.push(corner(window[0], window[1]));
+        steps.push(window[1]);
+    }
+    steps
+}
+
+/// Evaluates the uniform Catmull–Rom segment between `p1` and `p2` at `t` in `0.0..=1.0`.
+fn catmull_rom(
+    p0: (f64, f64),
+    p1: (f64, f64),
+    p2: (f64, f64),
+    p3: (f64, f64),
+    t: f64,
+) -> (f64, f64) {
+    let (t2, t3) = (t * t, t * t * t);
+    let blend = |a: f64, b: f64, c: f64, d: f64| {
+        0.5 * (2.0 * b
+            + (c - a) * t
+            + (2.0 * a - 5.0 * b + 4.0 * c - d) * t2
+            + (3.0 * b - a - 3.0 * c + d) * t3)
+    };
+    (blend(p0.0, p1.0, p2.0, p3.0), blend(p0.1, p1.1, p2.1, p3.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catmull_rom_passes_through_every_point() {
+        let points = [(0.0, 1.0), (1.0, 3.0), (2.0, 2.0), (4.0, 5.0)];
+        let smooth = Interpolation::CatmullRom.apply(&points);
+        assert_eq!(smooth.len(), 3 * SUBDIVISIONS + 1);
+        for (i, &point) in points.iter().enumerate() {
+            assert_eq!(smooth[i * SUBDIVISIONS], point);
+        }
+    }
+
+    #[test]
+    fn test_step_pre_changes_value_at_interval_start() {
+        let points = [(0.0, 1.0), (2.0, 3.0), (3.0, 0.0)];
+        assert_eq!(
+            Interpolation::StepPre.apply(&points).as_ref(),
+            &[(0.0, 1.0), (0.0, 3.0), (2.0, 3.0), (2.0, 0.0), (3.0, 0.0)]
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::figure::canvas::margins::Margins;
+
+/// The corner of the figure the legend is drawn in.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LegendPosition {
+    /// Above the plot area, aligned to its left edge.
+    TopLeft,
+    /// Above the plot area, aligned to its right edge.
+    TopRight,
+    /// Below the plot area, aligned to its left edge.
+    #[default]
+    BottomLeft,
+    /// Below the plot area, aligned to its right edge.
+    BottomRight,
+    /// No legend is drawn.
+    None,
+}
+
+impl LegendPosition {
+    /// Returns the top-left corner of the first legend row.
+    ///
+    /// Legends at the top end just above the plot area, legends at the bottom start just below
+    /// it. Right-aligned legends that are wider than the plot area start at its left edge.
+    ///
+    /// # Parameters
+    /// - `canvas_size`: The `(width, height)` of the canvas in pixels.
+    /// - `margins`: The margins around the plot area in pixels.
+    /// - `legend_size`: The `(width, height)` of a single legend row in pixels.
+    ///
+    /// # Returns
+    /// The `(x, y)` of the first row, or `None` for `LegendPosition::None`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::canvas::margins::Margins;
+    /// use dataviz::figure::utilities::legendposition::LegendPosition;
+    ///
+    /// let margins = Margins::uniform(40);
+    /// assert_eq!(LegendPosition::BottomLeft.origin((400, 300), margins, (100, 30)), Some((40, 260)));
+    /// assert_eq!(LegendPosition::TopRight.origin((400, 300), margins, (100, 30)), Some((260, 10)));
+    /// assert_eq!(LegendPosition::None.origin((400, 300), margins, (100, 30)), None);
+    /// ```
+    pub fn origin(
+        &self,
+        canvas_size: (u32, u32),
+        margins: Margins,
+        legend_size: (u32, u32),
+    ) -> Option<(u32, u32)> {
+        let (width, height) = canvas_size;
+        let plot_width = width.saturating_sub(margins.horizontal());
+        let left = margins.left;
+        let right = width
+            .saturating_sub(margins.right)
+            .saturating_sub(legend_size.0.min(plot_width));
+        let top = margins.top.saturating_sub(legend_size.1);
+        let bottom = height.saturating_sub(margins.bottom);
+
+        match self {
+            LegendPosition::TopLeft => Some((left, top)),
+            LegendPosition::TopRight => Some((right, top)),
+            LegendPosition::BottomLeft => Some((left, bottom)),
+            LegendPosition::BottomRight => Some((right, bottom)),
+            LegendPosition::None => None,
+        }
+    }
+
+    /// Returns whether additional legend rows are stacked below the first one.
+    ///
+    /// Rows of legends at the top grow downwards, rows of legends at the bottom grow upwards.
+    pub fn rows_grow_downwards(&self) -> bool {
+        matches!(self, LegendPosition::TopLeft | LegendPosition::TopRight)
+    }
+}
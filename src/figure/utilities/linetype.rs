@@ -1,10 +1,11 @@
 /// Represents the style of a line in a graph or chart.
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LineType {
     /// A solid line with no gaps.
     Solid,
-    /// A thick solid line with no gaps 
-    /// - The line thickness fixed at 5 pixels (+2 and -2 of (x,y) point)
+    /// A thick solid line with no gaps
+    /// - The line is 5 pixels wide, measured perpendicular to the line direction
     SolidThick,
     /// A dashed line with configurable dash length.
     /// - The `u32` value specifies the length of each dash in pixels.
@@ -0,0 +1,70 @@
+/// Controls how numeric values are rendered in axis labels and tooltips.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumberFormat {
+    /// Integral values are shown without decimals (`5`), all other values with two decimals (`5.25`).
+    #[default]
+    Auto,
+    /// Values are always shown with a fixed number of decimals.
+    /// - The `usize` value specifies the number of decimals.
+    Fixed(usize),
+}
+
+impl NumberFormat {
+    /// Formats a value according to the selected number format.
+    ///
+    /// # Parameters
+    /// - `value`: The value to format.
+    ///
+    /// # Returns
+    /// The formatted value as a `String`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::numberformat::NumberFormat;
+    ///
+    /// assert_eq!(NumberFormat::Auto.format(5.0), "5");
+    /// assert_eq!(NumberFormat::Auto.format(2.5), "2.50");
+    /// assert_eq!(NumberFormat::Fixed(1).format(5.0), "5.0");
+    /// ```
+    pub fn format(&self, value: f64) -> String {
+        match self {
+            NumberFormat::Auto => {
+                // Round to the displayed precision first so values such as 4.9999999 are treated as integral
+                let rounded = (value * 100.0).round() / 100.0;
+                if rounded.is_finite() && rounded.fract() == 0.0 {
+                    // Adding 0.0 turns -0.0 into 0.0
+                    format!("{}", rounded + 0.0)
+                } else {
+                    format!("{:.2}", value)
+                }
+            }
+            NumberFormat::Fixed(decimals) => format!("{:.*}", decimals, value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_format_drops_decimals_for_integers() {
+        assert_eq!(NumberFormat::Auto.format(5.0), "5");
+        assert_eq!(NumberFormat::Auto.format(-20.0), "-20");
+        assert_eq!(NumberFormat::Auto.format(-0.0), "0");
+        assert_eq!(NumberFormat::Auto.format(4.999999), "5");
+    }
+
+    #[test]
+    fn test_auto_format_keeps_decimals_for_fractions() {
+        assert_eq!(NumberFormat::Auto.format(2.5), "2.50");
+        assert_eq!(NumberFormat::Auto.format(0.126), "0.13");
+    }
+
+    #[test]
+    fn test_fixed_format() {
+        assert_eq!(NumberFormat::Fixed(2).format(5.0), "5.00");
+        assert_eq!(NumberFormat::Fixed(0).format(5.4), "5");
+    }
+}
@@ -0,0 +1,156 @@
+use std::{error::Error, fmt, num::ParseFloatError};
+
+/// Describes how numbers are written in imported data (e.g. CSV files).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum NumberLocale {
+    /// `.` as decimal separator and `,` for thousands grouping (`1,234.56`).
+    #[default]
+    Standard,
+    /// `,` as decimal separator and `.` for thousands grouping (`1.234,56`).
+    European,
+}
+
+/// An error while parsing a number written in a [`NumberLocale`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseNumberError {
+    /// A grouping separator does not separate groups of three digits, e.g. `1,5` in
+    /// `NumberLocale::Standard`.
+    InvalidGrouping,
+    /// The text is not a number once the separators are normalized.
+    InvalidNumber(ParseFloatError),
+}
+
+impl fmt::Display for ParseNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseNumberError::InvalidGrouping => write!(f, "invalid digit grouping"),
+            ParseNumberError::InvalidNumber(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for ParseNumberError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseNumberError::InvalidGrouping => None,
+            ParseNumberError::InvalidNumber(error) => Some(error),
+        }
+    }
+}
+
+impl From<ParseFloatError> for ParseNumberError {
+    fn from(error: ParseFloatError) -> Self {
+        ParseNumberError::InvalidNumber(error)
+    }
+}
+
+impl NumberLocale {
+    /// Returns the decimal separator of the locale.
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            NumberLocale::Standard => '.',
+            NumberLocale::European => ',',
+        }
+    }
+
+    /// Returns the thousands grouping separator of the locale.
+    pub fn grouping_separator(&self) -> char {
+        match self {
+            NumberLocale::Standard => ',',
+            NumberLocale::European => '.',
+        }
+    }
+
+    /// Parses a number written in this locale.
+    ///
+    /// Grouping separators are only accepted in the integer part between groups of three
+    /// digits (`1,234,567` but not `1,5` or `12,34`). They are removed and the decimal separator
+    /// is normalized to `.` before the text is parsed as `f64`. Surrounding whitespace is
+    /// ignored.
+    ///
+    /// # Parameters
+    /// - `text`: The number as written in the data.
+    ///
+    /// # Returns
+    /// The parsed value.
+    ///
+    /// # Errors
+    /// Returns `ParseNumberError::InvalidGrouping` if a grouping separator is misplaced, and
+    /// `ParseNumberError::InvalidNumber` if the normalized text is not a number.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::numberlocale::NumberLocale;
+    ///
+    /// assert_eq!(NumberLocale::European.parse("1.234,56"), Ok(1234.56));
+    /// assert_eq!(NumberLocale::Standard.parse("1,234.56"), Ok(1234.56));
+    /// assert!(NumberLocale::Standard.parse("1,5").is_err());
+    /// ```
+    pub fn parse(&self, text: &str) -> Result<f64, ParseNumberError> {
+        let grouping = self.grouping_separator();
+        let decimal = self.decimal_separator();
+        let text = text.trim();
+
+        let (integer, fraction) = text.split_once(decimal).unwrap_or((text, ""));
+        if fraction.contains(grouping) || !is_grouped_correctly(integer, grouping) {
+            return Err(ParseNumberError::InvalidGrouping);
+        }
+
+        let normalized: String = text
+            .chars()
+            .filter(|&c| c != grouping)
+            .map(|c| if c == decimal { '.' } else { c })
+            .collect();
+        Ok(normalized.parse()?)
+    }
+}
+
+/// Checks that the grouping separators of an integer part (with an optional sign) separate a
+/// leading group of one to three digits from groups of exactly three digits.
+fn is_grouped_correctly(integer: &str, grouping: char) -> bool {
+    if !integer.contains(grouping) {
+        return true;
+    }
+    let digits = integer.trim_start_matches(['+', '-']);
+    digits.split(grouping).enumerate().all(|(index, group)| {
+        let valid_length = if index == 0 {
+            (1..=3).contains(&group.len())
+        } else {
+            group.len() == 3
+        };
+        valid_length && group.chars().all(|c| c.is_ascii_digit())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_european_number() {
+        assert_eq!(NumberLocale::European.parse("1.234,56"), Ok(1234.56));
+        assert_eq!(NumberLocale::European.parse(" -0,5 "), Ok(-0.5));
+    }
+
+    #[test]
+    fn test_parse_standard_number() {
+        assert_eq!(NumberLocale::Standard.parse("1,234.56"), Ok(1234.56));
+        assert_eq!(NumberLocale::Standard.parse("42"), Ok(42.0));
+        assert!(NumberLocale::Standard.parse("abc").is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_misplaced_grouping_separators() {
+        assert_eq!(
+            NumberLocale::Standard.parse("1,5"),
+            Err(ParseNumberError::InvalidGrouping)
+        );
+        assert_eq!(
+            NumberLocale::European.parse("1.5"),
+            Err(ParseNumberError::InvalidGrouping)
+        );
+        assert!(NumberLocale::Standard.parse("12,34.5").is_err());
+        assert!(NumberLocale::Standard.parse("1.234,5").is_err());
+        assert_eq!(NumberLocale::Standard.parse("-1,234,567"), Ok(-1234567.0));
+    }
+}
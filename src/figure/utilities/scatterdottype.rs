@@ -14,3 +14,35 @@ pub enum ScatterDotType {
     /// - The `u32` value specifies the base width of the triangle in pixels.
     Triangle(u32),
 }
+
+impl ScatterDotType {
+    /// Returns the size of the dot in pixels (radius, side length, thickness or base width).
+    pub fn size(&self) -> u32 {
+        match self {
+            ScatterDotType::Circle(size)
+            | ScatterDotType::Square(size)
+            | ScatterDotType::Cross(size)
+            | ScatterDotType::Triangle(size) => *size,
+        }
+    }
+
+    /// Returns a dot of the same shape with a different size.
+    ///
+    /// # Parameters
+    /// - `size`: The new size of the dot in pixels.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::scatterdottype::ScatterDotType;
+    ///
+    /// assert_eq!(ScatterDotType::Circle(3).with_size(6).size(), 6);
+    /// ```
+    pub fn with_size(&self, size: u32) -> Self {
+        match self {
+            ScatterDotType::Circle(_) => ScatterDotType::Circle(size),
+            ScatterDotType::Square(_) => ScatterDotType::Square(size),
+            ScatterDotType::Cross(_) => ScatterDotType::Cross(size),
+            ScatterDotType::Triangle(_) => ScatterDotType::Triangle(size),
+        }
+    }
+}
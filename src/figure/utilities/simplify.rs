@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+
+/// Simplifies a polyline with the Ramer–Douglas–Peucker algorithm.
+///
+/// Points closer than `tolerance` to the simplified line are dropped, which keeps the shape
+/// of noisy data while reducing the number of segments, e.g. to keep exported SVGs small.
+/// The first and last points are always kept.
+///
+/// # Parameters
+/// - `points`: The points of the polyline, in drawing order.
+/// - `tolerance`: The maximum distance, in data units, of a dropped point from the simplified
+///   line, or `None` to keep all points.
+///
+/// # Returns
+/// The simplified points, or `points` itself if no simplification is applied.
+///
+/// # Example
+/// ```rust
+/// use dataviz::figure::utilities::simplify::simplify;
+///
+/// let points = [(0.0, 0.0), (1.0, 0.01), (2.0, -0.01), (3.0, 0.0)];
+/// assert_eq!(simplify(&points, Some(0.1)).as_ref(), &[(0.0, 0.0), (3.0, 0.0)]);
+/// assert_eq!(simplify(&points, None).len(), 4);
+/// ```
+pub fn simplify(points: &[(f64, f64)], tolerance: Option<f64>) -> Cow<'_, [(f64, f64)]> {
+    let tolerance = match tolerance {
+        Some(tolerance) if points.len() > 2 => tolerance,
+        _ => return Cow::Borrowed(points),
+    };
+
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    // Ranges still to be checked, handled iteratively so long series can't overflow the stack
+    let mut ranges = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = ranges.pop() {
+        let (farthest, distance) = (start + 1..end)
+            .map(|i| (i, distance_to_line(points[i], points[start], points[end])))
+            .fold((start, 0.0), |best, candidate| {
+                if candidate.1 > best.1 {
+                    candidate
+                } else {
+                    best
+                }
+            });
+
+        if distance > tolerance {
+            keep[farthest] = true;
+            ranges.push((start, farthest));
+            ranges.push((farthest, end));
+        }
+    }
+
+    Cow::Owned(
+        points
+            .iter()
+            .zip(keep)
+            .filter_map(|(&point, keep)| keep.then_some(point))
+            .collect(),
+    )
+}
+
+/// Returns the distance of `point` to the line through `start` and `end`.
+fn distance_to_line(point: (f64, f64), start: (f64, f64), end: (f64, f64)) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length = dx.hypot(dy);
+    if length == 0.0 {
+        return (point.0 - start.0).hypot(point.1 - start.1);
+    }
+    (dy * (point.0 - start.0) - dx * (point.1 - start.1)).abs() / length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corner_beyond_tolerance_is_kept() {
+        let points = [(0.0, 0.0), (1.0, 0.0), (2.0, 2.0), (3.0, 0.0), (4.0, 0.0)];
+        assert_eq!(
+            simplify(&points, Some(0.5)).as_ref(),
+            &[(0.0, 0.0), (1.0, 0.0), (2.0, 2.0), (3.0, 0.0), (4.0, 0.0)]
+        );
+        assert_eq!(
+            simplify(&points, Some(2.5)).as_ref(),
+            &[(0.0, 0.0), (4.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn test_short_lines_are_unchanged() {
+        let points = [(0.0, 0.0), (1.0, 5.0)];
+        assert!(matches!(simplify(&points, Some(1.0)), Cow::Borrowed(_)));
+    }
+}
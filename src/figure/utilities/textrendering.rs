@@ -0,0 +1,38 @@
+/// Controls how text is drawn on a `PixelCanvas`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextRendering {
+    /// Text is rasterized with the configured font.
+    #[default]
+    Normal,
+    /// Text is replaced by a filled rectangle of the text's size.
+    ///
+    /// The size is computed from the number of characters and the font size only, so the
+    /// output does not depend on the configured font or on how it is rasterized. Use it for
+    /// golden-image tests that should give identical results on every system.
+    BoxPlaceholder,
+}
+
+impl TextRendering {
+    /// Returns the size of the placeholder box drawn for `text` in `BoxPlaceholder` mode.
+    ///
+    /// Each character is half the font size wide, the box is as high as the font size.
+    ///
+    /// # Parameters
+    /// - `text`: The text to measure.
+    /// - `font_size`: The font size in pixels.
+    ///
+    /// # Returns
+    /// The `(width, height)` of the box in pixels.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::textrendering::TextRendering;
+    ///
+    /// assert_eq!(TextRendering::placeholder_size("100", 10.0), (15, 10));
+    /// ```
+    pub fn placeholder_size(text: &str, font_size: f32) -> (u32, u32) {
+        let width = text.chars().count() as f32 * font_size * 0.5;
+        (width.ceil() as u32, font_size.ceil() as u32)
+    }
+}
@@ -0,0 +1,122 @@
+use super::numberformat::NumberFormat;
+
+/// SI prefixes from 10^-12 to 10^12, in steps of 10^3.
+const SI_PREFIXES: [&str; 9] = ["p", "n", "µ", "m", "", "k", "M", "G", "T"];
+
+/// Controls how values are rendered in axis tick labels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TickFormat {
+    /// Tick labels use the figure's `number_format`, like tooltips do.
+    #[default]
+    Auto,
+    /// Values are shown with a fixed number of decimals (`1500.0`).
+    /// - The `usize` value specifies the number of decimals.
+    Fixed(usize),
+    /// Values are shown in scientific notation (`1.50e3`).
+    /// - The `usize` value specifies the number of decimals of the mantissa.
+    Scientific(usize),
+    /// Values are rounded to the nearest integer (`1500`), e.g. for counts or categories.
+    Integer,
+    /// Values are scaled to an SI prefix from `p` to `T` (`1.5k`, `20M`, `2.5m`).
+    SiPrefix,
+}
+
+impl TickFormat {
+    /// Formats a tick value according to the selected tick format.
+    ///
+    /// `TickFormat::Auto` formats like `NumberFormat::Auto` here, use
+    /// [`FigureConfig::format_tick`](crate::figure::configuration::figureconfig::FigureConfig::format_tick)
+    /// to honor the figure's `number_format`.
+    ///
+    /// # Parameters
+    /// - `value`: The value to format.
+    ///
+    /// # Returns
+    /// The formatted value as a `String`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::tickformat::TickFormat;
+    ///
+    /// assert_eq!(TickFormat::Fixed(1).format(1500.0), "1500.0");
+    /// assert_eq!(TickFormat::Scientific(2).format(1500.0), "1.50e3");
+    /// assert_eq!(TickFormat::Integer.format(1499.6), "1500");
+    /// assert_eq!(TickFormat::SiPrefix.format(1500.0), "1.5k");
+    /// ```
+    pub fn format(&self, value: f64) -> String {
+        match self {
+            TickFormat::Auto => NumberFormat::Auto.format(value),
+            TickFormat::Fixed(decimals) => NumberFormat::Fixed(*decimals).format(value),
+            TickFormat::Scientific(decimals) => format!("{:.*e}", decimals, value),
+            // Adding 0.0 turns -0.0 into 0.0
+            TickFormat::Integer => format!("{:.0}", value.round() + 0.0),
+            TickFormat::SiPrefix => si_prefix(value),
+        }
+    }
+}
+
+/// Formats `value` with at most two decimals and the closest SI prefix.
+fn si_prefix(value: f64) -> String {
+    if !value.is_finite() || value == 0.0 {
+        return format!("{}", value + 0.0);
+    }
+
+    let mut group = ((value.abs().log10() / 3.0).floor() as i32).clamp(-4, 4);
+    let mut mantissa = round_to_hundredths(value / 10f64.powi(group * 3));
+    // Rounding can carry the mantissa into the next prefix, e.g. 999.999 to 1000
+    if mantissa.abs() >= 1000.0 && group < 4 {
+        group += 1;
+        mantissa = round_to_hundredths(value / 10f64.powi(group * 3));
+    }
+
+    let digits = format!("{:.2}", mantissa);
+    let digits = digits.trim_end_matches('0').trim_end_matches('.');
+    format!("{}{}", digits, SI_PREFIXES[(group + 4) as usize])
+}
+
+fn round_to_hundredths(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_format_matches_number_format_auto() {
+        assert_eq!(TickFormat::Auto.format(5.0), "5");
+        assert_eq!(TickFormat::Auto.format(2.5), "2.50");
+    }
+
+    #[test]
+    fn test_fixed_format() {
+        assert_eq!(TickFormat::Fixed(2).format(100000.0), "100000.00");
+        assert_eq!(TickFormat::Fixed(0).format(2.4), "2");
+    }
+
+    #[test]
+    fn test_scientific_format() {
+        assert_eq!(TickFormat::Scientific(2).format(100000.0), "1.00e5");
+        assert_eq!(TickFormat::Scientific(1).format(-0.0015), "-1.5e-3");
+        assert_eq!(TickFormat::Scientific(0).format(0.0), "0e0");
+    }
+
+    #[test]
+    fn test_integer_format() {
+        assert_eq!(TickFormat::Integer.format(100000.0), "100000");
+        assert_eq!(TickFormat::Integer.format(2.5), "3");
+        assert_eq!(TickFormat::Integer.format(-0.4), "0");
+    }
+
+    #[test]
+    fn test_si_prefix_format() {
+        assert_eq!(TickFormat::SiPrefix.format(0.0), "0");
+        assert_eq!(TickFormat::SiPrefix.format(42.0), "42");
+        assert_eq!(TickFormat::SiPrefix.format(100000.0), "100k");
+        assert_eq!(TickFormat::SiPrefix.format(-2_500_000.0), "-2.5M");
+        assert_eq!(TickFormat::SiPrefix.format(0.0025), "2.5m");
+        assert_eq!(TickFormat::SiPrefix.format(999.999), "1k");
+        assert_eq!(TickFormat::SiPrefix.format(5e15), "5000T");
+    }
+}
@@ -0,0 +1,169 @@
+/// Computes the axis values at which ticks and tick labels are drawn.
+///
+/// Custom tick positions take precedence over the evenly spaced default. Custom values
+/// outside `[min, max]` (or non-finite ones) are skipped, since they would be drawn
+/// outside the plot area.
+///
+/// # Parameters
+/// - `custom`: Explicit tick positions, in data units, or `None` to use the default.
+/// - `min`: The minimum value of the axis range.
+/// - `max`: The maximum value of the axis range.
+/// - `num_ticks`: The number of intervals used for the default ticks.
+///
+/// # Returns
+/// The tick values, in data units. Without custom ticks this is `num_ticks + 1` evenly
+/// spaced values from `min` to `max`.
+///
+/// # Example
+/// ```rust
+/// use dataviz::figure::utilities::ticks::tick_values;
+///
+/// assert_eq!(tick_values(None, 0.0, 10.0, 2), vec![0.0, 5.0, 10.0]);
+/// assert_eq!(tick_values(Some(&[1.0, 3.0, 42.0]), 0.0, 10.0, 2), vec![1.0, 3.0]);
+/// ```
+pub fn tick_values(custom: Option<&[f64]>, min: f64, max: f64, num_ticks: usize) -> Vec<f64> {
+    match custom {
+        Some(ticks) => ticks
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite() && *value >= min && *value <= max)
+            .collect(),
+        None => {
+            let num_ticks = num_ticks.max(1);
+            let step = (max - min) / num_ticks as f64;
+            (0..=num_ticks).map(|i| min + i as f64 * step).collect()
+        }
+    }
+}
+
+/// Computes the axis values of ticks at round numbers, unless custom ticks are given.
+///
+/// Like [`tick_values`], but the default ticks are the [`nice_ticks`] within `[min, max]`
+/// instead of `num_ticks + 1` evenly spaced values, so their labels stay short.
+///
+/// # Parameters
+/// - `custom`: Explicit tick positions, in data units, or `None` to use the default.
+/// - `min`: The minimum value of the axis range.
+/// - `max`: The maximum value of the axis range.
+/// - `num_ticks`: The targeted number of intervals of the default ticks.
+///
+/// # Returns
+/// The tick values, in data units.
+///
+/// # Example
+/// ```rust
+/// use dataviz::figure::utilities::ticks::nice_tick_values;
+///
+/// assert_eq!(nice_tick_values(None, 0.0, 9.7, 4), vec![0.0, 2.0, 4.0, 6.0, 8.0]);
+/// ```
+pub fn nice_tick_values(custom: Option<&[f64]>, min: f64, max: f64, num_ticks: usize) -> Vec<f64> {
+    if custom.is_some() {
+        return tick_values(custom, min, max, num_ticks);
+    }
+
+    // Allow for rounding errors of the ticks at the ends of the range
+    let tolerance = (max - min).abs() * 1e-9;
+    nice_ticks(min, max, num_ticks + 1)
+        .into_iter()
+        .filter(|value| *value >= min - tolerance && *value <= max + tolerance)
+        .collect()
+}
+
+/// Computes about `target_count` ticks at round numbers covering `[min, max]`.
+///
+/// The spacing of the ticks is 1, 2 or 5 times a power of ten, using the "nice numbers"
+/// algorithm by Heckbert. The first and last ticks are the multiples of the spacing at or
+/// beyond `min` and `max`, so they may lie outside the range.
+///
+/// # Parameters
+/// - `min`: The minimum value of the range.
+/// - `max`: The maximum value of the range.
+/// - `target_count`: The targeted number of ticks.
+///
+/// # Returns
+/// The tick values in ascending order, `[min]` if `min == max`, or no values if the range
+/// isn't finite.
+///
+/// # Example
+/// ```rust
+/// use dataviz::figure::utilities::ticks::nice_ticks;
+///
+/// assert_eq!(nice_ticks(0.0, 9.7, 5), vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+/// assert_eq!(nice_ticks(3.0, 3.0, 5), vec![3.0]);
+/// ```
+pub fn nice_ticks(min: f64, max: f64, target_count: usize) -> Vec<f64> {
+    if !min.is_finite() || !max.is_finite() {
+        return Vec::new();
+    }
+    if min == max {
+        return vec![min];
+    }
+    let (min, max) = (min.min(max), min.max(max));
+
+    let range = nice_number(max - min, false);
+    let step = nice_number(range / target_count.saturating_sub(1).max(1) as f64, true);
+    // Round the ticks to the decimals of the step, so they print without rounding errors
+    let decimals = (-step.log10().floor()).max(0.0) as i32;
+    let factor = 10f64.powi(decimals);
+
+    let first = (min / step).floor() as i64;
+    let last = (max / step).ceil() as i64;
+    (first..=last)
+        .map(|i| (i as f64 * step * factor).round() / factor)
+        .collect()
+}
+
+/// Rounds `value` to 1, 2, 5 or 10 times a power of ten, to the nearest of them if `round`
+/// is set, or to the smallest one not below `value` otherwise.
+fn nice_number(value: f64, round: bool) -> f64 {
+    let exponent = value.log10().floor();
+    let fraction = value / 10f64.powf(exponent);
+    let nice_fraction = if round {
+        match fraction {
+            f if f < 1.5 => 1.0,
+            f if f < 3.0 => 2.0,
+            f if f < 7.0 => 5.0,
+            _ => 10.0,
+        }
+    } else {
+        match fraction {
+            f if f <= 1.0 => 1.0,
+            f if f <= 2.0 => 2.0,
+            f if f <= 5.0 => 5.0,
+            _ => 10.0,
+        }
+    };
+    nice_fraction * 10f64.powf(exponent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_ticks_are_evenly_spaced() {
+        assert_eq!(
+            tick_values(None, -10.0, 10.0, 4),
+            vec![-10.0, -5.0, 0.0, 5.0, 10.0]
+        );
+    }
+
+    #[test]
+    fn test_custom_ticks_outside_range_are_skipped() {
+        let custom = [-1.0, 0.0, 2.5, f64::NAN, 10.0, 11.0];
+        assert_eq!(
+            tick_values(Some(&custom), 0.0, 10.0, 10),
+            vec![0.0, 2.5, 10.0]
+        );
+    }
+
+    #[test]
+    fn test_nice_ticks_use_round_steps() {
+        assert_eq!(nice_ticks(0.0, 9.7, 5), vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(nice_ticks(-0.23, 0.41, 4), vec![-0.5, 0.0, 0.5]);
+        // Ticks are rounded to the step, not 0.30000000000000004
+        assert_eq!(nice_ticks(0.1, 0.35, 6), vec![0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(nice_ticks(5.0, 5.0, 10), vec![5.0]);
+        assert!(nice_ticks(0.0, f64::NAN, 5).is_empty());
+    }
+}
@@ -0,0 +1,94 @@
+use super::clipping::ClipRect;
+
+/// A straight line `y = slope * x + intercept` fitted to the points of a dataset.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrendLine {
+    /// The slope of the line.
+    pub slope: f64,
+    /// The y-value of the line at `x = 0`.
+    pub intercept: f64,
+    /// The RGB color of the line.
+    pub color: [u8; 3],
+}
+
+impl TrendLine {
+    /// Fits a trend line to the given points by ordinary least squares.
+    ///
+    /// # Parameters
+    /// - `points`: The `(x, y)` points to fit.
+    /// - `color`: The RGB color of the line.
+    ///
+    /// # Returns
+    /// The fitted `TrendLine`, or `None` if there are fewer than two points or all points share
+    /// the same x-value, since no single line fits them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use dataviz::figure::utilities::trendline::TrendLine;
+    ///
+    /// let line = TrendLine::fit(&[(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)], [255, 0, 0]).unwrap();
+    /// assert_eq!((line.slope, line.intercept), (2.0, 1.0));
+    /// assert_eq!(TrendLine::fit(&[(1.0, 1.0), (1.0, 2.0)], [255, 0, 0]), None);
+    /// ```
+    pub fn fit(points: &[(f64, f64)], color: [u8; 3]) -> Option<Self> {
+        if points.len() < 2 {
+            return None;
+        }
+
+        let n = points.len() as f64;
+        let mean_x = points.iter().map(|&(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|&(_, y)| y).sum::<f64>() / n;
+        let (covariance, variance) = points.iter().fold((0.0, 0.0), |(cov, var), &(x, y)| {
+            let dx = x - mean_x;
+            (cov + dx * (y - mean_y), var + dx * dx)
+        });
+        if variance == 0.0 || !variance.is_finite() {
+            return None;
+        }
+
+        let slope = covariance / variance;
+        Some(Self {
+            slope,
+            intercept: mean_y - slope * mean_x,
+            color,
+        })
+    }
+
+    /// Returns the value of the line at `x`.
+    pub fn value_at(&self, x: f64) -> f64 {
+        self.slope * x + self.intercept
+    }
+
+    /// Returns the part of the line that is visible within the plot limits.
+    ///
+    /// # Parameters
+    /// - `limits`: The plot limits `(x_min, x_max, y_min, y_max)` in data units.
+    ///
+    /// # Returns
+    /// The visible segment as `(x1, y1, x2, y2)` in data units, or `None` if the line doesn't
+    /// cross the plot area.
+    pub fn segment(&self, limits: (f64, f64, f64, f64)) -> Option<(f64, f64, f64, f64)> {
+        let (x_min, x_max, y_min, y_max) = limits;
+        ClipRect::new(x_min, y_min, x_max, y_max).clip_line(
+            x_min,
+            self.value_at(x_min),
+            x_max,
+            self.value_at(x_max),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_is_clipped_to_the_plot_limits() {
+        let line = TrendLine::fit(&[(0.0, 0.0), (10.0, 20.0)], [0, 0, 0]).unwrap();
+        assert_eq!(
+            line.segment((0.0, 10.0, 0.0, 10.0)),
+            Some((0.0, 0.0, 5.0, 10.0))
+        );
+        assert_eq!(line.segment((0.0, 10.0, 30.0, 40.0)), None);
+    }
+}
@@ -20,6 +20,7 @@
 //!   - [`areachart`](crate::figure::figuretypes::areachart): Create area charts for visualizing data trends.
 //!   - [`cartesiangraph`](crate::figure::figuretypes::cartesiangraph): Cartesian graphs for mathematical and data plotting.
 //!   - [`groupbarchart`](crate::figure::figuretypes::groupbarchart): Grouped bar charts for comparative data visualization.
+//!   - [`heatmap`](crate::figure::figuretypes::heatmap): Heatmaps coloring a grid of values along a gradient.
 //!   - [`histogram`](crate::figure::figuretypes::histogram): Histograms for frequency distribution analysis.
 //!   - [`piechart`](crate::figure::figuretypes::piechart): Pie charts for proportional data representation.
 //!   - [`quadrant1graph`](crate::figure::figuretypes::quadrant1graph): Graphs restricted to the first quadrant.
@@ -31,6 +32,7 @@
 //! - [`areachartdataset`](crate::figure::datasets::areachartdataset)
 //! - [`bardataset`](crate::figure::datasets::bardataset)
 //! - [`cartesiangraphdataset`](crate::figure::datasets::cartesiangraphdataset)
+//! - [`csv`](crate::figure::datasets::csv): Import of dataset points from CSV files.
 //! - [`scattergraphdataset`](crate::figure::datasets::scattergraphdataset)
 //! - [`linegraphdataset`](crate::figure::datasets::linegraphdataset)
 //! - [`dataset`](crate::figure::datasets::dataset): Common dataset traits for unifying data operations.
@@ -46,10 +48,26 @@
 //!
 //! ## Utilities
 //! Utility modules for managing chart attributes and behaviors. Includes:
+//! - [`annotation`](crate::figure::utilities::annotation): Reference lines (e.g. mean lines) drawn on top of chart data.
+//! - [`axisscale`](crate::figure::utilities::axisscale): Linear or logarithmic scaling of axis values.
 //! - [`axistype`](crate::figure::utilities::axistype): Enum for axis types (X or Y).
+//! - [`barlabeloverflow`](crate::figure::utilities::barlabeloverflow): Handling of bar value labels wider than their bar (overlap, rotate or hide).
+//! - [`barmode`](crate::figure::utilities::barmode): Arrangement of the bars of several datasets (grouped or stacked).
+//! - [`clipping`](crate::figure::utilities::clipping): Cohen–Sutherland clipping of line segments to the plot area.
+//! - [`histogrammode`](crate::figure::utilities::histogrammode): Quantity shown by histogram bars (counts, densities or cumulative counts).
+//! - [`hovermode`](crate::figure::utilities::hovermode): Hover display of interactive windows (nearest point or crosshair).
+//! - [`interpolation`](crate::figure::utilities::interpolation): Straight, smooth (Catmull–Rom) or stepped connection of line points.
+//! - [`legendposition`](crate::figure::utilities::legendposition): Placement of the legend in one of the figure's corners, or no legend at all.
 //! - [`linetype`](crate::figure::utilities::linetype): Styles for chart lines (solid, dashed, dotted).
+//! - [`numberformat`](crate::figure::utilities::numberformat): Number formatting for axis values and tooltips.
+//! - [`numberlocale`](crate::figure::utilities::numberlocale): Locale-aware number parsing for imported data.
 //! - [`orientation`](crate::figure::utilities::orientation): Orientation handling (horizontal or vertical).
 //! - [`scatterdottype`](crate::figure::utilities::scatterdottype): Dot styles for scatter plots (circle, square, triangle, etc.).
+//! - [`simplify`](crate::figure::utilities::simplify): Ramer–Douglas–Peucker simplification of line data.
+//! - [`textrendering`](crate::figure::utilities::textrendering): Text drawing modes, including font-independent placeholder boxes for golden tests.
+//! - [`tickformat`](crate::figure::utilities::tickformat): Number formats for axis tick labels (fixed, scientific, integer, SI prefix).
+//! - [`ticks`](crate::figure::utilities::ticks): Axis tick positions, either evenly spaced or user-defined.
+//! - [`trendline`](crate::figure::utilities::trendline): Least-squares trend lines fitted to scatter data.
 //!
 //! ## Configuration
 //! Centralized configuration for charts, providing a single source for appearance settings. Includes:
@@ -59,6 +77,7 @@
 //! Abstractions for rendering surfaces, including:
 //! - [`pixelcanvas`](crate::figure::canvas::pixelcanvas): Raster-based rendering for charts.
 //! - [`svgcanvas`](crate::figure::canvas::svgcanvas): Scalable vector graphics rendering for high-quality outputs.
+//! - [`margins`](crate::figure::canvas::margins): Per-side margins around the plot area of a canvas.
 //!
 //! ## Display
 //! Modules for interactivity and display management. Includes:
@@ -67,12 +86,17 @@
 //!   - [`hoverpiechart`](crate::figure::display::hoverpiechart), and more.
 //! - [`winop`](crate::figure::display::winop): Manage window operations for interactive displays.
 //!
+//! ## Layout
+//! - [`layout`](crate::figure::layout): Grids of several charts rendered into one image.
+//!
 //! ## Getting Started
 //! Here's a quick example of creating and rendering a pie chart:
 //!
 //! ```rust
 //! use dataviz::figure::figuretypes::piechart::PieChart;
 //! use dataviz::figure::configuration::figureconfig::FigureConfig;
+//! use dataviz::figure::canvas::svgcanvas::SvgCanvas;
+//! use dataviz::figure::drawers::drawer::Drawer;
 //!
 //! let mut pie_chart = PieChart::new("Market Share", FigureConfig::default());
 //! pie_chart.add_slice("Product A", 40.0, [255, 0, 0]);
@@ -80,7 +104,23 @@
 //! pie_chart.add_slice("Product C", 30.0, [0, 0, 255]);
 //!
 //! // Render the pie chart
-//! pie_chart.draw();
+//! let mut canvas = SvgCanvas::new(800, 600, "white", 50);
+//! pie_chart.draw_svg(&mut canvas);
+//! ```
+//!
+//! [`Figure`](crate::figure::figurefacade::Figure) bundles these steps, creating the canvas and
+//! saving the chart in one call:
+//!
+//! ```rust
+//! # use dataviz::figure::figuretypes::piechart::PieChart;
+//! # use dataviz::figure::configuration::figureconfig::FigureConfig;
+//! use dataviz::figure::figurefacade::Figure;
+//!
+//! # let mut pie_chart = PieChart::new("Market Share", FigureConfig::default());
+//! # pie_chart.add_slice("Product A", 40.0, [255, 0, 0]);
+//! let mut figure = Figure::new(pie_chart);
+//! let path = std::env::temp_dir().join("market_share.png");
+//! figure.save_png(path.to_str().unwrap(), 800, 600).unwrap();
 //! ```
 //!
 //! ## License
@@ -91,6 +131,7 @@ pub mod figure {
         pub mod areachart;
         pub mod cartesiangraph;
         pub mod groupbarchart;
+        pub mod heatmap;
         pub mod histogram;
         pub mod piechart;
         pub mod quadrant1graph;
@@ -102,6 +143,7 @@ pub mod figure {
         pub mod areachartdataset;
         pub mod bardataset;
         pub mod cartesiangraphdataset;
+        pub mod csv;
         pub mod dataset;
         pub mod scattergraphdataset;
         pub mod linegraphdataset;
@@ -112,6 +154,7 @@ pub mod figure {
         pub mod drawerareachart;
         pub mod drawerbarchart;
         pub mod drawercartesiangraph;
+        pub mod drawerheatmap;
         pub mod drawerhistogram;
         pub mod drawerpiechart;
         pub mod drawerquadrant1graph;
@@ -120,10 +163,26 @@ pub mod figure {
     }
 
     pub mod utilities {
+        pub mod annotation;
+        pub mod axisscale;
         pub mod axistype;
+        pub mod barlabeloverflow;
+        pub mod barmode;
+        pub mod clipping;
+        pub mod histogrammode;
+        pub mod hovermode;
+        pub mod interpolation;
+        pub mod legendposition;
         pub mod linetype;
+        pub mod numberformat;
+        pub mod numberlocale;
         pub mod orientation;
         pub mod scatterdottype;
+        pub mod simplify;
+        pub mod textrendering;
+        pub mod tickformat;
+        pub mod ticks;
+        pub mod trendline;
     }
 
     pub mod configuration {
@@ -131,8 +190,11 @@ pub mod figure {
     }
 
     pub mod canvas {
+        pub mod margins;
         pub mod pixelcanvas;
         pub mod svgcanvas;
+        #[cfg(test)]
+        pub(crate) mod testing;
     }
 
     pub mod display {
@@ -141,11 +203,14 @@ pub mod figure {
         pub mod hovercartesian;
         pub mod hovergroupbarchart;
         pub mod hoverhistogram;
+        pub mod hoverlinegraph;
         pub mod hoverpiechart;
         pub mod hoverquadrant1graph;
         pub mod hoverscatterchart;
         pub mod winop;
     }
 
+    pub mod figurefacade;
     pub mod figurefactory;
+    pub mod layout;
 }